@@ -0,0 +1,51 @@
+//! End-to-end benchmarks for ObjectId generation.
+//!
+//! Runs the compiled binary to measure large batches, comparing the current time
+//! path with the fixed timestamp one, and the cost of output assertions. Use `--save-baseline` and `--baseline` to
+//! compare the results between revisions.
+
+use std::process::{Command, Stdio};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+
+const NUMBER: &str = "1000000";
+
+fn run(args: &[&str]) {
+    let status = Command::new(env!("CARGO_BIN_EXE_spwd"))
+        .args(args)
+        .stdout(Stdio::null())
+        .status()
+        .expect("failed to run spwd");
+
+    assert!(status.success());
+}
+
+fn bench_objectid(c: &mut Criterion) {
+    let mut group = c.benchmark_group("oid");
+    group.sample_size(10);
+
+    group.bench_function("current time", |b| b.iter(|| run(&["-n", NUMBER, "oid"])));
+    group.bench_function("fixed timestamp", |b| {
+        b.iter(|| run(&["-n", NUMBER, "oid", "--timestamp", "1609459200"]))
+    });
+    group.bench_function("fixed timestamp with assertions", |b| {
+        b.iter(|| {
+            run(&[
+                "-n",
+                NUMBER,
+                "--assert-max-length",
+                "24",
+                "--assert-charset",
+                "[0-9a-f]+",
+                "oid",
+                "--timestamp",
+                "1609459200",
+            ])
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_objectid);
+criterion_main!(benches);
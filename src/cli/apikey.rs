@@ -0,0 +1,13 @@
+//! API key-specific CLI types and enumerations.
+//!
+//! This module defines types used exclusively by the API key subcommand:
+//!
+//! - [`ApiKeyChecksum`]: The checksums API keys can end with
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ApiKeyChecksum {
+    /// No checksum
+    None,
+    /// CRC32 of the random part, in 6 base62 characters
+    Crc32,
+}
@@ -0,0 +1,178 @@
+//! Composite identifier-specific CLI types.
+//!
+//! This module defines types used exclusively by the composite subcommand:
+//!
+//! - [`CompositeField`]: The kinds of fields a composite identifier can hold
+//! - [`CompositeLayout`]: The fields of a composite identifier, with their widths
+
+use std::fmt;
+
+use anyhow::anyhow;
+
+/// Largest total width of a layout (in bits).
+pub(crate) const MAX_LAYOUT_BITS: u32 = 128;
+
+/// Largest width of a single field (in bits).
+pub(crate) const MAX_FIELD_BITS: u32 = 64;
+
+/// Returns the largest value of a field of the given width (1 to 64 bits).
+pub(crate) fn max_field_value(bits: u32) -> u64 {
+    u64::MAX >> (MAX_FIELD_BITS - bits)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum CompositeField {
+    /// Milliseconds since the epoch
+    Time,
+    /// Shard id given with `--shard`
+    Shard,
+    /// Sequence number within the millisecond
+    Seq,
+}
+
+impl CompositeField {
+    const ALL: [CompositeField; 3] = [
+        CompositeField::Time,
+        CompositeField::Shard,
+        CompositeField::Seq,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            CompositeField::Time => "time",
+            CompositeField::Shard => "shard",
+            CompositeField::Seq => "seq",
+        }
+    }
+}
+
+impl fmt::Display for CompositeField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Fields of a composite identifier with their widths, from the most significant bits.
+///
+/// Every layout has a time field, no field appears twice, and the widths add up to at
+/// most [`MAX_LAYOUT_BITS`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct CompositeLayout(pub(crate) Vec<(CompositeField, u32)>);
+
+impl CompositeLayout {
+    /// Returns the total width of the fields (in bits).
+    pub(crate) fn bits(&self) -> u32 {
+        self.0.iter().map(|(_, bits)| bits).sum()
+    }
+
+    /// Returns the width of the given field, if the layout has it.
+    pub(crate) fn field_bits(&self, field: CompositeField) -> Option<u32> {
+        self.0
+            .iter()
+            .find(|(candidate, _)| *candidate == field)
+            .map(|(_, bits)| *bits)
+    }
+}
+
+/// Parses a layout like `time:41,shard:13,seq:10`.
+pub(crate) fn parse_layout(value: &str) -> anyhow::Result<CompositeLayout> {
+    let mut fields: Vec<(CompositeField, u32)> = Vec::new();
+
+    for part in value.split(',') {
+        let Some((name, bits)) = part.split_once(':') else {
+            return Err(anyhow!("field {part:?} is not written as name:bits"));
+        };
+
+        let field = CompositeField::ALL
+            .into_iter()
+            .find(|field| field.name() == name)
+            .ok_or_else(|| anyhow!("unknown field {name:?} (expected time, shard, or seq)"))?;
+
+        let bits = bits
+            .parse::<u32>()
+            .ok()
+            .filter(|bits| (1..=MAX_FIELD_BITS).contains(bits))
+            .ok_or_else(|| {
+                anyhow!("{field} field must be 1 to {MAX_FIELD_BITS} bits wide, not {bits:?}")
+            })?;
+
+        if fields.iter().any(|(candidate, _)| *candidate == field) {
+            return Err(anyhow!("{field} field appears more than once"));
+        }
+
+        fields.push((field, bits));
+    }
+
+    let layout = CompositeLayout(fields);
+
+    if layout.field_bits(CompositeField::Time).is_none() {
+        return Err(anyhow!("layout has no time field"));
+    }
+
+    if layout.bits() > MAX_LAYOUT_BITS {
+        return Err(anyhow!(
+            "layout is {} bits wide, more than {MAX_LAYOUT_BITS}",
+            layout.bits()
+        ));
+    }
+
+    Ok(layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_layout() {
+        let layout = parse_layout("time:41,shard:13,seq:10").unwrap();
+
+        assert_eq!(
+            layout.0,
+            [
+                (CompositeField::Time, 41),
+                (CompositeField::Shard, 13),
+                (CompositeField::Seq, 10)
+            ]
+        );
+        assert_eq!(layout.bits(), 64);
+        assert_eq!(layout.field_bits(CompositeField::Shard), Some(13));
+    }
+
+    #[test]
+    fn test_max_field_value() {
+        assert_eq!(max_field_value(1), 1);
+        assert_eq!(max_field_value(13), 8191);
+        assert_eq!(max_field_value(64), u64::MAX);
+    }
+
+    #[test]
+    fn test_parse_layout_errors() {
+        for (value, message) in [
+            ("time41", "field \"time41\" is not written as name:bits"),
+            ("time:41,", "field \"\" is not written as name:bits"),
+            (
+                "time:41,node:10",
+                "unknown field \"node\" (expected time, shard, or seq)",
+            ),
+            ("time:0", "time field must be 1 to 64 bits wide, not \"0\""),
+            (
+                "time:65",
+                "time field must be 1 to 64 bits wide, not \"65\"",
+            ),
+            ("time:x", "time field must be 1 to 64 bits wide, not \"x\""),
+            ("time:41,seq:2,seq:3", "seq field appears more than once"),
+            ("shard:13,seq:10", "layout has no time field"),
+            (
+                "time:64,shard:64,seq:1",
+                "layout is 129 bits wide, more than 128",
+            ),
+        ] {
+            assert_eq!(
+                parse_layout(value).unwrap_err().to_string(),
+                message,
+                "{value}"
+            );
+        }
+    }
+}
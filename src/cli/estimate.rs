@@ -0,0 +1,53 @@
+//! Estimate-specific CLI types and enumerations.
+//!
+//! This module defines types used exclusively by the estimate subcommand:
+//!
+//! - [`EstimateArgs`]: The estimate subcommand arguments
+//! - [`EstimatedType`]: The identifier types collision estimates are available for
+
+use std::fmt;
+
+use crate::utils;
+
+#[derive(clap::Args)]
+pub(crate) struct EstimateArgs {
+    /// Identifier type
+    #[arg(long = "type", value_name = "TYPE", value_enum)]
+    pub(crate) kind: EstimatedType,
+
+    /// Generation rate (k/m/g suffixes; per `s`, `ms`, `min`, or `h`, e.g. `50k/s`)
+    #[arg(long, value_parser = utils::parse_rate)]
+    pub(crate) rate: f64,
+
+    /// Print the estimate as JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum EstimatedType {
+    /// ULID (80 random bits per millisecond)
+    Ulid,
+    /// UUID version 4 (122 random bits)
+    #[value(name = "uuid-v4")]
+    UuidV4,
+    /// UUID version 7 (74 random bits per millisecond)
+    #[value(name = "uuid-v7")]
+    UuidV7,
+    /// ObjectId (24-bit counter per second)
+    #[value(name = "oid", alias = "objectid")]
+    ObjectId,
+}
+
+impl fmt::Display for EstimatedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EstimatedType::Ulid => "ulid",
+            EstimatedType::UuidV4 => "uuid-v4",
+            EstimatedType::UuidV7 => "uuid-v7",
+            EstimatedType::ObjectId => "oid",
+        };
+
+        write!(f, "{name}")
+    }
+}
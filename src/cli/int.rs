@@ -0,0 +1,59 @@
+//! Integer ID-specific CLI types and enumerations.
+//!
+//! This module defines types used exclusively by the int subcommand:
+//!
+//! - [`IntBits`]: The widths of random integers, and the ranges they span
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum IntBits {
+    /// 32 bits, for INTEGER columns
+    #[value(name = "32")]
+    B32,
+    /// 53 bits, for JavaScript numbers (which hold integers exactly up to 2^53)
+    #[value(name = "53")]
+    B53,
+    /// 63 bits, for non-negative BIGINT keys
+    #[value(name = "63")]
+    B63,
+    /// 64 bits
+    #[value(name = "64")]
+    B64,
+}
+
+impl IntBits {
+    /// Returns the number of bits.
+    pub(crate) fn bits(self) -> u32 {
+        match self {
+            IntBits::B32 => 32,
+            IntBits::B53 => 53,
+            IntBits::B63 => 63,
+            IntBits::B64 => 64,
+        }
+    }
+
+    /// Returns the lowest and highest integers of the width, as two's complement ones
+    /// when signed.
+    pub(crate) fn range(self, signed: bool) -> (i128, i128) {
+        let bits = self.bits();
+
+        match signed {
+            true => (-(1 << (bits - 1)), (1 << (bits - 1)) - 1),
+            false => (0, (1 << bits) - 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        assert_eq!(IntBits::B32.range(false), (0, u32::MAX.into()));
+        assert_eq!(IntBits::B32.range(true), (i32::MIN.into(), i32::MAX.into()));
+        assert_eq!(IntBits::B53.range(false), (0, (1 << 53) - 1));
+        assert_eq!(IntBits::B63.range(false), (0, i64::MAX.into()));
+        assert_eq!(IntBits::B64.range(false), (0, u64::MAX.into()));
+        assert_eq!(IntBits::B64.range(true), (i64::MIN.into(), i64::MAX.into()));
+    }
+}
@@ -0,0 +1,90 @@
+//! MAC address-specific CLI types.
+//!
+//! This module defines types used exclusively by the mac subcommand:
+//!
+//! - [`MacFormat`]: The notations MAC addresses can be printed in
+//! - [`parse_oui`]: Parses the OUI pinning the first three bytes
+
+use anyhow::anyhow;
+
+/// Length of an OUI (in bytes).
+const OUI_BYTES: usize = 3;
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum MacFormat {
+    /// Colon-separated bytes, like 02:1a:2b:3c:4d:5e
+    Colon,
+    /// Hyphen-separated bytes, like 02-1a-2b-3c-4d-5e
+    Hyphen,
+    /// Dot-separated groups of two bytes (Cisco), like 021a.2b3c.4d5e
+    Dot,
+    /// Bare hex digits, like 021a2b3c4d5e
+    Bare,
+}
+
+/// Parses an OUI like `00:16:3e`, `00-16-3e`, or `00163e`.
+pub(crate) fn parse_oui(value: &str) -> anyhow::Result<[u8; OUI_BYTES]> {
+    // Separated bytes are joined into bare digits, and anything else is left to fail
+    let digits = match value.chars().nth(2) {
+        Some(separator @ (':' | '-')) => {
+            let parts: Vec<&str> = value.split(separator).collect();
+
+            match parts.len() == OUI_BYTES && parts.iter().all(|part| part.len() == 2) {
+                true => parts.concat(),
+                false => String::new(),
+            }
+        }
+        _ => value.to_owned(),
+    };
+
+    let mut oui = [0; OUI_BYTES];
+    hex::decode_to_slice(&digits, &mut oui).map_err(|_| {
+        anyhow!("OUI {value:?} is not three bytes like 00:16:3e, 00-16-3e, or 00163e")
+    })?;
+
+    Ok(oui)
+}
+
+/// Formats the bytes of an OUI in the colon notation.
+pub(crate) fn format_oui(oui: &[u8; OUI_BYTES]) -> String {
+    oui.iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_oui() {
+        for value in ["00:16:3e", "00-16-3E", "00163e"] {
+            assert_eq!(parse_oui(value).unwrap(), [0x00, 0x16, 0x3e], "{value}");
+        }
+    }
+
+    #[test]
+    fn test_parse_oui_invalid() {
+        for value in [
+            "",
+            "00:16",
+            "00:16:3e:01",
+            "00.16.3e",
+            "00:16-3e",
+            "0:016:3e",
+            "00163g",
+        ] {
+            assert_eq!(
+                parse_oui(value).unwrap_err().to_string(),
+                format!("OUI {value:?} is not three bytes like 00:16:3e, 00-16-3e, or 00163e"),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_oui() {
+        assert_eq!(format_oui(&[0x00, 0x16, 0x3e]), "00:16:3e");
+    }
+}
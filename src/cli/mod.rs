@@ -8,25 +8,91 @@
 //! # Structure
 //!
 //! - [`Args`]: Top-level argument structure with global options (like `--num`)
-//! - [`Commands`]: Subcommands for each identifier type (UUID, ULID, ObjectId)
-//! - `uuid` submodule: UUID-specific types (versions, namespaces)
+//! - [`Commands`]: Subcommands for each identifier type (UUID, ULID, ObjectId, KSUID,
+//!   snowflake, composite, TSID, SCRU128, TypeID, Sqids, Hashids, NanoID, proquint, slug,
+//!   API key, etc.)
+//! - `uuid` submodule: UUID-specific types (arguments, versions, namespaces)
+//! - `estimate` submodule: Collision estimate arguments and identifier types
+//! - `shortcode` submodule: Short code arguments and alphabets
+//! - `composite` submodule: Composite identifier layouts
+//! - `int` submodule: Integer ID widths
+//! - `mac` submodule: MAC address notations and OUIs
+//! - `stamp` submodule: Timestamp slug precisions
+//! - `timestamp` submodule: `--timestamp` parsing shared by the time-based commands
+//! - `token` submodule: Token encodings
+//! - `tsid` submodule: TSID forms
+//! - `apikey` submodule: API key checksums
+//! - `preset` submodule: Presets bundling a subcommand and options for common ecosystems
+//! - `post` submodule: HTTP sink options (with the `http` feature)
 //!
 //! # Custom Validation
 //!
 //! Some validation rules are too complex for `clap`'s built-in validators:
+//! - Nil and max UUID compatibility with other UUID options (none apply to constants)
+//! - Namespace and name of UUID versions 3 and 5, and data or hash of version 8
 //! - Timestamp argument compatibility with UUID versions (only v1, v6, v7 support it)
+//! - Node id compatibility with UUID versions (only v1, v6 embed one)
+//! - Namespace, name, and hash compatibility with UUID versions (v3, v5, and v8 with a hash)
+//! - Empty name skipping compatibility with name input (only `--names-stdin` reads lines)
+//! - Timestamp range of Gregorian UUID versions (v1, v6 end in 5236)
+//! - Clock sequence compatibility with UUID versions (v1, v6) and range (14 bits)
+//! - Clock sequence state file compatibility with UUID versions (v1, v6)
+//! - Data read from stdin compatibility with UUID versions (only v8 takes data)
+//! - Special value exclusion compatibility with UUID versions (only v4 supports it)
+//! - Vanity prefix compatibility with UUID versions (only v4 supports it)
+//! - Per-millisecond quota compatibility with UUID versions (only v7 supports it)
+//! - Sub-millisecond precision compatibility with UUID versions (only v7 supports it)
+//! - Per-UUID node id compatibility with UUID versions (v1, v6) and fixed node ids
+//! - Uppercase UUID compatibility with forms (hex digits or base32 symbols only)
+//! - Short code alphabet left by banned substrings (at least two symbols)
+//! - Snowflake timestamp range relative to the epoch (41 bits from it)
+//! - Composite shard and timestamp ranges (fitting their fields of the layout)
+//! - TSID timestamp range relative to the epoch (42 bits from it)
+//! - Integer ID bounds (within the width, in order, and leaving two values to draw from)
+//! - MAC address OUI bits compatibility with the multicast and universal flags
+//! - Single identifier output with other numbers of results, or without identifiers
+//! - Number of results read from stdin with names or data read from stdin (one input at a
+//!   time)
+//! - Time mode compatibility with commands (only time-based ones read the clock)
+//! - Command availability in minimal builds (generators compiled out by features)
 //!
 //! These are checked in [`Args::parse()`] after `clap` performs basic validation.
 
+pub mod apikey;
+pub mod composite;
+mod debug;
+pub mod estimate;
+pub mod int;
+pub mod mac;
+#[cfg(feature = "http")]
+pub mod post;
+pub mod preset;
+pub mod shortcode;
+pub mod stamp;
+pub mod timestamp;
+pub mod token;
+pub mod tsid;
 pub mod uuid;
 mod validation;
 
+use std::io::Read;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
 use clap::error::{ContextKind, ContextValue, ErrorKind};
 use clap::{
-    CommandFactory, Parser, Subcommand, crate_description, crate_name, crate_version, value_parser,
+    CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum, crate_description, crate_name,
+    crate_version, value_parser,
 };
 
-use crate::utils;
+use crate::generators::{self, ksuid, pushid, scru128, snowflake, sqids, typeid};
+use crate::{literal, utils, words, z85};
+use timestamp::{Milliseconds, Seconds, TimestampArg};
+
+/// The standard URL-safe NanoID alphabet (64 symbols).
+const NANOID_ALPHABET: &str = "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
 #[derive(Parser)]
 #[command(
@@ -39,98 +105,1225 @@ pub(crate) struct Args {
     #[command(subcommand)]
     pub(crate) command: Commands,
 
-    /// Number of results
-    #[arg(short = 'n', long = "num", default_value = "1")]
+    /// Number of results (k/m/g suffixes; `-` for stdin, `@FILE` for a file)
+    #[arg(
+        short = 'n',
+        long = "num",
+        value_name = "NUMBER",
+        default_value = "1",
+        value_parser = parse_number_source
+    )]
+    number_source: NumberSource,
+
+    /// Number of results, resolved from the number source
+    #[arg(skip)]
     pub(crate) number: usize,
+
+    /// Generate past the safety limit of 10 million identifiers or 1 GB of output
+    #[arg(
+        long,
+        env = "SPWD_NO_LIMIT",
+        hide_env = true,
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
+    pub(crate) yes_really: bool,
+
+    /// Print exactly one identifier, without a trailing newline (for `$(...)`)
+    #[arg(long)]
+    pub(crate) single: bool,
+
+    /// Fail if an identifier is longer than this many characters
+    #[arg(long, value_name = "LENGTH")]
+    pub(crate) assert_max_length: Option<usize>,
+
+    /// Fail if an identifier does not fully match this regex
+    #[arg(long, value_name = "REGEX", value_parser = utils::parse_charset)]
+    pub(crate) assert_charset: Option<regex::Regex>,
+
+    /// Regenerate identifiers for which this `sh` command (`{}` for the id) exits 0
+    #[arg(long, value_name = "COMMAND", value_parser = utils::parse_probe_command)]
+    pub(crate) probe_cmd: Option<String>,
+
+    /// Number of probe commands running at once
+    #[arg(
+        long,
+        value_name = "NUMBER",
+        default_value = "1",
+        requires = "probe_cmd"
+    )]
+    pub(crate) probe_parallel: NonZeroUsize,
+
+    /// When time-based identifiers read the clock (`per-id` by default, `fixed` with
+    /// `--timestamp`)
+    #[arg(long, value_enum, value_name = "MODE")]
+    pub(crate) time_mode: Option<TimeMode>,
+
+    /// Also print the identifier in this format, as an extra tab-separated column
+    /// (repeatable; uuid, ulid, and oid only)
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub(crate) also_format: Vec<AlsoFormat>,
+
+    /// Append each identifier and its `--also-format` columns to this file
+    #[arg(long, value_name = "FILE", requires = "also_format")]
+    pub(crate) map_file: Option<PathBuf>,
+
+    /// Append to a map file even if its existing lines do not parse
+    #[arg(long, requires = "map_file")]
+    pub(crate) map_lenient: bool,
+
+    /// Generate the whole batch first, then print it in a random order
+    #[arg(long)]
+    pub(crate) shuffle: bool,
+
+    /// Seed of the `--shuffle` order, for a reproducible permutation
+    #[arg(long, requires = "shuffle")]
+    pub(crate) seed: Option<u64>,
+
+    /// Preset bundling a subcommand and its options for a common ecosystem
+    #[arg(long, value_enum)]
+    pub(crate) preset: Option<preset::Preset>,
+
+    /// Pin the clock of every generator to this Unix timestamp (in nanoseconds)
+    #[cfg(feature = "test-hooks")]
+    #[arg(
+        long,
+        hide = true,
+        env = "SPWD_FAKE_NOW",
+        value_name = "TIMESTAMP",
+        value_parser = utils::parse_timestamp_ns
+    )]
+    pub(crate) fake_now: Option<(u64, u32)>,
+
+    /// Print the resolved options as sorted `key=value` lines and exit
+    #[arg(long, hide = true)]
+    debug_args: bool,
+
+    #[cfg(feature = "http")]
+    #[command(flatten)]
+    pub(crate) post: post::PostArgs,
 }
 
-#[derive(Subcommand)]
-pub(crate) enum Commands {
-    /// Generate a new UUID
-    #[command(long_about = "Generates a new Universally Unique Identifier.")]
-    Uuid {
-        /// UUID version
-        #[arg(short, long, value_enum, default_value = "4")]
-        version: uuid::SupportedUUIDVersion,
+/// When time-based generators read the clock during a run.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TimeMode {
+    /// Read the clock for every identifier
+    PerId,
+    /// Read the clock once, before generating the first identifier
+    Fixed,
+}
 
-        /// UUID timestamp (in nanoseconds; versions 1, 6, and 7 only)
-        #[arg(long, value_parser = utils::parse_timestamp_ns)]
-        timestamp: Option<(u64, u32)>,
+/// Extra formats identifiers can be rendered in, from the same bytes.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum AlsoFormat {
+    /// Bitcoin base58
+    Base58,
+    /// Lowercase hexadecimal
+    Hex,
+}
 
-        /// UUID namespace (versions 3 and 5 only)
-        #[arg(long, required_if_eq_any = [("version", "3"), ("version", "5")])]
-        namespace: Option<uuid::SupportedUUIDNamespace>,
+/// Forms UUIDs and ULIDs can be printed in.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum IdFormat {
+    /// The canonical text form
+    #[default]
+    Canonical,
+    /// 12 words of the BIP39 English word list
+    Words,
+    /// The 128-bit value as a decimal integer (up to 39 digits)
+    Int,
+    /// RFC 4648 base32 of the 16 bytes, lowercase and unpadded (26 characters)
+    Base32,
+    /// Standard base64 of the 16 bytes, padded with `=` (24 characters)
+    Base64,
+    /// URL-safe base64 of the 16 bytes, unpadded (22 characters)
+    Base64url,
+    /// ZeroMQ Z85 of the 16 bytes (20 characters)
+    Z85,
+    /// A Rust `[u8; 16]` array of the 16 bytes
+    Rust,
+    /// A C `unsigned char[16]` initializer of the 16 bytes
+    C,
+    /// A Python `bytes.fromhex()` call of the 16 bytes
+    Python,
+}
 
-        /// UUID name (versions 3 and 5 only)
-        #[arg(long, required_if_eq_any = [("version", "3"), ("version", "5")])]
-        name: Option<String>,
+impl IdFormat {
+    /// Encodes the 16 bytes of an identifier in this form, or returns `None` for the
+    /// canonical form, which depends on the kind of identifier.
+    #[cfg_attr(not(any(feature = "uuid", feature = "ulid")), allow(dead_code))]
+    pub(crate) fn encode(self, bytes: &[u8; 16]) -> Option<String> {
+        match self {
+            IdFormat::Canonical => None,
+            IdFormat::Words => Some(words::encode(bytes)),
+            IdFormat::Int => Some(u128::from_be_bytes(*bytes).to_string()),
+            IdFormat::Base32 => Some(
+                generators::token::encode_base32(bytes)
+                    .trim_end_matches('=')
+                    .to_ascii_lowercase(),
+            ),
+            IdFormat::Base64 => Some(STANDARD.encode(bytes)),
+            IdFormat::Base64url => Some(URL_SAFE_NO_PAD.encode(bytes)),
+            IdFormat::Z85 => Some(z85::encode(bytes)),
+            IdFormat::Rust => Some(literal::rust(bytes)),
+            IdFormat::C => Some(literal::c(bytes)),
+            IdFormat::Python => Some(literal::python(bytes)),
+        }
+    }
+}
 
-        /// UUID node identifier (a MAC address; versions 1 and 6 only)
-        #[arg(long)]
-        node_id: Option<eui48::MacAddress>,
+/// Where the number of results comes from.
+#[derive(Clone, Debug, PartialEq)]
+enum NumberSource {
+    Value(usize),
+    Stdin,
+    File(PathBuf),
+}
 
-        /// UUID user data (hex-encoded; version 8 only)
-        #[arg(long, value_parser = utils::parse_data, required_if_eq("version", "8"))]
-        data: Option<[u8; 16]>,
-    },
+impl NumberSource {
+    /// Resolves the number, reading it from stdin or a file when needed.
+    fn resolve(&self) -> Result<usize, String> {
+        let (source, contents) = match self {
+            NumberSource::Value(number) => return Ok(*number),
+            NumberSource::Stdin => {
+                let mut contents = String::new();
+                let result = std::io::stdin().read_to_string(&mut contents);
+                ("stdin".to_owned(), result.map(|_| contents))
+            }
+            NumberSource::File(path) => (
+                format!("file {}", path.display()),
+                std::fs::read_to_string(path),
+            ),
+        };
+
+        let contents = contents.map_err(|e| format!("failed to read number from {source}: {e}"))?;
+
+        utils::parse_count(contents.trim())
+            .map_err(|e| format!("invalid number from {source}: {e}"))
+    }
+}
+
+/// Parses the number argument into a literal value, stdin (`-`), or a file (`@FILE`).
+fn parse_number_source(value: &str) -> anyhow::Result<NumberSource> {
+    match value {
+        "-" => Ok(NumberSource::Stdin),
+        _ => match value.strip_prefix('@') {
+            Some("") => Err(anyhow::anyhow!("file path must not be empty")),
+            Some(path) => Ok(NumberSource::File(PathBuf::from(path))),
+            None => utils::parse_count(value).map(NumberSource::Value),
+        },
+    }
+}
+
+#[derive(Subcommand)]
+pub(crate) enum Commands {
+    /// Generate a new UUID
+    #[command(
+        long_about = "Generates a new Universally Unique Identifier.",
+        hide = cfg!(not(feature = "uuid"))
+    )]
+    Uuid(uuid::UuidArgs),
 
     /// Generate a new ULID
     #[command(
-        long_about = "Generates a new Universally Unique Lexicographically Sortable Identifier."
+        long_about = "Generates a new Universally Unique Lexicographically Sortable Identifier.",
+        hide = cfg!(not(feature = "ulid"))
     )]
     Ulid {
-        /// ULID timestamp (in milliseconds)
-        #[arg(long, value_parser = value_parser!(u64))]
+        #[arg(
+            long,
+            help = TimestampArg::<Milliseconds>::help("ULID timestamp"),
+            value_parser = TimestampArg::<Milliseconds>::new()
+        )]
         timestamp: Option<u64>,
+
+        /// Append a Crockford check symbol (27 characters in total)
+        #[arg(long)]
+        check: bool,
+
+        /// Print ULIDs in this form
+        #[arg(
+            long,
+            value_enum,
+            default_value = "canonical",
+            conflicts_with = "check"
+        )]
+        format: IdFormat,
     },
 
     /// Generate a new ObjectId
     #[command(
         name = "oid",
         alias = "objectid",
-        long_about = "Generates a new MongoDB/BSON ObjectId."
+        long_about = "Generates a new MongoDB/BSON ObjectId.",
+        hide = cfg!(not(feature = "objectid"))
     )]
     ObjectId {
-        /// ObjectId timestamp (in seconds)
-        #[arg(long, value_parser = value_parser!(u32))]
-        timestamp: Option<u32>,
+        #[arg(
+            long,
+            help = TimestampArg::<Seconds>::help("ObjectId timestamp"),
+            value_parser = TimestampArg::<Seconds>::new().max(u32::MAX as u64)
+        )]
+        timestamp: Option<u64>,
     },
+
+    /// Generate a new KSUID
+    #[command(long_about = "Generates a new K-Sortable Unique Identifier.")]
+    Ksuid {
+        #[arg(
+            long,
+            help = TimestampArg::<Seconds>::help("KSUID timestamp"),
+            value_parser = TimestampArg::<Seconds>::new()
+                .min(ksuid::EPOCH)
+                .max(ksuid::MAX_TIMESTAMP)
+        )]
+        timestamp: Option<u64>,
+    },
+
+    /// Generate a new snowflake
+    #[command(
+        long_about = "Generates a new Twitter-style snowflake: a 64-bit integer holding a \
+                      millisecond timestamp, a worker id, and a sequence number."
+    )]
+    Snowflake {
+        /// Worker id (0-1023)
+        #[arg(
+            long,
+            default_value = "0",
+            value_parser = value_parser!(u16).range(0..=snowflake::MAX_WORKER_ID as i64)
+        )]
+        worker_id: u16,
+
+        /// Epoch the timestamps count from (in Unix milliseconds; Twitter's by default)
+        #[arg(long, default_value_t = snowflake::TWITTER_EPOCH)]
+        epoch: u64,
+
+        #[arg(
+            long,
+            help = TimestampArg::<Milliseconds>::help("Snowflake timestamp"),
+            value_parser = TimestampArg::<Milliseconds>::new()
+        )]
+        timestamp: Option<u64>,
+    },
+
+    /// Generate a new composite identifier
+    #[command(
+        long_about = "Generates a new integer identifier packing a millisecond timestamp, a shard \
+                      id, and a sequence number into the given bit layout (like Instagram's \
+                      time:41,shard:13,seq:10)."
+    )]
+    Composite {
+        /// Fields from the most significant bits, as name:bits (time required; shard, seq)
+        #[arg(long, value_parser = composite::parse_layout)]
+        layout: composite::CompositeLayout,
+
+        /// Epoch the timestamps count from (in Unix milliseconds)
+        #[arg(long, default_value = "0")]
+        epoch: u64,
+
+        /// Shard id, fitting the shard field of the layout (0 by default)
+        #[arg(long)]
+        shard: Option<u64>,
+
+        #[arg(
+            long,
+            help = TimestampArg::<Milliseconds>::help("Composite identifier timestamp"),
+            value_parser = TimestampArg::<Milliseconds>::new()
+        )]
+        timestamp: Option<u64>,
+    },
+
+    /// Generate a new TSID
+    #[command(
+        long_about = "Generates a new TSID, the time-sorted identifier of Java's tsid-creator: a \
+                      64-bit integer holding a millisecond timestamp, a node id, and a counter."
+    )]
+    Tsid {
+        /// Node id (0-1023; random by default)
+        #[arg(
+            long,
+            value_parser = value_parser!(u16).range(0..=crate::generators::tsid::MAX_NODE as i64)
+        )]
+        node: Option<u16>,
+
+        /// Epoch the timestamps count from (in Unix milliseconds; 2020-01-01 by default)
+        #[arg(long, default_value_t = crate::generators::tsid::TSID_EPOCH)]
+        epoch: u64,
+
+        #[arg(
+            long,
+            help = TimestampArg::<Milliseconds>::help("TSID timestamp"),
+            value_parser = TimestampArg::<Milliseconds>::new()
+        )]
+        timestamp: Option<u64>,
+
+        /// Form of the TSIDs
+        #[arg(long, value_enum, default_value = "base32")]
+        format: tsid::TsidFormat,
+    },
+
+    /// Generate a new SCRU128
+    #[command(
+        long_about = "Generates a new Sortable, Clock and Random number-based Unique identifier."
+    )]
+    Scru128 {
+        #[arg(
+            long,
+            help = TimestampArg::<Milliseconds>::help("SCRU128 timestamp"),
+            value_parser = TimestampArg::<Milliseconds>::new().max(scru128::MAX_TIMESTAMP)
+        )]
+        timestamp: Option<u64>,
+    },
+
+    /// Generate a new TypeID
+    #[command(
+        name = "typeid",
+        long_about = "Generates a new TypeID: a type prefix followed by a version 7 UUID in \
+                      lowercase Crockford base32."
+    )]
+    TypeId {
+        /// Type prefix (lowercase ASCII letters and underscores, at most 63)
+        #[arg(long, value_parser = utils::parse_typeid_prefix)]
+        prefix: String,
+
+        #[arg(
+            long,
+            help = TimestampArg::<Milliseconds>::help("TypeID timestamp"),
+            value_parser = TimestampArg::<Milliseconds>::new().max(typeid::MAX_TIMESTAMP)
+        )]
+        timestamp: Option<u64>,
+    },
+
+    /// Generate a new Firebase push ID
+    #[command(
+        name = "pushid",
+        long_about = "Generates a new Firebase push ID: a millisecond timestamp and a random \
+                      suffix, incremented within the same millisecond, in 20 sortable \
+                      characters."
+    )]
+    PushId {
+        #[arg(
+            long,
+            help = TimestampArg::<Milliseconds>::help("Push ID timestamp"),
+            value_parser = TimestampArg::<Milliseconds>::new().max(pushid::MAX_TIMESTAMP)
+        )]
+        timestamp: Option<u64>,
+    },
+
+    /// Generate a new CUID
+    #[command(
+        long_about = "Generates a new CUID in its original (v1) form: a timestamp, a counter, \
+                      a host fingerprint, and random blocks in 25 lowercase base36 characters."
+    )]
+    Cuid {
+        /// Generate a 7 to 10 character slug instead
+        #[arg(long)]
+        slug: bool,
+    },
+
+    /// Generate a new NUID
+    #[command(
+        long_about = "Generates a new NATS unique identifier in 22 base62 characters: a random \
+                      prefix kept for the run, and a sequence moving forward by a random \
+                      increment for every NUID."
+    )]
+    Nuid,
+
+    /// Encode integers into a Sqids string
+    #[command(
+        long_about = "Encodes integers into a Sqids string, a short and reversible identifier. \
+                      Without numbers, encodes sequential u32 values starting from a random one."
+    )]
+    Sqids {
+        /// Numbers to encode together (if none, sequential ones from a random u32)
+        #[arg(value_name = "NUMBER")]
+        numbers: Vec<u64>,
+
+        /// Symbols to encode with (at least 3 distinct ASCII ones)
+        #[arg(
+            long,
+            default_value = sqids::DEFAULT_ALPHABET,
+            value_parser = utils::parse_sqids_alphabet
+        )]
+        alphabet: String,
+
+        /// Minimum length of the encoded string
+        #[arg(long, value_name = "LENGTH", default_value = "0")]
+        min_length: u8,
+    },
+
+    /// Encode integers into a Hashids string
+    #[command(
+        long_about = "Encodes integers into a Hashids string, the salted predecessor of Sqids, \
+                      matching the reference implementations. Without numbers, encodes a random \
+                      u64; with --decode, prints the numbers of a hashid instead."
+    )]
+    Hashids {
+        /// Numbers to encode together (if none, a random u64 each time)
+        #[arg(value_name = "NUMBER", conflicts_with = "decode")]
+        numbers: Vec<u64>,
+
+        /// Salt making the strings distinct per project
+        #[arg(long, default_value = "")]
+        salt: String,
+
+        /// Symbols to encode with (at least 16 distinct ones, without spaces)
+        #[arg(
+            long,
+            default_value = crate::generators::hashids::DEFAULT_ALPHABET,
+            value_parser = utils::parse_hashids_alphabet
+        )]
+        alphabet: String,
+
+        /// Minimum length of the encoded string
+        #[arg(long, value_name = "LENGTH", default_value = "0")]
+        min_length: u8,
+
+        /// Decode this hashid back into its numbers instead
+        #[arg(long, value_name = "HASHID")]
+        decode: Option<String>,
+    },
+
+    /// Generate a new NanoID
+    #[command(
+        name = "nanoid",
+        long_about = "Generates a new NanoID, a random string over a URL-safe (or custom) alphabet."
+    )]
+    NanoId {
+        /// Number of symbols
+        #[arg(long, default_value = "21")]
+        size: NonZeroUsize,
+
+        /// Symbols to draw from (2 to 255 distinct ones)
+        #[arg(long, default_value = NANOID_ALPHABET, value_parser = utils::parse_alphabet)]
+        alphabet: String,
+    },
+
+    /// Generate a new short code
+    #[command(
+        long_about = "Generates a new short code, as short as the expected number of codes \
+                      and the acceptable collision probability allow."
+    )]
+    Shortcode(shortcode::ShortcodeArgs),
+
+    /// Generate a new random token
+    #[command(
+        long_about = "Generates a new random token, like an API key or another secret, from \
+                      a cryptographically secure random number generator."
+    )]
+    Token {
+        /// Number of random bytes (up to 1 MiB)
+        #[arg(
+            long,
+            default_value = "32",
+            value_parser = value_parser!(u64).range(1..=crate::generators::token::MAX_BYTES as u64)
+        )]
+        bytes: u64,
+
+        /// Text encoding of the bytes
+        #[arg(long, value_enum, default_value = "hex")]
+        encoding: token::TokenEncoding,
+    },
+
+    /// Generate a new proquint
+    #[command(
+        long_about = "Generates a new proquint, random bits spelled as pronounceable five-letter \
+                      syllables (like lusab-babad), or encodes the given hex data instead."
+    )]
+    Proquint {
+        /// Number of random bits (a multiple of 16, up to 8192)
+        #[arg(long, default_value = "32", value_parser = utils::parse_proquint_bits)]
+        bits: u32,
+
+        /// Encode the given hex data (whole 16-bit words) instead of random bits
+        #[arg(
+            long,
+            value_name = "HEX",
+            conflicts_with = "bits",
+            value_parser = utils::parse_proquint_hex
+        )]
+        from_hex: Option<String>,
+    },
+
+    /// Generate a new human-readable slug
+    #[command(
+        long_about = "Generates a new human-readable slug, like quiet-meadow-4821: random \
+                      adjectives and a noun from embedded word lists, and random digits."
+    )]
+    Slug {
+        /// Number of words (adjectives and a noun)
+        #[arg(long, default_value = "2", value_parser = value_parser!(u8).range(2..=3))]
+        words: u8,
+
+        /// Separator between the words and the number
+        #[arg(long, default_value = "-")]
+        separator: String,
+
+        /// Number of trailing random digits (none for 0)
+        #[arg(long, default_value = "4", value_parser = value_parser!(u8).range(0..=6))]
+        digits: u8,
+    },
+
+    /// Generate a new API key
+    #[command(
+        name = "apikey",
+        long_about = "Generates a new API key, like sk_live_<base62>: an optional prefix, random \
+                      bytes in base62, and a CRC32 checksum to reject mistyped keys with."
+    )]
+    ApiKey {
+        /// Prefix of the key (ASCII letters, digits, and underscores), followed by `_`
+        #[arg(long, value_parser = utils::parse_apikey_prefix)]
+        prefix: Option<String>,
+
+        /// Number of random bytes (16 to 64)
+        #[arg(
+            long,
+            default_value = "24",
+            value_parser = value_parser!(u64).range(
+                crate::generators::apikey::MIN_BYTES..=crate::generators::apikey::MAX_BYTES
+            )
+        )]
+        bytes: u64,
+
+        /// Checksum appended to the random part
+        #[arg(long, value_enum, default_value = "crc32")]
+        checksum: apikey::ApiKeyChecksum,
+    },
+
+    /// Generate a new Crockford base32 code
+    #[command(
+        long_about = "Generates a new random code in Crockford base32 (no I, L, O, or U), like \
+                      7KQ4X2M9TB0D, optionally followed by a mod-37 check symbol to catch \
+                      misreads with."
+    )]
+    Crockford {
+        /// Number of random symbols (excluding the check symbol)
+        #[arg(
+            long,
+            default_value = "12",
+            value_parser = value_parser!(u64).range(1..=crate::generators::crockford::MAX_LENGTH)
+        )]
+        length: u64,
+
+        /// Append a check symbol
+        #[arg(long)]
+        check: bool,
+    },
+
+    /// Generate a new numeric PIN
+    #[command(
+        long_about = "Generates a new random numeric code, like 048213, for one-time codes: \
+                      zero-padded decimal digits drawn uniformly from a cryptographically \
+                      secure generator."
+    )]
+    Pin {
+        /// Number of digits (4 to 12)
+        #[arg(
+            long,
+            default_value = "6",
+            value_parser = value_parser!(u8).range(
+                i64::from(crate::generators::pin::MIN_DIGITS)
+                    ..=i64::from(crate::generators::pin::MAX_DIGITS)
+            )
+        )]
+        digits: u8,
+
+        /// Never start with a zero (for systems that strip leading zeros)
+        #[arg(long)]
+        no_leading_zero: bool,
+    },
+
+    /// Generate a new random integer ID
+    #[command(
+        long_about = "Generates a new random integer ID in decimal, for tables keyed by random \
+                      BIGINT columns: of 32, 53 (safe in JavaScript), 63, or 64 bits, \
+                      optionally bounded, with every value equally likely."
+    )]
+    Int {
+        /// Width of the integers
+        #[arg(long, value_enum, default_value = "64")]
+        bits: int::IntBits,
+
+        /// Draw from the signed range of the width (like -2^63 to 2^63-1)
+        #[arg(long, conflicts_with = "unsigned")]
+        signed: bool,
+
+        /// Draw from the unsigned range of the width (like 0 to 2^64-1; the default)
+        #[arg(long)]
+        unsigned: bool,
+
+        /// Never generate zero
+        #[arg(long)]
+        nonzero: bool,
+
+        /// Lowest integer (the lowest of the width by default)
+        #[arg(long, allow_negative_numbers = true)]
+        min: Option<i128>,
+
+        /// Highest integer (the highest of the width by default)
+        #[arg(long, allow_negative_numbers = true)]
+        max: Option<i128>,
+    },
+
+    /// Generate a new random MAC address
+    #[command(
+        long_about = "Generates a new random MAC address, like 02:1a:2b:3c:4d:5e: locally \
+                      administered and unicast unless flipped, or with the first three bytes \
+                      pinned to an OUI."
+    )]
+    Mac {
+        /// Notation of the address
+        #[arg(long, value_enum, default_value = "colon")]
+        format: mac::MacFormat,
+
+        /// First three bytes (like 00:16:3e), whose bits must match the flags
+        #[arg(long, value_parser = mac::parse_oui)]
+        oui: Option<[u8; 3]>,
+
+        /// Set the multicast bit (for group addresses)
+        #[arg(long)]
+        multicast: bool,
+
+        /// Clear the locally administered bit (for vendor-assigned addresses)
+        #[arg(long)]
+        universal: bool,
+    },
+
+    /// Generate a new timestamp slug
+    #[command(long_about = "Generates a new timestamp slug for file names, like \
+                      20240613T142233Z-7f3k2q: a compact UTC timestamp and a short random \
+                      suffix, sortable by time and without colons.")]
+    Stamp {
+        /// Number of Crockford base32 suffix symbols (1-12)
+        #[arg(
+            long,
+            default_value_t = 6,
+            value_parser = value_parser!(u8).range(1..=crate::generators::stamp::MAX_SUFFIX_LENGTH as i64)
+        )]
+        suffix_length: u8,
+
+        /// Precision of the timestamp
+        #[arg(long, value_enum, default_value = "seconds")]
+        precision: stamp::StampPrecision,
+
+        #[arg(
+            long,
+            help = TimestampArg::<Milliseconds>::help("slug timestamp"),
+            value_parser = TimestampArg::<Milliseconds>::new().max(crate::generators::stamp::MAX_TIMESTAMP)
+        )]
+        timestamp: Option<u64>,
+    },
+
+    /// Generate sequential numbers
+    #[command(
+        long_about = "Generates consecutive numbers from a start value, optionally zero-padded \
+                      and prefixed (like INV-000042), failing instead of wrapping past 64 bits."
+    )]
+    Seq {
+        /// First value
+        #[arg(long, default_value = "1")]
+        start: u64,
+
+        /// Increment between values (positive)
+        #[arg(
+            long,
+            default_value = "1",
+            allow_negative_numbers = true,
+            value_parser = utils::parse_seq_step
+        )]
+        step: u64,
+
+        /// Zero-padding width of values (none for 0)
+        #[arg(
+            long,
+            default_value = "0",
+            value_parser = value_parser!(u8).range(0..=crate::generators::seq::MAX_PAD as i64)
+        )]
+        pad: u8,
+
+        /// Prefix of every value
+        #[arg(long, default_value = "")]
+        prefix: String,
+    },
+
+    /// Redact the node id of UUIDs (versions 1 and 6)
+    #[command(
+        long_about = "Replaces the node id of version 1 and 6 UUIDs with a locally-administered \
+                      constant (02:00:00:00:00:00), keeping the timestamp and clock sequence."
+    )]
+    Redact {
+        /// UUIDs to redact (read from stdin, one per line, for `-` or if none are given)
+        #[arg(value_name = "UUID")]
+        uuids: Vec<String>,
+
+        /// Replace node ids with zeros instead
+        #[arg(long)]
+        zero: bool,
+    },
+
+    /// Validate existing identifiers
+    #[command(
+        long_about = "Validates UUIDs, ULIDs, and ObjectIds (or API keys by their checksum, or \
+                      Crockford base32 codes by their check symbol), printing each one with its \
+                      result and exiting with 1 if any is invalid."
+    )]
+    Validate {
+        /// Identifiers to validate (read from stdin, one per line, for `-` or if none are given)
+        #[arg(value_name = "ID")]
+        ids: Vec<String>,
+
+        /// Fail identifiers with embedded timestamps in the future
+        #[arg(long)]
+        no_future: bool,
+
+        /// Allowance for clock skew with `--no-future` (ms, s, m, h, or d suffix)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            default_value = "5m",
+            requires = "no_future",
+            value_parser = utils::parse_duration
+        )]
+        skew: u64,
+
+        /// Check API keys by their CRC32 checksum instead
+        #[arg(long, conflicts_with = "no_future")]
+        apikey: bool,
+
+        /// Check Crockford base32 codes by their check symbol instead
+        #[arg(long, conflicts_with_all = ["no_future", "apikey"])]
+        crockford: bool,
+    },
+
+    /// Print chronological sort keys of existing identifiers
+    #[command(
+        long_about = "Prints a fixed-width hex key before each UUID, ULID, and ObjectId, such \
+                      that sorting the keys as text orders the identifiers by their embedded \
+                      timestamps, across types."
+    )]
+    Sortkey {
+        /// Identifiers to key (read from stdin, one per line, for `-` or if none are given)
+        #[arg(value_name = "ID")]
+        ids: Vec<String>,
+    },
+
+    /// Decode word sequences back into UUIDs
+    #[command(
+        long_about = "Decodes sequences of 12 words, as printed with `--format words`, back into \
+                      canonical UUIDs. ULIDs printed as words decode into the UUID with the same \
+                      bytes."
+    )]
+    Words {
+        /// Word sequences to decode (read from stdin, one per line, for `-` or if none are
+        /// given)
+        #[arg(value_name = "WORDS")]
+        phrases: Vec<String>,
+    },
+
+    /// Estimate collision probabilities for a generation rate
+    #[command(
+        long_about = "Estimates birthday-bound collision probabilities (or ObjectId counter \
+                      wrap risk) for an identifier type generated at a given rate."
+    )]
+    Estimate(estimate::EstimateArgs),
 }
 
 impl Args {
     /// Parses command-line arguments with additional custom validation.
     ///
     /// This extends `clap`'s built-in validation with application-specific rules
-    /// that are too complex to express declaratively. Currently validates:
+    /// that are too complex to express declaratively. It also resolves the number
+    /// of results, reading it from stdin or a file when requested, and expands a
+    /// `--preset` before parsing, and prints the resolved options for the hidden
+    /// `--debug-args` flag. Currently validates:
     ///
+    /// - UUID versions 3 and 5 get a namespace and a name, and version 8 its data or hash
     /// - UUID timestamps are only used with compatible versions (v1, v6, v7)
+    /// - UUID node ids are only used with compatible versions (v1, v6)
+    /// - UUID v1/v6 timestamps fit into the Gregorian timestamp range
+    /// - UUID clock sequences are only used with versions 1 and 6, within 14 bits
+    /// - UUID clock sequence state files are only used with versions 1 and 6
+    /// - UUID names are only skipped when empty with `--names-stdin`
+    /// - UUID data is only read from stdin for version 8
+    /// - UUID special value exclusion is only used with version 4
+    /// - UUID vanity prefixes are only used with version 4
+    /// - UUID per-millisecond quotas are only used with version 7
+    /// - UUID sub-millisecond precisions are only used with version 7
+    /// - UUID node ids drawn per UUID are only used with versions 1 and 6, without
+    ///   fixed node ids
+    /// - Uppercase UUIDs are only printed in forms of hex digits or base32 symbols
+    /// - Banned substrings leave at least two short code alphabet symbols
+    /// - Snowflake timestamps fit into the 41 bits after the epoch
+    /// - Composite shards and timestamps fit into their fields of the layout
+    /// - TSID timestamps fit into the 42 bits after the epoch
+    /// - Integer ID bounds fit into the width and leave at least two values
+    /// - MAC address OUIs have the bits the multicast and universal flags ask for
+    /// - Time modes are only used with commands reading the clock
+    /// - Extra formats are only used with commands printing canonical bytes
     ///
     /// # Panics
     ///
     /// Calls `std::process::exit` if validation fails, printing an error message
     /// to stderr in the same style as `clap` errors.
     pub(crate) fn parse() -> Self {
-        let args = <Self as Parser>::parse();
+        let raw_args = match preset::expand(
+            std::env::args_os().collect(),
+            &<Self as CommandFactory>::command(),
+        ) {
+            Ok(raw_args) => raw_args,
+            Err(message) => {
+                let mut cmd = <Self as CommandFactory>::command();
+                cmd.error(ErrorKind::ArgumentConflict, message).exit();
+            }
+        };
+
+        let cmd = <Self as CommandFactory>::command();
+        let matches = cmd.clone().get_matches_from(raw_args);
+        let mut args = <Self as FromArgMatches>::from_arg_matches(&matches)
+            .unwrap_or_else(|err| err.format(&mut cmd.clone()).exit());
+
+        // A single identifier must not depend on input, nor share stdout with anything
+        if args.single {
+            let prior = match (&args.number_source, &args.command) {
+                (NumberSource::Value(1), Commands::Estimate(_)) => Some("estimate".to_owned()),
+                (NumberSource::Value(1), Commands::Redact { .. }) => Some("redact".to_owned()),
+                (NumberSource::Value(1), Commands::Validate { .. }) => Some("validate".to_owned()),
+                (NumberSource::Value(1), Commands::Sortkey { .. }) => Some("sortkey".to_owned()),
+                (NumberSource::Value(1), Commands::Words { .. }) => Some("words".to_owned()),
+                (NumberSource::Value(1), Commands::Uuid(uuid_args)) if uuid_args.names_stdin => {
+                    Some("--names-stdin".to_owned())
+                }
+                (NumberSource::Value(1), _) => None,
+                _ => Some("--num <NUMBER>".to_owned()),
+            };
+
+            if let Some(prior) = prior {
+                let mut clap_err = clap::Error::new(ErrorKind::ArgumentConflict).with_cmd(&cmd);
+                clap_err.insert(
+                    ContextKind::InvalidArg,
+                    ContextValue::String("--single".to_owned()),
+                );
+                clap_err.insert(ContextKind::PriorArg, ContextValue::String(prior));
+                clap_err.exit();
+            }
+        }
+
+        // Stdin holds either the number of results or the input of the run, not both
+        if let (NumberSource::Stdin, Commands::Uuid(uuid_args)) =
+            (&args.number_source, &args.command)
+            && (uuid_args.names_stdin || uuid_args.data_stdin)
+        {
+            let arg = match uuid_args.names_stdin {
+                true => "--names-stdin",
+                false => "--data-stdin",
+            };
+
+            let mut clap_err = clap::Error::new(ErrorKind::ArgumentConflict).with_cmd(&cmd);
+            clap_err.insert(
+                ContextKind::InvalidArg,
+                ContextValue::String(arg.to_owned()),
+            );
+            clap_err.insert(
+                ContextKind::PriorArg,
+                ContextValue::String("--num -".to_owned()),
+            );
+            clap_err.exit();
+        }
 
-        if let Err(err) = validation::validate_args(&args.command) {
-            let cmd = <Self as CommandFactory>::command();
+        match args.number_source.resolve() {
+            Ok(number) => args.number = number,
+            Err(message) => {
+                let mut cmd = <Self as CommandFactory>::command();
+                cmd.error(ErrorKind::InvalidValue, message).exit();
+            }
+        }
+
+        if let Err(err) = validation::validate_args(&args.command)
+            .and_then(|()| validation::validate_time_mode(&args.command, args.time_mode))
+            .and_then(|()| validation::validate_also_format(&args.command, &args.also_format))
+        {
+            let mut cmd = <Self as CommandFactory>::command();
 
-            match err {
+            let (arg, prior) = match err {
                 validation::ValidationError::UuidTimestampVersionMismatch { version } => {
-                    let mut clap_err = clap::Error::new(ErrorKind::ArgumentConflict).with_cmd(&cmd);
+                    ("--timestamp", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidNodeIdVersionMismatch { version, arg } => {
+                    (arg, format!("--version {version}"))
+                }
+                validation::ValidationError::UuidTimestampOutOfRange { version } => {
+                    let (min, max) = utils::gregorian_range();
+                    let message = format!(
+                        "invalid value for '--timestamp': UUID version {version} timestamps \
+                         must be between {min} and {max}"
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::UuidMissingArguments { args } => {
+                    let mut clap_err =
+                        clap::Error::new(ErrorKind::MissingRequiredArgument).with_cmd(&cmd);
                     clap_err.insert(
                         ContextKind::InvalidArg,
-                        ContextValue::String("--timestamp".to_owned()),
+                        ContextValue::Strings(args.into_iter().map(String::from).collect()),
                     );
                     clap_err.insert(
-                        ContextKind::PriorArg,
-                        ContextValue::String("--version ".to_owned() + &version.to_string()),
+                        ContextKind::Usage,
+                        ContextValue::StyledStr(cmd.render_usage()),
                     );
                     clap_err.exit();
                 }
-            }
+                validation::ValidationError::UuidDataStdinVersionMismatch { version } => {
+                    ("--data-stdin", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidClockSeqVersionMismatch { version } => {
+                    ("--clock-seq <NUMBER>", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidClockSeqOutOfRange { clock_seq } => {
+                    let message = format!(
+                        "invalid value '{clock_seq}' for '--clock-seq <NUMBER>': UUID clock \
+                         sequences have 14 bits, from 0 to {}",
+                        uuid::MAX_CLOCK_SEQ
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::ShortcodeAlphabetExhausted { remaining } => {
+                    let message = format!(
+                        "invalid value for '--exclude-substrings': banned substrings leave \
+                         {remaining} alphabet symbol(s), but at least 2 are needed"
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::SnowflakeTimestampOutOfRange { epoch } => {
+                    let message = format!(
+                        "invalid value for '--timestamp': snowflake timestamps must be between \
+                         the epoch ({epoch}) and {} milliseconds",
+                        epoch.saturating_add(snowflake::MAX_OFFSET)
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::CompositeTimestampOutOfRange { epoch, bits } => {
+                    let message = format!(
+                        "invalid value for '--timestamp': composite timestamps must be between \
+                         the epoch ({epoch}) and {} milliseconds to fit the {bits}-bit time field",
+                        epoch.saturating_add(composite::max_field_value(bits))
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::CompositeShardOutOfRange { bits } => {
+                    let message = format!(
+                        "invalid value for '--shard': shards must be at most {} to fit the \
+                         {bits}-bit shard field",
+                        composite::max_field_value(bits)
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::CompositeShardWithoutField => {
+                    let message = "the argument '--shard <SHARD>' needs a shard field in \
+                                   '--layout <LAYOUT>'";
+                    cmd.error(ErrorKind::ArgumentConflict, message).exit();
+                }
+                validation::ValidationError::TsidTimestampOutOfRange { epoch } => {
+                    let message = format!(
+                        "invalid value for '--timestamp': TSID timestamps must be between the \
+                         epoch ({epoch}) and {} milliseconds",
+                        epoch.saturating_add(crate::generators::tsid::MAX_OFFSET)
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::IntBoundOutOfRange {
+                    arg,
+                    value,
+                    bits,
+                    signed,
+                } => {
+                    let (lowest, highest) = bits.range(signed);
+                    let kind = if signed { "signed" } else { "unsigned" };
+                    let message = format!(
+                        "invalid value for '{arg}': {value} is not in {lowest}..={highest} ({}-bit \
+                         {kind} integers)",
+                        bits.bits()
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::IntBoundsReversed { min, max } => {
+                    let message = format!(
+                        "invalid value for '--max': {max} is less than the minimum ({min})"
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::IntRangeTooSmall { min, max, nonzero } => {
+                    let without_zero = if nonzero { " besides zero" } else { "" };
+                    let message = format!(
+                        "invalid value for '--max': the range {min}..={max} has fewer than two \
+                         values{without_zero} to draw from"
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::MacOuiBitMismatch { oui, flag, given } => {
+                    let kind = match (flag, given) {
+                        ("--multicast", true) => "a unicast",
+                        ("--multicast", false) => "a multicast",
+                        (_, true) => "a locally administered",
+                        (_, false) => "a universally administered",
+                    };
+                    let relation = if given { "conflicts with" } else { "needs" };
+                    let message = format!(
+                        "invalid value for '--oui': {} is {kind} OUI, which {relation} '{flag}'",
+                        mac::format_oui(&oui)
+                    );
+                    cmd.error(ErrorKind::ValueValidation, message).exit();
+                }
+                validation::ValidationError::UuidExcludeSpecialVersionMismatch { version } => {
+                    ("--exclude-special", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidHashVersionMismatch { version } => {
+                    ("--hash <HASH>", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidNameVersionMismatch { version, arg } => {
+                    (arg, format!("--version {version}"))
+                }
+                validation::ValidationError::UuidSkipEmptyWithoutNamesStdin => {
+                    let message = "the argument '--skip-empty' needs '--names-stdin'";
+                    cmd.error(ErrorKind::ArgumentConflict, message).exit();
+                }
+                validation::ValidationError::UuidStartsWithVersionMismatch { version } => {
+                    ("--starts-with <HEX>", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidPerMsVersionMismatch { version } => {
+                    ("--per-ms", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidStateFileVersionMismatch { version } => {
+                    ("--state-file <PATH>", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidPrecisionVersionMismatch { version } => {
+                    ("--precision", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidNodeIdModeVersionMismatch { version } => {
+                    ("--node-id-mode per-id", format!("--version {version}"))
+                }
+                validation::ValidationError::UuidNodeIdModeConflict { arg } => {
+                    ("--node-id-mode per-id", arg.to_owned())
+                }
+                validation::ValidationError::UuidUppercaseFormatMismatch { format } => (
+                    "--uppercase",
+                    format!(
+                        "--format {}",
+                        format
+                            .to_possible_value()
+                            .expect("formats are not skipped")
+                            .get_name()
+                    ),
+                ),
+                validation::ValidationError::UuidConstantConflict { constant, arg } => {
+                    (constant, arg.to_owned())
+                }
+                validation::ValidationError::TimeModeUuidVersionMismatch { version } => {
+                    ("--time-mode <MODE>", format!("--version {version}"))
+                }
+                validation::ValidationError::TimeModeCommandMismatch => (
+                    "--time-mode <MODE>",
+                    matches
+                        .subcommand_name()
+                        .expect("a subcommand is required")
+                        .to_owned(),
+                ),
+                validation::ValidationError::TimeModePerIdWithFixedTime { arg } => {
+                    ("--time-mode per-id", arg.to_owned())
+                }
+                validation::ValidationError::AlsoFormatCommandMismatch => (
+                    "--also-format <FORMAT>",
+                    matches
+                        .subcommand_name()
+                        .expect("a subcommand is required")
+                        .to_owned(),
+                ),
+                validation::ValidationError::AlsoFormatShortUuid => {
+                    ("--also-format <FORMAT>", "--short".to_owned())
+                }
+                validation::ValidationError::AlsoFormatBinaryUuid => {
+                    ("--also-format <FORMAT>", "--binary".to_owned())
+                }
+                validation::ValidationError::AlsoFormatWords => {
+                    ("--also-format <FORMAT>", "--format <FORMAT>".to_owned())
+                }
+                validation::ValidationError::CommandNotCompiledIn { feature } => {
+                    let message = format!(
+                        "the '{}' command is not compiled in; rebuild with `--features {feature}`",
+                        matches.subcommand_name().expect("a subcommand is required")
+                    );
+                    cmd.error(ErrorKind::InvalidSubcommand, message).exit();
+                }
+            };
+
+            let mut clap_err = clap::Error::new(ErrorKind::ArgumentConflict).with_cmd(&cmd);
+            clap_err.insert(
+                ContextKind::InvalidArg,
+                ContextValue::String(arg.to_owned()),
+            );
+            clap_err.insert(ContextKind::PriorArg, ContextValue::String(prior));
+            clap_err.exit();
+        }
+
+        if args.debug_args {
+            print!("{}", debug::dump(&cmd, &matches));
+            std::process::exit(0);
         }
 
         args
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number_source_value() {
+        let result = parse_number_source("3k");
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), NumberSource::Value(3000));
+    }
+
+    #[test]
+    fn test_parse_number_source_stdin() {
+        let result = parse_number_source("-");
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), NumberSource::Stdin);
+    }
+
+    #[test]
+    fn test_parse_number_source_file() {
+        let result = parse_number_source("@count.txt");
+
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            NumberSource::File(PathBuf::from("count.txt"))
+        );
+    }
+
+    #[test]
+    fn test_parse_number_source_empty_file() {
+        let result = parse_number_source("@");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "file path must not be empty"
+        );
+    }
+
+    #[test]
+    fn test_resolve_missing_file() {
+        let source = NumberSource::File(PathBuf::from("/nonexistent/count.txt"));
+        let result = source.resolve();
+
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .starts_with("failed to read number from file /nonexistent/count.txt")
+        );
+    }
+}
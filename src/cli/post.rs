@@ -0,0 +1,27 @@
+//! HTTP sink CLI arguments (requires the `http` feature).
+//!
+//! This module defines the global options pushing generated identifiers to an HTTP
+//! endpoint, flattened into [`Args`](super::Args):
+//!
+//! - [`PostArgs`]: The endpoint, batch size, JSON field name, and stdout suppression
+
+use std::num::NonZeroUsize;
+
+#[derive(clap::Args)]
+pub(crate) struct PostArgs {
+    /// POST identifiers to this URL in batches, as a JSON array
+    #[arg(long, value_name = "URL")]
+    pub(crate) post: Option<String>,
+
+    /// Number of identifiers per POST request
+    #[arg(long, value_name = "NUMBER", default_value = "100", requires = "post")]
+    pub(crate) post_batch: NonZeroUsize,
+
+    /// JSON field holding the identifiers in POST requests
+    #[arg(long, value_name = "NAME", default_value = "ids", requires = "post")]
+    pub(crate) post_field: String,
+
+    /// Do not print identifiers to stdout when posting them
+    #[arg(short, long, requires = "post", conflicts_with = "single")]
+    pub(crate) quiet: bool,
+}
@@ -0,0 +1,312 @@
+//! Presets bundling a subcommand and its options for common ecosystems.
+//!
+//! A preset (`--preset NAME`) expands to a documented bundle of existing arguments
+//! before `clap` parses them, so presets need no special handling past this module:
+//!
+//! | Preset        | Expansion          | Output                                         |
+//! |---------------|--------------------|------------------------------------------------|
+//! | `postgres-v7` | `uuid --version 7` | Lowercase hyphenated UUIDs, monotonic in a run |
+//! | `mongo`       | `oid`              | 24 hex character ObjectIds                     |
+//! | `dynamo`      | `ksuid`            | 27 character base62 KSUIDs                     |
+//! | `kafka-key`   | `ulid`             | 26 character Crockford base32 ULIDs            |
+//!
+//! # Overrides
+//!
+//! When the preset's subcommand is also given explicitly, the preset's options are only
+//! inserted if the command line does not set them already, so explicit flags win (like
+//! `--preset postgres-v7 uuid --version 4`). Giving a different subcommand is an error.
+
+use std::ffi::{OsStr, OsString};
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Preset {
+    /// UUID version 7 for PostgreSQL primary keys (`uuid --version 7`)
+    #[value(name = "postgres-v7")]
+    PostgresV7,
+    /// ObjectId for MongoDB documents (`oid`)
+    Mongo,
+    /// KSUID for DynamoDB sort keys (`ksuid`)
+    Dynamo,
+    /// ULID for Kafka record keys (`ulid`)
+    #[value(name = "kafka-key")]
+    KafkaKey,
+}
+
+impl Preset {
+    /// Returns the subcommand the preset expands to.
+    fn command(&self) -> &'static str {
+        match self {
+            Preset::PostgresV7 => "uuid",
+            Preset::Mongo => "oid",
+            Preset::Dynamo => "ksuid",
+            Preset::KafkaKey => "ulid",
+        }
+    }
+
+    /// Returns the subcommand options (long names and values) the preset expands to.
+    fn options(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Preset::PostgresV7 => &[("version", "7")],
+            Preset::Mongo | Preset::Dynamo | Preset::KafkaKey => &[],
+        }
+    }
+}
+
+/// Expands a `--preset` among the top-level options into its subcommand and options.
+///
+/// Arguments without a (valid) preset are returned unchanged, leaving any errors to
+/// `clap`. Fails with a message when the preset conflicts with the given subcommand.
+pub(crate) fn expand(
+    mut args: Vec<OsString>,
+    cmd: &clap::Command,
+) -> Result<Vec<OsString>, String> {
+    let (preset, position) = scan(&args, cmd);
+
+    let Some(preset) = preset else {
+        return Ok(args);
+    };
+
+    let subcommand = args
+        .get(position)
+        .and_then(|token| token.to_str())
+        .and_then(|token| cmd.find_subcommand(token));
+
+    let insert_at = match subcommand {
+        Some(subcommand) if subcommand.get_name() != preset.command() => {
+            return Err(format!(
+                "preset '{}' expands to the '{}' command and cannot be used with '{}'",
+                preset_name(preset),
+                preset.command(),
+                subcommand.get_name()
+            ));
+        }
+        Some(_) => position + 1,
+        None => {
+            args.insert(position, OsString::from(preset.command()));
+            position + 1
+        }
+    };
+
+    let subcommand = cmd
+        .find_subcommand(preset.command())
+        .expect("presets expand to existing subcommands");
+    let given = &args[insert_at..];
+
+    let options: Vec<OsString> = preset
+        .options()
+        .iter()
+        .filter(|(long, _)| {
+            let short = subcommand
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(long))
+                .and_then(|arg| arg.get_short());
+
+            !given.iter().any(|token| is_option(token, long, short))
+        })
+        .flat_map(|(long, value)| [OsString::from(format!("--{long}")), OsString::from(value)])
+        .collect();
+
+    args.splice(insert_at..insert_at, options);
+
+    Ok(args)
+}
+
+/// Finds the preset among the top-level options, and the position of the subcommand (or
+/// where it would go).
+fn scan(args: &[OsString], cmd: &clap::Command) -> (Option<Preset>, usize) {
+    let mut preset = None;
+    let mut position = 1;
+
+    while let Some(token) = args.get(position).and_then(|token| token.to_str()) {
+        let (takes_value, inline_value) = if token == "--" {
+            break;
+        } else if let Some(option) = token.strip_prefix("--") {
+            let (name, inline_value) = match option.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (option, None),
+            };
+            let takes_value = cmd
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(name))
+                .is_some_and(|arg| arg.get_action().takes_values());
+
+            if name == "preset" {
+                let value = inline_value
+                    .or_else(|| args.get(position + 1).and_then(|value| value.to_str()));
+                preset = value.and_then(|value| clap::ValueEnum::from_str(value, false).ok());
+            }
+
+            (takes_value, inline_value)
+        } else if let Some(option) = token.strip_prefix('-')
+            && let Some(short) = option.chars().next()
+        {
+            let takes_value = cmd
+                .get_arguments()
+                .find(|arg| arg.get_short() == Some(short))
+                .is_some_and(|arg| arg.get_action().takes_values());
+
+            (takes_value, (option.len() > 1).then_some(""))
+        } else {
+            break;
+        };
+
+        position += if takes_value && inline_value.is_none() {
+            2
+        } else {
+            1
+        };
+    }
+
+    (preset, position.min(args.len()))
+}
+
+/// Returns whether the token sets the option with the given long (or short) name.
+fn is_option(token: &OsStr, long: &str, short: Option<char>) -> bool {
+    let Some(token) = token.to_str() else {
+        return false;
+    };
+
+    match token.strip_prefix("--") {
+        Some(option) => option.split('=').next() == Some(long),
+        None => short.is_some_and(|short| {
+            token
+                .strip_prefix('-')
+                .and_then(|option| option.chars().next())
+                == Some(short)
+        }),
+    }
+}
+
+/// Returns the command-line name of a preset.
+fn preset_name(preset: Preset) -> String {
+    clap::ValueEnum::to_possible_value(&preset)
+        .expect("presets are not skipped")
+        .get_name()
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+    use crate::cli::Args;
+
+    fn expand_args(args: &[&str]) -> Result<Vec<String>, String> {
+        let args = args.iter().map(OsString::from).collect();
+
+        expand(args, &Args::command()).map(|args| {
+            args.into_iter()
+                .map(|arg| arg.into_string().unwrap())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_expand_without_preset() {
+        assert_eq!(
+            expand_args(&["spwd", "-n", "3", "ulid"]).unwrap(),
+            ["spwd", "-n", "3", "ulid"]
+        );
+    }
+
+    #[test]
+    fn test_expand_appends_subcommand() {
+        assert_eq!(
+            expand_args(&["spwd", "--preset", "postgres-v7", "-n", "3"]).unwrap(),
+            [
+                "spwd",
+                "--preset",
+                "postgres-v7",
+                "-n",
+                "3",
+                "uuid",
+                "--version",
+                "7"
+            ]
+        );
+        assert_eq!(
+            expand_args(&["spwd", "--preset=mongo"]).unwrap(),
+            ["spwd", "--preset=mongo", "oid"]
+        );
+    }
+
+    #[test]
+    fn test_expand_into_given_subcommand() {
+        assert_eq!(
+            expand_args(&[
+                "spwd",
+                "--preset",
+                "postgres-v7",
+                "uuid",
+                "--timestamp",
+                "0"
+            ])
+            .unwrap(),
+            [
+                "spwd",
+                "--preset",
+                "postgres-v7",
+                "uuid",
+                "--version",
+                "7",
+                "--timestamp",
+                "0"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_keeps_explicit_options() {
+        assert_eq!(
+            expand_args(&["spwd", "--preset", "postgres-v7", "uuid", "-v", "4"]).unwrap(),
+            ["spwd", "--preset", "postgres-v7", "uuid", "-v", "4"]
+        );
+        assert_eq!(
+            expand_args(&["spwd", "--preset", "postgres-v7", "uuid", "--version=1"]).unwrap(),
+            ["spwd", "--preset", "postgres-v7", "uuid", "--version=1"]
+        );
+    }
+
+    #[test]
+    fn test_expand_skips_option_values() {
+        // The value of `--assert-charset` looks like a subcommand but is not one
+        assert_eq!(
+            expand_args(&["spwd", "--assert-charset", "oid", "--preset", "dynamo"]).unwrap(),
+            [
+                "spwd",
+                "--assert-charset",
+                "oid",
+                "--preset",
+                "dynamo",
+                "ksuid"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_conflicting_subcommand() {
+        let result = expand_args(&["spwd", "--preset", "mongo", "ulid"]);
+
+        assert_eq!(
+            result.unwrap_err(),
+            "preset 'mongo' expands to the 'oid' command and cannot be used with 'ulid'"
+        );
+    }
+
+    #[test]
+    fn test_expand_subcommand_alias() {
+        assert_eq!(
+            expand_args(&["spwd", "--preset", "mongo", "objectid"]).unwrap(),
+            ["spwd", "--preset", "mongo", "objectid"]
+        );
+    }
+
+    #[test]
+    fn test_expand_invalid_preset() {
+        assert_eq!(
+            expand_args(&["spwd", "--preset", "oracle"]).unwrap(),
+            ["spwd", "--preset", "oracle"]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Short code-specific CLI types and enumerations.
+//!
+//! This module defines types used exclusively by the short code subcommand:
+//!
+//! - [`ShortcodeArgs`]: The short code subcommand arguments
+//! - [`ShortcodeAlphabet`]: The alphabets short codes can be drawn from
+//! - [`BannedSubstrings`]: Substrings short codes must not contain (case-insensitively)
+
+use anyhow::anyhow;
+
+use crate::utils;
+
+#[derive(clap::Args)]
+pub(crate) struct ShortcodeArgs {
+    /// Expected number of codes in use (k/m/g suffixes)
+    #[arg(long, value_name = "NUMBER", value_parser = utils::parse_count)]
+    pub(crate) expected: usize,
+
+    /// Code alphabet
+    #[arg(long, value_enum, default_value = "no-lookalikes")]
+    pub(crate) alphabet: ShortcodeAlphabet,
+
+    /// Acceptable collision probability across the expected codes
+    #[arg(long, default_value = "1e-6", value_parser = utils::parse_probability)]
+    pub(crate) probability: f64,
+
+    /// Regenerate codes containing any of these substrings (one per line, case-insensitive)
+    #[arg(long, value_name = "FILE", value_parser = parse_banned_substrings)]
+    pub(crate) exclude_substrings: Option<BannedSubstrings>,
+
+    /// Print the chosen code length to stderr
+    #[arg(long)]
+    pub(crate) verbose: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ShortcodeAlphabet {
+    /// Digits only
+    Numeric,
+    /// Digits, uppercase, and lowercase letters
+    Alphanumeric,
+    /// Alphanumeric without easily confused symbols (0, 1, I, O, l, o)
+    NoLookalikes,
+}
+
+impl ShortcodeAlphabet {
+    /// Returns the symbols of the alphabet.
+    pub(crate) fn symbols(&self) -> &'static [u8] {
+        match self {
+            ShortcodeAlphabet::Numeric => b"0123456789",
+            ShortcodeAlphabet::Alphanumeric => {
+                b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"
+            }
+            ShortcodeAlphabet::NoLookalikes => {
+                b"23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz"
+            }
+        }
+    }
+}
+
+/// Substrings short codes must not contain, stored lowercased for case-insensitive matching.
+///
+/// Single-character substrings remove symbols from the alphabet outright, while longer
+/// ones are checked against every generated code.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct BannedSubstrings(pub(crate) Vec<String>);
+
+impl BannedSubstrings {
+    /// Returns the symbols not banned on their own.
+    pub(crate) fn filter_symbols(&self, symbols: &[u8]) -> Vec<u8> {
+        symbols
+            .iter()
+            .copied()
+            .filter(|symbol| {
+                !self.0.iter().any(|banned| {
+                    banned.len() == 1 && banned.as_bytes()[0] == symbol.to_ascii_lowercase()
+                })
+            })
+            .collect()
+    }
+
+    /// Returns whether the code contains any banned substring.
+    pub(crate) fn matches(&self, code: &str) -> bool {
+        let code = code.to_ascii_lowercase();
+
+        self.0.iter().any(|banned| code.contains(banned.as_str()))
+    }
+}
+
+/// Reads banned substrings from a file, one per line, skipping blank lines.
+fn parse_banned_substrings(path: &str) -> anyhow::Result<BannedSubstrings> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|e| anyhow!("failed to read {path}: {e}"))?;
+
+    Ok(BannedSubstrings(
+        contents
+            .lines()
+            .map(|line| line.trim().to_ascii_lowercase())
+            .filter(|line| !line.is_empty())
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_symbols() {
+        let banned = BannedSubstrings(vec![String::from("a"), String::from("bc")]);
+
+        assert_eq!(banned.filter_symbols(b"AaBbCc"), b"BbCc");
+    }
+
+    #[test]
+    fn test_matches_case_insensitive() {
+        let banned = BannedSubstrings(vec![String::from("bad")]);
+
+        assert!(banned.matches("xxBaDxx"));
+        assert!(!banned.matches("xxBAxDx"));
+    }
+}
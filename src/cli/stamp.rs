@@ -0,0 +1,13 @@
+//! Timestamp slug-specific CLI types and enumerations.
+//!
+//! This module defines types used exclusively by the stamp subcommand:
+//!
+//! - [`StampPrecision`]: The precisions of the timestamp part
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum StampPrecision {
+    /// Whole seconds, like 20240613T142233Z
+    Seconds,
+    /// Milliseconds, like 20240613T142233123Z
+    Milliseconds,
+}
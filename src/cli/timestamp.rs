@@ -0,0 +1,630 @@
+//! Shared parsing of `--timestamp` arguments.
+//!
+//! Every command taking a timestamp parses it with [`TimestampArg`], so they all accept
+//! the same syntaxes and fail with the same errors:
+//!
+//! - A Unix epoch integer, in the unit the identifier stores (like milliseconds for
+//!   ULIDs), or in any unit given with an `s`, `ms`, `us`, or `ns` suffix
+//! - An RFC 3339 date, like `2024-01-31T12:00:00Z` or `2024-01-31 12:00:00.5+02:00`
+//! - `now`, optionally shifted by a duration, like `now-1h` or `now+30d`
+//!
+//! Timestamps are converted to the precision of the identifier ([`Seconds`],
+//! [`Milliseconds`], or [`Nanoseconds`]), truncating finer parts, and checked against
+//! its range.
+
+use std::ffi::OsStr;
+use std::marker::PhantomData;
+
+use anyhow::anyhow;
+use clap::builder::TypedValueParser;
+
+use crate::{clock, utils};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Units an epoch integer can be suffixed with, in nanoseconds.
+const EPOCH_UNITS_NS: [(&str, u128); 4] = [
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+/// Precision a timestamp is parsed into.
+pub(crate) trait Precision: Clone + Send + Sync + 'static {
+    /// Parsed timestamp handed to the generator.
+    type Value: Clone + Send + Sync + 'static;
+
+    /// Unit of bare epoch integers and of the range bounds.
+    const UNIT: &'static str;
+
+    /// Nanoseconds in one unit.
+    const NANOS: u128;
+
+    /// Largest timestamp the parsed value can hold, in units.
+    const MAX: u128;
+
+    /// Converts a timestamp in units (within [`Precision::MAX`]) into the parsed value.
+    fn value(units: u128) -> Self::Value;
+}
+
+/// Timestamps in Unix seconds.
+#[derive(Clone, Copy)]
+pub(crate) struct Seconds;
+
+impl Precision for Seconds {
+    type Value = u64;
+
+    const UNIT: &'static str = "seconds";
+    const NANOS: u128 = NANOS_PER_SECOND;
+    const MAX: u128 = u64::MAX as u128;
+
+    fn value(units: u128) -> u64 {
+        units as u64
+    }
+}
+
+/// Timestamps in Unix milliseconds.
+#[derive(Clone, Copy)]
+pub(crate) struct Milliseconds;
+
+impl Precision for Milliseconds {
+    type Value = u64;
+
+    const UNIT: &'static str = "milliseconds";
+    const NANOS: u128 = 1_000_000;
+    const MAX: u128 = u64::MAX as u128;
+
+    fn value(units: u128) -> u64 {
+        units as u64
+    }
+}
+
+/// Timestamps in Unix nanoseconds, parsed into seconds and subsecond nanoseconds.
+#[derive(Clone, Copy)]
+pub(crate) struct Nanoseconds;
+
+impl Precision for Nanoseconds {
+    type Value = (u64, u32);
+
+    const UNIT: &'static str = "nanoseconds";
+    const NANOS: u128 = 1;
+    const MAX: u128 = u64::MAX as u128 * NANOS_PER_SECOND + (NANOS_PER_SECOND - 1);
+
+    fn value(units: u128) -> (u64, u32) {
+        (
+            (units / NANOS_PER_SECOND) as u64,
+            (units % NANOS_PER_SECOND) as u32,
+        )
+    }
+}
+
+/// `clap` value parser for `--timestamp` arguments of a given precision.
+///
+/// Bounds (set with [`TimestampArg::min`] and [`TimestampArg::max`]) are in the units of
+/// the precision, and errors for timestamps outside them show the dates they span.
+#[derive(Clone, Copy)]
+pub(crate) struct TimestampArg<P> {
+    min: u128,
+    max: u128,
+    bounded: bool,
+    precision: PhantomData<P>,
+}
+
+impl<P: Precision> TimestampArg<P> {
+    /// Creates a parser accepting any timestamp from the Unix epoch on.
+    pub(crate) const fn new() -> Self {
+        Self {
+            min: 0,
+            max: P::MAX,
+            bounded: false,
+            precision: PhantomData,
+        }
+    }
+
+    /// Sets the earliest accepted timestamp (in units of the precision).
+    pub(crate) const fn min(mut self, min: u64) -> Self {
+        self.min = min as u128;
+        self.bounded = true;
+        self
+    }
+
+    /// Sets the latest accepted timestamp (in units of the precision).
+    pub(crate) const fn max(mut self, max: u64) -> Self {
+        self.max = max as u128;
+        self.bounded = true;
+        self
+    }
+
+    /// Returns the help text of a timestamp argument, listing the accepted syntaxes.
+    pub(crate) fn help(subject: &str) -> String {
+        format!(
+            "{subject} (Unix {} with an optional s/ms/us/ns suffix, RFC 3339, or now±duration)",
+            P::UNIT
+        )
+    }
+
+    /// Parses a timestamp, resolving `now` to the given Unix seconds and nanoseconds.
+    pub(crate) fn parse(&self, value: &str, now: (u64, u32)) -> anyhow::Result<P::Value> {
+        let nanos = if let Some(shift) = value.strip_prefix("now") {
+            relative_nanos(shift, now)?
+        } else if looks_like_date(value) {
+            date_nanos(value)?
+        } else {
+            epoch_nanos(value, P::NANOS)?.ok_or_else(|| self.out_of_range(None))?
+        };
+
+        if nanos < 0 {
+            return Err(anyhow!(
+                "timestamp must not be before the Unix epoch (1970-01-01T00:00:00Z)"
+            ));
+        }
+
+        let units = nanos.unsigned_abs() / P::NANOS;
+        if units < self.min || units > self.max {
+            return Err(self.out_of_range(Some(units)));
+        }
+
+        Ok(P::value(units))
+    }
+
+    /// Describes a timestamp outside the bounds (or too large to compute in units).
+    fn out_of_range(&self, units: Option<u128>) -> anyhow::Error {
+        let timestamp = units.map_or_else(|| "timestamp".to_owned(), |units| units.to_string());
+        let (min, max, unit) = (self.min, self.max, P::UNIT);
+
+        let dates = match (self.bounded, date::<P>(min), date::<P>(max)) {
+            (true, Some(from), Some(to)) => format!(" ({from} to {to})"),
+            _ => String::new(),
+        };
+
+        anyhow!("{timestamp} is not in {min}..={max} {unit}{dates}")
+    }
+}
+
+impl<P: Precision> TypedValueParser for TimestampArg<P> {
+    type Value = P::Value;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let parser = self.clone();
+        let parse = move |value: &str| parser.parse(value, clock::now());
+
+        parse.parse_ref(cmd, arg, value)
+    }
+}
+
+/// Formats a timestamp (in units of a precision) as a date, if it is representable.
+fn date<P: Precision>(units: u128) -> Option<String> {
+    let nanos = units.checked_mul(P::NANOS)?;
+    let seconds = i64::try_from(nanos / NANOS_PER_SECOND).ok()?;
+
+    Some(utils::format_timestamp(
+        seconds,
+        (nanos % NANOS_PER_SECOND) as u32,
+    ))
+}
+
+/// Describes the accepted syntaxes, for values that match none of them.
+fn unrecognized(value: &str) -> anyhow::Error {
+    anyhow!(
+        "timestamp must be a Unix epoch integer (with an optional s, ms, us, or ns suffix), \
+         an RFC 3339 date, or now±duration, got {value:?}"
+    )
+}
+
+/// Parses an epoch integer into nanoseconds, or `None` when they do not fit.
+///
+/// Bare integers are in units of `unit_nanos` nanoseconds.
+fn epoch_nanos(value: &str, unit_nanos: u128) -> anyhow::Result<Option<i128>> {
+    let (negative, magnitude) = match value.strip_prefix('-') {
+        Some(magnitude) => (true, magnitude),
+        None => (false, value),
+    };
+
+    let split = magnitude
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(magnitude.len());
+    let (digits, suffix) = magnitude.split_at(split);
+
+    if digits.is_empty() {
+        return Err(unrecognized(value));
+    }
+
+    let unit_nanos = match EPOCH_UNITS_NS.iter().find(|(name, _)| *name == suffix) {
+        _ if suffix.is_empty() => unit_nanos,
+        Some((_, nanos)) => *nanos,
+        None if suffix.bytes().all(|c| c.is_ascii_alphabetic()) => {
+            return Err(anyhow!(
+                "unknown timestamp unit {suffix:?} (expected s, ms, us, or ns)"
+            ));
+        }
+        None => return Err(unrecognized(value)),
+    };
+
+    let nanos = digits
+        .parse::<u128>()
+        .ok()
+        .and_then(|number| number.checked_mul(unit_nanos))
+        .and_then(|nanos| i128::try_from(nanos).ok());
+
+    Ok(nanos.map(|nanos| if negative { -nanos } else { nanos }))
+}
+
+/// Parses the shift after `now` (nothing, or a signed duration) into nanoseconds.
+fn relative_nanos(shift: &str, now: (u64, u32)) -> anyhow::Result<i128> {
+    let now = i128::from(now.0) * NANOS_PER_SECOND as i128 + i128::from(now.1);
+
+    let (sign, duration) = match shift.split_at_checked(1) {
+        None => return Ok(now),
+        Some(("+", duration)) => (1, duration),
+        Some(("-", duration)) => (-1, duration),
+        Some(_) => {
+            return Err(anyhow!(
+                "relative timestamps must be now, now+DURATION, or now-DURATION, got \"now{shift}\""
+            ));
+        }
+    };
+
+    let millis = utils::parse_duration(duration)?;
+
+    Ok(now + sign * i128::from(millis) * 1_000_000)
+}
+
+/// Returns whether a value starts like an RFC 3339 date (four digits and a dash).
+fn looks_like_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+
+    bytes.len() > 4 && bytes[..4].iter().all(u8::is_ascii_digit) && bytes[4] == b'-'
+}
+
+/// Parses an RFC 3339 date into nanoseconds since the Unix epoch.
+///
+/// The date and time can be separated by `T`, `t`, or a space, fractions of a second
+/// beyond nanoseconds are truncated, and leap seconds are refused.
+fn date_nanos(value: &str) -> anyhow::Result<i128> {
+    let malformed = || {
+        anyhow!(
+            "RFC 3339 dates must look like 2024-01-31T12:00:00Z (with an optional fraction \
+             of a second, and Z or a ±HH:MM offset), got {value:?}"
+        )
+    };
+
+    let bytes = value.as_bytes();
+    if bytes.len() < 20
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return Err(malformed());
+    }
+
+    let [year, month, day, hour, minute, second] =
+        [0..4, 5..7, 8..10, 11..13, 14..16, 17..19].map(|range| digits(value.get(range)));
+    let (Some(year), Some(month), Some(day), Some(hour), Some(minute), Some(second)) =
+        (year, month, day, hour, minute, second)
+    else {
+        return Err(malformed());
+    };
+
+    let mut rest = &value[19..];
+    let mut subsec_nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let length = fraction
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(fraction.len());
+        if length == 0 {
+            return Err(malformed());
+        }
+
+        let nanos = format!("{:0<9}", &fraction[..length.min(9)]);
+        subsec_nanos = i128::from(digits(Some(&nanos)).ok_or_else(malformed)?);
+        rest = &fraction[length..];
+    }
+
+    let offset = match rest.as_bytes() {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let (Some(hours), Some(minutes)) = (digits(rest.get(1..3)), digits(rest.get(4..6)))
+            else {
+                return Err(malformed());
+            };
+            check_field(value, "offset hour", hours, 0, 23)?;
+            check_field(value, "offset minute", minutes, 0, 59)?;
+
+            let offset = i64::from(hours * 3600 + minutes * 60);
+            if *sign == b'-' { -offset } else { offset }
+        }
+        _ => return Err(malformed()),
+    };
+
+    check_field(value, "month", month, 1, 12)?;
+    check_field(value, "day", day, 1, days_in_month(year, month))?;
+    check_field(value, "hour", hour, 0, 23)?;
+    check_field(value, "minute", minute, 0, 59)?;
+    check_field(value, "second", second, 0, 59)?;
+
+    let seconds = days_from_civil(i64::from(year), i64::from(month), i64::from(day)) * 86_400
+        + i64::from(hour * 3600 + minute * 60 + second)
+        - offset;
+
+    Ok(i128::from(seconds) * NANOS_PER_SECOND as i128 + subsec_nanos)
+}
+
+/// Parses a run of ASCII digits (without signs) into a number.
+fn digits(value: Option<&str>) -> Option<u32> {
+    value
+        .filter(|value| value.bytes().all(|c| c.is_ascii_digit()))
+        .and_then(|value| value.parse().ok())
+}
+
+/// Checks that a field of an RFC 3339 date is within its range.
+fn check_field(value: &str, field: &str, number: u32, min: u32, max: u32) -> anyhow::Result<()> {
+    match number {
+        number if (min..=max).contains(&number) => Ok(()),
+        _ => Err(anyhow!(
+            "{field} must be between {min:02} and {max:02} in RFC 3339 date {value:?}"
+        )),
+    }
+}
+
+/// Returns the number of days in a month of the proleptic Gregorian calendar.
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days from the Unix epoch to a date (negative before it).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // NOTE: Howard Hinnant's civil-to-days algorithm, with eras of 400 years
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2023-11-14T22:13:20.123456789Z
+    const NOW: (u64, u32) = (1_700_000_000, 123_456_789);
+
+    fn seconds(value: &str) -> anyhow::Result<u64> {
+        TimestampArg::<Seconds>::new().parse(value, NOW)
+    }
+
+    fn millis(value: &str) -> anyhow::Result<u64> {
+        TimestampArg::<Milliseconds>::new().parse(value, NOW)
+    }
+
+    fn nanos(value: &str) -> anyhow::Result<(u64, u32)> {
+        TimestampArg::<Nanoseconds>::new().parse(value, NOW)
+    }
+
+    fn error<T: std::fmt::Debug>(result: anyhow::Result<T>) -> String {
+        result.unwrap_err().root_cause().to_string()
+    }
+
+    #[test]
+    fn test_bare_epoch_uses_the_precision_unit() {
+        assert_eq!(seconds("1700000000").unwrap(), 1_700_000_000);
+        assert_eq!(millis("1700000000123").unwrap(), 1_700_000_000_123);
+        assert_eq!(
+            nanos("1700000000123456789").unwrap(),
+            (1_700_000_000, 123_456_789)
+        );
+        assert_eq!(nanos("123456789").unwrap(), (0, 123_456_789));
+    }
+
+    #[test]
+    fn test_epoch_unit_suffixes() {
+        let cases = [
+            (
+                "1700000000s",
+                1_700_000_000,
+                1_700_000_000_000,
+                (1_700_000_000, 0),
+            ),
+            (
+                "1700000000123ms",
+                1_700_000_000,
+                1_700_000_000_123,
+                (1_700_000_000, 123_000_000),
+            ),
+            (
+                "1700000000123456us",
+                1_700_000_000,
+                1_700_000_000_123,
+                (1_700_000_000, 123_456_000),
+            ),
+            (
+                "1700000000123456789ns",
+                1_700_000_000,
+                1_700_000_000_123,
+                (1_700_000_000, 123_456_789),
+            ),
+        ];
+
+        for (value, expected_seconds, expected_millis, expected_nanos) in cases {
+            assert_eq!(seconds(value).unwrap(), expected_seconds, "{value}");
+            assert_eq!(millis(value).unwrap(), expected_millis, "{value}");
+            assert_eq!(nanos(value).unwrap(), expected_nanos, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_rfc3339_dates() {
+        let cases = [
+            ("2023-11-14T22:13:20Z", 0),
+            ("2023-11-14t22:13:20z", 0),
+            ("2023-11-14 22:13:20Z", 0),
+            ("2023-11-15T00:13:20+02:00", 0),
+            ("2023-11-14T20:43:20-01:30", 0),
+            ("2023-11-14T22:13:20.5Z", 500_000_000),
+            ("2023-11-14T22:13:20.123456789Z", 123_456_789),
+            ("2023-11-14T22:13:20.1234567891234Z", 123_456_789),
+        ];
+
+        for (value, subsec_nanos) in cases {
+            assert_eq!(seconds(value).unwrap(), 1_700_000_000, "{value}");
+            assert_eq!(
+                millis(value).unwrap(),
+                1_700_000_000_000 + u64::from(subsec_nanos) / 1_000_000,
+                "{value}"
+            );
+            assert_eq!(
+                nanos(value).unwrap(),
+                (1_700_000_000, subsec_nanos),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rfc3339_calendar() {
+        assert_eq!(seconds("1970-01-01T00:00:00Z").unwrap(), 0);
+        assert_eq!(seconds("2000-03-01T00:00:00Z").unwrap(), 951_868_800);
+        assert_eq!(seconds("2024-02-29T00:00:00Z").unwrap(), 1_709_164_800);
+        assert_eq!(seconds("9999-12-31T23:59:59Z").unwrap(), 253_402_300_799);
+    }
+
+    #[test]
+    fn test_now_with_shifts() {
+        assert_eq!(seconds("now").unwrap(), 1_700_000_000);
+        assert_eq!(millis("now").unwrap(), 1_700_000_000_123);
+        assert_eq!(nanos("now").unwrap(), NOW);
+
+        assert_eq!(seconds("now-1h").unwrap(), 1_699_996_400);
+        assert_eq!(millis("now+1500ms").unwrap(), 1_700_000_001_623);
+        assert_eq!(nanos("now+1d").unwrap(), (1_700_086_400, 123_456_789));
+        assert_eq!(seconds("now+30").unwrap(), 1_700_000_030);
+    }
+
+    #[test]
+    fn test_finer_parts_are_truncated() {
+        assert_eq!(seconds("1700000000999ms").unwrap(), 1_700_000_000);
+        assert_eq!(millis("1700000000999999us").unwrap(), 1_700_000_000_999);
+        assert_eq!(seconds("2023-11-14T22:13:20.999Z").unwrap(), 1_700_000_000);
+    }
+
+    #[test]
+    fn test_before_epoch() {
+        let message = "timestamp must not be before the Unix epoch (1970-01-01T00:00:00Z)";
+
+        assert_eq!(error(seconds("-1")), message);
+        assert_eq!(error(millis("-1000ms")), message);
+        assert_eq!(error(nanos("1969-12-31T23:59:59.999999999Z")), message);
+        assert_eq!(error(seconds("now-20000d")), message);
+        assert_eq!(seconds("-0").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let parser = TimestampArg::<Seconds>::new()
+            .min(1_400_000_000)
+            .max(4_102_444_800);
+
+        assert_eq!(parser.parse("1400000000", NOW).unwrap(), 1_400_000_000);
+        assert_eq!(parser.parse("now", NOW).unwrap(), 1_700_000_000);
+        assert_eq!(
+            error(parser.parse("1399999999", NOW)),
+            "1399999999 is not in 1400000000..=4102444800 seconds \
+             (2014-05-13T16:53:20Z to 2100-01-01T00:00:00Z)"
+        );
+        assert_eq!(
+            error(parser.parse("2100-01-01T00:00:01Z", NOW)),
+            "4102444801 is not in 1400000000..=4102444800 seconds \
+             (2014-05-13T16:53:20Z to 2100-01-01T00:00:00Z)"
+        );
+    }
+
+    #[test]
+    fn test_unbounded_overflow() {
+        assert_eq!(
+            error(millis("18446744073709551616")),
+            "18446744073709551616 is not in 0..=18446744073709551615 milliseconds"
+        );
+        assert_eq!(
+            error(seconds("99999999999999999999999999999999999999999")),
+            "timestamp is not in 0..=18446744073709551615 seconds"
+        );
+        assert_eq!(
+            nanos("18446744073709551615999999999").unwrap(),
+            (u64::MAX, 999_999_999)
+        );
+    }
+
+    #[test]
+    fn test_unrecognized_syntax() {
+        let message = "timestamp must be a Unix epoch integer (with an optional s, ms, us, or ns \
+                       suffix), an RFC 3339 date, or now±duration, got \"not_a_number\"";
+
+        assert_eq!(error(seconds("not_a_number")), message);
+        assert!(error(millis("")).starts_with("timestamp must be a Unix epoch integer"));
+        assert_eq!(
+            error(nanos("1700000000m")),
+            "unknown timestamp unit \"m\" (expected s, ms, us, or ns)"
+        );
+        assert_eq!(
+            error(seconds("now*2")),
+            "relative timestamps must be now, now+DURATION, or now-DURATION, got \"now*2\""
+        );
+        assert_eq!(
+            error(seconds("now+1w")),
+            "unknown duration unit \"w\" (expected ms, s, m, h, or d)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        let malformed = [
+            "2023-11-14",
+            "2023-11-14T22:13:20",
+            "2023-11-14T22:13Z",
+            "2023-11-14T22:13:20.Z",
+            "2023-11-14T22:13:20+0200",
+            "2023-11-14T22:13:20+02:0x",
+        ];
+
+        for value in malformed {
+            assert!(
+                error(seconds(value)).starts_with("RFC 3339 dates must look like"),
+                "{value}"
+            );
+        }
+
+        let out_of_range = [
+            ("2023-13-01T00:00:00Z", "month must be between 01 and 12"),
+            ("2023-02-29T00:00:00Z", "day must be between 01 and 28"),
+            ("2023-11-14T24:00:00Z", "hour must be between 00 and 23"),
+            ("2023-11-14T22:60:00Z", "minute must be between 00 and 59"),
+            ("2016-12-31T23:59:60Z", "second must be between 00 and 59"),
+            (
+                "2023-11-14T22:13:20+24:00",
+                "offset hour must be between 00 and 23",
+            ),
+        ];
+
+        for (value, message) in out_of_range {
+            assert_eq!(
+                error(millis(value)),
+                format!("{message} in RFC 3339 date {value:?}")
+            );
+        }
+    }
+}
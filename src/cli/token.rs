@@ -0,0 +1,17 @@
+//! Token-specific CLI types and enumerations.
+//!
+//! This module defines types used exclusively by the token subcommand:
+//!
+//! - [`TokenEncoding`]: The text encodings random tokens can be printed in
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TokenEncoding {
+    /// Lowercase hexadecimal
+    Hex,
+    /// Standard base64, padded with `=`
+    Base64,
+    /// URL-safe base64 (`-` and `_`), unpadded
+    Base64url,
+    /// RFC 4648 base32 (uppercase), padded with `=`
+    Base32,
+}
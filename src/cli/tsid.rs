@@ -0,0 +1,13 @@
+//! TSID-specific CLI types and enumerations.
+//!
+//! This module defines types used exclusively by the TSID subcommand:
+//!
+//! - [`TsidFormat`]: The forms TSIDs can be printed in
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TsidFormat {
+    /// 13 Crockford base32 characters (uppercase)
+    Base32,
+    /// 64-bit integer in decimal
+    Number,
+}
@@ -2,22 +2,313 @@
 //!
 //! This module defines types used exclusively by the UUID subcommand:
 //!
+//! - [`UuidArgs`]: The UUID subcommand arguments
 //! - [`SupportedUUIDVersion`]: The UUID versions supported by this tool (v1, v3-v8)
-//! - [`SupportedUUIDNamespace`]: Standard UUID namespaces for v3 and v5 (DNS, OID, URL, X500)
+//! - [`SupportedUUIDNamespace`]: UUID namespaces for v3, v5, and name-based v8 (the
+//!   standard DNS, OID, URL, and X500 ones, or any UUID)
+//! - [`NameBytes`]: Raw names of v3 and v5 UUIDs, read from a file or decoded from hex
+//! - [`DataPad`]: The end at which short v8 data is zero-extended
+//! - [`DataFill`]: The fill of the bytes short v8 data leaves out (zeros or random)
+//! - [`NodeIdMode`]: Whether v1 and v6 UUIDs of a run share a node id or get one each
+//! - [`TimestampPrecision`]: The precision of v7 timestamps (to the millisecond or finer)
+//! - [`SupportedUUIDHash`]: Hash functions of name-based v8 UUIDs (SHA-256)
+//! - [`UuidPrefix`]: Hex prefixes of the vanity search for v4 UUIDs
 //!
 //! These types integrate with `clap` through `ValueEnum` to provide CLI argument parsing
 //! and validation. They also implement conversions to the underlying `uuid` crate types.
 
+use std::ffi::OsStr;
 use std::fmt;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use clap::ValueEnum;
+use clap::builder::{PossibleValue, TypedValueParser};
+use clap::error::{ContextKind, ContextValue, ErrorKind};
+
+use super::IdFormat;
+use super::timestamp::{Nanoseconds, TimestampArg};
+use crate::utils;
+
+/// MAC address of the `--node-id` argument.
+#[cfg(feature = "uuid")]
+type NodeId = eui48::MacAddress;
+
+/// Unparsed `--node-id` argument, when the UUID generator (and the `eui48` crate) is
+/// compiled out and the command is refused anyway.
+#[cfg(not(feature = "uuid"))]
+type NodeId = String;
+
+#[derive(clap::Args, Clone, Default)]
+#[command(group = clap::ArgGroup::new("v8_input").args(["data", "data_stdin", "hash"]))]
+#[command(group = clap::ArgGroup::new("names").args(["name", "name_hex", "name_file", "names_stdin"]))]
+pub(crate) struct UuidArgs {
+    /// UUID version (also spelled like v7 or V7)
+    #[arg(
+        short,
+        long,
+        default_value = "4",
+        value_parser = VersionArg
+    )]
+    pub(crate) version: SupportedUUIDVersion,
+
+    #[arg(
+        long,
+        help = TimestampArg::<Nanoseconds>::help("UUID timestamp, versions 1, 6, and 7 only"),
+        value_parser = TimestampArg::<Nanoseconds>::new()
+    )]
+    pub(crate) timestamp: Option<(u64, u32)>,
+
+    /// UUID namespace: dns, oid, url, x500, or any UUID (versions 3 and 5, or 8 with
+    /// `--hash`)
+    #[arg(
+        long,
+        value_parser = parse_namespace,
+        required_if_eq("hash", "sha256")
+    )]
+    pub(crate) namespace: Option<SupportedUUIDNamespace>,
+
+    /// Derive the namespace from this name under `--namespace` first, with the same
+    /// version (versions 3 and 5 only)
+    #[arg(long)]
+    pub(crate) namespace_name: Option<String>,
+
+    /// UUID name (versions 3 and 5, or 8 with `--hash`)
+    #[arg(long, required_if_eq("hash", "sha256"))]
+    pub(crate) name: Option<String>,
+
+    /// UUID name as hex-encoded bytes, for binary keys (versions 3 and 5 only)
+    #[arg(long, value_name = "HEX", value_parser = parse_name_hex)]
+    pub(crate) name_hex: Option<NameBytes>,
+
+    /// Read the name from this file, as its raw contents (versions 3 and 5 only)
+    #[arg(long, value_name = "PATH", value_parser = parse_name_file)]
+    pub(crate) name_file: Option<NameBytes>,
+
+    /// Read names from stdin, one per line, printing a UUID for each in order instead of
+    /// `--num` of them (versions 3 and 5 only)
+    #[arg(long)]
+    pub(crate) names_stdin: bool,
+
+    /// Skip empty lines of `--names-stdin` instead of hashing the empty name
+    #[arg(long)]
+    pub(crate) skip_empty: bool,
+
+    /// UUID node identifier (a MAC address; versions 1 and 6 only)
+    #[cfg_attr(feature = "uuid", arg(long, value_parser = utils::parse_node_id))]
+    #[cfg_attr(not(feature = "uuid"), arg(long))]
+    pub(crate) node_id: Option<NodeId>,
+
+    /// Use the MAC address of a network interface as the node identifier, or a pseudo one
+    /// (with a warning) when none is found, as always on other platforms than Linux
+    /// (versions 1 and 6 only)
+    #[arg(long, conflicts_with = "node_id")]
+    pub(crate) hardware_node: bool,
+
+    /// UUID clock sequence (0-16383; versions 1 and 6 only)
+    #[arg(long, value_name = "NUMBER")]
+    pub(crate) clock_seq: Option<u16>,
+
+    /// Keep the clock sequence in this file across runs, incrementing it when the clock
+    /// went back since the last run (versions 1 and 6 only)
+    #[arg(long, value_name = "PATH", conflicts_with = "clock_seq")]
+    pub(crate) state_file: Option<PathBuf>,
+
+    /// Node identifier of the UUIDs of a run (versions 1 and 6 only)
+    #[arg(long, value_name = "MODE", value_enum, default_value = "fixed")]
+    pub(crate) node_id_mode: NodeIdMode,
+
+    /// UUID user data (hex-encoded; version 8 only)
+    #[arg(long, value_parser = utils::parse_data_hex)]
+    pub(crate) data: Option<String>,
+
+    /// Read the 16 bytes of UUID user data raw from stdin instead (version 8 only)
+    #[arg(long)]
+    pub(crate) data_stdin: bool,
+
+    /// End at which `--data` shorter than 32 hex digits is zero-extended (right by
+    /// default, so `--data 1` sets the first byte, and `left` the last one)
+    #[arg(
+        long,
+        value_enum,
+        default_value = "right",
+        requires = "data",
+        conflicts_with = "hash"
+    )]
+    pub(crate) data_pad: DataPad,
+
+    /// Fill of the bytes that `--data` shorter than 32 hex digits leaves out: zeros, or
+    /// random bits drawn fresh for every UUID (zero by default)
+    #[arg(
+        long,
+        value_enum,
+        default_value = "zero",
+        requires = "data",
+        conflicts_with = "hash"
+    )]
+    pub(crate) data_fill: DataFill,
+
+    /// Hash `--namespace` and `--name` into the UUID instead of `--data` (version 8 only)
+    #[arg(long, value_enum, conflicts_with = "data")]
+    pub(crate) hash: Option<SupportedUUIDHash>,
+
+    /// Regenerate UUIDs with all random bits zero or one (version 4 only)
+    #[arg(long)]
+    pub(crate) exclude_special: bool,
+
+    /// Only print UUIDs starting with these hex digits, searching random ones (up to 8
+    /// digits; version 4 only)
+    #[arg(long, value_name = "HEX", value_parser = parse_prefix)]
+    pub(crate) starts_with: Option<UuidPrefix>,
+
+    /// Candidates to try for each UUID before giving up on `--starts-with` (k/m/g suffixes)
+    #[arg(
+        long,
+        value_name = "NUMBER",
+        default_value = "10g",
+        value_parser = utils::parse_count,
+        requires = "starts_with"
+    )]
+    pub(crate) max_attempts: usize,
+
+    /// Number of UUIDs sharing each millisecond before stepping to the next (version 7 only)
+    #[arg(long, value_name = "NUMBER")]
+    pub(crate) per_ms: Option<NonZeroUsize>,
+
+    /// Precision of the timestamp, kept to the millisecond or with its fraction in the 12
+    /// bits after the version (version 7 only)
+    #[arg(long, value_enum, default_value = "ms")]
+    pub(crate) precision: TimestampPrecision,
+
+    /// Print the nil UUID (all zeros) instead of generating one
+    #[arg(long)]
+    pub(crate) nil: bool,
+
+    /// Print the max UUID (all ones) instead of generating one
+    #[arg(long)]
+    pub(crate) max: bool,
+
+    /// Print UUIDs as 22 base57 characters (the Python `shortuuid` form)
+    #[arg(long)]
+    pub(crate) short: bool,
+
+    /// Print UUIDs as Microsoft GUIDs: braced, uppercase, and with the bytes of `--format`
+    /// encodings and literals and of `--also-format` in GUID (mixed-endian) order
+    #[arg(long, conflicts_with = "short")]
+    pub(crate) guid: bool,
+
+    /// Print UUIDs in uppercase hex digits (or base32 symbols), like
+    /// 550E8400-E29B-41D4-A716-446655440000
+    #[arg(long, visible_alias = "upper", conflicts_with = "short")]
+    pub(crate) uppercase: bool,
+
+    /// Print UUIDs as 32 hex digits without hyphens, like 550e8400e29b41d4a716446655440000
+    #[arg(long, conflicts_with_all = ["short", "format"])]
+    pub(crate) simple: bool,
+
+    /// Print UUIDs as RFC 4122 URNs, like urn:uuid:550e8400-e29b-41d4-a716-446655440000
+    #[arg(long, conflicts_with_all = ["short", "format", "simple"])]
+    pub(crate) urn: bool,
+
+    /// Print UUIDs in braces, like {550e8400-e29b-41d4-a716-446655440000}
+    #[arg(long, conflicts_with_all = ["short", "format", "simple", "urn"])]
+    pub(crate) braces: bool,
+
+    /// Print UUIDs in this form
+    #[arg(
+        long,
+        value_enum,
+        default_value = "canonical",
+        conflicts_with = "short"
+    )]
+    pub(crate) format: IdFormat,
+
+    /// Write the raw 16 bytes of every UUID instead of text, without newlines, for
+    /// fixed-size binary records, in GUID order with `--guid` (refused on a terminal unless
+    /// `--force`)
+    #[arg(
+        long,
+        conflicts_with_all = ["short", "uppercase", "simple", "urn", "braces", "format"]
+    )]
+    pub(crate) binary: bool,
+
+    /// Write `--binary` UUIDs even when stdout is a terminal
+    #[arg(long, requires = "binary")]
+    pub(crate) force: bool,
+}
+
+impl UuidArgs {
+    /// Returns the name of name-based UUIDs, given inline (as text or hex) or read from a
+    /// file.
+    #[cfg_attr(not(feature = "uuid"), allow(dead_code))]
+    pub(crate) fn name_bytes(&self) -> Option<&[u8]> {
+        match (&self.name, &self.name_hex, &self.name_file) {
+            (Some(name), _, _) => Some(name.as_bytes()),
+            (None, Some(NameBytes(bytes)), _) | (None, None, Some(NameBytes(bytes))) => Some(bytes),
+            (None, None, None) => None,
+        }
+    }
+
+    /// Returns the `--data` of version 8 UUIDs, zero-extended at the `--data-pad` end.
+    #[cfg_attr(not(feature = "uuid"), allow(dead_code))]
+    pub(crate) fn data_bytes(&self) -> Option<[u8; 16]> {
+        self.data.as_ref().map(|data| {
+            utils::parse_data(data, self.data_pad).expect("data is validated when parsing")
+        })
+    }
+
+    /// Returns the bits left out by short `--data` that are random with `--data-fill
+    /// random` (none otherwise).
+    #[cfg_attr(not(feature = "uuid"), allow(dead_code))]
+    pub(crate) fn data_random_mask(&self) -> [u8; 16] {
+        match (&self.data, self.data_fill) {
+            (Some(data), DataFill::Random) => {
+                utils::data_mask(data, self.data_pad).map(|byte| !byte)
+            }
+            _ => [0; 16],
+        }
+    }
+
+    /// Returns the arguments of version 8 UUIDs of the given data, read with
+    /// `--data-stdin`.
+    pub(crate) fn with_data(&self, data: [u8; 16]) -> Self {
+        Self {
+            data: Some(hex::encode(data)),
+            data_stdin: false,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the arguments of version 1 and 6 UUIDs continuing from the clock sequence
+    /// of a `--state-file`.
+    pub(crate) fn with_clock_seq(&self, clock_seq: u16) -> Self {
+        Self {
+            clock_seq: Some(clock_seq),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the arguments deriving a single UUID from the given name, for each name
+    /// read with `--names-stdin`.
+    pub(crate) fn with_name(&self, name: String) -> Self {
+        Self {
+            name: Some(name),
+            names_stdin: false,
+            ..self.clone()
+        }
+    }
+}
 
 #[allow(clippy::upper_case_acronyms)]
-#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default)]
 pub(crate) enum SupportedUUIDVersion {
     #[value(name = "1")]
     V1 = 1,
     #[value(name = "3")]
     V3 = 3,
     #[value(name = "4")]
+    #[default]
     V4 = 4,
     #[value(name = "5")]
     V5 = 5,
@@ -35,13 +326,195 @@ impl fmt::Display for SupportedUUIDVersion {
     }
 }
 
+/// Parser of `--version`, taking the bare digits that `--help` lists, or the digits
+/// prefixed with `v` or `V` (like `v7`), as other tools spell versions.
+#[derive(Clone)]
+pub(crate) struct VersionArg;
+
+impl TypedValueParser for VersionArg {
+    type Value = SupportedUUIDVersion;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let version = value.to_str().and_then(|value| {
+            let digits = value.strip_prefix(['v', 'V']).unwrap_or(value);
+            SupportedUUIDVersion::from_str(digits, false).ok()
+        });
+
+        version.ok_or_else(|| {
+            let spellings = SupportedUUIDVersion::value_variants()
+                .iter()
+                .flat_map(|version| {
+                    [
+                        format!("{version}"),
+                        format!("v{version}"),
+                        format!("V{version}"),
+                    ]
+                })
+                .collect();
+
+            let mut err = clap::Error::new(ErrorKind::InvalidValue).with_cmd(cmd);
+            err.insert(
+                ContextKind::InvalidArg,
+                ContextValue::String(
+                    arg.map_or_else(|| "--version".to_owned(), ToString::to_string),
+                ),
+            );
+            err.insert(
+                ContextKind::InvalidValue,
+                ContextValue::String(value.to_string_lossy().into_owned()),
+            );
+            err.insert(ContextKind::ValidValue, ContextValue::Strings(spellings));
+            err
+        })
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            SupportedUUIDVersion::value_variants()
+                .iter()
+                .filter_map(ValueEnum::to_possible_value),
+        ))
+    }
+}
+
+/// Bytes of a `--name-hex` or the contents of a `--name-file`, hashed as they are
+/// (untrimmed, and not necessarily text).
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct NameBytes(Vec<u8>);
+
+/// Decodes a `--name-hex` (see [`utils::parse_name_hex`]).
+pub(crate) fn parse_name_hex(value: &str) -> anyhow::Result<NameBytes> {
+    utils::parse_name_hex(value).map(NameBytes)
+}
+
+/// Reads a `--name-file` whole.
+pub(crate) fn parse_name_file(path: &str) -> anyhow::Result<NameBytes> {
+    std::fs::read(path)
+        .map(NameBytes)
+        .map_err(|e| anyhow!("failed to read {path}: {e}"))
+}
+
+/// End at which short version 8 `--data` is zero-extended.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum DataPad {
+    /// Leading zeros, as for a number (`1` sets the last byte)
+    Left,
+    /// Trailing zeros, as for a prefix (`1` sets the first byte)
+    #[default]
+    Right,
+}
+
+/// Fill of the bytes left out by short version 8 `--data`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum DataFill {
+    /// Zeros, the same for every UUID
+    #[default]
+    Zero,
+    /// Random bits, drawn for every UUID
+    Random,
+}
+
+/// Node identifiers of the version 1 and 6 UUIDs of a run.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum NodeIdMode {
+    /// One node id for the whole run (`--node-id`, or a pseudo MAC address)
+    #[default]
+    Fixed,
+    /// A fresh pseudo MAC address for every UUID, so that UUIDs of a run cannot be
+    /// correlated by their node
+    PerId,
+}
+
+/// Precision of the timestamps of version 7 UUIDs.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum TimestampPrecision {
+    /// Milliseconds, followed by a counter keeping the UUIDs of a millisecond sorted
+    #[default]
+    Ms,
+    /// Microseconds, as a 12-bit fraction of the millisecond after the version
+    Us,
+    /// Nanoseconds, as a 12-bit fraction of the millisecond after the version (about
+    /// 244 ns a step)
+    Ns,
+}
+
+/// Hash functions of name-based version 8 UUIDs.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub(crate) enum SupportedUUIDHash {
+    /// SHA-256, as in the name-based example of RFC 9562
+    #[value(name = "sha256")]
+    Sha256,
+}
+
+/// Largest clock sequence of version 1 and 6 UUIDs (14 bits).
+pub(crate) const MAX_CLOCK_SEQ: u16 = (1 << 14) - 1;
+
+/// Largest number of hex digits in a vanity prefix.
+///
+/// Every further digit makes the search 16 times longer, and 8 digits (the first group of
+/// the canonical form) already take billions of candidates.
+pub(crate) const MAX_PREFIX_DIGITS: u32 = 8;
+
+/// Hex prefix a version 4 UUID must start with, as the value of its first digits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct UuidPrefix {
+    value: u32,
+    digits: u32,
+}
+
+#[cfg_attr(not(feature = "uuid"), allow(dead_code))]
+impl UuidPrefix {
+    /// Returns the number of hex digits of the prefix.
+    pub(crate) fn digits(&self) -> u32 {
+        self.digits
+    }
+
+    /// Returns whether the first 32 bits of a UUID start with the prefix.
+    pub(crate) fn matches(&self, head: u32) -> bool {
+        head >> (32 - 4 * self.digits) == self.value
+    }
+}
+
+impl fmt::Display for UuidPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:0width$x}", self.value, width = self.digits as usize)
+    }
+}
+
+/// Parses a vanity prefix of 1 to 8 hex digits (in either case).
+pub(crate) fn parse_prefix(value: &str) -> anyhow::Result<UuidPrefix> {
+    if let Some(c) = value.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(anyhow!("prefix contains {c:?}, which is not a hex digit"));
+    }
+
+    let digits = value.len() as u32;
+    match digits {
+        0 => Err(anyhow!("prefix is empty")),
+        1..=MAX_PREFIX_DIGITS => Ok(UuidPrefix {
+            value: u32::from_str_radix(value, 16).expect("hex digits"),
+            digits,
+        }),
+        _ => Err(anyhow!(
+            "prefix has {digits} hex digits, more than {MAX_PREFIX_DIGITS} (longer ones make \
+             the search impractical)"
+        )),
+    }
+}
+
 #[allow(clippy::upper_case_acronyms)]
-#[derive(clap::ValueEnum, Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) enum SupportedUUIDNamespace {
     DNS,
     OID,
     URL,
     X500,
+    /// A namespace of its own, like a company-wide one
+    Custom(uuid::Uuid),
 }
 
 impl From<&SupportedUUIDNamespace> for uuid::Uuid {
@@ -51,6 +524,93 @@ impl From<&SupportedUUIDNamespace> for uuid::Uuid {
             SupportedUUIDNamespace::OID => uuid::Uuid::NAMESPACE_OID,
             SupportedUUIDNamespace::URL => uuid::Uuid::NAMESPACE_URL,
             SupportedUUIDNamespace::X500 => uuid::Uuid::NAMESPACE_X500,
+            SupportedUUIDNamespace::Custom(uuid) => *uuid,
+        }
+    }
+}
+
+/// Parses a namespace: the name of a standard one, or a UUID (in any form the `uuid`
+/// crate parses).
+pub(crate) fn parse_namespace(value: &str) -> anyhow::Result<SupportedUUIDNamespace> {
+    match value {
+        "dns" => Ok(SupportedUUIDNamespace::DNS),
+        "oid" => Ok(SupportedUUIDNamespace::OID),
+        "url" => Ok(SupportedUUIDNamespace::URL),
+        "x500" => Ok(SupportedUUIDNamespace::X500),
+        _ => uuid::Uuid::try_parse(value)
+            .map(SupportedUUIDNamespace::Custom)
+            .map_err(|e| anyhow!("expected dns, oid, url, x500, or a UUID ({e})")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_namespace() {
+        assert_eq!(parse_namespace("dns").unwrap(), SupportedUUIDNamespace::DNS);
+        assert_eq!(
+            parse_namespace("x500").unwrap(),
+            SupportedUUIDNamespace::X500
+        );
+        assert_eq!(
+            parse_namespace("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap(),
+            SupportedUUIDNamespace::Custom(uuid::Uuid::NAMESPACE_DNS)
+        );
+    }
+
+    #[test]
+    fn test_parse_namespace_errors() {
+        for value in ["DNS", "example", "6ba7b810-9dad-11d1-80b4-00c04fd430c"] {
+            assert!(
+                parse_namespace(value)
+                    .unwrap_err()
+                    .to_string()
+                    .starts_with("expected dns, oid, url, x500, or a UUID ("),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        let prefix = parse_prefix("CaFe").unwrap();
+
+        assert_eq!(prefix.digits(), 4);
+        assert_eq!(prefix.to_string(), "cafe");
+        assert!(prefix.matches(0xcafe_1234));
+        assert!(!prefix.matches(0xcaff_1234));
+    }
+
+    #[test]
+    fn test_parse_prefix_keeps_leading_zeros() {
+        let prefix = parse_prefix("0000000").unwrap();
+
+        assert_eq!(prefix.to_string(), "0000000");
+        assert!(prefix.matches(0x0000_000f));
+        assert!(!prefix.matches(0x0000_001f));
+    }
+
+    #[test]
+    fn test_parse_prefix_errors() {
+        for (value, message) in [
+            ("", "prefix is empty"),
+            (
+                "caf\u{e9}",
+                "prefix contains '\u{e9}', which is not a hex digit",
+            ),
+            ("0x12", "prefix contains 'x', which is not a hex digit"),
+            (
+                "123456789",
+                "prefix has 9 hex digits, more than 8 (longer ones make the search impractical)",
+            ),
+        ] {
+            assert_eq!(
+                parse_prefix(value).unwrap_err().to_string(),
+                message,
+                "{value}"
+            );
         }
     }
 }
@@ -4,195 +4,2092 @@
 //! declarative API. Each validation rule checks argument combinations and returns
 //! a [`ValidationError`] if the combination is invalid.
 
-use super::Commands;
-use super::uuid::SupportedUUIDVersion;
+use super::composite::{self, CompositeField};
+use super::int::IntBits;
+use super::uuid::{MAX_CLOCK_SEQ, NodeIdMode, SupportedUUIDVersion, TimestampPrecision, UuidArgs};
+use super::{AlsoFormat, Commands, IdFormat, TimeMode};
+use crate::generators::{snowflake, tsid};
+use crate::utils;
 
 /// Validation errors for argument combinations that are invalid.
 ///
 /// These errors are converted to `clap` errors in the CLI parsing flow,
 /// ensuring users see error messages consistent with `clap`'s style.
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug)]
 pub(super) enum ValidationError {
     /// Timestamp argument used with incompatible UUID version.
     ///
     /// Only UUID versions 1, 6, and 7 support custom timestamps.
     UuidTimestampVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Node identifier argument used with incompatible UUID version.
+    ///
+    /// Only UUID versions 1 and 6 embed node ids; the others would silently ignore them.
+    UuidNodeIdVersionMismatch {
+        version: SupportedUUIDVersion,
+        arg: &'static str,
+    },
+
+    /// Timestamp argument outside the range of a Gregorian UUID version.
+    ///
+    /// UUID versions 1 and 6 store 60-bit counts of 100-nanosecond intervals since
+    /// 1582-10-15, which run out in 5236.
+    UuidTimestampOutOfRange { version: SupportedUUIDVersion },
+
+    /// Arguments a UUID version derives its UUIDs from, left out.
+    ///
+    /// Versions 3 and 5 need a namespace and a name, and version 8 its data or hash.
+    /// They are resolved from the parsed version, whichever way `--version` spells it.
+    UuidMissingArguments { args: Vec<&'static str> },
+
+    /// Data read from stdin with incompatible UUID version.
+    ///
+    /// Only UUID version 8 takes custom data; the others would consume stdin for
+    /// nothing.
+    UuidDataStdinVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Clock sequence argument used with incompatible UUID version.
+    ///
+    /// Only UUID versions 1 and 6 have a clock sequence.
+    UuidClockSeqVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Clock sequence argument beyond the 14 bits of UUID versions 1 and 6.
+    UuidClockSeqOutOfRange { clock_seq: u16 },
+
+    /// Special value exclusion used with incompatible UUID version.
+    ///
+    /// Only UUID version 4 is random enough to produce special-looking values.
+    UuidExcludeSpecialVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Hash function used with incompatible UUID version.
+    ///
+    /// Only version 8 leaves the hash of name-based UUIDs up to the application
+    /// (versions 3 and 5 fix MD5 and SHA-1).
+    UuidHashVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Namespace or name used without a name-based UUID version.
+    ///
+    /// Only versions 3 and 5, and version 8 with a hash function, derive UUIDs from
+    /// names; the others would silently ignore them. Derived namespaces, and names given
+    /// in hex or read from a file or stdin, are for versions 3 and 5 only.
+    UuidNameVersionMismatch {
+        version: SupportedUUIDVersion,
+        arg: &'static str,
+    },
+
+    /// Empty names skipped without reading names from stdin.
+    ///
+    /// Only `--names-stdin` reads lines that could be empty.
+    UuidSkipEmptyWithoutNamesStdin,
+
+    /// Vanity prefix used with incompatible UUID version.
+    ///
+    /// Only version 4 UUIDs start with random bits to search through.
+    UuidStartsWithVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Per-millisecond quota used with incompatible UUID version.
+    ///
+    /// Only UUID version 7 has a millisecond timestamp to step through.
+    UuidPerMsVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Clock sequence state file used with incompatible UUID version.
+    ///
+    /// Only versions 1 and 6 have a clock sequence to keep across runs.
+    UuidStateFileVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Sub-millisecond timestamp precision used with incompatible UUID version.
+    ///
+    /// Only UUID version 7 has the millisecond timestamp whose fraction it adds; versions
+    /// 1 and 6 already count 100-nanosecond intervals.
+    UuidPrecisionVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Node id drawn for every UUID with incompatible UUID version.
+    ///
+    /// Only versions 1 and 6 embed node ids; the others would silently ignore it.
+    UuidNodeIdModeVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Node id drawn for every UUID along with a node id of its own.
+    ///
+    /// A given or hardware node id is the same for every UUID, so it cannot also be a
+    /// fresh one each time.
+    UuidNodeIdModeConflict { arg: &'static str },
+
+    /// Uppercase UUIDs requested in a form without letters to raise.
+    ///
+    /// Only the hex text forms and base32 have a case; the other forms are either
+    /// case-sensitive (base64, Z85) or have no letters to raise (integers, words, code
+    /// literals).
+    UuidUppercaseFormatMismatch { format: IdFormat },
+
+    /// Banned substrings leaving too few alphabet symbols for short codes.
+    ///
+    /// Single-character bans remove symbols, and at least two are needed to tell
+    /// codes apart.
+    ShortcodeAlphabetExhausted { remaining: usize },
+
+    /// Snowflake timestamp outside the range of its epoch.
+    ///
+    /// Snowflakes store 41-bit millisecond offsets from the epoch, so timestamps must
+    /// not precede it nor be more than about 69 years past it.
+    SnowflakeTimestampOutOfRange { epoch: u64 },
+
+    /// Composite identifier timestamp outside the range of its epoch.
+    ///
+    /// The time field of the layout stores millisecond offsets from the epoch.
+    CompositeTimestampOutOfRange { epoch: u64, bits: u32 },
+
+    /// Composite identifier shard too large for the shard field of the layout.
+    CompositeShardOutOfRange { bits: u32 },
+
+    /// Composite identifier shard given without a shard field in the layout.
+    CompositeShardWithoutField,
+
+    /// TSID timestamp outside the range of its epoch.
+    ///
+    /// TSIDs store 42-bit millisecond offsets from the epoch, so timestamps must not
+    /// precede it nor be more than about 139 years past it.
+    TsidTimestampOutOfRange { epoch: u64 },
+
+    /// MAC address OUI with a bit contradicting the multicast or universal flag.
+    ///
+    /// An OUI pins the first byte, and with it the multicast and locally administered
+    /// bits, so a flag asking for the other value could not be honored.
+    MacOuiBitMismatch {
+        oui: [u8; 3],
+        flag: &'static str,
+        given: bool,
+    },
+
+    /// Integer ID bound outside the range of the width.
+    ///
+    /// Bounds narrow the range of the width (and signedness), so they must lie within it.
+    IntBoundOutOfRange {
+        arg: &'static str,
+        value: i128,
+        bits: IntBits,
+        signed: bool,
+    },
+
+    /// Integer ID minimum above the maximum.
+    IntBoundsReversed { min: i128, max: i128 },
+
+    /// Integer ID range with fewer than two values to draw from.
+    ///
+    /// A single value (or none, once zero is excluded) would make every ID the same.
+    IntRangeTooSmall { min: i128, max: i128, nonzero: bool },
+
+    /// Nil or max UUID requested along with generation options.
+    ///
+    /// The nil and max UUIDs are constants, so no version or other UUID option
+    /// applies to them (and only one of them can be printed).
+    UuidConstantConflict {
+        constant: &'static str,
+        arg: &'static str,
+    },
+
+    /// Time mode used with a UUID version that does not read the clock.
+    ///
+    /// Only UUID versions 1, 6, and 7 embed timestamps.
+    TimeModeUuidVersionMismatch { version: SupportedUUIDVersion },
+
+    /// Time mode used with a command that does not read the clock.
+    TimeModeCommandMismatch,
+
+    /// Per-identifier time mode used with an argument fixing the time.
+    ///
+    /// A given timestamp (or the starting point of a per-millisecond quota) is the
+    /// same for every identifier, so there is no clock to read per identifier.
+    TimeModePerIdWithFixedTime { arg: &'static str },
+
+    /// Extra formats used with a command without canonical identifier bytes.
+    ///
+    /// Only UUIDs, ULIDs, and ObjectIds decode back into the bytes the extra formats
+    /// render.
+    AlsoFormatCommandMismatch,
+
+    /// Extra formats used with short UUIDs.
+    ///
+    /// The short form already is another rendering, so the canonical one comes first.
+    AlsoFormatShortUuid,
+
+    /// Extra formats used with binary UUIDs.
+    ///
+    /// Raw bytes have no lines to add columns to.
+    AlsoFormatBinaryUuid,
+
+    /// Extra formats used with word sequences or code literals.
+    ///
+    /// Like the short form, words and literals are other renderings of the same bytes.
+    AlsoFormatWords,
+
+    /// Command whose generator was left out of the build.
+    ///
+    /// Minimal builds compile out some generators (and their dependencies) by feature,
+    /// keeping their subcommands hidden rather than unknown.
+    CommandNotCompiledIn { feature: &'static str },
+}
+
+/// Validates parsed CLI arguments for complex rules.
+///
+/// This function orchestrates all validation rules and returns the first
+/// error encountered, or `Ok(())` if all validations pass.
+pub(super) fn validate_args(commands: &Commands) -> Result<(), ValidationError> {
+    validate_command_compiled_in(commands)?;
+    validate_uuid_required_arguments(commands)?;
+    validate_uuid_constant_compatibility(commands)?;
+    validate_uuid_timestamp_compatibility(commands)?;
+    validate_uuid_node_id_compatibility(commands)?;
+    validate_uuid_name_compatibility(commands)?;
+    validate_uuid_skip_empty(commands)?;
+    validate_uuid_timestamp_range(commands)?;
+    validate_uuid_clock_seq(commands)?;
+    validate_uuid_state_file_compatibility(commands)?;
+    validate_uuid_data_stdin_compatibility(commands)?;
+    validate_uuid_exclude_special_compatibility(commands)?;
+    validate_uuid_starts_with_compatibility(commands)?;
+    validate_uuid_per_ms_compatibility(commands)?;
+    validate_uuid_precision_compatibility(commands)?;
+    validate_uuid_node_id_mode_compatibility(commands)?;
+    validate_uuid_uppercase_compatibility(commands)?;
+    validate_shortcode_alphabet(commands)?;
+    validate_snowflake_timestamp_range(commands)?;
+    validate_composite_ranges(commands)?;
+    validate_tsid_timestamp_range(commands)?;
+    validate_int_range(commands)?;
+    validate_mac_oui_bits(commands)?;
+    // TODO: future validation rules go here
+    Ok(())
+}
+
+/// Validates that UUID versions 3, 5, and 8 get the arguments they derive UUIDs from.
+fn validate_uuid_required_arguments(commands: &Commands) -> Result<(), ValidationError> {
+    let Commands::Uuid(args) = commands else {
+        return Ok(());
+    };
+
+    let named = args.name.is_some()
+        || args.name_hex.is_some()
+        || args.name_file.is_some()
+        || args.names_stdin;
+    let v8_input = args.data.is_some() || args.data_stdin || args.hash.is_some();
+
+    let missing: Vec<&'static str> = match args.version {
+        SupportedUUIDVersion::V3 | SupportedUUIDVersion::V5 => [
+            (args.namespace.is_none(), "--namespace <NAMESPACE>"),
+            (
+                !named,
+                "<--name <NAME>|--name-hex <HEX>|--name-file <PATH>|--names-stdin>",
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(missing, arg)| missing.then_some(arg))
+        .collect(),
+        SupportedUUIDVersion::V8 if !v8_input => {
+            vec!["<--data <DATA>|--data-stdin|--hash <HASH>>"]
+        }
+        _ => Vec::new(),
+    };
+
+    match missing.is_empty() {
+        true => Ok(()),
+        false => Err(ValidationError::UuidMissingArguments { args: missing }),
+    }
+}
+
+/// Validates that the generator of the command is compiled in.
+fn validate_command_compiled_in(commands: &Commands) -> Result<(), ValidationError> {
+    let (feature, compiled_in) = match commands {
+        Commands::Uuid(_) => ("uuid", cfg!(feature = "uuid")),
+        Commands::Ulid { .. } => ("ulid", cfg!(feature = "ulid")),
+        Commands::ObjectId { .. } => ("objectid", cfg!(feature = "objectid")),
+        _ => return Ok(()),
+    };
+
+    match compiled_in {
+        true => Ok(()),
+        false => Err(ValidationError::CommandNotCompiledIn { feature }),
+    }
+}
+
+/// Validates that the nil and max UUIDs are not combined with generation options.
+///
+/// The version has a default, so only versions other than the default one count as
+/// given explicitly.
+fn validate_uuid_constant_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    let Commands::Uuid(args) = commands else {
+        return Ok(());
+    };
+
+    let constant = match (args.nil, args.max) {
+        (true, _) => "--nil",
+        (false, true) => "--max",
+        (false, false) => return Ok(()),
+    };
+
+    let conflicts = [
+        (args.nil && args.max, "--max"),
+        (
+            !matches!(args.version, SupportedUUIDVersion::V4),
+            "--version <VERSION>",
+        ),
+        (args.timestamp.is_some(), "--timestamp <TIMESTAMP>"),
+        (args.clock_seq.is_some(), "--clock-seq <NUMBER>"),
+        (args.state_file.is_some(), "--state-file <PATH>"),
+        (args.namespace.is_some(), "--namespace <NAMESPACE>"),
+        (args.name.is_some(), "--name <NAME>"),
+        (args.name_hex.is_some(), "--name-hex <HEX>"),
+        (
+            args.namespace_name.is_some(),
+            "--namespace-name <NAMESPACE_NAME>",
+        ),
+        (args.name_file.is_some(), "--name-file <PATH>"),
+        (args.names_stdin, "--names-stdin"),
+        (args.node_id.is_some(), "--node-id <NODE_ID>"),
+        (args.hardware_node, "--hardware-node"),
+        (
+            args.node_id_mode == NodeIdMode::PerId,
+            "--node-id-mode <MODE>",
+        ),
+        (args.data.is_some(), "--data <DATA>"),
+        (args.data_stdin, "--data-stdin"),
+        (args.hash.is_some(), "--hash <HASH>"),
+        (args.exclude_special, "--exclude-special"),
+        (args.starts_with.is_some(), "--starts-with <HEX>"),
+        (args.per_ms.is_some(), "--per-ms <NUMBER>"),
+        (
+            args.precision != TimestampPrecision::Ms,
+            "--precision <PRECISION>",
+        ),
+    ];
+
+    if let Some((_, arg)) = conflicts.iter().find(|(conflict, _)| *conflict) {
+        return Err(ValidationError::UuidConstantConflict { constant, arg });
+    }
+
+    Ok(())
+}
+
+/// Validates that UUID timestamps are only used with compatible versions.
+///
+/// Only UUID versions 1, 6, and 7 support custom timestamps. Other versions
+/// (v3, v4, v5, v8) do not use timestamps in their generation algorithm.
+fn validate_uuid_timestamp_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && args.timestamp.is_some()
+        && !matches!(
+            args.version,
+            SupportedUUIDVersion::V1 | SupportedUUIDVersion::V6 | SupportedUUIDVersion::V7
+        )
+    {
+        return Err(ValidationError::UuidTimestampVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that UUID node ids are only used with compatible versions.
+///
+/// Only UUID versions 1 and 6 embed a node id (a MAC address); the others are random,
+/// name-based, or custom throughout.
+fn validate_uuid_node_id_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    let Commands::Uuid(args) = commands else {
+        return Ok(());
+    };
+
+    let arg = match (args.node_id.is_some(), args.hardware_node) {
+        (true, _) => "--node-id <NODE_ID>",
+        (false, true) => "--hardware-node",
+        (false, false) => return Ok(()),
+    };
+
+    if !matches!(
+        args.version,
+        SupportedUUIDVersion::V1 | SupportedUUIDVersion::V6
+    ) {
+        return Err(ValidationError::UuidNodeIdVersionMismatch {
+            version: args.version,
+            arg,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that UUID namespaces, names, and hashes are only used to derive UUIDs from
+/// names.
+///
+/// Versions 3 and 5 always hash names (with MD5 and SHA-1), and version 8 does with
+/// `--hash` (instead of taking `--data`). Namespaces are derived from names, and names
+/// given in hex or read from a file or stdin, for versions 3 and 5 only.
+fn validate_uuid_name_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    let Commands::Uuid(args) = commands else {
+        return Ok(());
+    };
+
+    let names_arg = [
+        (
+            args.namespace_name.is_some(),
+            "--namespace-name <NAMESPACE_NAME>",
+        ),
+        (args.name_hex.is_some(), "--name-hex <HEX>"),
+        (args.name_file.is_some(), "--name-file <PATH>"),
+        (args.names_stdin, "--names-stdin"),
+    ]
+    .into_iter()
+    .find_map(|(given, arg)| given.then_some(arg));
+
+    if let Some(arg) = names_arg
+        && !matches!(
+            args.version,
+            SupportedUUIDVersion::V3 | SupportedUUIDVersion::V5
+        )
+    {
+        return Err(ValidationError::UuidNameVersionMismatch {
+            version: args.version,
+            arg,
+        });
+    }
+
+    if args.hash.is_some() && !matches!(args.version, SupportedUUIDVersion::V8) {
+        return Err(ValidationError::UuidHashVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    let name_based = match args.version {
+        SupportedUUIDVersion::V3 | SupportedUUIDVersion::V5 => true,
+        SupportedUUIDVersion::V8 => args.hash.is_some(),
+        _ => false,
+    };
+
+    let arg = match (args.namespace.is_some(), args.name.is_some()) {
+        (true, _) => "--namespace <NAMESPACE>",
+        (false, true) => "--name <NAME>",
+        (false, false) => return Ok(()),
+    };
+
+    if name_based {
+        return Ok(());
+    }
+
+    Err(ValidationError::UuidNameVersionMismatch {
+        version: args.version,
+        arg,
+    })
+}
+
+/// Validates that UUID data is only read from stdin for version 8.
+fn validate_uuid_data_stdin_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && args.data_stdin
+        && !matches!(args.version, SupportedUUIDVersion::V8)
+    {
+        return Err(ValidationError::UuidDataStdinVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that empty names are only skipped when reading names from stdin.
+fn validate_uuid_skip_empty(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && args.skip_empty
+        && !args.names_stdin
+    {
+        return Err(ValidationError::UuidSkipEmptyWithoutNamesStdin);
+    }
+
+    Ok(())
+}
+
+/// Validates that UUID clock sequences are only used with versions 1 and 6, and fit into
+/// their 14 bits.
+fn validate_uuid_clock_seq(commands: &Commands) -> Result<(), ValidationError> {
+    let Commands::Uuid(args) = commands else {
+        return Ok(());
+    };
+    let Some(clock_seq) = args.clock_seq else {
+        return Ok(());
+    };
+
+    if !matches!(
+        args.version,
+        SupportedUUIDVersion::V1 | SupportedUUIDVersion::V6
+    ) {
+        return Err(ValidationError::UuidClockSeqVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    if clock_seq > MAX_CLOCK_SEQ {
+        return Err(ValidationError::UuidClockSeqOutOfRange { clock_seq });
+    }
+
+    Ok(())
+}
+
+/// Validates that UUID v1/v6 timestamps fit into the Gregorian timestamp range.
+///
+/// Timestamps are parsed as generic Unix timestamps, so ones past the 60-bit range of
+/// these versions would otherwise silently overflow during the conversion.
+fn validate_uuid_timestamp_range(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && let Some(timestamp) = args.timestamp
+        && matches!(
+            args.version,
+            SupportedUUIDVersion::V1 | SupportedUUIDVersion::V6
+        )
+        && utils::unix_to_gregorian_ticks(timestamp).is_none()
+    {
+        return Err(ValidationError::UuidTimestampOutOfRange {
+            version: args.version,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that UUID special value exclusion is only used with version 4.
+///
+/// Other versions either embed timestamps and node identifiers, or are derived
+/// deterministically from the input, so the exclusion does not apply to them.
+fn validate_uuid_exclude_special_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && args.exclude_special
+        && !matches!(args.version, SupportedUUIDVersion::V4)
+    {
+        return Err(ValidationError::UuidExcludeSpecialVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that UUID vanity prefixes are only used with version 4.
+///
+/// The other versions start with timestamps or hashes, which a search could not steer
+/// (or only by forging the time).
+fn validate_uuid_starts_with_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && args.starts_with.is_some()
+        && !matches!(args.version, SupportedUUIDVersion::V4)
+    {
+        return Err(ValidationError::UuidStartsWithVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that UUID per-millisecond quotas are only used with version 7.
+///
+/// Other time-based versions (v1, v6) use 100-nanosecond intervals rather than
+/// milliseconds, so stepping them in whole milliseconds does not apply.
+fn validate_uuid_per_ms_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && args.per_ms.is_some()
+        && !matches!(args.version, SupportedUUIDVersion::V7)
+    {
+        return Err(ValidationError::UuidPerMsVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that clock sequence state files are only used with versions 1 and 6.
+fn validate_uuid_state_file_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && args.state_file.is_some()
+        && !matches!(
+            args.version,
+            SupportedUUIDVersion::V1 | SupportedUUIDVersion::V6
+        )
+    {
+        return Err(ValidationError::UuidStateFileVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that sub-millisecond UUID timestamp precisions are only used with version 7.
+fn validate_uuid_precision_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && args.precision != TimestampPrecision::Ms
+        && !matches!(args.version, SupportedUUIDVersion::V7)
+    {
+        return Err(ValidationError::UuidPrecisionVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that node ids drawn for every UUID are only used with versions 1 and 6, and
+/// without a node id of their own.
+fn validate_uuid_node_id_mode_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    let Commands::Uuid(args) = commands else {
+        return Ok(());
+    };
+
+    if args.node_id_mode != NodeIdMode::PerId {
+        return Ok(());
+    }
+
+    if !matches!(
+        args.version,
+        SupportedUUIDVersion::V1 | SupportedUUIDVersion::V6
+    ) {
+        return Err(ValidationError::UuidNodeIdModeVersionMismatch {
+            version: args.version,
+        });
+    }
+
+    match (args.node_id.is_some(), args.hardware_node) {
+        (true, _) => Err(ValidationError::UuidNodeIdModeConflict {
+            arg: "--node-id <NODE_ID>",
+        }),
+        (false, true) => Err(ValidationError::UuidNodeIdModeConflict {
+            arg: "--hardware-node",
+        }),
+        (false, false) => Ok(()),
+    }
+}
+
+/// Validates that uppercase UUIDs are only printed in the canonical form or base32.
+fn validate_uuid_uppercase_compatibility(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Uuid(args) = commands
+        && args.uppercase
+        && !matches!(args.format, IdFormat::Canonical | IdFormat::Base32)
+    {
+        return Err(ValidationError::UuidUppercaseFormatMismatch {
+            format: args.format,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that banned substrings leave at least two short code alphabet symbols.
+fn validate_shortcode_alphabet(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Shortcode(args) = commands
+        && let Some(banned) = &args.exclude_substrings
+    {
+        let remaining = banned.filter_symbols(args.alphabet.symbols()).len();
+
+        if remaining < 2 {
+            return Err(ValidationError::ShortcodeAlphabetExhausted { remaining });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that snowflake timestamps fit into the 41 bits after the epoch.
+///
+/// The epoch is configurable, so the range can only be checked once both are known.
+fn validate_snowflake_timestamp_range(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Snowflake {
+        epoch,
+        timestamp: Some(timestamp),
+        ..
+    } = commands
+        && timestamp
+            .checked_sub(*epoch)
+            .is_none_or(|offset| offset > snowflake::MAX_OFFSET)
+    {
+        return Err(ValidationError::SnowflakeTimestampOutOfRange { epoch: *epoch });
+    }
+
+    Ok(())
+}
+
+/// Validates that composite identifier shards and timestamps fit into their fields.
+///
+/// The widths of the fields come from the layout, so the ranges can only be checked once
+/// the layout is parsed.
+fn validate_composite_ranges(commands: &Commands) -> Result<(), ValidationError> {
+    let Commands::Composite {
+        layout,
+        epoch,
+        shard,
+        timestamp,
+    } = commands
+    else {
+        return Ok(());
+    };
+
+    if let Some(shard) = shard {
+        let bits = layout
+            .field_bits(CompositeField::Shard)
+            .ok_or(ValidationError::CompositeShardWithoutField)?;
+
+        if *shard > composite::max_field_value(bits) {
+            return Err(ValidationError::CompositeShardOutOfRange { bits });
+        }
+    }
+
+    let bits = layout
+        .field_bits(CompositeField::Time)
+        .expect("layouts have a time field by parsing");
+
+    if let Some(timestamp) = timestamp
+        && timestamp
+            .checked_sub(*epoch)
+            .is_none_or(|offset| offset > composite::max_field_value(bits))
+    {
+        return Err(ValidationError::CompositeTimestampOutOfRange {
+            epoch: *epoch,
+            bits,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that TSID timestamps fit into the 42 bits after the epoch.
+fn validate_tsid_timestamp_range(commands: &Commands) -> Result<(), ValidationError> {
+    if let Commands::Tsid {
+        epoch,
+        timestamp: Some(timestamp),
+        ..
+    } = commands
+        && timestamp
+            .checked_sub(*epoch)
+            .is_none_or(|offset| offset > tsid::MAX_OFFSET)
+    {
+        return Err(ValidationError::TsidTimestampOutOfRange { epoch: *epoch });
+    }
+
+    Ok(())
 }
 
-/// Validates parsed CLI arguments for complex rules.
-///
-/// This function orchestrates all validation rules and returns the first
-/// error encountered, or `Ok(())` if all validations pass.
-pub(super) fn validate_args(commands: &Commands) -> Result<(), ValidationError> {
-    validate_uuid_timestamp_compatibility(commands)?;
-    // TODO: future validation rules go here
-    Ok(())
-}
+/// Validates that integer ID bounds lie within the width, in order, and leave at least
+/// two values to draw from.
+fn validate_int_range(commands: &Commands) -> Result<(), ValidationError> {
+    let Commands::Int {
+        bits,
+        signed,
+        nonzero,
+        min,
+        max,
+        ..
+    } = commands
+    else {
+        return Ok(());
+    };
+
+    let (lowest, highest) = bits.range(*signed);
+
+    for (arg, value) in [("--min", min), ("--max", max)] {
+        if let Some(value) = value
+            && !(lowest..=highest).contains(value)
+        {
+            return Err(ValidationError::IntBoundOutOfRange {
+                arg,
+                value: *value,
+                bits: *bits,
+                signed: *signed,
+            });
+        }
+    }
+
+    let (min, max) = (min.unwrap_or(lowest), max.unwrap_or(highest));
+    if min > max {
+        return Err(ValidationError::IntBoundsReversed { min, max });
+    }
+
+    let zero_excluded = *nonzero && (min..=max).contains(&0);
+    if max - min + 1 - i128::from(zero_excluded) < 2 {
+        return Err(ValidationError::IntRangeTooSmall {
+            min,
+            max,
+            nonzero: *nonzero,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that MAC address OUIs have the bits the multicast and universal flags ask
+/// for.
+///
+/// Without flags, addresses are locally administered and unicast, so an OUI of a vendor
+/// (universally administered) needs `--universal`.
+fn validate_mac_oui_bits(commands: &Commands) -> Result<(), ValidationError> {
+    let Commands::Mac {
+        oui: Some(oui),
+        multicast,
+        universal,
+        ..
+    } = commands
+    else {
+        return Ok(());
+    };
+
+    let multicast_bit = oui[0] & utils::MAC_MULTICAST_BIT != 0;
+    let local_bit = oui[0] & utils::MAC_LOCAL_BIT != 0;
+
+    let (flag, given) = match (multicast_bit == *multicast, local_bit != *universal) {
+        (false, _) => ("--multicast", *multicast),
+        (true, false) => ("--universal", *universal),
+        (true, true) => return Ok(()),
+    };
+
+    Err(ValidationError::MacOuiBitMismatch {
+        oui: *oui,
+        flag,
+        given,
+    })
+}
+
+/// Validates that a time mode is only used with commands reading the clock.
+///
+/// Without a time mode, the default depends on the command: a given timestamp is fixed,
+/// and otherwise the clock is read per identifier. So only an explicit `per-id` can
+/// contradict the other arguments.
+pub(super) fn validate_time_mode(
+    commands: &Commands,
+    time_mode: Option<TimeMode>,
+) -> Result<(), ValidationError> {
+    let Some(time_mode) = time_mode else {
+        return Ok(());
+    };
+
+    let fixed_by = match commands {
+        Commands::Uuid(args) if args.nil || args.max => {
+            return Err(ValidationError::UuidConstantConflict {
+                constant: if args.nil { "--nil" } else { "--max" },
+                arg: "--time-mode <MODE>",
+            });
+        }
+        Commands::Uuid(args) => match args.version {
+            SupportedUUIDVersion::V1 | SupportedUUIDVersion::V6 | SupportedUUIDVersion::V7 => {
+                match (args.timestamp, args.per_ms) {
+                    (Some(_), _) => Some("--timestamp <TIMESTAMP>"),
+                    (None, Some(_)) => Some("--per-ms <NUMBER>"),
+                    (None, None) => None,
+                }
+            }
+            version => return Err(ValidationError::TimeModeUuidVersionMismatch { version }),
+        },
+        Commands::ObjectId { timestamp } => timestamp.map(|_| "--timestamp <TIMESTAMP>"),
+        Commands::Ulid { timestamp, .. }
+        | Commands::Ksuid { timestamp }
+        | Commands::Snowflake { timestamp, .. }
+        | Commands::Composite { timestamp, .. }
+        | Commands::Tsid { timestamp, .. }
+        | Commands::Scru128 { timestamp }
+        | Commands::TypeId { timestamp, .. }
+        | Commands::PushId { timestamp }
+        | Commands::Stamp { timestamp, .. } => timestamp.map(|_| "--timestamp <TIMESTAMP>"),
+        Commands::Cuid { .. } => None,
+        _ => return Err(ValidationError::TimeModeCommandMismatch),
+    };
+
+    if let (TimeMode::PerId, Some(arg)) = (time_mode, fixed_by) {
+        return Err(ValidationError::TimeModePerIdWithFixedTime { arg });
+    }
+
+    Ok(())
+}
+
+/// Validates that extra formats are only used with commands printing canonical bytes.
+pub(super) fn validate_also_format(
+    commands: &Commands,
+    formats: &[AlsoFormat],
+) -> Result<(), ValidationError> {
+    if formats.is_empty() {
+        return Ok(());
+    }
+
+    match commands {
+        Commands::Uuid(args) if args.short => Err(ValidationError::AlsoFormatShortUuid),
+        Commands::Uuid(args) if args.binary => Err(ValidationError::AlsoFormatBinaryUuid),
+        Commands::Uuid(UuidArgs {
+            format: IdFormat::Words | IdFormat::Rust | IdFormat::C | IdFormat::Python,
+            ..
+        })
+        | Commands::Ulid {
+            format: IdFormat::Words | IdFormat::Rust | IdFormat::C | IdFormat::Python,
+            ..
+        } => Err(ValidationError::AlsoFormatWords),
+        Commands::Uuid(_) | Commands::Ulid { .. } | Commands::ObjectId { .. } => Ok(()),
+        _ => Err(ValidationError::AlsoFormatCommandMismatch),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::cli::mac::MacFormat;
+    use crate::cli::shortcode::{BannedSubstrings, ShortcodeAlphabet, ShortcodeArgs};
+    use crate::cli::tsid::TsidFormat;
+    use crate::cli::uuid::UuidArgs;
+    #[cfg(feature = "uuid")]
+    use crate::cli::uuid::{
+        SupportedUUIDHash, SupportedUUIDNamespace, parse_name_hex, parse_prefix,
+    };
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v1_with_timestamp_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V1,
+            timestamp: Some((1234567890, 0)),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v6_with_timestamp_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V6,
+            timestamp: Some((1234567890, 0)),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v7_with_timestamp_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            timestamp: Some((1234567890, 0)),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v1_with_max_timestamp_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V1,
+            timestamp: Some((103_072_857_660, 684_697_500)),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v6_with_timestamp_out_of_range_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V6,
+            timestamp: Some((103_072_857_660, 684_697_600)),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidTimestampOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v7_with_large_timestamp_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            timestamp: Some((103_072_857_661, 0)),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v3_with_timestamp_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V3,
+            timestamp: Some((1234567890, 0)),
+            namespace: Some(SupportedUUIDNamespace::DNS),
+            name: Some(String::from("test")),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidTimestampVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v4_with_timestamp_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            timestamp: Some((1234567890, 0)),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidTimestampVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v6_with_node_id_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V6,
+            node_id: Some(eui48::MacAddress::new([0x01, 0x23, 0x45, 0x67, 0x89, 0xab])),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v4_with_node_id_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            node_id: Some(eui48::MacAddress::new([0x01, 0x23, 0x45, 0x67, 0x89, 0xab])),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidNodeIdVersionMismatch {
+                version: SupportedUUIDVersion::V4,
+                arg: "--node-id <NODE_ID>",
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v7_with_hardware_node_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            hardware_node: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidNodeIdVersionMismatch {
+                version: SupportedUUIDVersion::V7,
+                arg: "--hardware-node",
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v5_with_timestamp_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V5,
+            timestamp: Some((1234567890, 0)),
+            namespace: Some(SupportedUUIDNamespace::URL),
+            name: Some(String::from("test")),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidTimestampVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v8_with_timestamp_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V8,
+            timestamp: Some((1234567890, 0)),
+            data: Some(String::from("00")),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidTimestampVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_without_timestamp_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v4_with_exclude_special_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            exclude_special: true,
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v7_with_exclude_special_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            exclude_special: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidExcludeSpecialVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v4_with_starts_with_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            starts_with: Some(parse_prefix("cafe").unwrap()),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v7_with_starts_with_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            starts_with: Some(parse_prefix("cafe").unwrap()),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidStartsWithVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v4_with_data_stdin_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            data_stdin: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidDataStdinVersionMismatch {
+                version: SupportedUUIDVersion::V4
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_missing_arguments_invalid() {
+        let cases = [
+            (
+                SupportedUUIDVersion::V3,
+                vec![
+                    "--namespace <NAMESPACE>",
+                    "<--name <NAME>|--name-hex <HEX>|--name-file <PATH>|--names-stdin>",
+                ],
+            ),
+            (
+                SupportedUUIDVersion::V5,
+                vec![
+                    "--namespace <NAMESPACE>",
+                    "<--name <NAME>|--name-hex <HEX>|--name-file <PATH>|--names-stdin>",
+                ],
+            ),
+            (
+                SupportedUUIDVersion::V8,
+                vec!["<--data <DATA>|--data-stdin|--hash <HASH>>"],
+            ),
+        ];
+
+        for (version, expected) in cases {
+            let cmd = Commands::Uuid(UuidArgs {
+                version,
+                ..Default::default()
+            });
+
+            match validate_args(&cmd) {
+                Err(ValidationError::UuidMissingArguments { args }) => {
+                    assert_eq!(args, expected, "v{version}")
+                }
+                other => panic!("v{version}: unexpected {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_skip_empty_without_names_stdin_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V5,
+            namespace: Some(SupportedUUIDNamespace::DNS),
+            name: Some(String::from("foo")),
+            skip_empty: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidSkipEmptyWithoutNamesStdin)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v6_with_clock_seq_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V6,
+            clock_seq: Some(MAX_CLOCK_SEQ),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v4_with_clock_seq_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            clock_seq: Some(0),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidClockSeqVersionMismatch {
+                version: SupportedUUIDVersion::V4
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v1_with_clock_seq_out_of_range() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V1,
+            clock_seq: Some(MAX_CLOCK_SEQ + 1),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidClockSeqOutOfRange { clock_seq: 16384 })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v7_with_per_ms_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            per_ms: NonZeroUsize::new(50),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v7_with_precision_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            precision: TimestampPrecision::Us,
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v1_with_precision_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V1,
+            precision: TimestampPrecision::Ns,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidPrecisionVersionMismatch {
+                version: SupportedUUIDVersion::V1
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v1_with_per_ms_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V1,
+            per_ms: NonZeroUsize::new(50),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidPerMsVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v6_with_node_id_mode_per_id_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V6,
+            node_id_mode: NodeIdMode::PerId,
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v7_with_node_id_mode_per_id_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            node_id_mode: NodeIdMode::PerId,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidNodeIdModeVersionMismatch {
+                version: SupportedUUIDVersion::V7
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_node_id_mode_per_id_with_hardware_node_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V1,
+            node_id_mode: NodeIdMode::PerId,
+            hardware_node: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidNodeIdModeConflict {
+                arg: "--hardware-node"
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v8_with_hash_and_name_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V8,
+            namespace: Some(SupportedUUIDNamespace::DNS),
+            name: Some(String::from("example.com")),
+            hash: Some(SupportedUUIDHash::Sha256),
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v5_with_hash_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V5,
+            namespace: Some(SupportedUUIDNamespace::DNS),
+            name: Some(String::from("example.com")),
+            hash: Some(SupportedUUIDHash::Sha256),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidHashVersionMismatch {
+                version: SupportedUUIDVersion::V5
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v8_with_name_without_hash_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V8,
+            data: Some(String::from("00")),
+            namespace: Some(SupportedUUIDNamespace::DNS),
+            name: Some(String::from("example.com")),
+            ..Default::default()
+        });
 
-/// Validates that UUID timestamps are only used with compatible versions.
-///
-/// Only UUID versions 1, 6, and 7 support custom timestamps. Other versions
-/// (v3, v4, v5, v8) do not use timestamps in their generation algorithm.
-fn validate_uuid_timestamp_compatibility(commands: &Commands) -> Result<(), ValidationError> {
-    if let Commands::Uuid {
-        version, timestamp, ..
-    } = commands
-        && timestamp.is_some()
-        && !matches!(
-            version,
-            SupportedUUIDVersion::V1 | SupportedUUIDVersion::V6 | SupportedUUIDVersion::V7
-        )
-    {
-        return Err(ValidationError::UuidTimestampVersionMismatch { version: *version });
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidNameVersionMismatch {
+                arg: "--namespace <NAMESPACE>",
+                ..
+            })
+        ));
     }
 
-    Ok(())
-}
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v4_with_name_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            name: Some(String::from("example.com")),
+            ..Default::default()
+        });
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::cli::uuid::SupportedUUIDNamespace;
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidNameVersionMismatch {
+                arg: "--name <NAME>",
+                ..
+            })
+        ));
+    }
 
     #[test]
-    fn test_uuid_v1_with_timestamp_valid() {
-        let cmd = Commands::Uuid {
-            version: SupportedUUIDVersion::V1,
+    #[cfg(feature = "uuid")]
+    fn test_uuid_namespace_and_name_without_name_based_version_invalid() {
+        for version in [
+            SupportedUUIDVersion::V1,
+            SupportedUUIDVersion::V4,
+            SupportedUUIDVersion::V6,
+            SupportedUUIDVersion::V7,
+            SupportedUUIDVersion::V8,
+        ] {
+            let data = matches!(version, SupportedUUIDVersion::V8).then(|| String::from("bar"));
+            let namespace = Commands::Uuid(UuidArgs {
+                version,
+                namespace: Some(SupportedUUIDNamespace::DNS),
+                data: data.clone(),
+                ..Default::default()
+            });
+            let name = Commands::Uuid(UuidArgs {
+                version,
+                name: Some(String::from("foo")),
+                data,
+                ..Default::default()
+            });
+
+            assert!(
+                matches!(
+                    validate_args(&namespace),
+                    Err(ValidationError::UuidNameVersionMismatch {
+                        arg: "--namespace <NAMESPACE>",
+                        ..
+                    })
+                ),
+                "v{version}"
+            );
+            assert!(
+                matches!(
+                    validate_args(&name),
+                    Err(ValidationError::UuidNameVersionMismatch {
+                        arg: "--name <NAME>",
+                        ..
+                    })
+                ),
+                "v{version}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v4_with_namespace_name_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            namespace_name: Some(String::from("myapp.example.com")),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidNameVersionMismatch {
+                version: SupportedUUIDVersion::V4,
+                arg: "--namespace-name <NAMESPACE_NAME>",
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v8_with_name_hex_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V8,
+            hash: Some(SupportedUUIDHash::Sha256),
+            namespace: Some(SupportedUUIDNamespace::DNS),
+            name_hex: Some(parse_name_hex("0011223344556677").unwrap()),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidNameVersionMismatch {
+                version: SupportedUUIDVersion::V8,
+                arg: "--name-hex <HEX>",
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_v8_with_names_stdin_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V8,
+            hash: Some(SupportedUUIDHash::Sha256),
+            namespace: Some(SupportedUUIDNamespace::DNS),
+            names_stdin: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidNameVersionMismatch {
+                version: SupportedUUIDVersion::V8,
+                arg: "--names-stdin",
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_nil_valid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            nil: true,
+            ..Default::default()
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_nil_with_version_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            nil: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidConstantConflict {
+                constant: "--nil",
+                arg: "--version <VERSION>"
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_max_with_timestamp_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
             timestamp: Some((1234567890, 0)),
-            namespace: None,
-            name: None,
-            node_id: None,
-            data: None,
+            max: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidConstantConflict {
+                constant: "--max",
+                arg: "--timestamp <TIMESTAMP>"
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_nil_with_max_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            nil: true,
+            max: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::UuidConstantConflict {
+                constant: "--nil",
+                arg: "--max"
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "ulid")]
+    fn test_ulid_no_validation_needed() {
+        let cmd = Commands::Ulid {
+            timestamp: Some(1234567890),
+            check: false,
+            format: IdFormat::Canonical,
         };
 
         assert!(validate_args(&cmd).is_ok());
     }
 
     #[test]
-    fn test_uuid_v6_with_timestamp_valid() {
-        let cmd = Commands::Uuid {
-            version: SupportedUUIDVersion::V6,
-            timestamp: Some((1234567890, 0)),
-            namespace: None,
-            name: None,
-            node_id: None,
-            data: None,
+    #[cfg(not(feature = "ulid"))]
+    fn test_ulid_not_compiled_in() {
+        let cmd = Commands::Ulid {
+            timestamp: None,
+            check: false,
+            format: IdFormat::Canonical,
+        };
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::CommandNotCompiledIn { feature: "ulid" })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "objectid")]
+    fn test_objectid_no_validation_needed() {
+        let cmd = Commands::ObjectId {
+            timestamp: Some(1234567890),
         };
 
         assert!(validate_args(&cmd).is_ok());
     }
 
     #[test]
-    fn test_uuid_v7_with_timestamp_valid() {
-        let cmd = Commands::Uuid {
-            version: SupportedUUIDVersion::V7,
-            timestamp: Some((1234567890, 0)),
-            namespace: None,
-            name: None,
-            node_id: None,
-            data: None,
+    fn test_shortcode_with_banned_substrings_valid() {
+        let cmd = Commands::Shortcode(ShortcodeArgs {
+            expected: 1000,
+            alphabet: ShortcodeAlphabet::Numeric,
+            probability: 1e-6,
+            exclude_substrings: Some(BannedSubstrings(vec![
+                String::from("0"),
+                String::from("666"),
+            ])),
+            verbose: false,
+        });
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    fn test_shortcode_with_every_symbol_banned_invalid() {
+        let cmd = Commands::Shortcode(ShortcodeArgs {
+            expected: 1000,
+            alphabet: ShortcodeAlphabet::Numeric,
+            probability: 1e-6,
+            exclude_substrings: Some(BannedSubstrings(('0'..='9').map(String::from).collect())),
+            verbose: false,
+        });
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::ShortcodeAlphabetExhausted { remaining: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_snowflake_with_timestamp_valid() {
+        let cmd = Commands::Snowflake {
+            worker_id: 0,
+            epoch: snowflake::TWITTER_EPOCH,
+            timestamp: Some(snowflake::TWITTER_EPOCH + snowflake::MAX_OFFSET),
         };
 
         assert!(validate_args(&cmd).is_ok());
     }
 
     #[test]
-    fn test_uuid_v3_with_timestamp_invalid() {
-        let cmd = Commands::Uuid {
-            version: SupportedUUIDVersion::V3,
-            timestamp: Some((1234567890, 0)),
-            namespace: Some(SupportedUUIDNamespace::DNS),
-            name: Some(String::from("test")),
-            node_id: None,
-            data: None,
+    fn test_snowflake_with_timestamp_before_epoch_invalid() {
+        let cmd = Commands::Snowflake {
+            worker_id: 0,
+            epoch: 1000,
+            timestamp: Some(999),
         };
 
         assert!(matches!(
             validate_args(&cmd),
-            Err(ValidationError::UuidTimestampVersionMismatch { .. })
+            Err(ValidationError::SnowflakeTimestampOutOfRange { epoch: 1000 })
         ));
     }
 
     #[test]
-    fn test_uuid_v4_with_timestamp_invalid() {
-        let cmd = Commands::Uuid {
-            version: SupportedUUIDVersion::V4,
-            timestamp: Some((1234567890, 0)),
-            namespace: None,
-            name: None,
-            node_id: None,
-            data: None,
+    fn test_snowflake_with_timestamp_past_range_invalid() {
+        let cmd = Commands::Snowflake {
+            worker_id: 0,
+            epoch: 0,
+            timestamp: Some(snowflake::MAX_OFFSET + 1),
         };
 
         assert!(matches!(
             validate_args(&cmd),
-            Err(ValidationError::UuidTimestampVersionMismatch { .. })
+            Err(ValidationError::SnowflakeTimestampOutOfRange { epoch: 0 })
         ));
     }
 
     #[test]
-    fn test_uuid_v5_with_timestamp_invalid() {
-        let cmd = Commands::Uuid {
-            version: SupportedUUIDVersion::V5,
-            timestamp: Some((1234567890, 0)),
-            namespace: Some(SupportedUUIDNamespace::URL),
-            name: Some(String::from("test")),
-            node_id: None,
-            data: None,
+    fn test_tsid_with_timestamp_valid() {
+        let cmd = Commands::Tsid {
+            node: None,
+            epoch: tsid::TSID_EPOCH,
+            timestamp: Some(tsid::TSID_EPOCH + tsid::MAX_OFFSET),
+            format: TsidFormat::Base32,
+        };
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    fn test_tsid_with_timestamp_before_epoch_invalid() {
+        let cmd = Commands::Tsid {
+            node: Some(0),
+            epoch: 1000,
+            timestamp: Some(999),
+            format: TsidFormat::Number,
         };
 
         assert!(matches!(
             validate_args(&cmd),
-            Err(ValidationError::UuidTimestampVersionMismatch { .. })
+            Err(ValidationError::TsidTimestampOutOfRange { epoch: 1000 })
         ));
     }
 
+    fn int(
+        bits: IntBits,
+        signed: bool,
+        nonzero: bool,
+        min: Option<i128>,
+        max: Option<i128>,
+    ) -> Commands {
+        Commands::Int {
+            bits,
+            signed,
+            unsigned: false,
+            nonzero,
+            min,
+            max,
+        }
+    }
+
     #[test]
-    fn test_uuid_v8_with_timestamp_invalid() {
-        let cmd = Commands::Uuid {
-            version: SupportedUUIDVersion::V8,
-            timestamp: Some((1234567890, 0)),
-            namespace: None,
-            name: None,
-            node_id: None,
-            data: Some([0u8; 16]),
+    fn test_int_bounds_valid() {
+        for cmd in [
+            int(IntBits::B64, false, false, None, None),
+            int(IntBits::B64, true, true, None, None),
+            int(IntBits::B53, false, false, Some(1), Some((1 << 53) - 1)),
+            int(IntBits::B32, true, false, Some(-5), Some(-4)),
+            int(IntBits::B32, false, true, Some(0), Some(2)),
+        ] {
+            assert!(validate_args(&cmd).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_int_bound_out_of_range_invalid() {
+        for (cmd, expected_arg) in [
+            (int(IntBits::B32, false, false, Some(-1), None), "--min"),
+            (
+                int(IntBits::B53, false, false, None, Some(1 << 53)),
+                "--max",
+            ),
+            (
+                int(IntBits::B64, true, false, None, Some(i128::from(u64::MAX))),
+                "--max",
+            ),
+        ] {
+            assert!(matches!(
+                validate_args(&cmd),
+                Err(ValidationError::IntBoundOutOfRange { arg, .. }) if arg == expected_arg
+            ));
+        }
+    }
+
+    #[test]
+    fn test_int_bounds_reversed_invalid() {
+        let cmd = int(IntBits::B64, false, false, Some(10), Some(5));
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::IntBoundsReversed { min: 10, max: 5 })
+        ));
+    }
+
+    #[test]
+    fn test_int_range_too_small_invalid() {
+        for cmd in [
+            int(IntBits::B64, false, false, Some(7), Some(7)),
+            int(IntBits::B64, false, true, None, Some(1)),
+            int(IntBits::B32, true, true, Some(0), Some(0)),
+        ] {
+            assert!(matches!(
+                validate_args(&cmd),
+                Err(ValidationError::IntRangeTooSmall { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_mac_oui_matching_flags_valid() {
+        for (oui, multicast, universal) in [
+            ([0x02, 0x00, 0x00], false, false),
+            ([0x03, 0x00, 0x00], true, false),
+            ([0x00, 0x16, 0x3e], false, true),
+            ([0x01, 0x00, 0x5e], true, true),
+        ] {
+            let cmd = Commands::Mac {
+                format: MacFormat::Colon,
+                oui: Some(oui),
+                multicast,
+                universal,
+            };
+
+            assert!(validate_args(&cmd).is_ok(), "{oui:?}");
+        }
+    }
+
+    #[test]
+    fn test_mac_oui_contradicting_flags_invalid() {
+        for (oui, multicast, universal, expected_flag, expected_given) in [
+            ([0x00, 0x16, 0x3e], false, false, "--universal", false),
+            ([0x02, 0x00, 0x00], false, true, "--universal", true),
+            ([0x01, 0x00, 0x5e], false, true, "--multicast", false),
+            ([0x02, 0x00, 0x00], true, false, "--multicast", true),
+        ] {
+            let cmd = Commands::Mac {
+                format: MacFormat::Bare,
+                oui: Some(oui),
+                multicast,
+                universal,
+            };
+
+            assert!(
+                matches!(
+                    validate_args(&cmd),
+                    Err(ValidationError::MacOuiBitMismatch { flag, given, .. })
+                        if flag == expected_flag && given == expected_given
+                ),
+                "{oui:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_composite_with_shard_and_timestamp_valid() {
+        let cmd = Commands::Composite {
+            layout: composite::parse_layout("time:41,shard:13,seq:10").unwrap(),
+            epoch: 1000,
+            shard: Some(8191),
+            timestamp: Some(1000 + composite::max_field_value(41)),
+        };
+
+        assert!(validate_args(&cmd).is_ok());
+    }
+
+    #[test]
+    fn test_composite_with_shard_out_of_range_invalid() {
+        let cmd = Commands::Composite {
+            layout: composite::parse_layout("time:41,shard:13,seq:10").unwrap(),
+            epoch: 0,
+            shard: Some(8192),
+            timestamp: None,
         };
 
         assert!(matches!(
             validate_args(&cmd),
-            Err(ValidationError::UuidTimestampVersionMismatch { .. })
+            Err(ValidationError::CompositeShardOutOfRange { bits: 13 })
         ));
     }
 
     #[test]
-    fn test_uuid_without_timestamp_valid() {
-        let cmd = Commands::Uuid {
-            version: SupportedUUIDVersion::V4,
+    fn test_composite_with_shard_without_field_invalid() {
+        let cmd = Commands::Composite {
+            layout: composite::parse_layout("time:41,seq:10").unwrap(),
+            epoch: 0,
+            shard: Some(0),
             timestamp: None,
-            namespace: None,
-            name: None,
-            node_id: None,
-            data: None,
         };
 
-        assert!(validate_args(&cmd).is_ok());
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::CompositeShardWithoutField)
+        ));
     }
 
     #[test]
-    fn test_ulid_no_validation_needed() {
+    fn test_composite_with_timestamp_before_epoch_invalid() {
+        let cmd = Commands::Composite {
+            layout: composite::parse_layout("time:41").unwrap(),
+            epoch: 1000,
+            shard: None,
+            timestamp: Some(999),
+        };
+
+        assert!(matches!(
+            validate_args(&cmd),
+            Err(ValidationError::CompositeTimestampOutOfRange {
+                epoch: 1000,
+                bits: 41
+            })
+        ));
+    }
+
+    #[test]
+    fn test_time_mode_fixed_with_timestamp_valid() {
         let cmd = Commands::Ulid {
             timestamp: Some(1234567890),
+            check: false,
+            format: IdFormat::Canonical,
         };
 
-        assert!(validate_args(&cmd).is_ok());
+        assert!(validate_time_mode(&cmd, Some(TimeMode::Fixed)).is_ok());
+        assert!(validate_time_mode(&cmd, None).is_ok());
     }
 
     #[test]
-    fn test_objectid_no_validation_needed() {
+    fn test_time_mode_per_id_with_timestamp_invalid() {
         let cmd = Commands::ObjectId {
             timestamp: Some(1234567890),
         };
 
-        assert!(validate_args(&cmd).is_ok());
+        assert!(matches!(
+            validate_time_mode(&cmd, Some(TimeMode::PerId)),
+            Err(ValidationError::TimeModePerIdWithFixedTime {
+                arg: "--timestamp <TIMESTAMP>"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_time_mode_per_id_with_per_ms_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            per_ms: NonZeroUsize::new(50),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_time_mode(&cmd, Some(TimeMode::PerId)),
+            Err(ValidationError::TimeModePerIdWithFixedTime {
+                arg: "--per-ms <NUMBER>"
+            })
+        ));
+        assert!(validate_time_mode(&cmd, Some(TimeMode::Fixed)).is_ok());
+    }
+
+    #[test]
+    fn test_time_mode_uuid_v4_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_time_mode(&cmd, Some(TimeMode::Fixed)),
+            Err(ValidationError::TimeModeUuidVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_time_mode_without_clock_invalid() {
+        let cmd = Commands::NanoId {
+            size: NonZeroUsize::new(21).unwrap(),
+            alphabet: String::from("01"),
+        };
+
+        assert!(matches!(
+            validate_time_mode(&cmd, Some(TimeMode::PerId)),
+            Err(ValidationError::TimeModeCommandMismatch)
+        ));
+        assert!(validate_time_mode(&cmd, None).is_ok());
+    }
+
+    #[test]
+    fn test_also_format_valid() {
+        let cmd = Commands::Ulid {
+            timestamp: None,
+            check: true,
+            format: IdFormat::Canonical,
+        };
+
+        assert!(validate_also_format(&cmd, &[AlsoFormat::Base58, AlsoFormat::Hex]).is_ok());
+    }
+
+    #[test]
+    fn test_also_format_short_uuid_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            short: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_also_format(&cmd, &[AlsoFormat::Base58]),
+            Err(ValidationError::AlsoFormatShortUuid)
+        ));
+    }
+
+    #[test]
+    fn test_also_format_binary_uuid_invalid() {
+        let cmd = Commands::Uuid(UuidArgs {
+            binary: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            validate_also_format(&cmd, &[AlsoFormat::Hex]),
+            Err(ValidationError::AlsoFormatBinaryUuid)
+        ));
+    }
+
+    #[test]
+    fn test_also_format_without_bytes_invalid() {
+        let cmd = Commands::NanoId {
+            size: NonZeroUsize::new(21).unwrap(),
+            alphabet: String::from("01"),
+        };
+
+        assert!(matches!(
+            validate_also_format(&cmd, &[AlsoFormat::Hex]),
+            Err(ValidationError::AlsoFormatCommandMismatch)
+        ));
+        assert!(validate_also_format(&cmd, &[]).is_ok());
     }
 }
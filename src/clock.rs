@@ -0,0 +1,80 @@
+//! Source of the current time for generators.
+//!
+//! Generators without a fixed timestamp take "now" from [`now`] instead of calling the
+//! identifier crates' own now-constructors (like `Uuid::now_v7()` or `Ulid::new()`), so
+//! there is a single place where the time comes from.
+//!
+//! # Time Modes
+//!
+//! By default, the clock is read for every identifier. With `--time-mode fixed`, it is
+//! pinned to its first reading with [`pin`] before the generation loop, so every
+//! identifier of the run carries the same instant (while generators keep their own
+//! counters and random bits to stay unique).
+//!
+//! # Test Hooks
+//!
+//! With the `test-hooks` feature, the hidden `--fake-now` option (or the `SPWD_FAKE_NOW`
+//! environment variable) pins the clock to a given instant for the whole run, which lets
+//! tests check the "current time" paths deterministically. Without the feature, the clock
+//! always reads the system time.
+
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+/// Instant the clock is pinned to, as Unix seconds and subsecond nanoseconds.
+static PINNED: OnceLock<(u64, u32)> = OnceLock::new();
+
+/// Pins the clock to its current reading for the rest of the run.
+///
+/// Does nothing if the clock is already pinned (like to a fake instant).
+pub(crate) fn pin() {
+    PINNED.get_or_init(read_system_time);
+}
+
+/// Pins the clock to the given Unix timestamp for the rest of the run.
+#[cfg(feature = "test-hooks")]
+pub(crate) fn freeze(timestamp: (u64, u32)) {
+    PINNED
+        .set(timestamp)
+        .expect("the clock is only frozen once per run");
+}
+
+/// Returns the instant the clock is pinned to, if it is.
+#[cfg(feature = "uuid")]
+pub(crate) fn pinned() -> Option<(u64, u32)> {
+    PINNED.get().copied()
+}
+
+/// Returns the current Unix timestamp as seconds and subsecond nanoseconds.
+pub(crate) fn now() -> (u64, u32) {
+    match PINNED.get() {
+        Some(timestamp) => *timestamp,
+        None => read_system_time(),
+    }
+}
+
+/// Reads the system time as a Unix timestamp.
+fn read_system_time() -> (u64, u32) {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+
+    (now.as_secs(), now.subsec_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_now_reads_system_time() {
+        let before = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let (seconds, subsec_nanos) = now();
+
+        assert!(seconds >= before && seconds - before <= 1);
+        assert!(subsec_nanos < 1_000_000_000);
+    }
+}
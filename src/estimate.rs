@@ -0,0 +1,315 @@
+//! Collision probability estimates for capacity planning.
+//!
+//! Random identifiers collide when two of them draw the same random bits. For `n`
+//! identifiers drawn uniformly from `2^b` values, the standard birthday approximation
+//! gives the probability of at least one collision as:
+//!
+//! ```text
+//! p ≈ 1 - exp(-n² / 2^(b+1))
+//! ```
+//!
+//! The approximation's relative error is on the order of `n / 2^b`, far below the six
+//! significant digits reported for any realistic rate. It's evaluated with `expm1`, so
+//! tiny probabilities don't round down to zero.
+//!
+//! # Types
+//!
+//! - **ULID, UUID v7**: Identifiers only collide within the same millisecond, so a day is
+//!   86,400,000 independent draws of `rate / 1000` identifiers each
+//! - **UUID v4**: There's no timestamp, so a whole day of identifiers shares one space
+//! - **ObjectId**: Uniqueness comes from a 24-bit counter rather than random bits, so the
+//!   risk is the counter wrapping around within a single second
+//!
+//! The same approximation sizes short codes: [`minimum_length`] finds the shortest code
+//! over an alphabet that keeps the probability acceptable for the expected population.
+
+use serde_json::json;
+
+use crate::cli::estimate::{EstimateArgs, EstimatedType};
+
+/// Milliseconds in a day, the number of independent draws for time-ordered types.
+const MILLIS_PER_DAY: f64 = 86_400_000.0;
+
+/// Seconds in a day.
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// ObjectId counter size (in bits).
+const OBJECTID_COUNTER_BITS: u32 = 24;
+
+/// Collision risk estimate for an identifier type at a given rate.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Estimate {
+    /// Birthday-bound collision probabilities of random bits.
+    Collision {
+        random_bits: u32,
+        per_millisecond: f64,
+        per_day: f64,
+    },
+
+    /// Counter exhaustion of counter-based identifiers.
+    CounterWrap {
+        counter_bits: u32,
+        capacity_per_second: u64,
+        utilization: f64,
+        wraps: bool,
+    },
+}
+
+impl Estimate {
+    /// Estimates the collision risk of an identifier type at a rate (per second).
+    pub(crate) fn new(kind: EstimatedType, rate: f64) -> Self {
+        let per_millisecond = rate / 1000.0;
+
+        match kind {
+            EstimatedType::Ulid | EstimatedType::UuidV7 => {
+                let random_bits = random_bits(kind);
+                let exponent = birthday_exponent(per_millisecond, random_bits.into());
+
+                Estimate::Collision {
+                    random_bits,
+                    per_millisecond: collision_probability(per_millisecond, random_bits.into()),
+                    per_day: -(-exponent * MILLIS_PER_DAY).exp_m1(),
+                }
+            }
+            EstimatedType::UuidV4 => {
+                let random_bits = random_bits(kind);
+
+                Estimate::Collision {
+                    random_bits,
+                    per_millisecond: collision_probability(per_millisecond, random_bits.into()),
+                    per_day: collision_probability(rate * SECONDS_PER_DAY, random_bits.into()),
+                }
+            }
+            EstimatedType::ObjectId => {
+                let capacity_per_second = 1u64 << OBJECTID_COUNTER_BITS;
+
+                Estimate::CounterWrap {
+                    counter_bits: OBJECTID_COUNTER_BITS,
+                    capacity_per_second,
+                    utilization: rate / capacity_per_second as f64,
+                    wraps: rate > capacity_per_second as f64,
+                }
+            }
+        }
+    }
+}
+
+/// Returns the number of random bits of a random identifier type.
+fn random_bits(kind: EstimatedType) -> u32 {
+    match kind {
+        EstimatedType::Ulid => 80,
+        EstimatedType::UuidV4 => 122,
+        EstimatedType::UuidV7 => 74,
+        EstimatedType::ObjectId => 0,
+    }
+}
+
+/// Returns the birthday approximation exponent `n² / 2^(bits+1)`.
+fn birthday_exponent(count: f64, bits: f64) -> f64 {
+    count * count / (bits + 1.0).exp2()
+}
+
+/// Returns the probability of at least one collision among `count` identifiers
+/// with `bits` random bits, using the birthday approximation.
+pub(crate) fn collision_probability(count: f64, bits: f64) -> f64 {
+    -(-birthday_exponent(count, bits)).exp_m1()
+}
+
+/// Returns the shortest length of codes over an alphabet of `alphabet_size` symbols
+/// keeping the collision probability among `expected` codes at or below `probability`.
+pub(crate) fn minimum_length(expected: usize, alphabet_size: usize, probability: f64) -> usize {
+    let bits_per_symbol = (alphabet_size as f64).log2();
+
+    (1..)
+        .find(|&length| {
+            collision_probability(expected as f64, length as f64 * bits_per_symbol) <= probability
+        })
+        .expect("collision probability decreases with length")
+}
+
+/// Renders the estimate for the CLI arguments as plain text or JSON.
+pub(crate) fn report(args: &EstimateArgs) -> String {
+    let estimate = Estimate::new(args.kind, args.rate);
+
+    if args.json {
+        let kind = args.kind.to_string();
+
+        let value = match estimate {
+            Estimate::Collision {
+                random_bits,
+                per_millisecond,
+                per_day,
+            } => json!({
+                "type": kind,
+                "rate_per_second": args.rate,
+                "random_bits": random_bits,
+                "collision_probability": {
+                    "per_millisecond": per_millisecond,
+                    "per_day": per_day,
+                },
+            }),
+            Estimate::CounterWrap {
+                counter_bits,
+                capacity_per_second,
+                utilization,
+                wraps,
+            } => json!({
+                "type": kind,
+                "rate_per_second": args.rate,
+                "counter_bits": counter_bits,
+                "counter_wrap": {
+                    "capacity_per_second": capacity_per_second,
+                    "utilization": utilization,
+                    "wraps": wraps,
+                },
+            }),
+        };
+
+        return value.to_string();
+    }
+
+    let details = match estimate {
+        Estimate::Collision {
+            random_bits,
+            per_millisecond,
+            per_day,
+        } => format!(
+            "random bits: {random_bits}\n\
+             collision probability per millisecond: {per_millisecond:.5e}\n\
+             collision probability per day: {per_day:.5e}"
+        ),
+        Estimate::CounterWrap {
+            counter_bits,
+            capacity_per_second,
+            utilization,
+            wraps,
+        } => format!(
+            "counter bits: {counter_bits}\n\
+             counter capacity per second: {capacity_per_second}\n\
+             counter utilization: {utilization:.5e}\n\
+             counter wraps within a second: {}",
+            if wraps { "yes" } else { "no" }
+        ),
+    };
+
+    format!("type: {}\nrate: {}/s\n{details}", args.kind, args.rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts that two probabilities agree to six significant digits.
+    fn assert_close(actual: f64, expected: f64) {
+        let error = ((actual - expected) / expected).abs();
+
+        assert!(error < 1e-6, "{actual:e} is not close to {expected:e}");
+    }
+
+    #[test]
+    fn test_collision_probability_small() {
+        // 50 identifiers in 80 bits: 2500 / 2^81
+        assert_close(collision_probability(50.0, 80.0), 1.0339757656912846e-21);
+    }
+
+    #[test]
+    fn test_collision_probability_half() {
+        // The classic birthday problem: about 1.1774 * sqrt(N) draws give 50%
+        let count = (2.0 * 2f64.ln()).sqrt() * 2f64.powi(32);
+
+        assert_close(collision_probability(count, 64.0), 0.5);
+    }
+
+    #[test]
+    fn test_collision_probability_zero() {
+        assert_eq!(collision_probability(0.0, 80.0), 0.0);
+    }
+
+    #[test]
+    fn test_minimum_length() {
+        // 100,000 codes at 1e-6 need A^L ≥ 10^10 / (2 * 1e-6) = 5 * 10^15
+        assert_eq!(minimum_length(100_000, 56, 1e-6), 9);
+        assert_eq!(minimum_length(100_000, 62, 1e-6), 9);
+        assert_eq!(minimum_length(100_000, 10, 1e-6), 16);
+        assert_eq!(minimum_length(100_000, 32, 1e-6), 11);
+
+        // 1000 codes at 1%: 56^4 ≈ 9.8 * 10^6 falls short of 5 * 10^7
+        assert_eq!(minimum_length(1000, 56, 0.01), 5);
+    }
+
+    #[test]
+    fn test_minimum_length_empty_population() {
+        assert_eq!(minimum_length(0, 10, 1e-6), 1);
+    }
+
+    #[test]
+    fn test_estimate_ulid() {
+        let Estimate::Collision {
+            random_bits,
+            per_millisecond,
+            per_day,
+        } = Estimate::new(EstimatedType::Ulid, 50_000.0)
+        else {
+            panic!("ULID estimate must be a collision estimate");
+        };
+
+        assert_eq!(random_bits, 80);
+        assert_close(per_millisecond, 1.0339757656912846e-21);
+        assert_close(per_day, 8.9335506155723e-14);
+    }
+
+    #[test]
+    fn test_estimate_uuid_v7() {
+        let Estimate::Collision {
+            random_bits,
+            per_millisecond,
+            per_day,
+        } = Estimate::new(EstimatedType::UuidV7, 50_000.0)
+        else {
+            panic!("UUID v7 estimate must be a collision estimate");
+        };
+
+        assert_eq!(random_bits, 74);
+        assert_close(per_millisecond, 6.617444900424221e-20);
+        assert_close(per_day, 5.717472393950182e-12);
+    }
+
+    #[test]
+    fn test_estimate_uuid_v4() {
+        let Estimate::Collision {
+            random_bits,
+            per_millisecond,
+            per_day,
+        } = Estimate::new(EstimatedType::UuidV4, 50_000.0)
+        else {
+            panic!("UUID v4 estimate must be a collision estimate");
+        };
+
+        assert_eq!(random_bits, 122);
+        assert_close(per_millisecond, 2.350988701644575e-34);
+        assert_close(per_day, 1.7550036618228687e-18);
+    }
+
+    #[test]
+    fn test_estimate_objectid() {
+        assert_eq!(
+            Estimate::new(EstimatedType::ObjectId, 50_000.0),
+            Estimate::CounterWrap {
+                counter_bits: 24,
+                capacity_per_second: 16_777_216,
+                utilization: 50_000.0 / 16_777_216.0,
+                wraps: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_estimate_objectid_wraps() {
+        let estimate = Estimate::new(EstimatedType::ObjectId, 20_000_000.0);
+
+        assert!(matches!(
+            estimate,
+            Estimate::CounterWrap { wraps: true, .. }
+        ));
+    }
+}
@@ -0,0 +1,202 @@
+//! Prefixed API key generator.
+//!
+//! API keys are random secrets shaped like `sk_live_<base62>`, in the style of GitHub
+//! tokens: a prefix tells key kinds apart at a glance (and to secret scanners), and a
+//! checksum lets consumers reject mistyped keys without a database lookup.
+//!
+//! # Format
+//!
+//! An API key consists of:
+//! - Prefix: Optional, followed by an underscore
+//! - Random part: Random bytes (24 by default) as a zero-padded base62 number
+//! - Checksum: Optional CRC32 of the random part (as text), in 6 base62 characters
+//!
+//! # Randomness
+//!
+//! Bytes come from the thread-local generator of the `rand` crate, a cryptographically
+//! secure PRNG seeded from the operating system, as for tokens.
+
+use rand::RngCore;
+
+use crate::cli::apikey::ApiKeyChecksum;
+use crate::generators::Generate;
+
+/// Smallest number of random bytes in an API key (128 bits).
+pub const MIN_BYTES: u64 = 16;
+
+/// Largest number of random bytes in an API key.
+pub const MAX_BYTES: u64 = 64;
+
+/// Base62 alphabet (digits, then uppercase and lowercase letters).
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Length of a base62-encoded CRC32 checksum (62^6 exceeds 2^32).
+const CHECKSUM_LENGTH: usize = 6;
+
+/// API key generator with a fixed prefix, size, and checksum.
+pub struct ApiKeyGenerator {
+    prefix: String,
+    bytes: usize,
+    checksum: ApiKeyChecksum,
+}
+
+impl ApiKeyGenerator {
+    pub fn new(prefix: Option<&str>, bytes: usize, checksum: ApiKeyChecksum) -> Self {
+        Self {
+            prefix: prefix
+                .map(|prefix| format!("{prefix}_"))
+                .unwrap_or_default(),
+            bytes,
+            checksum,
+        }
+    }
+}
+
+impl Generate for ApiKeyGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let mut bytes = vec![0; self.bytes];
+        rand::rng().fill_bytes(&mut bytes);
+
+        let random = encode_base62(&bytes, base62_length(self.bytes));
+
+        Ok(match self.checksum {
+            ApiKeyChecksum::None => format!("{}{random}", self.prefix),
+            ApiKeyChecksum::Crc32 => format!("{}{random}{}", self.prefix, checksum(&random)),
+        })
+    }
+}
+
+/// Verifies the CRC32 checksum at the end of an API key, returning the reason it fails.
+pub(crate) fn verify(key: &str) -> Result<(), String> {
+    let body = key.rsplit('_').next().unwrap_or(key);
+
+    if body.len() <= CHECKSUM_LENGTH || !body.bytes().all(|c| BASE62.contains(&c)) {
+        return Err(String::from(
+            "invalid API key: expected base62 symbols followed by a checksum",
+        ));
+    }
+
+    let (random, expected) = body.split_at(body.len() - CHECKSUM_LENGTH);
+    if checksum(random) != expected {
+        return Err(String::from("API key checksum mismatch"));
+    }
+
+    Ok(())
+}
+
+/// Returns the number of base62 symbols needed for any value of the given bytes.
+fn base62_length(bytes: usize) -> usize {
+    // 62^n never equals 256^m, so the ceiling is never off by rounding
+    (bytes as f64 * 8.0 / 62_f64.log2()).ceil() as usize
+}
+
+/// Encodes big-endian bytes as a base62 number, zero-padded to the given length.
+fn encode_base62(bytes: &[u8], length: usize) -> String {
+    let mut bytes = bytes.to_vec();
+    let mut encoded = vec![BASE62[0]; length];
+
+    for symbol in encoded.iter_mut().rev() {
+        // Long division of the whole number by 62, keeping the quotient in place
+        let mut remainder = 0u32;
+        for byte in bytes.iter_mut() {
+            let value = (remainder << 8) | u32::from(*byte);
+            *byte = (value / 62) as u8;
+            remainder = value % 62;
+        }
+
+        *symbol = BASE62[remainder as usize];
+    }
+
+    String::from_utf8(encoded).expect("base62 symbols are ASCII")
+}
+
+/// Computes the base62 checksum of the random part of a key.
+fn checksum(random: &str) -> String {
+    encode_base62(&crc32(random.as_bytes()).to_be_bytes(), CHECKSUM_LENGTH)
+}
+
+/// Computes the CRC-32 (ISO-HDLC, as in zlib and PNG) of bytes.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb8_8320,
+                _ => crc >> 1,
+            };
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn test_base62_length() {
+        assert_eq!(base62_length(16), 22);
+        assert_eq!(base62_length(24), 33);
+        assert_eq!(base62_length(4), CHECKSUM_LENGTH);
+    }
+
+    #[test]
+    fn test_encode_base62_bounds() {
+        assert_eq!(encode_base62(&[0; 4], 6), "000000");
+        assert_eq!(encode_base62(&[0xff; 4], 6), "4gfFC3");
+        assert_eq!(encode_base62(&[0, 61], 3), "00z");
+    }
+
+    #[test]
+    fn test_generate_with_checksum() {
+        let mut generator = ApiKeyGenerator::new(Some("sk_live"), 24, ApiKeyChecksum::Crc32);
+        let key = generator.generate().unwrap();
+
+        assert!(key.starts_with("sk_live_"));
+        assert_eq!(key.len(), 8 + 33 + CHECKSUM_LENGTH);
+        assert!(verify(&key).is_ok());
+    }
+
+    #[test]
+    fn test_generate_without_checksum() {
+        let mut generator = ApiKeyGenerator::new(None, 16, ApiKeyChecksum::None);
+        let key = generator.generate().unwrap();
+
+        assert_eq!(key.len(), 22);
+        assert!(key.bytes().all(|c| BASE62.contains(&c)));
+    }
+
+    #[test]
+    fn test_verify_mistyped() {
+        let mut generator = ApiKeyGenerator::new(Some("pk"), 24, ApiKeyChecksum::Crc32);
+        let key = generator.generate().unwrap();
+
+        // Swapping a random symbol for another one
+        let mut symbols = key.into_bytes();
+        symbols[5] = match symbols[5] {
+            b'a' => b'b',
+            _ => b'a',
+        };
+        let mistyped = String::from_utf8(symbols).unwrap();
+
+        assert_eq!(
+            verify(&mistyped),
+            Err(String::from("API key checksum mismatch"))
+        );
+    }
+
+    #[test]
+    fn test_verify_malformed() {
+        assert!(verify("sk_live_").is_err());
+        assert!(verify("sk_live_abc-def-ghi").is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! Crockford base32 code generator.
+//!
+//! Crockford base32 codes are random symbols from an alphabet without the look-alike `I`,
+//! `L`, `O`, and `U`, like `7KQ4X2M9TB0D`, for labels people read and scanners misread.
+//! An optional check symbol catches single-symbol mistakes and swapped adjacent symbols.
+//!
+//! # Format
+//!
+//! A code consists of:
+//! - Random part: Random symbols (12 by default), 5 random bits each
+//! - Check symbol: Optional, the random part's value modulo 37, from the 32 symbols
+//!   and the 5 extra check symbols `*~$=U`
+
+use crate::generators::Generate;
+use crate::utils;
+
+/// Largest number of random symbols in a code.
+pub const MAX_LENGTH: u64 = 64;
+
+/// Crockford base32 alphabet (digits, then uppercase letters without I, L, O, and U).
+const SYMBOLS: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Crockford base32 code generator of a fixed length.
+pub struct CrockfordGenerator {
+    length: usize,
+    check: bool,
+}
+
+impl CrockfordGenerator {
+    pub fn new(length: usize, check: bool) -> Self {
+        Self { length, check }
+    }
+}
+
+impl Generate for CrockfordGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let mut code: String = (0..self.length)
+            .map(|_| SYMBOLS[usize::from(rand::random::<u8>() & 0x1f)] as char)
+            .collect();
+
+        if self.check {
+            code.push(utils::crockford_check_symbol(&code)?);
+        }
+
+        Ok(code)
+    }
+}
+
+/// Verifies the check symbol at the end of a Crockford base32 code, returning the reason
+/// it fails.
+pub(crate) fn verify(code: &str) -> Result<(), String> {
+    let Some((index, symbol)) = code.char_indices().last().filter(|(index, _)| *index > 0) else {
+        return Err(String::from(
+            "invalid Crockford base32 code: expected symbols followed by a check symbol",
+        ));
+    };
+
+    let expected = utils::crockford_check_symbol(&code[..index])
+        .map_err(|e| format!("invalid Crockford base32 code: {e}"))?;
+
+    // Check symbols are read as leniently as the others
+    let read = match symbol.to_ascii_uppercase() {
+        'O' => '0',
+        'I' | 'L' => '1',
+        symbol => symbol,
+    };
+
+    if read != expected {
+        return Err(format!(
+            "check symbol mismatch: expected {expected:?}, got {symbol:?}"
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_format() {
+        let mut generator = CrockfordGenerator::new(12, false);
+        let code = generator.generate().unwrap();
+
+        assert_eq!(code.len(), 12);
+        assert!(code.bytes().all(|c| SYMBOLS.contains(&c)), "{code}");
+    }
+
+    #[test]
+    fn test_generate_with_check_symbol() {
+        let mut generator = CrockfordGenerator::new(12, true);
+
+        for _ in 0..100 {
+            let code = generator.generate().unwrap();
+
+            assert_eq!(code.len(), 13);
+            assert!(verify(&code).is_ok(), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_verify_published_examples() {
+        // From the Crockford base32 spec and its reference implementations: 1234 is "16J"
+        // (1234 ≡ 13, "D"), and 32 to 36 take the extra check symbols
+        for code in ["16JD", "00", "10*", "11~", "12$", "13=", "14U", "150", "ZZ"] {
+            assert!(verify(code).is_ok(), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_verify_aliases_and_hyphens() {
+        assert!(verify("16j-d").is_ok());
+        assert!(verify("I6JD").is_ok());
+        assert!(verify("14u").is_ok());
+        assert!(verify("15o").is_ok());
+    }
+
+    #[test]
+    fn test_verify_misread() {
+        assert_eq!(
+            verify("16KD"),
+            Err(String::from("check symbol mismatch: expected 'E', got 'D'"))
+        );
+    }
+
+    #[test]
+    fn test_verify_malformed() {
+        assert!(verify("").is_err());
+        assert!(verify("D").is_err());
+        assert_eq!(
+            verify("1U6JD"),
+            Err(String::from(
+                "invalid Crockford base32 code: invalid Crockford base32 character 'U'"
+            ))
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! CUID (collision-resistant identifier) generator, in its original (v1) form.
+//!
+//! CUIDs are 25-character lowercase base36 strings, like `cjld2cjxh0000qzrmn831i7rn`.
+//! The format has been superseded by CUID2, but older services still emit it.
+//!
+//! # Format
+//!
+//! A CUID consists of:
+//! - Prefix: `c`
+//! - Timestamp: Milliseconds since Unix epoch (8 characters until 2059)
+//! - Counter: 4 characters, advancing for every CUID of the run and wrapping at 36^4
+//! - Fingerprint: 4 characters, from the process id and the host name
+//! - Random: 2 blocks of 4 random characters
+//!
+//! Blocks are padded with zeros, or cut to their last characters, as the reference
+//! implementation does.
+//!
+//! # Slugs
+//!
+//! Slugs are the 7 to 10 character variant: the last 2 timestamp characters, the counter
+//! (unpadded, up to 4 characters), the first and last fingerprint characters, and 2
+//! random characters.
+
+use crate::clock;
+use crate::generators::Generate;
+
+/// Base36 alphabet (digits, then lowercase letters).
+const BASE36: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Number of values in a 4-character block (36^4).
+const BLOCK_VALUES: u64 = 36 * 36 * 36 * 36;
+
+/// Length of counter, fingerprint, and random blocks.
+const BLOCK_LENGTH: usize = 4;
+
+/// CUID generator keeping the counter and fingerprint of the run.
+pub struct CuidGenerator {
+    slug: bool,
+    counter: u64,
+    fingerprint: String,
+}
+
+impl CuidGenerator {
+    pub fn new(slug: bool) -> Self {
+        Self {
+            slug,
+            counter: 0,
+            fingerprint: fingerprint(std::process::id(), &hostname()),
+        }
+    }
+
+    /// Returns the current counter value, advancing it for the next CUID.
+    fn next_counter(&mut self) -> u64 {
+        let counter = self.counter;
+        self.counter = (self.counter + 1) % BLOCK_VALUES;
+        counter
+    }
+}
+
+impl Generate for CuidGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let (seconds, subsec_nanos) = clock::now();
+        let timestamp = to_base36(seconds * 1000 + u64::from(subsec_nanos) / 1_000_000);
+        let counter = to_base36(self.next_counter());
+
+        if self.slug {
+            let print = &self.fingerprint;
+            let random = to_base36(rand::random_range(0..36 * 36));
+
+            return Ok(format!(
+                "{}{}{}{}{}",
+                last(&timestamp, 2),
+                last(&counter, BLOCK_LENGTH),
+                &print[..1],
+                &print[print.len() - 1..],
+                pad(&random, 2)
+            ));
+        }
+
+        let random = (0..2)
+            .map(|_| {
+                pad(
+                    &to_base36(rand::random_range(0..BLOCK_VALUES)),
+                    BLOCK_LENGTH,
+                )
+            })
+            .collect::<String>();
+
+        Ok(format!(
+            "c{timestamp}{}{}{random}",
+            pad(&counter, BLOCK_LENGTH),
+            self.fingerprint
+        ))
+    }
+}
+
+/// Derives the 4-character fingerprint from the process id and the host name.
+///
+/// The host part is the sum of the host name's character codes, plus its length and 36,
+/// as in the reference implementation for Node.js.
+fn fingerprint(pid: u32, hostname: &str) -> String {
+    let host = hostname
+        .chars()
+        .fold(hostname.chars().count() as u64 + 36, |sum, c| {
+            sum + u64::from(c)
+        });
+
+    format!(
+        "{}{}",
+        pad(&to_base36(u64::from(pid)), 2),
+        pad(&to_base36(host), 2)
+    )
+}
+
+/// Reads the host name, falling back to `localhost` where it is unavailable.
+fn hostname() -> String {
+    std::fs::read_to_string("/proc/sys/kernel/hostname")
+        .or_else(|_| std::fs::read_to_string("/etc/hostname"))
+        .ok()
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .map(|name| name.trim().to_owned())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| String::from("localhost"))
+}
+
+/// Writes a number in lowercase base36.
+fn to_base36(mut value: u64) -> String {
+    let mut digits = Vec::new();
+
+    loop {
+        digits.push(BASE36[(value % 36) as usize]);
+        value /= 36;
+
+        if value == 0 {
+            break;
+        }
+    }
+
+    digits.reverse();
+    String::from_utf8(digits).expect("base36 symbols are ASCII")
+}
+
+/// Pads a string with leading zeros, or cuts it to its last characters, to the size.
+fn pad(value: &str, size: usize) -> String {
+    match value.len() < size {
+        true => format!("{value:0>size$}"),
+        false => last(value, size).to_owned(),
+    }
+}
+
+/// Returns up to the last given number of characters of an ASCII string.
+fn last(value: &str, size: usize) -> &str {
+    &value[value.len().saturating_sub(size)..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base36() {
+        assert_eq!(to_base36(0), "0");
+        assert_eq!(to_base36(35), "z");
+        assert_eq!(to_base36(1_700_000_000_000), "loyw3v28");
+    }
+
+    #[test]
+    fn test_pad() {
+        assert_eq!(pad("7", 4), "0007");
+        assert_eq!(pad("abcdef", 4), "cdef");
+        assert_eq!(pad("abcd", 4), "abcd");
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        // "host" sums to 446, plus its length (4) and 36: 486, or "di" in base36
+        assert_eq!(fingerprint(1234, "host"), "yadi");
+        assert_eq!(fingerprint(5, "host"), "05di");
+    }
+
+    #[test]
+    fn test_generate_format() {
+        let mut generator = CuidGenerator::new(false);
+        let cuid = generator.generate().unwrap();
+
+        assert_eq!(cuid.len(), 25);
+        assert!(cuid.starts_with('c'));
+        assert!(cuid.bytes().all(|c| BASE36.contains(&c)), "{cuid}");
+        assert_eq!(cuid[13..17], generator.fingerprint);
+    }
+
+    #[test]
+    fn test_generate_counter_advances() {
+        let mut generator = CuidGenerator::new(false);
+
+        let first = generator.generate().unwrap();
+        let second = generator.generate().unwrap();
+
+        assert_eq!(&first[9..13], "0000");
+        assert_eq!(&second[9..13], "0001");
+    }
+
+    #[test]
+    fn test_counter_wraps() {
+        let mut generator = CuidGenerator::new(false);
+        generator.counter = BLOCK_VALUES - 1;
+
+        assert_eq!(generator.next_counter(), BLOCK_VALUES - 1);
+        assert_eq!(generator.next_counter(), 0);
+    }
+
+    #[test]
+    fn test_generate_slug() {
+        let mut generator = CuidGenerator::new(true);
+        generator.counter = 36 * 36 * 36;
+
+        let first = generator.generate().unwrap();
+        let second = generator.generate().unwrap();
+
+        // 2 timestamp, 4 counter ("1000" and "1001"), 2 fingerprint, and 2 random characters
+        assert_eq!(first.len(), 10);
+        assert_eq!(&first[2..6], "1000");
+        assert_eq!(&second[2..6], "1001");
+    }
+}
@@ -0,0 +1,334 @@
+//! Hashids encoder and decoder.
+//!
+//! Hashids are the predecessors of Sqids: short strings encoding one or more
+//! non-negative integers, made distinct per project by a salt. They are still minted by
+//! many services, so the encoder follows the classic algorithm (version 1 of the
+//! reference implementations), and its output matches theirs for the same salt,
+//! alphabet, and minimum length.
+//!
+//! # Encoding
+//!
+//! The alphabet is split into symbols, separators (from `cfhistuCFHISTU`), and guards,
+//! all shuffled with the salt. A lottery symbol picked from the numbers comes first; then
+//! each number is written in the base of the alphabet, reshuffled with the lottery and
+//! the salt before every number, and followed by a separator. Short strings are wrapped
+//! in guards and then in halves of further shuffles of the alphabet up to the minimum
+//! length.
+//!
+//! # Decoding
+//!
+//! Decoding reverses these steps, and then encodes the numbers again: strings that do
+//! not come back unchanged were not made with the same salt, alphabet, and minimum
+//! length, and are refused.
+
+use anyhow::anyhow;
+
+use crate::generators::Generate;
+
+/// The default Hashids alphabet (62 symbols).
+pub const DEFAULT_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890";
+
+/// Symbols used as separators, when the alphabet has them.
+const SEPARATORS: &str = "cfhistuCFHISTU";
+
+/// Largest ratio of alphabet symbols to separators.
+const SEPARATOR_RATIO: f64 = 3.5;
+
+/// Number of alphabet symbols per guard.
+const GUARD_RATIO: usize = 12;
+
+/// Hashids encoder for given numbers, or for random ones.
+pub struct HashidsGenerator {
+    numbers: Vec<u64>,
+    encoder: Encoder,
+}
+
+impl HashidsGenerator {
+    /// Creates an encoder; the alphabet must be validated (see
+    /// [`crate::utils::parse_hashids_alphabet`]).
+    pub fn new(numbers: &[u64], salt: &str, alphabet: &str, min_length: u8) -> Self {
+        Self {
+            numbers: numbers.to_vec(),
+            encoder: Encoder::new(salt, alphabet, min_length),
+        }
+    }
+}
+
+impl Generate for HashidsGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        Ok(match self.numbers.is_empty() {
+            true => self.encoder.encode(&[rand::random()]),
+            false => self.encoder.encode(&self.numbers),
+        })
+    }
+}
+
+/// Decodes a hashid back into its numbers.
+pub fn decode(
+    hashid: &str,
+    salt: &str,
+    alphabet: &str,
+    min_length: u8,
+) -> anyhow::Result<Vec<u64>> {
+    Encoder::new(salt, alphabet, min_length)
+        .decode(hashid)
+        .ok_or_else(|| {
+            anyhow!("{hashid:?} is not a hashid of this salt, alphabet, and minimum length")
+        })
+}
+
+/// Symbols, separators, and guards derived from the salt and the alphabet.
+struct Encoder {
+    salt: Vec<char>,
+    alphabet: Vec<char>,
+    separators: Vec<char>,
+    guards: Vec<char>,
+    min_length: usize,
+}
+
+impl Encoder {
+    fn new(salt: &str, alphabet: &str, min_length: u8) -> Self {
+        let salt: Vec<char> = salt.chars().collect();
+
+        // Separators are taken out of the alphabet, keeping their own order
+        let mut separators: Vec<char> = SEPARATORS
+            .chars()
+            .filter(|c| alphabet.contains(*c))
+            .collect();
+        let mut alphabet: Vec<char> = alphabet
+            .chars()
+            .filter(|c| !separators.contains(c))
+            .collect();
+        shuffle(&mut separators, &salt);
+
+        if separators.is_empty()
+            || alphabet.len() as f64 / separators.len() as f64 > SEPARATOR_RATIO
+        {
+            let length = ((alphabet.len() as f64 / SEPARATOR_RATIO).ceil() as usize).max(2);
+
+            if length > separators.len() {
+                let missing = length - separators.len();
+                separators.extend(alphabet.drain(..missing));
+            } else {
+                separators.truncate(length);
+            }
+        }
+
+        shuffle(&mut alphabet, &salt);
+
+        let guard_count = alphabet.len().div_ceil(GUARD_RATIO);
+        let guards = match alphabet.len() < 3 {
+            true => separators.drain(..guard_count).collect(),
+            false => alphabet.drain(..guard_count).collect(),
+        };
+
+        Self {
+            salt,
+            alphabet,
+            separators,
+            guards,
+            min_length: usize::from(min_length),
+        }
+    }
+
+    /// Encodes the numbers into a string of at least the minimum length.
+    fn encode(&self, numbers: &[u64]) -> String {
+        let mut alphabet = self.alphabet.clone();
+
+        let numbers_hash = numbers
+            .iter()
+            .enumerate()
+            .map(|(index, number)| number % (index as u64 + 100))
+            .sum::<u64>();
+
+        let lottery = alphabet[(numbers_hash % alphabet.len() as u64) as usize];
+        let mut encoded = vec![lottery];
+
+        for (index, number) in numbers.iter().enumerate() {
+            self.reshuffle(&mut alphabet, lottery);
+
+            let start = encoded.len();
+            encode_number(*number, &alphabet, &mut encoded);
+
+            if index < numbers.len() - 1 {
+                let number = number % (u64::from(encoded[start]) + index as u64);
+                encoded.push(self.separators[(number % self.separators.len() as u64) as usize]);
+            }
+        }
+
+        if encoded.len() < self.min_length {
+            let index = (numbers_hash + u64::from(encoded[0])) % self.guards.len() as u64;
+            encoded.insert(0, self.guards[index as usize]);
+
+            if encoded.len() < self.min_length {
+                let index = (numbers_hash + u64::from(encoded[2])) % self.guards.len() as u64;
+                encoded.push(self.guards[index as usize]);
+            }
+        }
+
+        let half = alphabet.len() / 2;
+
+        while encoded.len() < self.min_length {
+            let key = alphabet.clone();
+            shuffle(&mut alphabet, &key);
+
+            encoded.splice(0..0, alphabet[half..].iter().copied());
+            encoded.extend_from_slice(&alphabet[..half]);
+
+            let excess = encoded.len().saturating_sub(self.min_length);
+            if excess > 0 {
+                encoded.drain(..excess / 2);
+                encoded.truncate(self.min_length);
+            }
+        }
+
+        encoded.into_iter().collect()
+    }
+
+    /// Decodes a string into its numbers, if it is the encoding of them.
+    fn decode(&self, hashid: &str) -> Option<Vec<u64>> {
+        let parts: Vec<&str> = hashid.split(|c| self.guards.contains(&c)).collect();
+        let breakdown = match parts.len() {
+            2 | 3 => parts[1],
+            _ => parts[0],
+        };
+
+        let mut chars = breakdown.chars();
+        let lottery = chars.next()?;
+
+        let mut alphabet = self.alphabet.clone();
+        let mut numbers = Vec::new();
+
+        for part in chars.as_str().split(|c| self.separators.contains(&c)) {
+            self.reshuffle(&mut alphabet, lottery);
+            numbers.push(decode_number(part, &alphabet)?);
+        }
+
+        (self.encode(&numbers) == hashid).then_some(numbers)
+    }
+
+    /// Reshuffles the alphabet before a number, with the lottery, the salt, and itself.
+    fn reshuffle(&self, alphabet: &mut [char], lottery: char) {
+        let key: Vec<char> = std::iter::once(lottery)
+            .chain(self.salt.iter().copied())
+            .chain(alphabet.iter().copied())
+            .take(alphabet.len())
+            .collect();
+
+        shuffle(alphabet, &key);
+    }
+}
+
+/// Shuffles the symbols with a salt, as the reference implementations do.
+fn shuffle(symbols: &mut [char], salt: &[char]) {
+    if salt.is_empty() {
+        return;
+    }
+
+    let mut sum = 0;
+
+    for (i, v) in (1..symbols.len()).rev().zip((0..salt.len()).cycle()) {
+        let integer = salt[v] as usize;
+        sum += integer;
+        symbols.swap(i, (integer + v + sum) % i);
+    }
+}
+
+/// Appends a number written in the base of the alphabet (most significant first).
+fn encode_number(mut number: u64, alphabet: &[char], encoded: &mut Vec<char>) {
+    let start = encoded.len();
+
+    loop {
+        encoded.push(alphabet[(number % alphabet.len() as u64) as usize]);
+        number /= alphabet.len() as u64;
+
+        if number == 0 {
+            break;
+        }
+    }
+
+    encoded[start..].reverse();
+}
+
+/// Reads a number written in the base of the alphabet, if it fits into 64 bits.
+fn decode_number(part: &str, alphabet: &[char]) -> Option<u64> {
+    part.chars().try_fold(0u64, |number, c| {
+        let digit = alphabet.iter().position(|symbol| *symbol == c)?;
+
+        number
+            .checked_mul(alphabet.len() as u64)?
+            .checked_add(digit as u64)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(numbers: &[u64], salt: &str, min_length: u8) -> String {
+        Encoder::new(salt, DEFAULT_ALPHABET, min_length).encode(numbers)
+    }
+
+    #[test]
+    fn test_encode_reference_examples() {
+        assert_eq!(encode(&[1, 2, 3], "this is my salt", 0), "laHquq");
+        assert_eq!(encode(&[12345], "this is my salt", 0), "NkK9");
+        assert_eq!(encode(&[1], "this is my salt", 8), "gB0NV05e");
+    }
+
+    #[test]
+    fn test_encode_without_salt() {
+        assert_eq!(encode(&[1, 2, 3], "", 0), "o2fXhV");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for numbers in [
+            &[0][..],
+            &[1, 2, 3],
+            &[u64::MAX],
+            &[u64::MAX, 0, 42, u64::MAX],
+        ] {
+            for min_length in [0, 10, 40] {
+                let hashid = encode(numbers, "salt", min_length);
+
+                assert!(hashid.len() >= usize::from(min_length));
+                assert_eq!(
+                    decode(&hashid, "salt", DEFAULT_ALPHABET, min_length).unwrap(),
+                    numbers
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_other_salt() {
+        let hashid = encode(&[1, 2, 3], "this is my salt", 0);
+
+        assert_eq!(
+            decode(&hashid, "another salt", DEFAULT_ALPHABET, 0)
+                .unwrap_err()
+                .to_string(),
+            "\"laHquq\" is not a hashid of this salt, alphabet, and minimum length"
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        for hashid in ["", "!", "laHquq!"] {
+            assert!(decode(hashid, "this is my salt", DEFAULT_ALPHABET, 0).is_err());
+        }
+    }
+
+    #[test]
+    fn test_generate_random_numbers() {
+        let mut generator = HashidsGenerator::new(&[], "salt", DEFAULT_ALPHABET, 0);
+
+        let hashid = generator.generate().unwrap();
+
+        assert_eq!(
+            decode(&hashid, "salt", DEFAULT_ALPHABET, 0).unwrap().len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Random integer ID generator.
+//!
+//! Integer IDs are plain random numbers, printed in decimal, for tables keyed by random
+//! `BIGINT` (or `INTEGER`) columns. They span the range of a width (32, 53, 63, or 64
+//! bits, signed or not), optionally narrowed by bounds and without zero.
+//!
+//! # Randomness
+//!
+//! Integers come from the thread-local generator of the `rand` crate (a cryptographically
+//! secure PRNG), sampled with a `Uniform` distribution over the range. It rejects the raw
+//! values that would otherwise wrap around unevenly, so every integer of the range is
+//! equally likely. Zero is likewise rejected and drawn again when excluded.
+
+use rand::distr::{Distribution, Uniform};
+
+use crate::generators::Generate;
+
+/// Random integer generator over an inclusive range.
+pub struct IntGenerator {
+    range: Uniform<i128>,
+    nonzero: bool,
+}
+
+impl IntGenerator {
+    /// Creates a generator; the range must be validated to hold at least two integers
+    /// (besides zero, when excluded).
+    pub fn new(min: i128, max: i128, nonzero: bool) -> Self {
+        Self {
+            range: Uniform::new_inclusive(min, max).expect("the range is validated"),
+            nonzero,
+        }
+    }
+}
+
+impl Generate for IntGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let mut rng = rand::rng();
+
+        loop {
+            let value = self.range.sample(&mut rng);
+
+            if !(self.nonzero && value == 0) {
+                return Ok(value.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_within_bounds() {
+        let mut generator = IntGenerator::new(-3, 3, false);
+
+        for _ in 0..1000 {
+            let value: i128 = generator.generate().unwrap().parse().unwrap();
+
+            assert!((-3..=3).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_generate_nonzero() {
+        let mut generator = IntGenerator::new(-1, 1, true);
+
+        assert!((0..1000).all(|_| generator.generate().unwrap() != "0"));
+    }
+
+    #[test]
+    fn test_generate_full_range() {
+        let mut generator = IntGenerator::new(i64::MIN.into(), u64::MAX.into(), false);
+
+        let value: i128 = generator.generate().unwrap().parse().unwrap();
+
+        assert!(value >= i64::MIN.into() && value <= u64::MAX.into());
+    }
+}
@@ -0,0 +1,157 @@
+//! KSUID (K-Sortable Unique Identifier) generator.
+//!
+//! KSUIDs are 160-bit identifiers popularized by Segment's Go library. They are:
+//! - Sortable by creation time (with second precision)
+//! - Encoded as 27-character base62 strings (`0-9A-Za-z`)
+//! - Mostly random, with 128 bits of payload
+//!
+//! # Format
+//!
+//! A KSUID consists of:
+//! - 4 bytes: Timestamp (seconds since the KSUID epoch, `1400000000` in Unix time)
+//! - 16 bytes: Random payload
+//!
+//! The 20 bytes are read as a big-endian number and encoded in base62, left-padded with
+//! zeros to 27 characters. Since the padding keeps every KSUID the same length, the
+//! string order matches the numeric order, and so the timestamp order.
+//!
+//! # Usage
+//!
+//! The generator can operate in two modes:
+//! - **Current time**: Uses the current time from [`clock`] (default)
+//! - **Fixed timestamp**: Uses a provided Unix timestamp in seconds
+//!
+//! When using a fixed timestamp, the timestamp portion remains constant but the
+//! random payload changes with each generation, ensuring uniqueness.
+
+use crate::clock;
+use crate::generators::Generate;
+
+/// Unix timestamp (in seconds) of the KSUID epoch.
+pub const EPOCH: u64 = 1_400_000_000;
+
+/// Latest Unix timestamp (in seconds) a KSUID can hold.
+pub const MAX_TIMESTAMP: u64 = EPOCH + u32::MAX as u64;
+
+/// Length of a base62-encoded KSUID.
+const ENCODED_LENGTH: usize = 27;
+
+/// Base62 alphabet, in ASCII order so that string order matches numeric order.
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// KSUID generator that can use either current time or a fixed timestamp.
+///
+/// The generator stores an optional timestamp in seconds since Unix epoch.
+/// If `None`, it generates KSUIDs using the current system time.
+pub struct KsuidGenerator {
+    timestamp: Option<u64>,
+}
+
+impl KsuidGenerator {
+    pub fn new(timestamp: Option<u64>) -> Self {
+        Self { timestamp }
+    }
+}
+
+impl Generate for KsuidGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let seconds = self.timestamp.unwrap_or_else(|| clock::now().0);
+        let offset = u32::try_from(seconds.saturating_sub(EPOCH))
+            .expect("timestamps are validated to fit the KSUID range");
+
+        let mut bytes = [0u8; 20];
+        bytes[..4].copy_from_slice(&offset.to_be_bytes());
+        bytes[4..].copy_from_slice(&rand::random::<[u8; 16]>());
+
+        Ok(encode_base62(bytes))
+    }
+}
+
+/// Encodes 20 big-endian bytes as a zero-padded, 27-character base62 string.
+fn encode_base62(mut bytes: [u8; 20]) -> String {
+    let mut encoded = [BASE62[0]; ENCODED_LENGTH];
+
+    for symbol in encoded.iter_mut().rev() {
+        // Long division of the whole number by 62, keeping the quotient in place
+        let mut remainder = 0u32;
+        for byte in bytes.iter_mut() {
+            let value = (remainder << 8) | u32::from(*byte);
+            *byte = (value / 62) as u8;
+            remainder = value % 62;
+        }
+
+        *symbol = BASE62[remainder as usize];
+    }
+
+    String::from_utf8(encoded.to_vec()).expect("base62 symbols are ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper function to validate KSUID format
+    fn assert_ksuid_format(ksuid: &str) {
+        assert_eq!(ksuid.len(), 27, "KSUID should be 27 characters long");
+        assert!(
+            ksuid.chars().all(|c| c.is_ascii_alphanumeric()),
+            "KSUID should only contain base62 characters"
+        );
+    }
+
+    #[test]
+    fn test_generate_without_timestamp() {
+        let mut generator = KsuidGenerator::new(None);
+
+        let ksuid = generator.generate().unwrap();
+        assert_ksuid_format(&ksuid);
+    }
+
+    #[test]
+    fn test_generate_with_timestamp() {
+        let mut generator = KsuidGenerator::new(Some(1609459200));
+
+        let first = generator.generate().unwrap();
+        let second = generator.generate().unwrap();
+
+        assert_ksuid_format(&first);
+        assert_ksuid_format(&second);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_encode_base62_bounds() {
+        assert_eq!(encode_base62([0; 20]), "000000000000000000000000000");
+        assert_eq!(encode_base62([0xFF; 20]), "aWgEPTl1tmebfsQzFP4bxwgy80V");
+    }
+
+    #[test]
+    fn test_encode_base62_known_value() {
+        // Example from the reference implementation's documentation
+        let mut bytes = [0u8; 20];
+        hex::decode_to_slice("0669F7EFB5A1CD34B5F99D1154FB6853345C9735", &mut bytes).unwrap();
+
+        assert_eq!(encode_base62(bytes), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+    }
+
+    #[test]
+    fn test_generate_at_timestamp_bounds() {
+        let mut first_after_epoch = [0u8; 20];
+        first_after_epoch[3] = 1;
+        let ksuid = KsuidGenerator::new(Some(EPOCH)).generate().unwrap();
+        assert!(ksuid < encode_base62(first_after_epoch), "{ksuid}");
+
+        let mut latest = [0u8; 20];
+        latest[..4].fill(0xFF);
+        let ksuid = KsuidGenerator::new(Some(MAX_TIMESTAMP)).generate().unwrap();
+        assert!(ksuid >= encode_base62(latest), "{ksuid}");
+    }
+
+    #[test]
+    fn test_generate_sorts_by_timestamp() {
+        let earlier = KsuidGenerator::new(Some(1609459200)).generate().unwrap();
+        let later = KsuidGenerator::new(Some(1609459201)).generate().unwrap();
+
+        assert!(earlier < later, "{earlier} >= {later}");
+    }
+}
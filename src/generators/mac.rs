@@ -0,0 +1,91 @@
+//! Random MAC address generator.
+//!
+//! MAC addresses are 6 bytes, printed as hex in one of several notations. Random ones
+//! come in handy for VM configurations and network tests.
+//!
+//! # Bits
+//!
+//! The two low bits of the first byte tell what kind of address it is:
+//! - Bit 0 (individual/group): Set for multicast addresses, clear for unicast ones
+//! - Bit 1 (universal/local): Set for locally administered addresses, clear for
+//!   universally administered ones (assigned to vendors through their OUI)
+//!
+//! Addresses are locally administered and unicast by default, so they never clash with
+//! real hardware; `--multicast` and `--universal` flip these bits. An OUI pins the first
+//! three bytes (bits included), and only the last three are random.
+
+use crate::cli::mac::MacFormat;
+use crate::generators::Generate;
+use crate::utils;
+
+/// Random MAC address generator with fixed bits and notation.
+pub struct MacGenerator {
+    oui: Option<[u8; 3]>,
+    multicast: bool,
+    universal: bool,
+    format: MacFormat,
+}
+
+impl MacGenerator {
+    pub fn new(oui: Option<[u8; 3]>, multicast: bool, universal: bool, format: MacFormat) -> Self {
+        Self {
+            oui,
+            multicast,
+            universal,
+            format,
+        }
+    }
+}
+
+impl Generate for MacGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let mac = utils::generate_pseudo_mac(self.oui, self.multicast, self.universal);
+
+        Ok(format(&mac, self.format))
+    }
+}
+
+/// Formats MAC address bytes in the given notation.
+fn format(mac: &[u8; 6], format: MacFormat) -> String {
+    let digits = hex::encode(mac);
+
+    match format {
+        MacFormat::Colon => group(&digits, 2, ":"),
+        MacFormat::Hyphen => group(&digits, 2, "-"),
+        MacFormat::Dot => group(&digits, 4, "."),
+        MacFormat::Bare => digits,
+    }
+}
+
+/// Joins groups of the given number of hex digits with a separator.
+fn group(digits: &str, size: usize, separator: &str) -> String {
+    digits
+        .as_bytes()
+        .chunks(size)
+        .map(|chunk| std::str::from_utf8(chunk).expect("hex digits are ASCII"))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAC: [u8; 6] = [0x02, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e];
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format(&MAC, MacFormat::Colon), "02:1a:2b:3c:4d:5e");
+        assert_eq!(format(&MAC, MacFormat::Hyphen), "02-1a-2b-3c-4d-5e");
+        assert_eq!(format(&MAC, MacFormat::Dot), "021a.2b3c.4d5e");
+        assert_eq!(format(&MAC, MacFormat::Bare), "021a2b3c4d5e");
+    }
+
+    #[test]
+    fn test_generate_oui() {
+        let mut generator =
+            MacGenerator::new(Some([0x00, 0x16, 0x3e]), false, true, MacFormat::Bare);
+
+        assert!(generator.generate().unwrap().starts_with("00163e"));
+    }
+}
@@ -1,8 +1,11 @@
 //! Identifier generator implementations and abstraction layer.
 //!
-//! This module provides a unified interface for generating different types of unique identifiers.
-//! Each identifier type (UUID, ULID, ObjectId) has its own submodule with a generator struct that
-//! implements the [`Generate`] trait.
+//! This module provides a unified interface for generating different types of unique
+//! identifiers. Each identifier type (UUID, ULID, ObjectId, KSUID, snowflake, composite,
+//! TSID, SCRU128, TypeID, push ID, CUID, NUID, Sqids, Hashids, NanoID, short code, token,
+//! proquint, slug, API key, Crockford base32, PIN, integer ID, MAC address, timestamp
+//! slug, sequence) has its own submodule with a generator struct that implements the
+//! [`Generate`] trait.
 //!
 //! # Architecture
 //!
@@ -10,11 +13,41 @@
 //!
 //! 1. [`Generate`] trait: Common interface for all generators
 //! 2. [`Generator`] enum: Top-level wrapper that dispatches to specific generators
-//! 3. Type-specific generators: [`uuid::UuidGenerator`], [`ulid::UlidGenerator`], [`objectid::ObjectIdGenerator`]
+//! 3. Type-specific generators, one per submodule:
+//!    - [`uuid::UuidGenerator`]
+//!    - [`ulid::UlidGenerator`]
+//!    - [`objectid::ObjectIdGenerator`]
+//!    - [`ksuid::KsuidGenerator`]
+//!    - [`snowflake::SnowflakeGenerator`]
+//!    - [`composite::CompositeGenerator`]
+//!    - [`tsid::TsidGenerator`]
+//!    - [`scru128::Scru128Generator`]
+//!    - [`typeid::TypeIdGenerator`]
+//!    - [`pushid::PushIdGenerator`]
+//!    - [`cuid::CuidGenerator`]
+//!    - [`nuid::NuidGenerator`]
+//!    - [`sqids::SqidsGenerator`]
+//!    - [`hashids::HashidsGenerator`]
+//!    - [`nanoid::NanoIdGenerator`]
+//!    - [`shortcode::ShortcodeGenerator`]
+//!    - [`token::TokenGenerator`]
+//!    - [`proquint::ProquintGenerator`]
+//!    - [`slug::SlugGenerator`]
+//!    - [`apikey::ApiKeyGenerator`]
+//!    - [`crockford::CrockfordGenerator`]
+//!    - [`pin::PinGenerator`]
+//!    - [`int::IntGenerator`]
+//!    - [`mac::MacGenerator`]
+//!    - [`stamp::StampGenerator`]
+//!    - [`seq::SeqGenerator`]
 //!
 //! This pattern allows the main application logic to work with any generator type
 //! without knowing the specifics of each identifier format.
 //!
+//! The UUID, ULID, and ObjectId generators are compiled in with the `uuid`, `ulid`, and
+//! `objectid` features (on by default), so minimal builds can leave them and their
+//! dependencies out.
+//!
 //! # Usage Flow
 //!
 //! ```text
@@ -24,11 +57,39 @@
 //! The [`Generator::from`] implementation handles the conversion from CLI commands
 //! to the appropriate generator instance.
 
+pub mod apikey;
+pub mod composite;
+pub mod crockford;
+pub mod cuid;
+pub mod hashids;
+pub mod int;
+pub mod ksuid;
+pub mod mac;
+pub mod nanoid;
+pub mod nuid;
+#[cfg(feature = "objectid")]
 pub mod objectid;
+pub mod pin;
+pub mod proquint;
+pub mod pushid;
+pub mod scru128;
+pub mod seq;
+pub mod shortcode;
+pub mod slug;
+pub mod snowflake;
+pub mod sqids;
+pub mod stamp;
+pub mod token;
+pub mod tsid;
+pub mod typeid;
+#[cfg(feature = "ulid")]
 pub mod ulid;
+#[cfg(feature = "uuid")]
 pub mod uuid;
 
 use crate::cli::Commands;
+#[cfg(feature = "uuid")]
+use crate::cli::IdFormat;
 
 /// Common interface for identifier generators.
 ///
@@ -37,7 +98,11 @@ use crate::cli::Commands;
 /// remain agnostic to the specific identifier type being generated.
 pub trait Generate {
     /// Generates a new identifier and returns it as a string.
-    fn generate(&self) -> String;
+    ///
+    /// Takes `&mut self` so generators can carry state (such as counters) across
+    /// the identifiers of a single run. Fails when a generator cannot produce a valid
+    /// identifier (for example, when its retry budget runs out).
+    fn generate(&mut self) -> anyhow::Result<String>;
 }
 
 /// Top-level generator wrapper that dispatches to specific identifier generators.
@@ -46,17 +111,105 @@ pub trait Generate {
 /// polymorphically. It's constructed from CLI [`Commands`] and delegates
 /// generation to the appropriate underlying generator.
 pub enum Generator {
+    #[cfg(feature = "uuid")]
     Uuid(uuid::UuidGenerator),
+    #[cfg(feature = "uuid")]
+    ShortUuid(uuid::ShortUuidGenerator),
+    #[cfg(feature = "uuid")]
+    GuidUuid(uuid::GuidUuidGenerator),
+    #[cfg(feature = "uuid")]
+    EncodedUuid(uuid::EncodedUuidGenerator),
+    #[cfg(feature = "uuid")]
+    TextUuid(uuid::TextUuidGenerator),
+    #[cfg(feature = "ulid")]
     Ulid(ulid::UlidGenerator),
+    #[cfg(feature = "objectid")]
     ObjectId(objectid::ObjectIdGenerator),
+    Ksuid(ksuid::KsuidGenerator),
+    Snowflake(snowflake::SnowflakeGenerator),
+    Composite(composite::CompositeGenerator),
+    Tsid(tsid::TsidGenerator),
+    Scru128(scru128::Scru128Generator),
+    TypeId(typeid::TypeIdGenerator),
+    PushId(pushid::PushIdGenerator),
+    Cuid(cuid::CuidGenerator),
+    Nuid(nuid::NuidGenerator),
+    Sqids(sqids::SqidsGenerator),
+    Hashids(hashids::HashidsGenerator),
+    NanoId(nanoid::NanoIdGenerator),
+    Shortcode(shortcode::ShortcodeGenerator),
+    Token(token::TokenGenerator),
+    Proquint(proquint::ProquintGenerator),
+    Slug(slug::SlugGenerator),
+    ApiKey(apikey::ApiKeyGenerator),
+    Crockford(crockford::CrockfordGenerator),
+    Pin(pin::PinGenerator),
+    Int(int::IntGenerator),
+    Mac(mac::MacGenerator),
+    Stamp(stamp::StampGenerator),
+    Seq(seq::SeqGenerator),
 }
 
 impl Generate for Generator {
-    fn generate(&self) -> String {
+    fn generate(&mut self) -> anyhow::Result<String> {
         match self {
+            #[cfg(feature = "uuid")]
             Generator::Uuid(g) => g.generate(),
+            #[cfg(feature = "uuid")]
+            Generator::ShortUuid(g) => g.generate(),
+            #[cfg(feature = "uuid")]
+            Generator::GuidUuid(g) => g.generate(),
+            #[cfg(feature = "uuid")]
+            Generator::EncodedUuid(g) => g.generate(),
+            #[cfg(feature = "uuid")]
+            Generator::TextUuid(g) => g.generate(),
+            #[cfg(feature = "ulid")]
             Generator::Ulid(g) => g.generate(),
+            #[cfg(feature = "objectid")]
             Generator::ObjectId(g) => g.generate(),
+            Generator::Ksuid(g) => g.generate(),
+            Generator::Snowflake(g) => g.generate(),
+            Generator::Composite(g) => g.generate(),
+            Generator::Tsid(g) => g.generate(),
+            Generator::Scru128(g) => g.generate(),
+            Generator::TypeId(g) => g.generate(),
+            Generator::PushId(g) => g.generate(),
+            Generator::Cuid(g) => g.generate(),
+            Generator::Nuid(g) => g.generate(),
+            Generator::Sqids(g) => g.generate(),
+            Generator::Hashids(g) => g.generate(),
+            Generator::NanoId(g) => g.generate(),
+            Generator::Shortcode(g) => g.generate(),
+            Generator::Token(g) => g.generate(),
+            Generator::Proquint(g) => g.generate(),
+            Generator::Slug(g) => g.generate(),
+            Generator::ApiKey(g) => g.generate(),
+            Generator::Crockford(g) => g.generate(),
+            Generator::Pin(g) => g.generate(),
+            Generator::Int(g) => g.generate(),
+            Generator::Mac(g) => g.generate(),
+            Generator::Stamp(g) => g.generate(),
+            Generator::Seq(g) => g.generate(),
+        }
+    }
+}
+
+impl Generator {
+    /// Returns the timestamp (with the clock sequence) of the last version 1 or 6 UUID
+    /// generated, if any, to save with `--state-file`.
+    pub fn last_uuid_timestamp(&self) -> Option<::uuid::Timestamp> {
+        match self {
+            #[cfg(feature = "uuid")]
+            Generator::Uuid(g) => g.last_timestamp(),
+            #[cfg(feature = "uuid")]
+            Generator::ShortUuid(g) => g.last_timestamp(),
+            #[cfg(feature = "uuid")]
+            Generator::GuidUuid(g) => g.last_timestamp(),
+            #[cfg(feature = "uuid")]
+            Generator::EncodedUuid(g) => g.last_timestamp(),
+            #[cfg(feature = "uuid")]
+            Generator::TextUuid(g) => g.last_timestamp(),
+            _ => None,
         }
     }
 }
@@ -64,25 +217,190 @@ impl Generate for Generator {
 impl From<&Commands> for Generator {
     fn from(command: &Commands) -> Self {
         match command {
-            Commands::Uuid {
-                version,
+            #[cfg(feature = "uuid")]
+            Commands::Uuid(args) if args.short => Generator::ShortUuid(
+                uuid::ShortUuidGenerator::new(uuid::UuidGenerator::from_args(args)),
+            ),
+            #[cfg(feature = "uuid")]
+            Commands::Uuid(args) if args.format != IdFormat::Canonical => {
+                Generator::EncodedUuid(uuid::EncodedUuidGenerator::new(
+                    uuid::UuidGenerator::from_args(args),
+                    args.format,
+                    args.uppercase,
+                    args.guid,
+                ))
+            }
+            #[cfg(feature = "uuid")]
+            Commands::Uuid(args) if args.guid && !args.simple && !args.urn => Generator::GuidUuid(
+                uuid::GuidUuidGenerator::new(uuid::UuidGenerator::from_args(args)),
+            ),
+            #[cfg(feature = "uuid")]
+            // GUIDs in other hex forms keep their uppercase digits
+            Commands::Uuid(args) if args.simple || args.urn || args.braces || args.uppercase => {
+                let form = match (args.simple, args.urn, args.braces) {
+                    (true, _, _) => uuid::UuidText::Simple,
+                    (_, true, _) => uuid::UuidText::Urn,
+                    (_, _, true) => uuid::UuidText::Braced,
+                    _ => uuid::UuidText::Hyphenated,
+                };
+
+                Generator::TextUuid(uuid::TextUuidGenerator::new(
+                    uuid::UuidGenerator::from_args(args),
+                    form,
+                    args.uppercase || args.guid,
+                ))
+            }
+            #[cfg(feature = "uuid")]
+            Commands::Uuid(args) => Generator::Uuid(uuid::UuidGenerator::from_args(args)),
+            #[cfg(feature = "ulid")]
+            Commands::Ulid {
+                timestamp,
+                check,
+                format,
+            } => Generator::Ulid(ulid::UlidGenerator::new(*timestamp, *check, *format)),
+            #[cfg(feature = "objectid")]
+            Commands::ObjectId { timestamp } => {
+                // NOTE: The parser caps ObjectId timestamps at 32 bits
+                let timestamp = timestamp.map(|timestamp| timestamp as u32);
+                Generator::ObjectId(objectid::ObjectIdGenerator::new(timestamp))
+            }
+            Commands::Ksuid { timestamp } => {
+                Generator::Ksuid(ksuid::KsuidGenerator::new(*timestamp))
+            }
+            Commands::Snowflake {
+                worker_id,
+                epoch,
+                timestamp,
+            } => Generator::Snowflake(snowflake::SnowflakeGenerator::new(
+                *worker_id, *epoch, *timestamp,
+            )),
+            Commands::Composite {
+                layout,
+                epoch,
+                shard,
                 timestamp,
-                namespace,
-                name,
-                node_id,
-                data,
-            } => Generator::Uuid(uuid::UuidGenerator::from_params(
-                *version,
+            } => Generator::Composite(composite::CompositeGenerator::new(
+                layout,
+                *epoch,
+                shard.unwrap_or(0),
                 *timestamp,
-                namespace.as_ref(),
-                name.as_ref(),
-                node_id.as_ref(),
-                data.as_ref(),
             )),
-            Commands::Ulid { timestamp } => Generator::Ulid(ulid::UlidGenerator::new(*timestamp)),
-            Commands::ObjectId { timestamp } => {
-                Generator::ObjectId(objectid::ObjectIdGenerator::new(*timestamp))
+            Commands::Tsid {
+                node,
+                epoch,
+                timestamp,
+                format,
+            } => Generator::Tsid(tsid::TsidGenerator::new(*node, *epoch, *timestamp, *format)),
+            Commands::Scru128 { timestamp } => {
+                Generator::Scru128(scru128::Scru128Generator::new(*timestamp))
+            }
+            Commands::TypeId { prefix, timestamp } => {
+                Generator::TypeId(typeid::TypeIdGenerator::new(prefix, *timestamp))
+            }
+            Commands::PushId { timestamp } => {
+                Generator::PushId(pushid::PushIdGenerator::new(*timestamp))
+            }
+            Commands::Cuid { slug } => Generator::Cuid(cuid::CuidGenerator::new(*slug)),
+            Commands::Nuid => Generator::Nuid(nuid::NuidGenerator::new()),
+            Commands::Sqids {
+                numbers,
+                alphabet,
+                min_length,
+            } => Generator::Sqids(sqids::SqidsGenerator::new(numbers, alphabet, *min_length)),
+            Commands::Hashids {
+                numbers,
+                salt,
+                alphabet,
+                min_length,
+                ..
+            } => Generator::Hashids(hashids::HashidsGenerator::new(
+                numbers,
+                salt,
+                alphabet,
+                *min_length,
+            )),
+            Commands::NanoId { size, alphabet } => {
+                Generator::NanoId(nanoid::NanoIdGenerator::new(size.get(), alphabet))
+            }
+            Commands::Shortcode(args) => {
+                Generator::Shortcode(shortcode::ShortcodeGenerator::from_args(args))
+            }
+            Commands::Token { bytes, encoding } => {
+                Generator::Token(token::TokenGenerator::new(*bytes as usize, *encoding))
+            }
+            Commands::Proquint { bits, from_hex } => {
+                Generator::Proquint(proquint::ProquintGenerator::new(*bits, from_hex.as_deref()))
             }
+            Commands::Slug {
+                words,
+                separator,
+                digits,
+            } => Generator::Slug(slug::SlugGenerator::new(*words, separator, *digits)),
+            Commands::ApiKey {
+                prefix,
+                bytes,
+                checksum,
+            } => Generator::ApiKey(apikey::ApiKeyGenerator::new(
+                prefix.as_deref(),
+                *bytes as usize,
+                *checksum,
+            )),
+            Commands::Crockford { length, check } => {
+                Generator::Crockford(crockford::CrockfordGenerator::new(*length as usize, *check))
+            }
+            Commands::Pin {
+                digits,
+                no_leading_zero,
+            } => Generator::Pin(pin::PinGenerator::new(*digits, *no_leading_zero)),
+            Commands::Int {
+                bits,
+                signed,
+                nonzero,
+                min,
+                max,
+                ..
+            } => {
+                let (lowest, highest) = bits.range(*signed);
+
+                Generator::Int(int::IntGenerator::new(
+                    min.unwrap_or(lowest),
+                    max.unwrap_or(highest),
+                    *nonzero,
+                ))
+            }
+            Commands::Mac {
+                format,
+                oui,
+                multicast,
+                universal,
+            } => Generator::Mac(mac::MacGenerator::new(
+                *oui, *multicast, *universal, *format,
+            )),
+            Commands::Stamp {
+                suffix_length,
+                precision,
+                timestamp,
+            } => Generator::Stamp(stamp::StampGenerator::new(
+                *suffix_length,
+                *precision,
+                *timestamp,
+            )),
+            Commands::Seq {
+                start,
+                step,
+                pad,
+                prefix,
+            } => Generator::Seq(seq::SeqGenerator::new(*start, *step, *pad, prefix)),
+            Commands::Redact { .. }
+            | Commands::Validate { .. }
+            | Commands::Sortkey { .. }
+            | Commands::Words { .. }
+            | Commands::Estimate(_) => unreachable!(
+                "redactions, validations, sort keys, decoded words, and estimates do not \
+                 generate identifiers"
+            ),
+            #[cfg(not(all(feature = "uuid", feature = "ulid", feature = "objectid")))]
+            _ => unreachable!("commands compiled out are refused when parsing arguments"),
         }
     }
 }
@@ -0,0 +1,69 @@
+//! NanoID generator.
+//!
+//! NanoIDs are random strings over a URL-safe alphabet, commonly used for short URL
+//! slugs. By default, they're 21 symbols long over 64 symbols (`A-Za-z0-9_-`), giving
+//! 126 random bits, comparable to a version 4 UUID.
+//!
+//! # Alphabet
+//!
+//! A custom alphabet of 2 to 255 distinct symbols can be used instead. Every symbol is
+//! drawn uniformly, so alphabets of any size stay unbiased.
+
+use crate::generators::Generate;
+
+/// NanoID generator drawing a fixed number of symbols from an alphabet.
+pub struct NanoIdGenerator {
+    symbols: Vec<char>,
+    size: usize,
+}
+
+impl NanoIdGenerator {
+    pub fn new(size: usize, alphabet: &str) -> Self {
+        Self {
+            symbols: alphabet.chars().collect(),
+            size,
+        }
+    }
+}
+
+impl Generate for NanoIdGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        Ok((0..self.size)
+            .map(|_| self.symbols[rand::random_range(0..self.symbols.len())])
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL_ALPHABET: &str = "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    #[test]
+    fn test_generate_default() {
+        let mut generator = NanoIdGenerator::new(21, URL_ALPHABET);
+
+        let nanoid = generator.generate().unwrap();
+
+        assert_eq!(nanoid.len(), 21);
+        assert!(nanoid.chars().all(|c| URL_ALPHABET.contains(c)));
+    }
+
+    #[test]
+    fn test_generate_custom_size() {
+        let mut generator = NanoIdGenerator::new(12, URL_ALPHABET);
+
+        assert_eq!(generator.generate().unwrap().len(), 12);
+    }
+
+    #[test]
+    fn test_generate_custom_alphabet() {
+        let mut generator = NanoIdGenerator::new(64, "αβ");
+
+        let nanoid = generator.generate().unwrap();
+
+        assert_eq!(nanoid.chars().count(), 64);
+        assert!(nanoid.chars().all(|c| c == 'α' || c == 'β'));
+    }
+}
@@ -0,0 +1,150 @@
+//! NUID (NATS unique identifier) generator.
+//!
+//! NUIDs are the identifiers the NATS clients use for inboxes and such. They are:
+//! - 22 characters long, over the base62 alphabet (`0-9A-Za-z`)
+//! - Cheap to generate in bulk, and unique without coordination
+//! - Not sortable, and not meant to be secrets
+//!
+//! # Format
+//!
+//! A NUID consists of:
+//! - Prefix: 12 random characters, one per random byte (modulo 62)
+//! - Sequence: 10 characters, a base62 number below 62^10
+//!
+//! # Sequence
+//!
+//! Following the reference implementation, the prefix is kept for the whole run, and
+//! the sequence starts at a random value and moves forward by a random increment (from
+//! 33 to 332, drawn once) for every NUID. When the sequence reaches 62^10, a new prefix
+//! is drawn and the sequence and increment start over.
+
+use crate::generators::Generate;
+
+/// Base62 alphabet of the reference implementation.
+const DIGITS: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Number of prefix characters.
+const PREFIX_LENGTH: usize = 12;
+
+/// Number of sequence characters.
+const SEQ_LENGTH: usize = 10;
+
+/// Sequence values stay below 62^10.
+const MAX_SEQ: u64 = 62u64.pow(SEQ_LENGTH as u32);
+
+/// Smallest sequence increment.
+const MIN_INC: u64 = 33;
+
+/// Sequence increments stay below this value.
+const MAX_INC: u64 = 333;
+
+/// NUID generator keeping a prefix and sequence for the whole run.
+pub struct NuidGenerator {
+    prefix: [u8; PREFIX_LENGTH],
+    seq: u64,
+    inc: u64,
+}
+
+impl NuidGenerator {
+    pub fn new() -> Self {
+        let mut generator = Self {
+            prefix: [DIGITS[0]; PREFIX_LENGTH],
+            seq: 0,
+            inc: 0,
+        };
+        generator.randomize_prefix();
+        generator.reset_sequence();
+
+        generator
+    }
+
+    /// Draws a new prefix from random bytes.
+    fn randomize_prefix(&mut self) {
+        self.prefix = rand::random::<[u8; PREFIX_LENGTH]>()
+            .map(|byte| DIGITS[usize::from(byte) % DIGITS.len()]);
+    }
+
+    /// Draws a new starting sequence value and increment.
+    fn reset_sequence(&mut self) {
+        self.seq = rand::random_range(0..MAX_SEQ);
+        self.inc = rand::random_range(MIN_INC..MAX_INC);
+    }
+}
+
+impl Generate for NuidGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        self.seq += self.inc;
+        if self.seq >= MAX_SEQ {
+            self.randomize_prefix();
+            self.reset_sequence();
+        }
+
+        Ok(encode(&self.prefix, self.seq))
+    }
+}
+
+/// Encodes a prefix and a sequence value (below 62^10) as a NUID.
+fn encode(prefix: &[u8; PREFIX_LENGTH], seq: u64) -> String {
+    let mut encoded = [DIGITS[0]; PREFIX_LENGTH + SEQ_LENGTH];
+    encoded[..PREFIX_LENGTH].copy_from_slice(prefix);
+
+    let mut value = seq;
+    for symbol in encoded[PREFIX_LENGTH..].iter_mut().rev() {
+        *symbol = DIGITS[(value % 62) as usize];
+        value /= 62;
+    }
+
+    String::from_utf8(encoded.to_vec()).expect("base62 symbols are ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_known_values() {
+        let prefix = *b"ABCDEFGHIJKL";
+
+        assert_eq!(encode(&prefix, 0), "ABCDEFGHIJKL0000000000");
+        assert_eq!(encode(&prefix, 62 * 62 + 1), "ABCDEFGHIJKL0000000101");
+        assert_eq!(encode(&prefix, MAX_SEQ - 1), "ABCDEFGHIJKLzzzzzzzzzz");
+    }
+
+    #[test]
+    fn test_new_ranges() {
+        let generator = NuidGenerator::new();
+
+        assert!(generator.prefix.iter().all(|c| DIGITS.contains(c)));
+        assert!(generator.seq < MAX_SEQ);
+        assert!((MIN_INC..MAX_INC).contains(&generator.inc));
+    }
+
+    #[test]
+    fn test_generate_increments_sequence() {
+        let mut generator = NuidGenerator::new();
+        generator.seq = 1000;
+        generator.inc = 100;
+        let prefix = generator.prefix;
+
+        let first = generator.generate().unwrap();
+        let second = generator.generate().unwrap();
+
+        assert_eq!(first, encode(&prefix, 1100));
+        assert_eq!(second, encode(&prefix, 1200));
+    }
+
+    #[test]
+    fn test_generate_rolls_over() {
+        let mut generator = NuidGenerator::new();
+        generator.seq = MAX_SEQ - 100;
+        generator.inc = 100;
+        generator.prefix = [b'-'; PREFIX_LENGTH];
+
+        let nuid = generator.generate().unwrap();
+
+        // The prefix is drawn anew, and the sequence starts over without an increment
+        assert_ne!(generator.prefix, [b'-'; PREFIX_LENGTH]);
+        assert!(generator.seq < MAX_SEQ);
+        assert_eq!(nuid, encode(&generator.prefix, generator.seq));
+    }
+}
@@ -17,47 +17,61 @@
 //! # Usage
 //!
 //! The generator can operate in two modes:
-//! - **Current time**: Uses the system clock (default)
+//! - **Current time**: Uses the current time from [`clock`] (default)
 //! - **Fixed timestamp**: Uses a provided seconds-since-epoch timestamp
 //!
 //! When using a fixed timestamp, the timestamp portion is deterministic but
 //! the random and counter portions still change, ensuring uniqueness.
+//!
+//! # Design
+//!
+//! The BSON crate does not provide a constructor for ObjectId with a custom timestamp,
+//! so the generator mirrors what the crate does internally: it draws the 5-byte random
+//! value once per run and keeps its own counter, starting at a random value and wrapping
+//! at 24 bits. Each ObjectId is then assembled with `from_parts()`, taking the timestamp
+//! from the [`clock`] unless a fixed one is given, without calling `ObjectId::new()`.
 
+use crate::clock;
 use crate::generators::Generate;
 
+/// Maximum value of the 3-byte ObjectId counter.
+const MAX_COUNTER: u32 = 0xFF_FFFF;
+
 /// ObjectId generator that can use either current time or a fixed timestamp.
 ///
 /// The generator stores an optional timestamp in seconds since Unix epoch.
 /// If `None`, it generates ObjectIds using the current system time.
 pub struct ObjectIdGenerator {
     timestamp: Option<u32>,
+    random: [u8; 5],
+    counter: u32,
 }
 
 impl ObjectIdGenerator {
     pub fn new(timestamp: Option<u32>) -> Self {
-        Self { timestamp }
+        Self {
+            timestamp,
+            random: rand::random(),
+            counter: rand::random_range(0..=MAX_COUNTER),
+        }
+    }
+
+    /// Returns the current counter bytes (big-endian) and advances the counter.
+    fn next_counter(&mut self) -> [u8; 3] {
+        let [_, high, middle, low] = self.counter.to_be_bytes();
+        self.counter = (self.counter + 1) & MAX_COUNTER;
+
+        [high, middle, low]
     }
 }
 
 impl Generate for ObjectIdGenerator {
-    fn generate(&self) -> String {
-        match self.timestamp {
-            Some(seconds) => {
-                // HACK: The BSON crate does not provide a constructor for ObjectId with a custom
-                // timestamp. So, the workaround is to use original process identifier and counter
-                // bytes, then rebuild it with our timestamp using from_parts(). This maintains
-                // the original ObjectId generation behavior for everything except the timestamp
-                // portion.
-                let oid = bson::oid::ObjectId::new().bytes();
-                bson::oid::ObjectId::from_parts(
-                    seconds,
-                    [oid[4], oid[5], oid[6], oid[7], oid[8]],
-                    [oid[9], oid[10], oid[11]],
-                )
-                .to_hex()
-            }
-            None => bson::oid::ObjectId::new().to_hex(),
-        }
+    fn generate(&mut self) -> anyhow::Result<String> {
+        // ObjectId timestamps are 32-bit seconds, so they wrap in 2106
+        let seconds = self.timestamp.unwrap_or_else(|| clock::now().0 as u32);
+        let counter = self.next_counter();
+
+        Ok(bson::oid::ObjectId::from_parts(seconds, self.random, counter).to_hex())
     }
 }
 
@@ -76,38 +90,38 @@ mod tests {
 
     #[test]
     fn test_new_without_timestamp() {
-        let generator = ObjectIdGenerator::new(None);
+        let mut generator = ObjectIdGenerator::new(None);
 
         assert!(generator.timestamp.is_none());
 
-        let oid_str = generator.generate();
+        let oid_str = generator.generate().unwrap();
         assert_objectid_format(&oid_str);
     }
 
     #[test]
     fn test_new_with_timestamp() {
         let timestamp = 1234567890;
-        let generator = ObjectIdGenerator::new(Some(timestamp));
+        let mut generator = ObjectIdGenerator::new(Some(timestamp));
 
         assert_eq!(generator.timestamp, Some(1234567890));
 
-        let oid_str = generator.generate();
+        let oid_str = generator.generate().unwrap();
         assert_objectid_format(&oid_str);
     }
 
     #[test]
     fn test_generate_without_timestamp() {
-        let generator = ObjectIdGenerator::new(None);
+        let mut generator = ObjectIdGenerator::new(None);
 
-        let oid = generator.generate();
+        let oid = generator.generate().unwrap();
         assert_objectid_format(&oid);
     }
 
     #[test]
     fn test_generate_with_zero_timestamp() {
-        let generator = ObjectIdGenerator::new(Some(0));
+        let mut generator = ObjectIdGenerator::new(Some(0));
 
-        let oid_str = generator.generate();
+        let oid_str = generator.generate().unwrap();
         assert_objectid_format(&oid_str);
 
         // ObjectId with timestamp 0 should start with 8 zeros
@@ -117,12 +131,53 @@ mod tests {
     #[test]
     fn test_generate_with_max_u32_timestamp() {
         // Maximum u32 timestamp (year 2106)
-        let generator = ObjectIdGenerator::new(Some(u32::MAX));
+        let mut generator = ObjectIdGenerator::new(Some(u32::MAX));
 
-        let oid_str = generator.generate();
+        let oid_str = generator.generate().unwrap();
         assert_objectid_format(&oid_str);
 
         // Maximum u32 as hex should be "ffffffff"
         assert!(oid_str.starts_with("ffffffff"));
     }
+
+    #[test]
+    fn test_generate_with_timestamp_shares_random_value() {
+        let mut generator = ObjectIdGenerator::new(Some(1234567890));
+
+        let first = generator.generate().unwrap();
+        let second = generator.generate().unwrap();
+
+        // Timestamp and random value portions are the same within a run
+        assert_eq!(first[..18], second[..18]);
+        assert_ne!(first[18..], second[18..]);
+    }
+
+    #[test]
+    fn test_next_counter_increments() {
+        let mut generator = ObjectIdGenerator::new(Some(0));
+        generator.counter = 0x01_02FF;
+
+        assert_eq!(generator.next_counter(), [0x01, 0x02, 0xFF]);
+        assert_eq!(generator.next_counter(), [0x01, 0x03, 0x00]);
+    }
+
+    #[test]
+    fn test_next_counter_wraps() {
+        let mut generator = ObjectIdGenerator::new(Some(0));
+        generator.counter = MAX_COUNTER;
+
+        assert_eq!(generator.next_counter(), [0xFF, 0xFF, 0xFF]);
+        assert_eq!(generator.next_counter(), [0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_generate_with_timestamp_unique_batch() {
+        let mut generator = ObjectIdGenerator::new(Some(1234567890));
+
+        let batch: std::collections::HashSet<String> = (0..100_000)
+            .map(|_| generator.generate().unwrap())
+            .collect();
+
+        assert_eq!(batch.len(), 100_000);
+    }
 }
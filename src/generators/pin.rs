@@ -0,0 +1,86 @@
+//! Numeric PIN generator.
+//!
+//! PINs are random decimal codes, like `048213`, for one-time codes read out to or typed
+//! by people. They are zero-padded to a fixed number of digits (6 by default), or drawn
+//! without a leading zero for systems that parse them as numbers.
+//!
+//! # Randomness
+//!
+//! Codes come from the thread-local generator of the `rand` crate (a cryptographically
+//! secure PRNG), sampled with a `Uniform` distribution over the whole range. It rejects
+//! the raw values that would otherwise wrap around unevenly, so every code is equally
+//! likely, with no modulo bias (unlike `random_range`, whose single samples may be
+//! slightly biased).
+
+use rand::distr::{Distribution, Uniform};
+
+use crate::generators::Generate;
+
+/// Smallest number of digits.
+pub const MIN_DIGITS: u8 = 4;
+
+/// Largest number of digits.
+pub const MAX_DIGITS: u8 = 12;
+
+/// Numeric PIN generator of a fixed number of digits.
+pub struct PinGenerator {
+    digits: usize,
+    range: Uniform<u64>,
+}
+
+impl PinGenerator {
+    pub fn new(digits: u8, no_leading_zero: bool) -> Self {
+        let end = 10u64.pow(u32::from(digits));
+        let start = if no_leading_zero { end / 10 } else { 0 };
+
+        Self {
+            digits: usize::from(digits),
+            range: Uniform::new(start, end).expect("PINs have at least one digit"),
+        }
+    }
+}
+
+impl Generate for PinGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let pin = self.range.sample(&mut rand::rng());
+
+        Ok(format!("{pin:0width$}", width = self.digits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_digits() {
+        for digits in MIN_DIGITS..=MAX_DIGITS {
+            let mut generator = PinGenerator::new(digits, false);
+
+            let pin = generator.generate().unwrap();
+
+            assert_eq!(pin.len(), usize::from(digits));
+            assert!(pin.bytes().all(|c| c.is_ascii_digit()));
+        }
+    }
+
+    #[test]
+    fn test_generate_no_leading_zero() {
+        let mut generator = PinGenerator::new(MIN_DIGITS, true);
+
+        for _ in 0..1000 {
+            let pin = generator.generate().unwrap();
+
+            assert_eq!(pin.len(), 4);
+            assert!(!pin.starts_with('0'));
+        }
+    }
+
+    #[test]
+    fn test_generate_leading_zeros_padded() {
+        let mut generator = PinGenerator::new(MIN_DIGITS, false);
+
+        // About one PIN in ten starts with a zero, which must be kept
+        assert!((0..1000).any(|_| generator.generate().unwrap().starts_with('0')));
+    }
+}
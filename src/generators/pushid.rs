@@ -0,0 +1,206 @@
+//! Firebase push ID generator.
+//!
+//! Push IDs are the keys Firebase Realtime Database assigns to pushed children. They
+//! are:
+//! - 20 characters long, over a URL-safe base64 variant in ASCII order
+//! - Sortable by creation time, and strictly increasing within a generator
+//!
+//! # Format
+//!
+//! A push ID consists of:
+//! - Timestamp: 8 characters of 6 bits each (milliseconds since Unix epoch)
+//! - Random suffix: 12 characters of 6 bits each
+//!
+//! # Same Millisecond Rule
+//!
+//! Following the reference algorithm, the random suffix is drawn anew for every
+//! millisecond, and incremented by one (as a 72-bit number) for further push IDs within
+//! the same millisecond. So push IDs of a run never repeat and sort in the order they were
+//! generated, even with a fixed timestamp. A clock running backwards keeps the latest
+//! timestamp, rather than breaking the order as the reference algorithm does.
+//!
+//! # Usage
+//!
+//! The generator can operate in two modes:
+//! - **Current time**: Uses the current time from [`clock`] (default)
+//! - **Fixed timestamp**: Uses a provided millisecond timestamp
+
+use anyhow::anyhow;
+
+use crate::clock;
+use crate::generators::Generate;
+
+/// Latest millisecond timestamp a push ID can hold (48 bits).
+pub const MAX_TIMESTAMP: u64 = (1 << 48) - 1;
+
+/// Push ID alphabet, in ASCII order so that string order matches numeric order.
+const PUSH_CHARS: &[u8; 64] = b"-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz";
+
+/// Number of timestamp characters.
+const TIMESTAMP_LENGTH: usize = 8;
+
+/// Number of random suffix characters.
+const RANDOM_LENGTH: usize = 12;
+
+/// Push ID generator keeping the random suffix of the last millisecond.
+pub struct PushIdGenerator {
+    timestamp: Option<u64>,
+    last_timestamp: Option<u64>,
+    random: [u8; RANDOM_LENGTH],
+}
+
+impl PushIdGenerator {
+    pub fn new(timestamp: Option<u64>) -> Self {
+        Self {
+            timestamp,
+            last_timestamp: None,
+            random: [0; RANDOM_LENGTH],
+        }
+    }
+
+    /// Advances the random suffix to the given timestamp, drawing new values from the
+    /// given source.
+    fn advance(
+        &mut self,
+        timestamp: u64,
+        random: impl FnOnce() -> [u8; RANDOM_LENGTH],
+    ) -> anyhow::Result<u64> {
+        match self.last_timestamp {
+            Some(last) if timestamp <= last => {
+                // Incrementing the suffix, carrying from the last character
+                let Some(index) = self.random.iter().rposition(|value| *value < 63) else {
+                    return Err(anyhow!(
+                        "push ID random suffix exhausted at timestamp {last}"
+                    ));
+                };
+
+                self.random[index] += 1;
+                self.random[index + 1..].fill(0);
+
+                Ok(last)
+            }
+            _ => {
+                self.last_timestamp = Some(timestamp);
+                self.random = random().map(|value| value & 0x3f);
+
+                Ok(timestamp)
+            }
+        }
+    }
+}
+
+impl Generate for PushIdGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let timestamp = self.timestamp.unwrap_or_else(|| {
+            let (seconds, subsec_nanos) = clock::now();
+            seconds * 1000 + u64::from(subsec_nanos) / 1_000_000
+        });
+
+        let timestamp = self.advance(timestamp, rand::random)?;
+
+        Ok(encode(timestamp, &self.random))
+    }
+}
+
+/// Encodes a timestamp and random suffix values (each below 64) as a push ID.
+fn encode(timestamp: u64, random: &[u8; RANDOM_LENGTH]) -> String {
+    let mut encoded = [PUSH_CHARS[0]; TIMESTAMP_LENGTH + RANDOM_LENGTH];
+
+    for (index, symbol) in encoded[..TIMESTAMP_LENGTH].iter_mut().enumerate() {
+        let shift = 6 * (TIMESTAMP_LENGTH - 1 - index);
+        *symbol = PUSH_CHARS[(timestamp >> shift & 0x3f) as usize];
+    }
+    for (symbol, value) in encoded[TIMESTAMP_LENGTH..].iter_mut().zip(random) {
+        *symbol = PUSH_CHARS[usize::from(*value)];
+    }
+
+    String::from_utf8(encoded.to_vec()).expect("push ID symbols are ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_bounds() {
+        assert_eq!(encode(0, &[0; RANDOM_LENGTH]), "--------------------");
+        assert_eq!(
+            encode(MAX_TIMESTAMP, &[63; RANDOM_LENGTH]),
+            "zzzzzzzzzzzzzzzzzzzz"
+        );
+    }
+
+    #[test]
+    fn test_encode_timestamp() {
+        // 1700000000000 ms is 0, 24, 47, 15, 57, 22, 32, 0 in 6-bit groups
+        assert_eq!(
+            encode(1_700_000_000_000, &[0; RANDOM_LENGTH]),
+            "-NjEtLV-------------"
+        );
+    }
+
+    #[test]
+    fn test_advance_increments_within_millisecond() {
+        let mut generator = PushIdGenerator::new(None);
+        generator.advance(1000, || [5; RANDOM_LENGTH]).unwrap();
+        generator.advance(1000, || [9; RANDOM_LENGTH]).unwrap();
+
+        assert_eq!(generator.random[..11], [5; 11]);
+        assert_eq!(generator.random[11], 6);
+    }
+
+    #[test]
+    fn test_advance_carries() {
+        let mut generator = PushIdGenerator::new(None);
+        let mut random = [7; RANDOM_LENGTH];
+        random[10..].fill(63);
+        generator.advance(1000, || random).unwrap();
+        generator.advance(1000, || [9; RANDOM_LENGTH]).unwrap();
+
+        assert_eq!(generator.random[..9], [7; 9]);
+        assert_eq!(generator.random[9..], [8, 0, 0]);
+    }
+
+    #[test]
+    fn test_advance_rerolls_next_millisecond() {
+        let mut generator = PushIdGenerator::new(None);
+        generator.advance(1000, || [5; RANDOM_LENGTH]).unwrap();
+        let timestamp = generator.advance(1001, || [200; RANDOM_LENGTH]).unwrap();
+
+        assert_eq!(timestamp, 1001);
+        assert_eq!(generator.random, [200 & 0x3f; RANDOM_LENGTH]);
+    }
+
+    #[test]
+    fn test_advance_keeps_order_on_rollback() {
+        let mut generator = PushIdGenerator::new(None);
+        generator.advance(1000, || [5; RANDOM_LENGTH]).unwrap();
+        let timestamp = generator.advance(900, || [9; RANDOM_LENGTH]).unwrap();
+
+        assert_eq!(timestamp, 1000);
+        assert_eq!(generator.random[11], 6);
+    }
+
+    #[test]
+    fn test_advance_exhausted() {
+        let mut generator = PushIdGenerator::new(None);
+        generator.advance(1000, || [63; RANDOM_LENGTH]).unwrap();
+        let result = generator.advance(1000, || [0; RANDOM_LENGTH]);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "push ID random suffix exhausted at timestamp 1000"
+        );
+    }
+
+    #[test]
+    fn test_generate_with_timestamp_monotonic() {
+        let mut generator = PushIdGenerator::new(Some(1_700_000_000_000));
+
+        let ids: Vec<String> = (0..1000).map(|_| generator.generate().unwrap()).collect();
+
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(ids.iter().all(|id| id.starts_with("-NjEtLV-")));
+    }
+}
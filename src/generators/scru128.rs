@@ -0,0 +1,250 @@
+//! SCRU128 (Sortable, Clock and Random number-based Unique identifier) generator.
+//!
+//! SCRU128 identifiers are 128-bit identifiers that are:
+//! - Sortable by creation time, and strictly monotonic within a generator
+//! - Encoded as 25-character base36 strings (`0-9a-z`)
+//! - Made unique with counters and random bits rather than a node id
+//!
+//! # Format
+//!
+//! A SCRU128 identifier consists of:
+//! - 48-bit timestamp (milliseconds since Unix epoch)
+//! - 24-bit `counter_hi`, reseeded randomly every second
+//! - 24-bit `counter_lo`, reseeded randomly every millisecond and incremented otherwise
+//! - 32-bit entropy, random for every identifier
+//!
+//! # Counters
+//!
+//! The generator follows the reference algorithm: an identifier in the same (or an
+//! earlier, within a 10 second rollback allowance) millisecond increments `counter_lo`,
+//! carrying into `counter_hi`, and into the timestamp once both overflow. So identifiers
+//! of a run are strictly increasing, even with a fixed timestamp. A larger clock
+//! rollback resets the generator state.
+//!
+//! # Usage
+//!
+//! The generator can operate in two modes:
+//! - **Current time**: Uses the current time from [`clock`] (default)
+//! - **Fixed timestamp**: Uses a provided millisecond timestamp
+
+use anyhow::anyhow;
+
+use crate::clock;
+use crate::generators::Generate;
+
+/// Latest millisecond timestamp SCRU128 can hold (48 bits).
+pub const MAX_TIMESTAMP: u64 = (1 << 48) - 1;
+
+/// Largest value of the 24-bit counters.
+const MAX_COUNTER: u32 = (1 << 24) - 1;
+
+/// Clock rollback (in milliseconds) tolerated before resetting the generator state.
+const ROLLBACK_ALLOWANCE: u64 = 10_000;
+
+/// Length of a base36-encoded SCRU128 identifier.
+const ENCODED_LENGTH: usize = 25;
+
+/// Base36 alphabet, in ASCII order so that string order matches numeric order.
+const BASE36: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// SCRU128 generator keeping the counter state of the run.
+pub struct Scru128Generator {
+    timestamp: Option<u64>,
+    last_timestamp: u64,
+    counter_hi: u32,
+    counter_lo: u32,
+    counter_hi_timestamp: Option<u64>,
+}
+
+impl Scru128Generator {
+    pub fn new(timestamp: Option<u64>) -> Self {
+        Self {
+            timestamp,
+            last_timestamp: 0,
+            counter_hi: 0,
+            counter_lo: 0,
+            counter_hi_timestamp: None,
+        }
+    }
+
+    /// Advances the counter state to the given timestamp, drawing random values from
+    /// the given source.
+    fn advance(
+        &mut self,
+        timestamp: u64,
+        mut random_counter: impl FnMut() -> u32,
+    ) -> anyhow::Result<()> {
+        if timestamp > self.last_timestamp || self.counter_hi_timestamp.is_none() {
+            self.last_timestamp = timestamp;
+            self.counter_lo = random_counter();
+        } else if timestamp + ROLLBACK_ALLOWANCE >= self.last_timestamp {
+            self.counter_lo += 1;
+
+            if self.counter_lo > MAX_COUNTER {
+                self.counter_lo = 0;
+                self.counter_hi += 1;
+
+                if self.counter_hi > MAX_COUNTER {
+                    self.counter_hi = 0;
+                    self.last_timestamp += 1;
+                    self.counter_lo = random_counter();
+                }
+            }
+        } else {
+            self.last_timestamp = timestamp;
+            self.counter_lo = random_counter();
+            self.counter_hi_timestamp = None;
+        }
+
+        if self.last_timestamp > MAX_TIMESTAMP {
+            return Err(anyhow!(
+                "SCRU128 counters exhausted at the maximum timestamp {MAX_TIMESTAMP}"
+            ));
+        }
+
+        if self
+            .counter_hi_timestamp
+            .is_none_or(|since| self.last_timestamp - since >= 1000)
+        {
+            self.counter_hi_timestamp = Some(self.last_timestamp);
+            self.counter_hi = random_counter();
+        }
+
+        Ok(())
+    }
+}
+
+impl Generate for Scru128Generator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let timestamp = self.timestamp.unwrap_or_else(|| {
+            let (seconds, subsec_nanos) = clock::now();
+            seconds * 1000 + u64::from(subsec_nanos) / 1_000_000
+        });
+
+        self.advance(timestamp, || rand::random::<u32>() & MAX_COUNTER)?;
+
+        let value = u128::from(self.last_timestamp) << 80
+            | u128::from(self.counter_hi) << 56
+            | u128::from(self.counter_lo) << 32
+            | u128::from(rand::random::<u32>());
+
+        Ok(encode_base36(value))
+    }
+}
+
+/// Encodes a 128-bit value as a zero-padded, 25-character base36 string.
+fn encode_base36(mut value: u128) -> String {
+    let mut encoded = [BASE36[0]; ENCODED_LENGTH];
+
+    for symbol in encoded.iter_mut().rev() {
+        *symbol = BASE36[(value % 36) as usize];
+        value /= 36;
+    }
+
+    String::from_utf8(encoded.to_vec()).expect("base36 symbols are ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_base36_bounds() {
+        assert_eq!(encode_base36(0), "0000000000000000000000000");
+        assert_eq!(encode_base36(u128::MAX), "f5lxx1zz5pnorynqglhzmsp33");
+    }
+
+    #[test]
+    fn test_encode_base36_known_value() {
+        // The example identifier of the specification, in lowercase
+        let value = 0x017fa1d8beb625087798d410f90dfec1_u128;
+
+        assert_eq!(encode_base36(value), "036z951mhjikzik2gsl81gr7l");
+    }
+
+    #[test]
+    fn test_generate_format() {
+        let mut generator = Scru128Generator::new(None);
+        let id = generator.generate().unwrap();
+
+        assert_eq!(id.len(), 25);
+        assert!(id.bytes().all(|c| BASE36.contains(&c)), "{id}");
+    }
+
+    #[test]
+    fn test_generate_with_timestamp_monotonic() {
+        let mut generator = Scru128Generator::new(Some(1_700_000_000_000));
+
+        let ids: Vec<String> = (0..1000).map(|_| generator.generate().unwrap()).collect();
+
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_advance_increments_counter_lo() {
+        let mut generator = Scru128Generator::new(None);
+        generator.advance(1000, || 5).unwrap();
+        generator.advance(1000, || 7).unwrap();
+
+        assert_eq!(generator.last_timestamp, 1000);
+        assert_eq!(generator.counter_hi, 5);
+        assert_eq!(generator.counter_lo, 6);
+    }
+
+    #[test]
+    fn test_advance_carries_into_timestamp() {
+        let mut generator = Scru128Generator::new(None);
+        generator.advance(1000, || MAX_COUNTER).unwrap();
+        generator.advance(1000, || 3).unwrap();
+
+        // Both counters overflowed, so the timestamp moved a millisecond ahead
+        assert_eq!(generator.last_timestamp, 1001);
+        assert_eq!(generator.counter_hi, 0);
+        assert_eq!(generator.counter_lo, 3);
+    }
+
+    #[test]
+    fn test_advance_reseeds_counter_hi_every_second() {
+        let mut generator = Scru128Generator::new(None);
+        generator.advance(1000, || 1).unwrap();
+        generator.advance(1999, || 2).unwrap();
+        assert_eq!(generator.counter_hi, 1);
+
+        generator.advance(2000, || 3).unwrap();
+        assert_eq!(generator.counter_hi, 3);
+    }
+
+    #[test]
+    fn test_advance_tolerates_small_rollback() {
+        let mut generator = Scru128Generator::new(None);
+        generator.advance(20_000, || 1).unwrap();
+        generator.advance(15_000, || 9).unwrap();
+
+        assert_eq!(generator.last_timestamp, 20_000);
+        assert_eq!(generator.counter_lo, 2);
+    }
+
+    #[test]
+    fn test_advance_resets_on_large_rollback() {
+        let mut generator = Scru128Generator::new(None);
+        generator.advance(20_000, || 1).unwrap();
+        generator.advance(5_000, || 9).unwrap();
+
+        assert_eq!(generator.last_timestamp, 5_000);
+        assert_eq!(generator.counter_hi, 9);
+        assert_eq!(generator.counter_lo, 9);
+    }
+
+    #[test]
+    fn test_advance_exhausted_at_max_timestamp() {
+        let mut generator = Scru128Generator::new(None);
+        generator.advance(MAX_TIMESTAMP, || MAX_COUNTER).unwrap();
+        let result = generator.advance(MAX_TIMESTAMP, || MAX_COUNTER);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "SCRU128 counters exhausted at the maximum timestamp 281474976710655"
+        );
+    }
+}
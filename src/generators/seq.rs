@@ -0,0 +1,110 @@
+//! Sequential number generator.
+//!
+//! Sometimes the identifier needed is just the next number: fixture rows, file names, or
+//! ticket numbers. Sequences count up from a start value by a fixed step, optionally
+//! zero-padded and prefixed, like `INV-000042`.
+//!
+//! # Format
+//!
+//! A sequence value consists of:
+//! - Prefix: Optional, as given
+//! - Number: Decimal, zero-padded to a minimum width (unpadded by default)
+//!
+//! # Overflow
+//!
+//! Values never wrap around: a run stepping past the largest 64-bit value fails at that
+//! point, after writing out the values before it.
+
+use anyhow::anyhow;
+
+use crate::generators::Generate;
+
+/// Largest zero-padding width (the number of digits in the largest 64-bit value).
+pub const MAX_PAD: u8 = 20;
+
+/// Sequence generator counting up from a start value.
+pub struct SeqGenerator {
+    value: u64,
+    step: u64,
+    started: bool,
+    pad: usize,
+    prefix: String,
+}
+
+impl SeqGenerator {
+    pub fn new(start: u64, step: u64, pad: u8, prefix: &str) -> Self {
+        Self {
+            value: start,
+            step,
+            started: false,
+            pad: usize::from(pad),
+            prefix: prefix.to_owned(),
+        }
+    }
+}
+
+impl Generate for SeqGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        // Stepping before all but the first value, so the last one never overflows
+        if self.started {
+            self.value = self.value.checked_add(self.step).ok_or_else(|| {
+                anyhow!(
+                    "sequence overflows 64 bits after {} with a step of {}",
+                    self.value,
+                    self.step
+                )
+            })?;
+        }
+        self.started = true;
+
+        Ok(format!(
+            "{}{:0pad$}",
+            self.prefix,
+            self.value,
+            pad = self.pad
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_consecutive() {
+        let mut generator = SeqGenerator::new(1, 1, 0, "");
+        let values: Vec<_> = (0..3).map(|_| generator.generate().unwrap()).collect();
+
+        assert_eq!(values, ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_generate_padded_with_prefix() {
+        let mut generator = SeqGenerator::new(998, 2, 4, "INV-");
+        let values: Vec<_> = (0..3).map(|_| generator.generate().unwrap()).collect();
+
+        assert_eq!(values, ["INV-0998", "INV-1000", "INV-1002"]);
+    }
+
+    #[test]
+    fn test_generate_wider_than_pad() {
+        let mut generator = SeqGenerator::new(123_456, 1, 3, "");
+
+        assert_eq!(generator.generate().unwrap(), "123456");
+    }
+
+    #[test]
+    fn test_generate_overflow() {
+        let mut generator = SeqGenerator::new(u64::MAX - 1, 1, 0, "");
+
+        assert_eq!(generator.generate().unwrap(), "18446744073709551614");
+        assert_eq!(generator.generate().unwrap(), "18446744073709551615");
+
+        let result = generator.generate();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "sequence overflows 64 bits after 18446744073709551615 with a step of 1"
+        );
+    }
+}
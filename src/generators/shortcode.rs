@@ -0,0 +1,234 @@
+//! Short code generator.
+//!
+//! Short codes are random strings over a small alphabet, meant for humans to read and
+//! type (like invite codes). Their length is the shortest one that keeps the collision
+//! probability among the expected number of codes at or below the acceptable one.
+//!
+//! # Sizing
+//!
+//! The length is computed by [`estimate::minimum_length`] with the same birthday
+//! approximation as the `estimate` command: `n` codes of length `L` over an alphabet of
+//! `A` symbols collide with a probability of about `1 - exp(-n² / 2A^L)`.
+//!
+//! # Banned Substrings
+//!
+//! Single-character banned substrings shrink the alphabet before sizing. Codes containing
+//! longer ones are regenerated, up to a retry budget. Since that also shrinks the space,
+//! [`ShortcodeGenerator::clean_fraction`] estimates how much of it is left, so the caller
+//! can warn when the collision probability no longer holds.
+
+use anyhow::anyhow;
+
+use crate::cli::shortcode::{BannedSubstrings, ShortcodeArgs};
+use crate::estimate;
+use crate::generators::Generate;
+
+/// Maximum number of attempts to draw a code free of banned substrings.
+const RETRY_BUDGET: usize = 1000;
+
+/// Short code generator drawing a fixed number of symbols from an alphabet.
+pub struct ShortcodeGenerator {
+    symbols: Vec<u8>,
+    length: usize,
+    banned: BannedSubstrings,
+}
+
+impl ShortcodeGenerator {
+    pub fn new(symbols: &[u8], length: usize, banned: BannedSubstrings) -> Self {
+        Self {
+            symbols: banned.filter_symbols(symbols),
+            length,
+            banned,
+        }
+    }
+
+    pub fn from_args(args: &ShortcodeArgs) -> Self {
+        let banned = args.exclude_substrings.clone().unwrap_or_default();
+        let symbols = banned.filter_symbols(args.alphabet.symbols());
+        let length = estimate::minimum_length(args.expected, symbols.len(), args.probability);
+
+        Self::new(&symbols, length, banned)
+    }
+
+    /// Returns the length of the generated codes.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Estimates the fraction of codes free of banned substrings.
+    ///
+    /// Occurrences of each banned substring are treated as independent rare events, so
+    /// the fraction is `exp(-λ)`, where `λ` is their expected number in a random code.
+    pub fn clean_fraction(&self) -> f64 {
+        let alphabet_size = self.symbols.len() as f64;
+
+        let expected_occurrences: f64 = self
+            .banned
+            .0
+            .iter()
+            .filter(|banned| banned.len() <= self.length)
+            .map(|banned| {
+                let positions = (self.length - banned.len() + 1) as f64;
+                let probability: f64 = banned
+                    .bytes()
+                    .map(|c| {
+                        let matching = self
+                            .symbols
+                            .iter()
+                            .filter(|symbol| symbol.to_ascii_lowercase() == c)
+                            .count();
+                        matching as f64 / alphabet_size
+                    })
+                    .product();
+
+                positions * probability
+            })
+            .sum();
+
+        (-expected_occurrences).exp()
+    }
+
+    /// Returns the collision probability among the expected number of codes, accounting
+    /// for the ones excluded by banned substrings.
+    pub fn collision_probability(&self, expected: usize) -> f64 {
+        let bits = self.length as f64 * (self.symbols.len() as f64).log2();
+
+        estimate::collision_probability(expected as f64, bits + self.clean_fraction().log2())
+    }
+
+    /// Draws a code free of banned substrings from the given source of random indices.
+    fn generate_with(
+        &self,
+        mut random_index: impl FnMut(usize) -> usize,
+    ) -> anyhow::Result<String> {
+        for _ in 0..RETRY_BUDGET {
+            let code: String = (0..self.length)
+                .map(|_| self.symbols[random_index(self.symbols.len())] as char)
+                .collect();
+
+            if !self.banned.matches(&code) {
+                return Ok(code);
+            }
+        }
+
+        Err(anyhow!(
+            "no short code free of banned substrings after {RETRY_BUDGET} attempts"
+        ))
+    }
+}
+
+impl Generate for ShortcodeGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        self.generate_with(|size| rand::random_range(0..size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::shortcode::ShortcodeAlphabet;
+
+    #[test]
+    fn test_generate_respects_length() {
+        let mut generator = ShortcodeGenerator::new(
+            ShortcodeAlphabet::Alphanumeric.symbols(),
+            7,
+            BannedSubstrings::default(),
+        );
+
+        for _ in 0..100 {
+            assert_eq!(generator.generate().unwrap().len(), 7);
+        }
+    }
+
+    #[test]
+    fn test_generate_respects_alphabet() {
+        let symbols = ShortcodeAlphabet::NoLookalikes.symbols();
+        let mut generator = ShortcodeGenerator::new(symbols, 32, BannedSubstrings::default());
+
+        for _ in 0..100 {
+            let code = generator.generate().unwrap();
+
+            assert!(code.bytes().all(|c| symbols.contains(&c)), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_from_args() {
+        let args = ShortcodeArgs {
+            expected: 100_000,
+            alphabet: ShortcodeAlphabet::NoLookalikes,
+            probability: 1e-6,
+            exclude_substrings: None,
+            verbose: false,
+        };
+
+        let mut generator = ShortcodeGenerator::from_args(&args);
+
+        assert_eq!(generator.length(), 9);
+        assert_eq!(generator.generate().unwrap().len(), 9);
+    }
+
+    #[test]
+    fn test_from_args_with_banned_symbols() {
+        // Banning every letter leaves the 8 digits
+        let banned = ('a'..='z').map(String::from).collect();
+        let args = ShortcodeArgs {
+            expected: 100_000,
+            alphabet: ShortcodeAlphabet::NoLookalikes,
+            probability: 1e-6,
+            exclude_substrings: Some(BannedSubstrings(banned)),
+            verbose: false,
+        };
+
+        let mut generator = ShortcodeGenerator::from_args(&args);
+
+        assert_eq!(generator.symbols, b"23456789");
+        assert_eq!(generator.length(), 18);
+        assert!(
+            generator
+                .generate()
+                .unwrap()
+                .bytes()
+                .all(|c| c.is_ascii_digit())
+        );
+    }
+
+    #[test]
+    fn test_generate_regenerates_banned() {
+        let banned = BannedSubstrings(vec![String::from("ab")]);
+        let generator = ShortcodeGenerator::new(b"AB", 2, banned);
+
+        // The first draw spells "AB" (banned, case-insensitively), the second "BB"
+        let mut indices = [0, 1, 1, 1].into_iter();
+        let code = generator.generate_with(|_| indices.next().unwrap());
+
+        assert_eq!(code.unwrap(), "BB");
+    }
+
+    #[test]
+    fn test_generate_exhausts_retry_budget() {
+        let banned = BannedSubstrings(vec![String::from("ab")]);
+        let generator = ShortcodeGenerator::new(b"AB", 2, banned);
+
+        let mut indices = [0, 1].into_iter().cycle();
+        let result = generator.generate_with(|_| indices.next().unwrap());
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "no short code free of banned substrings after 1000 attempts"
+        );
+    }
+
+    #[test]
+    fn test_clean_fraction() {
+        let generator = ShortcodeGenerator::new(b"0123456789", 4, BannedSubstrings::default());
+        assert_eq!(generator.clean_fraction(), 1.0);
+
+        // "12" is expected 3 times in 100 codes of 4 digits
+        let banned = BannedSubstrings(vec![String::from("12"), String::from("12345")]);
+        let generator = ShortcodeGenerator::new(b"0123456789", 4, banned);
+        assert!((generator.clean_fraction() - (-0.03f64).exp()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,262 @@
+//! Human-readable slug generator.
+//!
+//! Slugs are Heroku-style names, like `quiet-meadow-4821`, for things people refer to by
+//! name: ephemeral environments, test clusters, and such. They are not meant to be
+//! unique on their own, so pair them with a collision check where it matters.
+//!
+//! # Format
+//!
+//! A slug consists of, joined by a separator (`-` by default):
+//! - Adjectives: 1 or 2 of them
+//! - Noun: 1
+//! - Number: 0 to 6 random digits, zero-padded (omitted for 0 digits)
+//!
+//! # Randomness
+//!
+//! Words come from the embedded lists below, each drawn uniformly (the `rand` crate's
+//! ranges reject out-of-range samples rather than taking a biased modulo), and so does
+//! the number.
+
+use crate::generators::Generate;
+
+/// Adjectives leading a slug.
+const ADJECTIVES: [&str; 78] = [
+    "aged",
+    "amber",
+    "ancient",
+    "autumn",
+    "billowing",
+    "bitter",
+    "black",
+    "blue",
+    "bold",
+    "brave",
+    "broken",
+    "calm",
+    "cold",
+    "cool",
+    "crimson",
+    "damp",
+    "dark",
+    "delicate",
+    "divine",
+    "dry",
+    "eager",
+    "empty",
+    "falling",
+    "floral",
+    "fragrant",
+    "frosty",
+    "gentle",
+    "golden",
+    "green",
+    "hidden",
+    "hollow",
+    "holy",
+    "icy",
+    "late",
+    "lingering",
+    "little",
+    "lively",
+    "long",
+    "lucky",
+    "mellow",
+    "misty",
+    "muddy",
+    "nameless",
+    "noble",
+    "old",
+    "patient",
+    "polished",
+    "proud",
+    "purple",
+    "quiet",
+    "red",
+    "restless",
+    "rough",
+    "shy",
+    "silent",
+    "silver",
+    "small",
+    "snowy",
+    "solitary",
+    "sparkling",
+    "spring",
+    "still",
+    "summer",
+    "swift",
+    "tender",
+    "twilight",
+    "velvet",
+    "vivid",
+    "wandering",
+    "warm",
+    "weathered",
+    "white",
+    "wild",
+    "winter",
+    "wise",
+    "wispy",
+    "withered",
+    "young",
+];
+
+/// Nouns following the adjectives.
+const NOUNS: [&str; 75] = [
+    "bird",
+    "breeze",
+    "brook",
+    "bush",
+    "butterfly",
+    "canyon",
+    "cherry",
+    "cloud",
+    "darkness",
+    "dawn",
+    "dew",
+    "dream",
+    "dust",
+    "ember",
+    "feather",
+    "field",
+    "fire",
+    "firefly",
+    "flower",
+    "fog",
+    "forest",
+    "frog",
+    "frost",
+    "garden",
+    "glade",
+    "glitter",
+    "grass",
+    "harbor",
+    "haze",
+    "hill",
+    "island",
+    "lake",
+    "lantern",
+    "leaf",
+    "meadow",
+    "moon",
+    "morning",
+    "mountain",
+    "night",
+    "orchard",
+    "paper",
+    "pine",
+    "pond",
+    "rain",
+    "reef",
+    "resonance",
+    "ridge",
+    "river",
+    "sea",
+    "shadow",
+    "shape",
+    "silence",
+    "sky",
+    "smoke",
+    "snow",
+    "snowflake",
+    "sound",
+    "star",
+    "stone",
+    "sun",
+    "sunset",
+    "surf",
+    "thunder",
+    "tide",
+    "trail",
+    "tree",
+    "valley",
+    "violet",
+    "voice",
+    "water",
+    "waterfall",
+    "wave",
+    "wildflower",
+    "willow",
+    "wind",
+];
+
+/// Slug generator with a fixed shape.
+pub struct SlugGenerator {
+    words: u8,
+    separator: String,
+    digits: u8,
+}
+
+impl SlugGenerator {
+    pub fn new(words: u8, separator: &str, digits: u8) -> Self {
+        Self {
+            words,
+            separator: separator.to_owned(),
+            digits,
+        }
+    }
+}
+
+impl Generate for SlugGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let mut parts = Vec::with_capacity(usize::from(self.words) + 1);
+
+        for _ in 1..self.words {
+            parts.push(ADJECTIVES[rand::random_range(0..ADJECTIVES.len())].to_owned());
+        }
+        parts.push(NOUNS[rand::random_range(0..NOUNS.len())].to_owned());
+
+        if self.digits > 0 {
+            let number = rand::random_range(0..10_u32.pow(u32::from(self.digits)));
+            parts.push(format!(
+                "{number:0width$}",
+                width = usize::from(self.digits)
+            ));
+        }
+
+        Ok(parts.join(&self.separator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_lists() {
+        for list in [&ADJECTIVES[..], &NOUNS[..]] {
+            assert!(
+                list.iter()
+                    .all(|word| word.bytes().all(|c| c.is_ascii_lowercase()))
+            );
+            assert!(
+                list.windows(2).all(|pair| pair[0] < pair[1]),
+                "sorted and distinct"
+            );
+        }
+        assert!(ADJECTIVES.iter().all(|word| !NOUNS.contains(word)));
+    }
+
+    #[test]
+    fn test_generate_shape() {
+        let mut generator = SlugGenerator::new(3, "_", 6);
+        let slug = generator.generate().unwrap();
+        let parts: Vec<&str> = slug.split('_').collect();
+
+        assert_eq!(parts.len(), 4, "{slug}");
+        assert!(ADJECTIVES.contains(&parts[0]) && ADJECTIVES.contains(&parts[1]));
+        assert!(NOUNS.contains(&parts[2]));
+        assert_eq!(parts[3].len(), 6);
+        assert!(parts[3].bytes().all(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_generate_without_digits() {
+        let mut generator = SlugGenerator::new(2, "-", 0);
+        let slug = generator.generate().unwrap();
+        let (adjective, noun) = slug.split_once('-').unwrap();
+
+        assert!(ADJECTIVES.contains(&adjective));
+        assert!(NOUNS.contains(&noun));
+    }
+}
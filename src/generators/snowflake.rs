@@ -0,0 +1,208 @@
+//! Twitter-style snowflake identifier generator.
+//!
+//! Snowflakes are 64-bit integers, printed in decimal, that are:
+//! - Sortable by creation time (with millisecond precision)
+//! - Unique per worker without coordination, as long as workers have distinct ids
+//! - Compact enough for a signed 64-bit database column
+//!
+//! # Format
+//!
+//! A snowflake consists of (from the most significant bit):
+//! - 1 bit: Unused (always zero, keeping the value positive as a signed integer)
+//! - 41 bits: Timestamp (milliseconds since a custom epoch, Twitter's by default)
+//! - 10 bits: Worker id
+//! - 12 bits: Sequence number within the millisecond
+//!
+//! # Sequence
+//!
+//! The generator keeps a sequence counter for the whole run, reset whenever the
+//! millisecond moves forward. So within a millisecond, snowflakes are strictly
+//! increasing, and once all 4096 sequence numbers are used, generation fails rather
+//! than producing duplicates. A clock going backwards is treated as still being in the
+//! last millisecond, keeping the output increasing.
+
+use anyhow::anyhow;
+
+use crate::clock;
+use crate::generators::Generate;
+
+/// Twitter's snowflake epoch (2010-11-04T01:42:54.657Z), in Unix milliseconds.
+pub const TWITTER_EPOCH: u64 = 1_288_834_974_657;
+
+/// Largest timestamp offset from the epoch (41 bits, about 69 years).
+pub const MAX_OFFSET: u64 = (1 << 41) - 1;
+
+/// Largest worker id (10 bits).
+pub const MAX_WORKER_ID: u16 = (1 << 10) - 1;
+
+/// Number of sequence numbers per millisecond (12 bits).
+const SEQUENCE_SIZE: u64 = 1 << 12;
+
+/// Snowflake generator that can use either current time or a fixed timestamp.
+///
+/// The generator stores an optional timestamp in milliseconds since Unix epoch.
+/// If `None`, it generates snowflakes using the current time.
+pub struct SnowflakeGenerator {
+    worker_id: u16,
+    epoch: u64,
+    timestamp: Option<u64>,
+    last_offset: Option<u64>,
+    sequence: u64,
+}
+
+impl SnowflakeGenerator {
+    pub fn new(worker_id: u16, epoch: u64, timestamp: Option<u64>) -> Self {
+        Self {
+            worker_id,
+            epoch,
+            timestamp,
+            last_offset: None,
+            sequence: 0,
+        }
+    }
+
+    /// Returns the timestamp offset from the epoch for the next snowflake.
+    fn next_offset(&self) -> anyhow::Result<u64> {
+        let millis = self.timestamp.unwrap_or_else(|| {
+            let (seconds, subsec_nanos) = clock::now();
+            seconds * 1000 + u64::from(subsec_nanos) / 1_000_000
+        });
+
+        millis
+            .checked_sub(self.epoch)
+            .filter(|offset| *offset <= MAX_OFFSET)
+            .ok_or_else(|| {
+                anyhow!(
+                    "timestamp {millis} is outside the 41-bit range of the snowflake epoch {}",
+                    self.epoch
+                )
+            })
+    }
+}
+
+impl Generate for SnowflakeGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let offset = self.next_offset()?;
+
+        let offset = match self.last_offset {
+            Some(last) if offset <= last => {
+                self.sequence += 1;
+                if self.sequence >= SEQUENCE_SIZE {
+                    return Err(anyhow!(
+                        "snowflake sequence exhausted: more than {SEQUENCE_SIZE} identifiers \
+                         in one millisecond"
+                    ));
+                }
+                last
+            }
+            _ => {
+                self.sequence = 0;
+                offset
+            }
+        };
+        self.last_offset = Some(offset);
+
+        let snowflake = (offset << 22) | (u64::from(self.worker_id) << 12) | self.sequence;
+
+        Ok(snowflake.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_layout() {
+        let mut generator = SnowflakeGenerator::new(5, TWITTER_EPOCH, Some(TWITTER_EPOCH + 1));
+
+        let snowflake: u64 = generator.generate().unwrap().parse().unwrap();
+
+        assert_eq!(snowflake >> 22, 1);
+        assert_eq!((snowflake >> 12) & 0x3FF, 5);
+        assert_eq!(snowflake & 0xFFF, 0);
+    }
+
+    #[test]
+    fn test_generate_known_value() {
+        // A tweet id, decoding to worker 378 and sequence 0 at 2022-06-28T16:07:40.105Z
+        let mut generator = SnowflakeGenerator::new(378, TWITTER_EPOCH, Some(1_656_432_460_105));
+
+        assert_eq!(generator.generate().unwrap(), "1541815603606036480");
+    }
+
+    #[test]
+    fn test_generate_increments_sequence() {
+        let mut generator = SnowflakeGenerator::new(1023, 0, Some(1));
+
+        let snowflakes: Vec<u64> = (0..SEQUENCE_SIZE)
+            .map(|_| generator.generate().unwrap().parse().unwrap())
+            .collect();
+
+        assert!(snowflakes.windows(2).all(|pair| pair[1] == pair[0] + 1));
+        assert_eq!(snowflakes[0] & 0xFFF, 0);
+        assert_eq!(snowflakes[4095] & 0xFFF, 4095);
+    }
+
+    #[test]
+    fn test_generate_sequence_overflow() {
+        let mut generator = SnowflakeGenerator::new(0, 0, Some(1));
+
+        for _ in 0..SEQUENCE_SIZE {
+            generator.generate().unwrap();
+        }
+
+        let result = generator.generate();
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "snowflake sequence exhausted: more than 4096 identifiers in one millisecond"
+        );
+    }
+
+    #[test]
+    fn test_generate_resets_sequence() {
+        let mut generator = SnowflakeGenerator::new(0, 0, Some(1));
+        generator.generate().unwrap();
+        generator.generate().unwrap();
+
+        generator.timestamp = Some(2);
+        let snowflake: u64 = generator.generate().unwrap().parse().unwrap();
+
+        assert_eq!(snowflake, 2 << 22);
+    }
+
+    #[test]
+    fn test_generate_clock_going_backwards() {
+        let mut generator = SnowflakeGenerator::new(0, 0, Some(2));
+        generator.generate().unwrap();
+
+        generator.timestamp = Some(1);
+        let snowflake: u64 = generator.generate().unwrap().parse().unwrap();
+
+        assert_eq!(snowflake, (2 << 22) | 1);
+    }
+
+    #[test]
+    fn test_generate_before_epoch() {
+        let mut generator = SnowflakeGenerator::new(0, 1000, Some(999));
+        let result = generator.generate();
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "timestamp 999 is outside the 41-bit range of the snowflake epoch 1000"
+        );
+    }
+
+    #[test]
+    fn test_generate_without_timestamp() {
+        let mut generator = SnowflakeGenerator::new(0, TWITTER_EPOCH, None);
+
+        let first: u64 = generator.generate().unwrap().parse().unwrap();
+        let second: u64 = generator.generate().unwrap().parse().unwrap();
+
+        assert!(first < second);
+    }
+}
@@ -0,0 +1,200 @@
+//! Sqids encoder.
+//!
+//! Sqids (formerly Hashids) are short strings encoding one or more non-negative
+//! integers, commonly used to hide numeric database keys in URLs. Unlike other
+//! identifiers, they are not random: the same numbers, alphabet, and minimum length
+//! always give the same string, and it can be decoded back into the numbers.
+//!
+//! # Encoding
+//!
+//! The encoder follows the specification: the alphabet is shuffled deterministically,
+//! rotated by an offset derived from the numbers, and each number is written in the
+//! resulting base, separated by a symbol and reshuffling the alphabet in between. Short
+//! strings are padded with further shuffles of the alphabet up to the minimum length.
+//!
+//! No blocklist is applied, so the output matches reference implementations configured
+//! with an empty blocklist.
+//!
+//! # Usage
+//!
+//! The generator can operate in two modes:
+//! - **Given numbers**: Encodes the same numbers into every identifier
+//! - **Random numbers**: Encodes sequential `u32` values, starting from a random one
+
+use crate::generators::Generate;
+
+/// The default Sqids alphabet (62 symbols).
+pub const DEFAULT_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Sqids encoder for given numbers, or for sequential random ones.
+pub struct SqidsGenerator {
+    alphabet: Vec<u8>,
+    min_length: usize,
+    numbers: Vec<u64>,
+    next: u32,
+}
+
+impl SqidsGenerator {
+    /// Creates an encoder; the alphabet must be validated ASCII of at least 3 symbols.
+    pub fn new(numbers: &[u64], alphabet: &str, min_length: u8) -> Self {
+        let mut alphabet = alphabet.as_bytes().to_vec();
+        shuffle(&mut alphabet);
+
+        Self {
+            alphabet,
+            min_length: usize::from(min_length),
+            numbers: numbers.to_vec(),
+            next: rand::random(),
+        }
+    }
+
+    /// Encodes the numbers into a string of at least the minimum length.
+    fn encode(&self, numbers: &[u64]) -> String {
+        let size = self.alphabet.len();
+
+        let offset = numbers
+            .iter()
+            .enumerate()
+            .fold(numbers.len(), |offset, (index, number)| {
+                offset + usize::from(self.alphabet[(number % size as u64) as usize]) + index
+            })
+            % size;
+
+        let mut alphabet = self.alphabet.clone();
+        alphabet.rotate_left(offset);
+
+        let mut encoded = vec![alphabet[0]];
+        alphabet.reverse();
+
+        for (index, number) in numbers.iter().enumerate() {
+            encode_number(*number, &alphabet[1..], &mut encoded);
+
+            if index < numbers.len() - 1 {
+                encoded.push(alphabet[0]);
+                shuffle(&mut alphabet);
+            }
+        }
+
+        if encoded.len() < self.min_length {
+            encoded.push(alphabet[0]);
+
+            while encoded.len() < self.min_length {
+                shuffle(&mut alphabet);
+                let missing = (self.min_length - encoded.len()).min(size);
+                encoded.extend_from_slice(&alphabet[..missing]);
+            }
+        }
+
+        String::from_utf8(encoded).expect("Sqids alphabets are ASCII")
+    }
+}
+
+impl Generate for SqidsGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        if !self.numbers.is_empty() {
+            return Ok(self.encode(&self.numbers));
+        }
+
+        let number = self.next;
+        self.next = self.next.wrapping_add(1);
+
+        Ok(self.encode(&[u64::from(number)]))
+    }
+}
+
+/// Shuffles the alphabet deterministically, as the specification does.
+fn shuffle(alphabet: &mut [u8]) {
+    let size = alphabet.len();
+
+    for i in 0..size - 1 {
+        let j = size - 1 - i;
+        let r = (i * j + usize::from(alphabet[i]) + usize::from(alphabet[j])) % size;
+        alphabet.swap(i, r);
+    }
+}
+
+/// Appends a number written in the base of the alphabet (most significant first).
+fn encode_number(mut number: u64, alphabet: &[u8], encoded: &mut Vec<u8>) {
+    let start = encoded.len();
+
+    loop {
+        encoded.push(alphabet[(number % alphabet.len() as u64) as usize]);
+        number /= alphabet.len() as u64;
+
+        if number == 0 {
+            break;
+        }
+    }
+
+    encoded[start..].reverse();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(numbers: &[u64], alphabet: &str, min_length: u8) -> String {
+        SqidsGenerator::new(numbers, alphabet, min_length).encode(numbers)
+    }
+
+    #[test]
+    fn test_encode_specification_examples() {
+        assert_eq!(encode(&[1, 2, 3], DEFAULT_ALPHABET, 0), "86Rf07");
+        assert_eq!(encode(&[0], DEFAULT_ALPHABET, 0), "bM");
+        assert_eq!(encode(&[1], DEFAULT_ALPHABET, 0), "Uk");
+    }
+
+    #[test]
+    fn test_encode_custom_alphabet() {
+        assert_eq!(encode(&[1, 2, 3], "0123456789abcdef", 0), "489158");
+    }
+
+    #[test]
+    fn test_encode_min_length() {
+        assert_eq!(encode(&[0], DEFAULT_ALPHABET, 8), "bMZn4Y5F");
+        assert_eq!(
+            encode(&[1, 2, 3], DEFAULT_ALPHABET, 62),
+            "86Rf07xd4zBmiJXQG6otHEbew02c3PWsUOLZxADhCpKj7aVFv9I8RquYrNlSTM"
+        );
+    }
+
+    #[test]
+    fn test_encode_max_number() {
+        let sqid = encode(&[u64::MAX], DEFAULT_ALPHABET, 0);
+
+        assert!(
+            sqid.bytes()
+                .all(|c| DEFAULT_ALPHABET.as_bytes().contains(&c))
+        );
+    }
+
+    #[test]
+    fn test_generate_given_numbers() {
+        let mut generator = SqidsGenerator::new(&[1, 2, 3], DEFAULT_ALPHABET, 0);
+
+        assert_eq!(generator.generate().unwrap(), "86Rf07");
+        assert_eq!(generator.generate().unwrap(), "86Rf07");
+    }
+
+    #[test]
+    fn test_generate_sequential_numbers() {
+        let mut generator = SqidsGenerator::new(&[], DEFAULT_ALPHABET, 0);
+        generator.next = 41;
+
+        let first = generator.generate().unwrap();
+        let second = generator.generate().unwrap();
+
+        assert_eq!(first, generator.encode(&[41]));
+        assert_eq!(second, generator.encode(&[42]));
+    }
+
+    #[test]
+    fn test_generate_sequential_numbers_wrap() {
+        let mut generator = SqidsGenerator::new(&[], DEFAULT_ALPHABET, 0);
+        generator.next = u32::MAX;
+
+        generator.generate().unwrap();
+
+        assert_eq!(generator.next, 0);
+    }
+}
@@ -0,0 +1,190 @@
+//! Timestamp slug generator.
+//!
+//! Timestamp slugs are names like `20240613T142233Z-7f3k2q` for log directories, backups,
+//! and other files, that are:
+//! - Sortable by creation time, as strings (and so in directory listings)
+//! - Safe in file names, with only `[0-9A-Za-z_-]` (no colons)
+//! - Readable, with the UTC time in the compact ISO 8601 form
+//!
+//! # Format
+//!
+//! A slug consists of:
+//! - Timestamp: `YYYYMMDDTHHMMSSZ`, with three more digits before the `Z` for milliseconds
+//! - Separator: `-`
+//! - Suffix: Lowercase Crockford base32 symbols (6 by default)
+//!
+//! # Suffix
+//!
+//! The suffix is random for every second (or millisecond), and incremented by one for
+//! further slugs within the same one, so the slugs of a run never repeat and are strictly
+//! increasing, even with a fixed timestamp. A clock going backwards is treated as still
+//! being in the last second (or millisecond).
+
+use anyhow::anyhow;
+
+use crate::cli::stamp::StampPrecision;
+use crate::generators::Generate;
+use crate::{clock, utils};
+
+/// Latest millisecond timestamp with a four-digit year (9999-12-31T23:59:59.999Z).
+pub const MAX_TIMESTAMP: u64 = 253_402_300_799_999;
+
+/// Largest number of suffix symbols (fitting 60 bits).
+pub const MAX_SUFFIX_LENGTH: u8 = 12;
+
+/// Crockford base32 alphabet in lowercase (digits, then letters without i, l, o, and u).
+const SYMBOLS: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+/// Timestamp slug generator that can use either current time or a fixed timestamp.
+///
+/// The generator stores an optional timestamp in milliseconds since Unix epoch.
+/// If `None`, it generates slugs using the current time.
+pub struct StampGenerator {
+    suffix_length: u8,
+    precision: StampPrecision,
+    timestamp: Option<u64>,
+    last: Option<(u64, u64)>,
+}
+
+impl StampGenerator {
+    pub fn new(suffix_length: u8, precision: StampPrecision, timestamp: Option<u64>) -> Self {
+        Self {
+            suffix_length,
+            precision,
+            timestamp,
+            last: None,
+        }
+    }
+
+    /// Returns the timestamp read from the clock (or the fixed timestamp), truncated to
+    /// the precision, in milliseconds.
+    fn clock_millis(&self) -> u64 {
+        let millis = self.timestamp.unwrap_or_else(|| {
+            let (seconds, subsec_nanos) = clock::now();
+            seconds * 1000 + u64::from(subsec_nanos) / 1_000_000
+        });
+
+        match self.precision {
+            StampPrecision::Seconds => millis - millis % 1000,
+            StampPrecision::Milliseconds => millis,
+        }
+    }
+
+    /// Advances to the given timestamp, drawing the suffix of a new second (or
+    /// millisecond) from the given source.
+    ///
+    /// Returns the timestamp and suffix of the next slug.
+    fn advance(&mut self, millis: u64, random: impl FnOnce() -> u64) -> anyhow::Result<(u64, u64)> {
+        let max_suffix = (1u64 << (5 * u32::from(self.suffix_length))) - 1;
+
+        let next = match self.last {
+            Some((last, suffix)) if millis <= last => {
+                if suffix == max_suffix {
+                    return Err(anyhow!(
+                        "stamp suffixes exhausted within a {}; use a longer '--suffix-length'",
+                        match self.precision {
+                            StampPrecision::Seconds => "second",
+                            StampPrecision::Milliseconds => "millisecond",
+                        }
+                    ));
+                }
+                (last, suffix + 1)
+            }
+            _ => (millis, random() & max_suffix),
+        };
+
+        self.last = Some(next);
+        Ok(next)
+    }
+
+    /// Formats a timestamp and suffix as a slug.
+    fn format(&self, millis: u64, suffix: u64) -> String {
+        // NOTE: The extended form has dashes and colons, that the compact one leaves out
+        let date = utils::format_timestamp((millis / 1000) as i64, 0).replace(['-', ':'], "");
+        let mut slug = date.trim_end_matches('Z').to_owned();
+
+        if self.precision == StampPrecision::Milliseconds {
+            slug.push_str(&format!("{:03}", millis % 1000));
+        }
+
+        slug.push_str("Z-");
+        slug.extend(
+            (0..self.suffix_length)
+                .rev()
+                .map(|index| SYMBOLS[(suffix >> (5 * u32::from(index)) & 0x1f) as usize] as char),
+        );
+
+        slug
+    }
+}
+
+impl Generate for StampGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let millis = self.clock_millis();
+        let (millis, suffix) = self.advance(millis, rand::random)?;
+
+        Ok(self.format(millis, suffix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_seconds() {
+        let generator = StampGenerator::new(6, StampPrecision::Seconds, None);
+
+        assert_eq!(
+            generator.format(1_718_288_553_000, 0xef1_cc57),
+            "20240613T142233Z-7f3k2q"
+        );
+    }
+
+    #[test]
+    fn test_format_milliseconds() {
+        let generator = StampGenerator::new(4, StampPrecision::Milliseconds, None);
+
+        assert_eq!(
+            generator.format(1_718_288_553_042, 31),
+            "20240613T142233042Z-000z"
+        );
+    }
+
+    #[test]
+    fn test_format_max_timestamp() {
+        let generator = StampGenerator::new(1, StampPrecision::Milliseconds, None);
+
+        assert_eq!(generator.format(MAX_TIMESTAMP, 0), "99991231T235959999Z-0");
+    }
+
+    #[test]
+    fn test_clock_millis_truncated_to_seconds() {
+        let generator = StampGenerator::new(6, StampPrecision::Seconds, Some(1_718_288_553_999));
+
+        assert_eq!(generator.clock_millis(), 1_718_288_553_000);
+    }
+
+    #[test]
+    fn test_advance_increments_within_timestamp() {
+        let mut generator = StampGenerator::new(6, StampPrecision::Seconds, None);
+
+        assert_eq!(generator.advance(5000, || 41).unwrap(), (5000, 41));
+        assert_eq!(generator.advance(5000, || 7).unwrap(), (5000, 42));
+        // A clock going backwards stays in the last second
+        assert_eq!(generator.advance(4000, || 7).unwrap(), (5000, 43));
+        assert_eq!(generator.advance(6000, || 7).unwrap(), (6000, 7));
+    }
+
+    #[test]
+    fn test_advance_suffix_exhausted() {
+        let mut generator = StampGenerator::new(1, StampPrecision::Seconds, None);
+
+        assert_eq!(generator.advance(5000, || 30).unwrap(), (5000, 30));
+        assert_eq!(generator.advance(5000, || 0).unwrap(), (5000, 31));
+        assert_eq!(
+            generator.advance(5000, || 0).unwrap_err().to_string(),
+            "stamp suffixes exhausted within a second; use a longer '--suffix-length'"
+        );
+    }
+}
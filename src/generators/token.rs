@@ -0,0 +1,190 @@
+//! Random token generator.
+//!
+//! Tokens are random byte strings meant as secrets (API keys, session tokens, and
+//! such), printed in a text encoding. The default 32 bytes give 256 random bits.
+//!
+//! # Randomness
+//!
+//! Bytes come from the thread-local generator of the `rand` crate, a cryptographically
+//! secure PRNG seeded from the operating system, the same source UUIDs and other
+//! random identifiers use.
+//!
+//! # Encodings
+//!
+//! - **hex**: 2 characters per byte
+//! - **base64**: 4 characters per 3 bytes, padded with `=`
+//! - **base64url**: Like base64 with `-` and `_` instead of `+` and `/`, unpadded
+//! - **base32**: 8 characters per 5 bytes, padded with `=`
+
+use anyhow::anyhow;
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use rand::RngCore;
+
+use crate::cli::token::TokenEncoding;
+use crate::generators::Generate;
+
+/// Largest number of random bytes in a token (1 MiB).
+pub const MAX_BYTES: usize = 1 << 20;
+
+/// RFC 4648 base32 alphabet.
+const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Random token generator with a fixed size and encoding.
+pub struct TokenGenerator {
+    bytes: usize,
+    encoding: TokenEncoding,
+}
+
+impl TokenGenerator {
+    pub fn new(bytes: usize, encoding: TokenEncoding) -> Self {
+        Self { bytes, encoding }
+    }
+}
+
+impl Generate for TokenGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let mut bytes = vec![0; self.bytes];
+        rand::rng().fill_bytes(&mut bytes);
+
+        Ok(encode(&bytes, self.encoding))
+    }
+}
+
+/// Encodes bytes in the given encoding.
+fn encode(bytes: &[u8], encoding: TokenEncoding) -> String {
+    match encoding {
+        TokenEncoding::Hex => hex::encode(bytes),
+        TokenEncoding::Base64 => STANDARD.encode(bytes),
+        TokenEncoding::Base64url => URL_SAFE_NO_PAD.encode(bytes),
+        TokenEncoding::Base32 => encode_base32(bytes),
+    }
+}
+
+/// Encodes bytes as padded RFC 4648 base32.
+pub(crate) fn encode_base32(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(5) * 8);
+
+    for chunk in bytes.chunks(5) {
+        let mut block = [0; 5];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let value = block
+            .iter()
+            .fold(0_u64, |value, byte| value << 8 | u64::from(*byte));
+
+        // Every 5 bits of input give a symbol, and the rest of the block is padding
+        let symbols = (chunk.len() * 8).div_ceil(5);
+        for index in 0..8 {
+            match index < symbols {
+                true => encoded.push(BASE32[(value >> (35 - index * 5) & 0x1f) as usize] as char),
+                false => encoded.push('='),
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Decodes RFC 4648 base32, in either case and with or without padding.
+pub(crate) fn decode_base32(encoded: &str) -> anyhow::Result<Vec<u8>> {
+    let symbols = encoded.trim_end_matches('=');
+    let mut bytes = Vec::with_capacity(symbols.len() * 5 / 8);
+    let (mut value, mut bits) = (0_u64, 0);
+
+    for symbol in symbols.chars() {
+        let digit = BASE32
+            .iter()
+            .position(|candidate| char::from(*candidate) == symbol.to_ascii_uppercase())
+            .ok_or_else(|| {
+                anyhow!("invalid base32 '{encoded}': unexpected character {symbol:?}")
+            })?;
+
+        value = value << 5 | digit as u64;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((value >> bits) as u8);
+            value &= (1 << bits) - 1;
+        }
+    }
+
+    // The symbols left over only pad the last byte
+    match bits < 5 && value == 0 {
+        true => Ok(bytes),
+        false => Err(anyhow!("invalid base32 '{encoded}': trailing bits")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_base32_rfc_vectors() {
+        // The test vectors of RFC 4648, section 10
+        let vectors = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+
+        for (input, expected) in vectors {
+            assert_eq!(encode_base32(input.as_bytes()), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_decode_base32_rfc_vectors() {
+        for (expected, encoded) in [("foob", "MZXW6YQ="), ("foobar", "mzxw6ytboi")] {
+            assert_eq!(
+                decode_base32(encoded).unwrap(),
+                expected.as_bytes(),
+                "{encoded:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_base32_invalid() {
+        assert_eq!(
+            decode_base32("MZXW1").unwrap_err().to_string(),
+            "invalid base32 'MZXW1': unexpected character '1'"
+        );
+        assert_eq!(
+            decode_base32("MZXW6YR").unwrap_err().to_string(),
+            "invalid base32 'MZXW6YR': trailing bits"
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        let bytes = [0xfb, 0xff, 0xbf];
+
+        assert_eq!(encode(&bytes, TokenEncoding::Hex), "fbffbf");
+        assert_eq!(encode(&bytes, TokenEncoding::Base64), "+/+/");
+        assert_eq!(encode(&bytes, TokenEncoding::Base64url), "-_-_");
+        assert_eq!(encode(&bytes[..1], TokenEncoding::Base64), "+w==");
+        assert_eq!(encode(&bytes[..1], TokenEncoding::Base64url), "-w");
+    }
+
+    #[test]
+    fn test_generate_size() {
+        let mut generator = TokenGenerator::new(32, TokenEncoding::Hex);
+
+        let token = generator.generate().unwrap();
+
+        assert_eq!(hex::decode(token).unwrap().len(), 32);
+    }
+
+    #[test]
+    fn test_generate_distinct() {
+        let mut generator = TokenGenerator::new(16, TokenEncoding::Base64url);
+
+        assert_ne!(generator.generate().unwrap(), generator.generate().unwrap());
+    }
+}
@@ -0,0 +1,262 @@
+//! TSID (time-sorted identifier) generator.
+//!
+//! TSIDs are the 64-bit identifiers of the Java `tsid-creator` library, that are:
+//! - Sortable by creation time (with millisecond precision), as numbers and as strings
+//! - Printed as 13 Crockford base32 characters, or as 64-bit integers in decimal
+//!
+//! # Format
+//!
+//! A TSID consists of (from the most significant bit):
+//! - 42 bits: Timestamp (milliseconds since a custom epoch, 2020-01-01 by default)
+//! - 10 bits: Node id
+//! - 12 bits: Counter
+//!
+//! # Counter
+//!
+//! As in `tsid-creator`, the counter starts at a random value for every millisecond and
+//! is incremented by one for further TSIDs within the same millisecond. When it
+//! overflows, the carry moves the timestamp a millisecond ahead of the clock (until the
+//! clock catches up), so TSIDs of a run never repeat and are strictly increasing, even
+//! with a fixed timestamp. A clock going backwards is treated as still being in the last
+//! millisecond.
+
+use anyhow::anyhow;
+
+use crate::cli::tsid::TsidFormat;
+use crate::generators::Generate;
+use crate::{clock, utils};
+
+/// TSID epoch (2020-01-01T00:00:00Z), in Unix milliseconds.
+pub const TSID_EPOCH: u64 = 1_577_836_800_000;
+
+/// Largest timestamp offset from the epoch (42 bits, about 139 years).
+pub const MAX_OFFSET: u64 = (1 << 42) - 1;
+
+/// Largest node id (10 bits).
+pub const MAX_NODE: u16 = (1 << 10) - 1;
+
+/// Width of the counter (in bits).
+const COUNTER_BITS: u32 = 12;
+
+/// Largest counter value.
+const MAX_COUNTER: u16 = (1 << COUNTER_BITS) - 1;
+
+/// Number of Crockford base32 characters of a TSID.
+const TSID_LENGTH: usize = 13;
+
+/// TSID generator that can use either current time or a fixed timestamp.
+///
+/// The generator stores an optional timestamp in milliseconds since Unix epoch.
+/// If `None`, it generates TSIDs using the current time.
+pub struct TsidGenerator {
+    node: u16,
+    epoch: u64,
+    timestamp: Option<u64>,
+    format: TsidFormat,
+    last_offset: Option<u64>,
+    counter: u16,
+}
+
+impl TsidGenerator {
+    /// Creates a generator, with a random node id when none is given.
+    pub fn new(node: Option<u16>, epoch: u64, timestamp: Option<u64>, format: TsidFormat) -> Self {
+        Self {
+            node: node.unwrap_or_else(|| rand::random::<u16>() & MAX_NODE),
+            epoch,
+            timestamp,
+            format,
+            last_offset: None,
+            counter: 0,
+        }
+    }
+
+    /// Returns the timestamp offset from the epoch read from the clock (or the fixed
+    /// timestamp).
+    fn clock_offset(&self) -> anyhow::Result<u64> {
+        let millis = self.timestamp.unwrap_or_else(|| {
+            let (seconds, subsec_nanos) = clock::now();
+            seconds * 1000 + u64::from(subsec_nanos) / 1_000_000
+        });
+
+        millis
+            .checked_sub(self.epoch)
+            .filter(|offset| *offset <= MAX_OFFSET)
+            .ok_or_else(|| {
+                anyhow!(
+                    "timestamp {millis} is outside the 42-bit range of the TSID epoch {}",
+                    self.epoch
+                )
+            })
+    }
+
+    /// Advances the counter to the given offset, drawing the counter of a new millisecond
+    /// from the given source.
+    ///
+    /// Returns the offset of the next TSID, which is ahead of the given one after the
+    /// counter overflowed.
+    fn advance(&mut self, offset: u64, random: impl FnOnce() -> u16) -> anyhow::Result<u64> {
+        let offset = match self.last_offset {
+            Some(last) if offset <= last => {
+                if self.counter < MAX_COUNTER {
+                    self.counter += 1;
+                    last
+                } else {
+                    self.counter = 0;
+                    last + 1
+                }
+            }
+            _ => {
+                self.counter = random() & MAX_COUNTER;
+                offset
+            }
+        };
+
+        if offset > MAX_OFFSET {
+            return Err(anyhow!(
+                "TSID counter exhausted at the end of the 42-bit range of the TSID epoch {}",
+                self.epoch
+            ));
+        }
+
+        self.last_offset = Some(offset);
+
+        Ok(offset)
+    }
+}
+
+impl Generate for TsidGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let offset = self.clock_offset()?;
+        let offset = self.advance(offset, rand::random)?;
+
+        let tsid =
+            (offset << 22) | (u64::from(self.node) << COUNTER_BITS) | u64::from(self.counter);
+
+        Ok(match self.format {
+            TsidFormat::Base32 => encode(tsid),
+            TsidFormat::Number => tsid.to_string(),
+        })
+    }
+}
+
+/// Encodes a TSID as 13 Crockford base32 characters (the first one holding 4 bits).
+fn encode(tsid: u64) -> String {
+    let encoded = utils::encode_crockford(u128::from(tsid));
+
+    // The leading characters only encode the zero bits above the 64 of a TSID
+    encoded[encoded.len() - TSID_LENGTH..].to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_known_values() {
+        assert_eq!(encode(0), "0000000000000");
+        assert_eq!(encode(u64::MAX), "FZZZZZZZZZZZZ");
+        assert_eq!(encode(388_400_145_978_465_528), "0ARYZVZXW377R");
+    }
+
+    #[test]
+    fn test_generate_layout() {
+        let mut generator = TsidGenerator::new(
+            Some(5),
+            TSID_EPOCH,
+            Some(TSID_EPOCH + 1),
+            TsidFormat::Number,
+        );
+
+        let tsid: u64 = generator.generate().unwrap().parse().unwrap();
+
+        assert_eq!(tsid >> 22, 1);
+        assert_eq!((tsid >> 12) & 0x3FF, 5);
+    }
+
+    #[test]
+    fn test_generate_base32() {
+        let mut generator = TsidGenerator::new(None, TSID_EPOCH, None, TsidFormat::Base32);
+
+        let tsid = generator.generate().unwrap();
+
+        assert_eq!(tsid.len(), TSID_LENGTH);
+        assert!(
+            tsid.bytes()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+        );
+    }
+
+    #[test]
+    fn test_advance_increments_counter() {
+        let mut generator = TsidGenerator::new(Some(0), 0, None, TsidFormat::Number);
+
+        assert_eq!(generator.advance(7, || 100).unwrap(), 7);
+        assert_eq!(generator.counter, 100);
+        assert_eq!(generator.advance(7, || unreachable!()).unwrap(), 7);
+        assert_eq!(generator.counter, 101);
+    }
+
+    #[test]
+    fn test_advance_draws_counter_every_millisecond() {
+        let mut generator = TsidGenerator::new(Some(0), 0, None, TsidFormat::Number);
+        generator.advance(7, || 100).unwrap();
+
+        assert_eq!(generator.advance(8, || 0xFFFF).unwrap(), 8);
+        assert_eq!(generator.counter, MAX_COUNTER);
+    }
+
+    #[test]
+    fn test_advance_carries_into_timestamp() {
+        let mut generator = TsidGenerator::new(Some(0), 0, None, TsidFormat::Number);
+        generator.advance(7, || MAX_COUNTER).unwrap();
+
+        assert_eq!(generator.advance(7, || unreachable!()).unwrap(), 8);
+        assert_eq!(generator.counter, 0);
+
+        // The clock catching up keeps counting from the carried millisecond
+        assert_eq!(generator.advance(8, || unreachable!()).unwrap(), 8);
+        assert_eq!(generator.counter, 1);
+    }
+
+    #[test]
+    fn test_advance_clock_going_backwards() {
+        let mut generator = TsidGenerator::new(Some(0), 0, None, TsidFormat::Number);
+        generator.advance(7, || 0).unwrap();
+
+        assert_eq!(generator.advance(6, || unreachable!()).unwrap(), 7);
+        assert_eq!(generator.counter, 1);
+    }
+
+    #[test]
+    fn test_advance_exhausted() {
+        let mut generator = TsidGenerator::new(Some(0), 0, None, TsidFormat::Number);
+        generator.advance(MAX_OFFSET, || MAX_COUNTER).unwrap();
+
+        assert_eq!(
+            generator
+                .advance(MAX_OFFSET, || unreachable!())
+                .unwrap_err()
+                .to_string(),
+            "TSID counter exhausted at the end of the 42-bit range of the TSID epoch 0"
+        );
+    }
+
+    #[test]
+    fn test_generate_strictly_increasing() {
+        let mut generator = TsidGenerator::new(Some(1023), 0, Some(1), TsidFormat::Base32);
+
+        let tsids: Vec<String> = (0..10_000).map(|_| generator.generate().unwrap()).collect();
+
+        assert!(tsids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_generate_before_epoch() {
+        let mut generator = TsidGenerator::new(Some(0), 1000, Some(999), TsidFormat::Number);
+
+        assert_eq!(
+            generator.generate().unwrap_err().to_string(),
+            "timestamp 999 is outside the 42-bit range of the TSID epoch 1000"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! TypeID generator.
+//!
+//! TypeIDs are type-safe, K-sortable identifiers like `user_01h455vb4pex5vsknk084sn02q`.
+//! They are:
+//! - Prefixed with the type of the entity they identify
+//! - Sortable by creation time (with millisecond precision) within a type
+//! - Backed by a version 7 UUID, so they fit into UUID database columns
+//!
+//! # Format
+//!
+//! A TypeID consists of:
+//! - Type prefix: Up to 63 lowercase ASCII letters and underscores
+//! - Separator: An underscore (omitted along with an empty prefix)
+//! - Suffix: The 128-bit UUID as 26 lowercase Crockford base32 symbols
+//!
+//! The suffix encoding matches the ULID one (the UUID is read as a big-endian number
+//! with two leading zero bits), so it is delegated to the `ulid` crate and lowercased.
+//!
+//! # Usage
+//!
+//! The generator can operate in two modes:
+//! - **Current time**: Uses the current time from [`clock`] (default)
+//! - **Fixed timestamp**: Uses a provided millisecond timestamp
+//!
+//! Like the v7 UUID generator, it holds a single `ContextV7` for the whole run, so
+//! TypeIDs sharing a millisecond stay monotonic within the batch.
+
+use crate::clock;
+use crate::generators::Generate;
+use crate::utils;
+
+/// Latest millisecond timestamp a version 7 UUID can hold (48 bits).
+pub const MAX_TIMESTAMP: u64 = (1 << 48) - 1;
+
+/// TypeID generator drawing version 7 UUIDs with a given type prefix.
+pub struct TypeIdGenerator {
+    prefix: String,
+    timestamp: Option<u64>,
+    context: uuid::ContextV7,
+}
+
+impl TypeIdGenerator {
+    pub fn new(prefix: &str, timestamp: Option<u64>) -> Self {
+        Self {
+            prefix: prefix.to_owned(),
+            timestamp,
+            context: uuid::ContextV7::new(),
+        }
+    }
+}
+
+impl Generate for TypeIdGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let (seconds, subsec_nanos) = match self.timestamp {
+            Some(millis) => (millis / 1000, (millis % 1000) as u32 * 1_000_000),
+            None => clock::now(),
+        };
+        let uuid = uuid::Uuid::new_v7(uuid::Timestamp::from_unix(
+            &self.context,
+            seconds,
+            subsec_nanos,
+        ));
+
+        Ok(encode(&self.prefix, uuid))
+    }
+}
+
+/// Encodes a UUID as a TypeID with the given prefix.
+fn encode(prefix: &str, uuid: uuid::Uuid) -> String {
+    let suffix = utils::encode_crockford(uuid.as_u128()).to_ascii_lowercase();
+
+    match prefix.is_empty() {
+        true => suffix,
+        false => format!("{prefix}_{suffix}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_specification_example() {
+        let uuid = uuid::Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap();
+
+        assert_eq!(encode("user", uuid), "user_01h455vb4pex5vsknk084sn02q");
+        assert_eq!(encode("", uuid), "01h455vb4pex5vsknk084sn02q");
+    }
+
+    #[test]
+    fn test_encode_bounds() {
+        assert_eq!(
+            encode("nil", uuid::Uuid::nil()),
+            "nil_00000000000000000000000000"
+        );
+        assert_eq!(
+            encode("max", uuid::Uuid::max()),
+            "max_7zzzzzzzzzzzzzzzzzzzzzzzzz"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ulid")]
+    fn test_generate_with_timestamp() {
+        let mut generator = TypeIdGenerator::new("order", Some(1_700_000_000_123));
+
+        let typeid = generator.generate().unwrap();
+        let suffix = typeid.strip_prefix("order_").unwrap();
+        let uuid = uuid::Uuid::from_u128(ulid::Ulid::from_string(suffix).unwrap().0);
+
+        assert_eq!(uuid.get_version_num(), 7);
+        assert_eq!(
+            uuid.get_timestamp().unwrap().to_unix(),
+            (1_700_000_000, 123_000_000)
+        );
+    }
+
+    #[test]
+    fn test_generate_monotonic() {
+        let mut generator = TypeIdGenerator::new("event", Some(1_700_000_000_000));
+
+        let typeids: Vec<String> = (0..1000).map(|_| generator.generate().unwrap()).collect();
+
+        assert!(typeids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -15,15 +15,23 @@
 //! # Usage
 //!
 //! The generator can operate in two modes:
-//! - **Current time**: Uses the system clock (default)
+//! - **Current time**: Uses the current time from [`clock`] (default)
 //! - **Fixed timestamp**: Uses a provided millisecond timestamp for deterministic generation
 //!
 //! When using a fixed timestamp, the timestamp portion remains constant but the
 //! random portion changes with each generation, ensuring uniqueness.
+//!
+//! Optionally, a Crockford check symbol (modulo 37) can be appended, producing a
+//! 27-character ULID that lets consumers detect single-character typos. ULIDs can also be
+//! printed in other forms of their 16 bytes instead: 12 words (see [`crate::words`]), a
+//! decimal integer, or base64.
 
 use std::time::{Duration, SystemTime};
 
+use crate::cli::IdFormat;
+use crate::clock;
 use crate::generators::Generate;
+use crate::utils;
 
 /// ULID generator that can use either current time or a fixed timestamp.
 ///
@@ -31,23 +39,43 @@ use crate::generators::Generate;
 /// If `None`, it generates ULIDs using the current system time.
 pub struct UlidGenerator {
     timestamp: Option<u64>,
+    check: bool,
+    format: IdFormat,
 }
 
 impl UlidGenerator {
-    pub fn new(timestamp: Option<u64>) -> Self {
-        Self { timestamp }
+    pub fn new(timestamp: Option<u64>, check: bool, format: IdFormat) -> Self {
+        Self {
+            timestamp,
+            check,
+            format,
+        }
     }
 }
 
 impl Generate for UlidGenerator {
-    fn generate(&self) -> String {
-        match self.timestamp {
-            Some(millis) => {
-                ulid::Ulid::from_datetime(SystemTime::UNIX_EPOCH + Duration::from_millis(millis))
-                    .to_string()
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let since_epoch = match self.timestamp {
+            Some(millis) => Duration::from_millis(millis),
+            None => {
+                let (seconds, subsec_nanos) = clock::now();
+                Duration::new(seconds, subsec_nanos)
             }
-            None => ulid::Ulid::new().to_string(),
+        };
+        let ulid = ulid::Ulid::from_datetime(SystemTime::UNIX_EPOCH + since_epoch);
+        if let Some(encoded) = self.format.encode(&ulid.to_bytes()) {
+            return Ok(encoded);
+        }
+
+        let mut ulid = ulid.to_string();
+
+        if self.check {
+            let symbol = utils::crockford_check_symbol(&ulid)
+                .expect("ULID strings are valid Crockford base32");
+            ulid.push(symbol);
         }
+
+        Ok(ulid)
     }
 }
 
@@ -68,38 +96,38 @@ mod tests {
 
     #[test]
     fn test_new_without_timestamp() {
-        let generator = UlidGenerator::new(None);
+        let mut generator = UlidGenerator::new(None, false, IdFormat::Canonical);
 
         assert!(generator.timestamp.is_none());
 
-        let ulid_str = generator.generate();
+        let ulid_str = generator.generate().unwrap();
         assert_ulid_format(&ulid_str);
     }
 
     #[test]
     fn test_new_with_timestamp() {
         let timestamp = 1234567890123;
-        let generator = UlidGenerator::new(Some(timestamp));
+        let mut generator = UlidGenerator::new(Some(timestamp), false, IdFormat::Canonical);
 
         assert_eq!(generator.timestamp, Some(1234567890123));
 
-        let ulid_str = generator.generate();
+        let ulid_str = generator.generate().unwrap();
         assert_ulid_format(&ulid_str);
     }
 
     #[test]
     fn test_generate_without_timestamp() {
-        let generator = UlidGenerator::new(None);
+        let mut generator = UlidGenerator::new(None, false, IdFormat::Canonical);
 
-        let ulid = generator.generate();
+        let ulid = generator.generate().unwrap();
         assert_ulid_format(&ulid);
     }
 
     #[test]
     fn test_generate_with_zero_timestamp() {
-        let generator = UlidGenerator::new(Some(0));
+        let mut generator = UlidGenerator::new(Some(0), false, IdFormat::Canonical);
 
-        let ulid_str = generator.generate();
+        let ulid_str = generator.generate().unwrap();
         assert_ulid_format(&ulid_str);
 
         // ULID with timestamp 0 should start with all zeros
@@ -109,9 +137,45 @@ mod tests {
     #[test]
     fn test_generate_with_max_timestamp() {
         // Maximum timestamp that won't overflow (281474976710655 ms = about year 10889)
-        let generator = UlidGenerator::new(Some(281474976710655));
+        let mut generator = UlidGenerator::new(Some(281474976710655), false, IdFormat::Canonical);
 
-        let ulid_str = generator.generate();
+        let ulid_str = generator.generate().unwrap();
         assert_ulid_format(&ulid_str);
     }
+
+    #[test]
+    fn test_generate_with_check() {
+        let mut generator = UlidGenerator::new(None, true, IdFormat::Canonical);
+
+        let ulid_str = generator.generate().unwrap();
+        assert_eq!(
+            ulid_str.len(),
+            27,
+            "ULID with check should be 27 characters long"
+        );
+        assert_ulid_format(&ulid_str[..26]);
+
+        // The check symbol round-trips through the payload
+        let symbol = utils::crockford_check_symbol(&ulid_str[..26]).unwrap();
+        assert!(ulid_str.ends_with(symbol));
+    }
+
+    #[test]
+    fn test_generate_with_check_and_zero_timestamp() {
+        let mut generator = UlidGenerator::new(Some(0), true, IdFormat::Canonical);
+
+        let ulid_str = generator.generate().unwrap();
+        assert_eq!(ulid_str.len(), 27);
+        assert!(ulid_str.starts_with("0000000000"));
+    }
+
+    #[test]
+    fn test_generate_int() {
+        let mut generator = UlidGenerator::new(Some(1), false, IdFormat::Int);
+
+        let value: u128 = generator.generate().unwrap().parse().unwrap();
+
+        // The 48-bit timestamp sits above the 80 random bits
+        assert_eq!(value >> 80, 1);
+    }
 }
@@ -5,11 +5,16 @@
 //!
 //! - **v1**: Time-based with MAC address (legacy, has privacy concerns)
 //! - **v3**: Name-based using MD5 hashing (deterministic)
-//! - **v4**: Random (most common for general use)
+//! - **v4**: Random (most common for general use; optionally excluding special values)
 //! - **v5**: Name-based using SHA-1 hashing (deterministic, preferred over v3)
 //! - **v6**: Time-ordered, improved over v1 (better database indexing)
-//! - **v7**: Time-ordered with Unix timestamp (recommended for new systems)
-//! - **v8**: Custom/experimental format
+//! - **v7**: Time-ordered with Unix timestamp (recommended for new systems; optionally
+//!   stepping the timestamp by a millisecond after a given number of UUIDs)
+//! - **v8**: Custom/experimental format (given data, with the rest zero or random, or
+//!   name-based with SHA-256)
+//!
+//! The nil (all zeros) and max (all ones) UUIDs are also available as constant
+//! variants, handy as bounds of range queries and as test fixtures.
 //!
 //! # Design
 //!
@@ -18,166 +23,810 @@
 //! with type-safe constructors ([`new_v1`], [`new_v3`], etc.) while sharing a
 //! common [`Generate`] implementation.
 //!
-//! The [`from_params`] method provides a unified interface for CLI argument conversion,
+//! The [`from_args`] method provides a unified interface for CLI argument conversion,
 //! delegating to the appropriate version-specific constructor.
 //!
+//! The v4 variant searching for a vanity prefix draws candidates until one starts with
+//! it, on every available thread for long prefixes.
+//!
+//! The v7 variant holds a single `ContextV7` for the whole run, so UUIDs sharing a
+//! millisecond get an incrementing counter and stay monotonic within the batch (the
+//! fixed-length counter method of RFC 9562: 42 bits seeded randomly each millisecond).
+//! The times it is given never go backwards, so a clock stepping back is treated as still
+//! being in the last millisecond.
+//! With [`TimestampPrecision::Us`] or [`TimestampPrecision::Ns`], the 12 bits after the
+//! version hold the fraction of the millisecond instead (method 3 of RFC 9562), followed
+//! by random bits; UUIDs of the same fraction count up from the previous one.
+//! The v1 and v6 variants likewise hold a single clock sequence `Context`, seeded
+//! randomly unless a clock sequence is given, and count up from it for every UUID of the
+//! run, reading the current time from [`clock::now`] rather than the crate's `now_v*`
+//! functions. With a fixed timestamp, the timestamp moves forward by a tick whenever the
+//! 14 bits of the clock sequence wrap around, so no two UUIDs of a batch are equal.
+//! They keep one node id for the run, or draw a fresh pseudo MAC address for every UUID
+//! with [`NodeIdMode::PerId`].
+//!
+//! # Short Form
+//!
+//! [`ShortUuidGenerator`] wraps a [`UuidGenerator`] of any version and prints its UUIDs
+//! as 22 base57 symbols, the form of Python's `shortuuid` library (the Flickr-like
+//! alphabet without the look-alike `0`, `1`, `I`, `O`, and `l`).
+//!
+//! [`EncodedUuidGenerator`] likewise prints them in a form of their 16 bytes: 12 words
+//! (see [`crate::words`]), their 128-bit value in decimal, unpadded base32 (26
+//! case-insensitive characters, for DNS labels), base64 (padded, or URL-safe and
+//! unpadded in 22 characters, as in JWT claims), Z85 (see [`crate::z85`]), or Rust, C,
+//! and Python code literals (see [`crate::literal`]), with the bytes in the mixed-endian
+//! order of Microsoft GUIDs if requested, and
+//! [`GuidUuidGenerator`] in the braced uppercase form of the Windows registry (like
+//! `{CFBFF0D1-9375-5685-968C-48CE8B15AE17}`). [`TextUuidGenerator`] prints the other
+//! hex forms of the `uuid` crate's formatters, in either case: hyphenated (with uppercase
+//! digits, as the lowercase ones are the default), `simple` (32 digits alone), the
+//! RFC 4122 URN (like `urn:uuid:cfbff0d1-9375-5685-968c-48ce8b15ae17`), or braced.
+//!
 //! [`new_v1`]: UuidGenerator::new_v1
 //! [`new_v3`]: UuidGenerator::new_v3
-//! [`from_params`]: UuidGenerator::from_params
-
-use crate::cli::uuid::{SupportedUUIDNamespace, SupportedUUIDVersion};
+//! [`from_args`]: UuidGenerator::from_args
+
+use std::num::NonZeroUsize;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+
+use anyhow::anyhow;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use crate::cli::IdFormat;
+use crate::cli::uuid::{
+    NodeIdMode, SupportedUUIDHash, SupportedUUIDNamespace, SupportedUUIDVersion,
+    TimestampPrecision, UuidArgs, UuidPrefix,
+};
+use crate::clock;
 use crate::generators::Generate;
 use crate::utils;
 
+/// Version 4 UUIDs whose random bits are all zeros or all ones.
+///
+/// These are the closest a random UUID can get to the nil and max UUIDs, since the
+/// version and variant bits are always set.
+const SPECIAL_V4: [uuid::Uuid; 2] = [
+    uuid::Builder::from_random_bytes([0x00; 16]).into_uuid(),
+    uuid::Builder::from_random_bytes([0xff; 16]).into_uuid(),
+];
+
+/// Number of prefix hex digits above which the vanity search runs on every thread.
+///
+/// Up to 4 digits, a match takes about 65 thousand candidates, which are quicker to try
+/// than starting the threads.
+const PARALLEL_PREFIX_DIGITS: u32 = 4;
+
+/// Number of candidates a vanity search thread claims from the attempt budget at once.
+const SEARCH_BATCH: u64 = 1 << 16;
+
+/// Base57 alphabet of short UUIDs: alphanumerics without `0`, `1`, `I`, `O`, and `l`.
+const SHORT_ALPHABET: &[u8; 57] = b"23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Number of steps of the 12-bit fraction of a millisecond of version 7 UUIDs.
+const V7_FRACTION_STEPS: u128 = 1 << 12;
+
+/// Mask of the 48-bit millisecond timestamp of version 7 UUIDs.
+const V7_MILLIS_MASK: u128 = (1 << 48) - 1;
+
+/// Number of distinct 14-bit clock sequences of version 1 and 6 UUIDs.
+const CLOCK_SEQ_VALUES: u64 = 1 << 14;
+
+/// Length of a base57-encoded UUID.
+const SHORT_LENGTH: usize = 22;
+
 /// UUID generator with variants for each supported version.
 ///
 /// Each variant stores the configuration specific to that UUID version.
 /// Use the version-specific constructors ([`UuidGenerator::new_v1`], etc.)
-/// or [`UuidGenerator::from_params`] for CLI integration.
+/// or [`UuidGenerator::from_args`] for CLI integration.
 pub enum UuidGenerator {
     V1 {
         node_id: [u8; 6],
+        node_id_mode: NodeIdMode,
         timestamp: Option<(u64, u32)>,
+        context: uuid::Context,
+        generated: u64,
+        last_timestamp: Option<uuid::Timestamp>,
     },
     V3 {
         namespace: uuid::Uuid,
-        name: String,
+        name: Vec<u8>,
+    },
+    V4 {
+        exclude_special: bool,
+    },
+    V4Prefix {
+        prefix: UuidPrefix,
+        max_attempts: usize,
+        exclude_special: bool,
     },
-    V4,
     V5 {
         namespace: uuid::Uuid,
-        name: String,
+        name: Vec<u8>,
     },
     V6 {
         node_id: [u8; 6],
+        node_id_mode: NodeIdMode,
         timestamp: Option<(u64, u32)>,
+        context: uuid::Context,
+        generated: u64,
+        last_timestamp: Option<uuid::Timestamp>,
     },
     V7 {
         timestamp: Option<(u64, u32)>,
+        per_ms: Option<NonZeroUsize>,
+        precision: TimestampPrecision,
+        context: uuid::ContextV7,
+        generated: usize,
+        last: (u64, u32),
+        previous: u128,
     },
     V8 {
         data: [u8; 16],
+        random_mask: [u8; 16],
     },
+    Nil,
+    Max,
 }
 
 impl UuidGenerator {
     fn resolve_node_id(node_id: Option<&eui48::MacAddress>) -> [u8; 6] {
         match node_id {
             Some(mac) => mac.to_array(),
-            None => utils::generate_pseudo_mac().to_array(),
+            None => utils::generate_pseudo_mac(None, false, false),
+        }
+    }
+
+    /// Returns the `--node-id`, or with `--hardware-node` the MAC address of a network
+    /// interface.
+    fn node_id_arg(args: &UuidArgs) -> Option<eui48::MacAddress> {
+        match (args.node_id, args.hardware_node) {
+            (Some(mac), _) => Some(mac),
+            (None, true) => Some(eui48::MacAddress::new(Self::hardware_node_id(
+                utils::hardware_mac(),
+            ))),
+            (None, false) => None,
+        }
+    }
+
+    /// Returns the hardware MAC address found, or a pseudo one with a warning when there
+    /// is none.
+    fn hardware_node_id(mac: Option<[u8; 6]>) -> [u8; 6] {
+        mac.unwrap_or_else(|| {
+            eprintln!(
+                "warning: no network interface with a universal MAC address found; using a \
+                 pseudo one as the node id"
+            );
+            utils::generate_pseudo_mac(None, false, false)
+        })
+    }
+
+    /// Returns the namespace, or the one derived from a name under it with the given
+    /// name-based version (like `v5(DNS, "myapp.example.com")` for a per-application
+    /// namespace).
+    fn resolve_namespace(
+        namespace: &SupportedUUIDNamespace,
+        namespace_name: Option<&str>,
+        derive: fn(&uuid::Uuid, &[u8]) -> uuid::Uuid,
+    ) -> SupportedUUIDNamespace {
+        match namespace_name {
+            Some(name) => {
+                SupportedUUIDNamespace::Custom(derive(&namespace.into(), name.as_bytes()))
+            }
+            None => namespace.clone(),
         }
     }
 
-    pub fn new_v1(node_id: Option<&eui48::MacAddress>, timestamp: Option<(u64, u32)>) -> Self {
+    /// Creates a version 1 UUID generator; the clock sequence (14 bits) is random unless
+    /// given.
+    pub fn new_v1(
+        node_id: Option<&eui48::MacAddress>,
+        node_id_mode: NodeIdMode,
+        timestamp: Option<(u64, u32)>,
+        clock_seq: Option<u16>,
+    ) -> Self {
         Self::V1 {
             node_id: Self::resolve_node_id(node_id),
+            node_id_mode,
             timestamp,
+            context: clock_seq.map_or_else(uuid::Context::new_random, uuid::Context::new),
+            generated: 0,
+            last_timestamp: None,
         }
     }
 
-    pub fn new_v3(namespace: &SupportedUUIDNamespace, name: &str) -> Self {
+    pub fn new_v3(namespace: &SupportedUUIDNamespace, name: impl AsRef<[u8]>) -> Self {
         Self::V3 {
             namespace: namespace.into(),
-            name: name.to_string(),
+            name: name.as_ref().to_vec(),
         }
     }
 
-    pub fn new_v4() -> Self {
-        Self::V4
+    pub fn new_v4(exclude_special: bool) -> Self {
+        Self::V4 { exclude_special }
+    }
+
+    /// Creates a version 4 UUID generator searching for UUIDs starting with a prefix.
+    pub fn new_v4_with_prefix(
+        prefix: UuidPrefix,
+        max_attempts: usize,
+        exclude_special: bool,
+    ) -> Self {
+        Self::V4Prefix {
+            prefix,
+            max_attempts,
+            exclude_special,
+        }
     }
 
-    pub fn new_v5(namespace: &SupportedUUIDNamespace, name: &str) -> Self {
+    pub fn new_v5(namespace: &SupportedUUIDNamespace, name: impl AsRef<[u8]>) -> Self {
         Self::V5 {
             namespace: namespace.into(),
-            name: name.to_string(),
+            name: name.as_ref().to_vec(),
         }
     }
 
-    pub fn new_v6(node_id: Option<&eui48::MacAddress>, timestamp: Option<(u64, u32)>) -> Self {
+    /// Creates a version 6 UUID generator; the clock sequence (14 bits) is random unless
+    /// given.
+    pub fn new_v6(
+        node_id: Option<&eui48::MacAddress>,
+        node_id_mode: NodeIdMode,
+        timestamp: Option<(u64, u32)>,
+        clock_seq: Option<u16>,
+    ) -> Self {
         Self::V6 {
             node_id: Self::resolve_node_id(node_id),
+            node_id_mode,
             timestamp,
+            context: clock_seq.map_or_else(uuid::Context::new_random, uuid::Context::new),
+            generated: 0,
+            last_timestamp: None,
         }
     }
 
-    pub fn new_v7(timestamp: Option<(u64, u32)>) -> Self {
-        Self::V7 { timestamp }
+    /// Creates a version 7 UUID generator; timestamps finer than milliseconds take the 12
+    /// bits after the version.
+    pub fn new_v7(
+        timestamp: Option<(u64, u32)>,
+        per_ms: Option<NonZeroUsize>,
+        precision: TimestampPrecision,
+    ) -> Self {
+        // Stepping needs a fixed starting point, so the current time is taken once
+        let timestamp = match (timestamp, per_ms) {
+            (None, Some(_)) => Some(clock::now()),
+            _ => timestamp,
+        };
+
+        Self::V7 {
+            timestamp,
+            per_ms,
+            precision,
+            context: uuid::ContextV7::new(),
+            generated: 0,
+            last: (0, 0),
+            previous: 0,
+        }
     }
 
     pub fn new_v8(data: [u8; 16]) -> Self {
-        Self::V8 { data }
+        Self::V8 {
+            data,
+            random_mask: [0; 16],
+        }
     }
 
-    pub fn from_params(
-        version: SupportedUUIDVersion,
-        timestamp: Option<(u64, u32)>,
-        namespace: Option<&SupportedUUIDNamespace>,
-        name: Option<&String>,
-        node_id: Option<&eui48::MacAddress>,
-        data: Option<&[u8; 16]>,
-    ) -> Self {
-        match version {
-            SupportedUUIDVersion::V1 => Self::new_v1(node_id, timestamp),
+    /// Creates a version 8 UUID generator drawing the bits of the mask fresh for every
+    /// UUID, like the bytes after a tenant tag, and taking the others from the data.
+    pub fn new_v8_random_fill(data: [u8; 16], random_mask: [u8; 16]) -> Self {
+        Self::V8 { data, random_mask }
+    }
+
+    /// Creates a name-based version 8 UUID generator, hashing with SHA-256.
+    ///
+    /// The first 16 bytes of `SHA-256(namespace || name)` become the UUID, with the
+    /// version and variant bits set over 6 of them (keeping 122 bits of the hash), as in
+    /// the name-based example of RFC 9562.
+    pub fn new_v8_sha256(namespace: &SupportedUUIDNamespace, name: &str) -> Self {
+        let namespace: uuid::Uuid = namespace.into();
+        let hash = Sha256::new()
+            .chain_update(namespace.as_bytes())
+            .chain_update(name.as_bytes())
+            .finalize();
+
+        Self::new_v8(hash[..16].try_into().expect("SHA-256 hashes are 32 bytes"))
+    }
+
+    pub fn from_args(args: &UuidArgs) -> Self {
+        if args.nil {
+            return Self::Nil;
+        }
+        if args.max {
+            return Self::Max;
+        }
+
+        match args.version {
+            SupportedUUIDVersion::V1 => Self::new_v1(
+                Self::node_id_arg(args).as_ref(),
+                args.node_id_mode,
+                args.timestamp,
+                args.clock_seq,
+            ),
             SupportedUUIDVersion::V3 => Self::new_v3(
-                namespace.expect("namespace is required for UUID v3 by clap validation"),
-                name.expect("name is required for UUID v3 by clap validation"),
+                &Self::resolve_namespace(
+                    args.namespace
+                        .as_ref()
+                        .expect("namespace is required for UUID v3 by clap validation"),
+                    args.namespace_name.as_deref(),
+                    uuid::Uuid::new_v3,
+                ),
+                args.name_bytes()
+                    .expect("name is required for UUID v3 by clap validation"),
             ),
-            SupportedUUIDVersion::V4 => Self::new_v4(),
+            SupportedUUIDVersion::V4 => match args.starts_with {
+                Some(prefix) => {
+                    Self::new_v4_with_prefix(prefix, args.max_attempts, args.exclude_special)
+                }
+                None => Self::new_v4(args.exclude_special),
+            },
             SupportedUUIDVersion::V5 => Self::new_v5(
-                namespace.expect("namespace is required for UUID v5 by clap validation"),
-                name.expect("name is required for UUID v5 by clap validation"),
+                &Self::resolve_namespace(
+                    args.namespace
+                        .as_ref()
+                        .expect("namespace is required for UUID v5 by clap validation"),
+                    args.namespace_name.as_deref(),
+                    uuid::Uuid::new_v5,
+                ),
+                args.name_bytes()
+                    .expect("name is required for UUID v5 by clap validation"),
+            ),
+            SupportedUUIDVersion::V6 => Self::new_v6(
+                Self::node_id_arg(args).as_ref(),
+                args.node_id_mode,
+                args.timestamp,
+                args.clock_seq,
             ),
-            SupportedUUIDVersion::V6 => Self::new_v6(node_id, timestamp),
-            SupportedUUIDVersion::V7 => Self::new_v7(timestamp),
-            SupportedUUIDVersion::V8 => {
-                Self::new_v8(*data.expect("data is required for UUID v8 by clap validation"))
+            SupportedUUIDVersion::V7 => Self::new_v7(args.timestamp, args.per_ms, args.precision),
+            SupportedUUIDVersion::V8 => match args.hash {
+                Some(SupportedUUIDHash::Sha256) => Self::new_v8_sha256(
+                    args.namespace
+                        .as_ref()
+                        .expect("namespace is required with --hash by clap validation"),
+                    args.name
+                        .as_ref()
+                        .expect("name is required with --hash by clap validation"),
+                ),
+                None => Self::new_v8_random_fill(
+                    args.data_bytes()
+                        .expect("data is required for UUID v8 without --hash by clap validation"),
+                    args.data_random_mask(),
+                ),
+            },
+        }
+    }
+}
+
+impl UuidGenerator {
+    /// Builds a version 4 UUID from the given random bytes, retrying while it is special.
+    fn new_v4_excluding_special(mut random_bytes: impl FnMut() -> [u8; 16]) -> uuid::Uuid {
+        loop {
+            let uuid = uuid::Builder::from_random_bytes(random_bytes()).into_uuid();
+
+            if !SPECIAL_V4.contains(&uuid) {
+                return uuid;
+            }
+        }
+    }
+
+    /// Searches random version 4 UUIDs for one starting with the prefix, giving up after
+    /// the given number of candidates.
+    ///
+    /// Candidates are only the first 32 random bits, and the rest are drawn for a match,
+    /// so long prefixes are cheap to try. Prefixes longer than [`PARALLEL_PREFIX_DIGITS`]
+    /// are searched on every available thread, sharing the attempt budget.
+    fn new_v4_with_prefix_search(
+        prefix: UuidPrefix,
+        max_attempts: usize,
+        exclude_special: bool,
+    ) -> anyhow::Result<uuid::Uuid> {
+        let max_attempts = max_attempts as u64;
+        let claimed = AtomicU64::new(0);
+        let found = OnceLock::new();
+
+        let search = || {
+            let mut rng = rand::rng();
+
+            while found.get().is_none() {
+                let start = claimed.fetch_add(SEARCH_BATCH, Ordering::Relaxed);
+                if start >= max_attempts {
+                    return;
+                }
+
+                for _ in start..max_attempts.min(start + SEARCH_BATCH) {
+                    let head: u32 = rng.random();
+                    if !prefix.matches(head) {
+                        continue;
+                    }
+
+                    let mut bytes: [u8; 16] = rng.random();
+                    bytes[..4].copy_from_slice(&head.to_be_bytes());
+                    let uuid = uuid::Builder::from_random_bytes(bytes).into_uuid();
+
+                    if !(exclude_special && SPECIAL_V4.contains(&uuid)) {
+                        let _ = found.set(uuid);
+                        return;
+                    }
+                }
             }
+        };
+
+        let threads = match prefix.digits() > PARALLEL_PREFIX_DIGITS {
+            true => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            false => 1,
+        };
+
+        match threads {
+            1 => search(),
+            _ => thread::scope(|scope| {
+                for _ in 0..threads {
+                    scope.spawn(search);
+                }
+            }),
+        }
+
+        found.into_inner().ok_or_else(|| {
+            anyhow!("no UUID starting with {prefix} found in {max_attempts} attempts")
+        })
+    }
+
+    /// Moves a Unix timestamp forward by the given number of milliseconds.
+    fn step_millis((seconds, subsec_nanos): (u64, u32), millis: u64) -> (u64, u32) {
+        let nanos = subsec_nanos as u64 + (millis % 1000) * 1_000_000;
+
+        (
+            seconds.saturating_add(millis / 1000 + nanos / 1_000_000_000),
+            (nanos % 1_000_000_000) as u32,
+        )
+    }
+
+    /// Builds a version 7 UUID with the fraction of the millisecond in the 12 bits after
+    /// the version (method 3 of RFC 9562), counting up from the previous UUID of the run
+    /// when it has the same time.
+    fn new_v7_sub_ms(
+        seconds: u64,
+        subsec_nanos: u32,
+        precision: TimestampPrecision,
+        previous: &mut u128,
+    ) -> uuid::Uuid {
+        let millis = seconds * 1000 + u64::from(subsec_nanos / 1_000_000);
+        let nanos = match precision {
+            TimestampPrecision::Us => subsec_nanos % 1_000_000 / 1000 * 1000,
+            _ => subsec_nanos % 1_000_000,
+        };
+        let fraction = u128::from(nanos) * V7_FRACTION_STEPS / 1_000_000;
+
+        // The top bit of the random tail is left clear, so counting up cannot carry out
+        // of it
+        let tail = u128::from(rand::random::<u64>() >> 3);
+
+        let uuid = (u128::from(millis) & V7_MILLIS_MASK) << 80
+            | 0x7 << 76
+            | fraction << 64
+            | 0b10 << 62
+            | tail;
+
+        *previous = match uuid >> 64 == *previous >> 64 {
+            true => *previous + 1,
+            false => uuid,
+        };
+        uuid::Uuid::from_u128(*previous)
+    }
+
+    /// Returns the time of the next version 1 or 6 UUID: the current one, or the fixed
+    /// one moved forward by a 100-nanosecond tick every time the 14-bit clock sequence
+    /// has gone through all its values, so that a batch of any size stays unique. A pinned
+    /// clock (`--time-mode fixed`) counts as a fixed time.
+    ///
+    /// Fails once the fixed time would be moved past the latest Gregorian timestamp.
+    fn clock_seq_timestamp(
+        timestamp: Option<(u64, u32)>,
+        generated: &mut u64,
+    ) -> anyhow::Result<(u64, u32)> {
+        let Some((seconds, subsec_nanos)) = timestamp.or_else(clock::pinned) else {
+            return Ok(clock::now());
+        };
+
+        let ticks = *generated / CLOCK_SEQ_VALUES;
+        *generated += 1;
+
+        let nanos = subsec_nanos as u64 + (ticks % 10_000_000) * 100;
+        let stepped = (
+            seconds.saturating_add(ticks / 10_000_000 + nanos / 1_000_000_000),
+            (nanos % 1_000_000_000) as u32,
+        );
+
+        match utils::unix_to_gregorian_ticks(stepped) {
+            Some(_) => Ok(stepped),
+            None => Err(anyhow!(
+                "UUID timestamps exhausted: more than {CLOCK_SEQ_VALUES} UUIDs per \
+                 100-nanosecond tick would move the timestamp past {}",
+                utils::gregorian_range().1
+            )),
         }
     }
 }
 
-impl Generate for UuidGenerator {
-    fn generate(&self) -> String {
+impl UuidGenerator {
+    /// Returns the timestamp (with the clock sequence) of the last version 1 or 6 UUID of
+    /// the run, if any, to save with `--state-file`.
+    pub fn last_timestamp(&self) -> Option<uuid::Timestamp> {
         match self {
-            UuidGenerator::V1 { node_id, timestamp } => match timestamp {
-                Some((seconds, subsec_nanos)) => uuid::Uuid::new_v1(
-                    uuid::Timestamp::from_unix(uuid::Context::new(0), *seconds, *subsec_nanos),
-                    node_id,
-                )
-                .to_string(),
-                None => uuid::Uuid::now_v1(node_id).to_string(),
-            },
-            UuidGenerator::V3 { namespace, name } => {
-                uuid::Uuid::new_v3(namespace, name.as_bytes()).to_string()
+            UuidGenerator::V1 { last_timestamp, .. } | UuidGenerator::V6 { last_timestamp, .. } => {
+                *last_timestamp
             }
-            UuidGenerator::V4 => uuid::Uuid::new_v4().to_string(),
-            UuidGenerator::V5 { namespace, name } => {
-                uuid::Uuid::new_v5(namespace, name.as_bytes()).to_string()
+            _ => None,
+        }
+    }
+
+    /// Generates the next UUID of the run.
+    fn next_uuid(&mut self) -> anyhow::Result<uuid::Uuid> {
+        Ok(match self {
+            UuidGenerator::V1 {
+                node_id,
+                node_id_mode,
+                timestamp,
+                context,
+                generated,
+                last_timestamp,
+            } => {
+                if *node_id_mode == NodeIdMode::PerId {
+                    *node_id = utils::generate_pseudo_mac(None, false, false);
+                }
+
+                let (seconds, subsec_nanos) = Self::clock_seq_timestamp(*timestamp, generated)?;
+                let timestamp = uuid::Timestamp::from_unix(&*context, seconds, subsec_nanos);
+                *last_timestamp = Some(timestamp);
+                uuid::Uuid::new_v1(timestamp, node_id)
             }
-            UuidGenerator::V6 { node_id, timestamp } => match timestamp {
-                Some((seconds, subsec_nanos)) => uuid::Uuid::new_v6(
-                    uuid::Timestamp::from_unix(
-                        uuid::Context::new_random(),
-                        *seconds,
-                        *subsec_nanos,
-                    ),
-                    node_id,
-                )
-                .to_string(),
-                None => uuid::Uuid::now_v6(node_id).to_string(),
+            UuidGenerator::V3 { namespace, name } => uuid::Uuid::new_v3(namespace, name),
+            UuidGenerator::V4 { exclude_special } => match exclude_special {
+                true => Self::new_v4_excluding_special(rand::random),
+                false => uuid::Uuid::new_v4(),
             },
-            UuidGenerator::V7 { timestamp } => {
-                match timestamp {
-                    Some((seconds, subsec_nanos)) => uuid::Uuid::new_v7(
-                        uuid::Timestamp::from_unix(uuid::ContextV7::new(), *seconds, *subsec_nanos),
-                    )
-                    .to_string(),
-                    None => uuid::Uuid::now_v7().to_string(),
+            UuidGenerator::V4Prefix {
+                prefix,
+                max_attempts,
+                exclude_special,
+            } => Self::new_v4_with_prefix_search(*prefix, *max_attempts, *exclude_special)?,
+            UuidGenerator::V5 { namespace, name } => uuid::Uuid::new_v5(namespace, name),
+            UuidGenerator::V6 {
+                node_id,
+                node_id_mode,
+                timestamp,
+                context,
+                generated,
+                last_timestamp,
+            } => {
+                if *node_id_mode == NodeIdMode::PerId {
+                    *node_id = utils::generate_pseudo_mac(None, false, false);
+                }
+
+                let (seconds, subsec_nanos) = Self::clock_seq_timestamp(*timestamp, generated)?;
+                let timestamp = uuid::Timestamp::from_unix(&*context, seconds, subsec_nanos);
+                *last_timestamp = Some(timestamp);
+                uuid::Uuid::new_v6(timestamp, node_id)
+            }
+            UuidGenerator::V7 {
+                timestamp,
+                per_ms,
+                precision,
+                context,
+                generated,
+                last,
+                previous,
+            } => {
+                let step = per_ms.map_or(0, |per_ms| *generated / per_ms);
+                *generated += 1;
+
+                let now = match timestamp {
+                    Some(timestamp) => Self::step_millis(*timestamp, step as u64),
+                    None => clock::now(),
+                };
+
+                // The context keeps the later second of a clock going backwards but takes
+                // the larger subsecond part, which can jump the UUIDs almost a second ahead
+                *last = (*last).max(now);
+                let (seconds, subsec_nanos) = *last;
+
+                match precision {
+                    TimestampPrecision::Ms => uuid::Uuid::new_v7(uuid::Timestamp::from_unix(
+                        &*context,
+                        seconds,
+                        subsec_nanos,
+                    )),
+                    _ => Self::new_v7_sub_ms(seconds, subsec_nanos, *precision, previous),
                 }
             }
-            UuidGenerator::V8 { data } => uuid::Uuid::new_v8(*data).to_string(),
+            UuidGenerator::V8 { data, random_mask } => {
+                let random: [u8; 16] = rand::random();
+                uuid::Uuid::new_v8(std::array::from_fn(|index| {
+                    data[index] | random[index] & random_mask[index]
+                }))
+            }
+            UuidGenerator::Nil => uuid::Uuid::nil(),
+            UuidGenerator::Max => uuid::Uuid::max(),
+        })
+    }
+}
+
+impl Generate for UuidGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        Ok(self.next_uuid()?.to_string())
+    }
+}
+
+/// UUID generator printing its UUIDs in the short base57 form.
+pub struct ShortUuidGenerator(UuidGenerator);
+
+impl ShortUuidGenerator {
+    pub fn new(generator: UuidGenerator) -> Self {
+        Self(generator)
+    }
+
+    /// Returns the timestamp of the last version 1 or 6 UUID (see
+    /// [`UuidGenerator::last_timestamp`]).
+    pub fn last_timestamp(&self) -> Option<uuid::Timestamp> {
+        self.0.last_timestamp()
+    }
+}
+
+impl Generate for ShortUuidGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        Ok(encode_short(self.0.next_uuid()?))
+    }
+}
+
+/// UUID generator printing its UUIDs in a form encoding their 16 bytes (words, a
+/// decimal integer, base32, base64, Z85, or code literals), in the UUID order or the
+/// mixed-endian order of Microsoft GUIDs.
+pub struct EncodedUuidGenerator {
+    generator: UuidGenerator,
+    format: IdFormat,
+    uppercase: bool,
+    guid: bool,
+}
+
+impl EncodedUuidGenerator {
+    /// Creates a generator; the format must not be the canonical one, and only base32
+    /// is validated to be printed in uppercase.
+    pub fn new(generator: UuidGenerator, format: IdFormat, uppercase: bool, guid: bool) -> Self {
+        Self {
+            generator,
+            format,
+            uppercase,
+            guid,
         }
     }
+
+    /// Returns the timestamp of the last version 1 or 6 UUID (see
+    /// [`UuidGenerator::last_timestamp`]).
+    pub fn last_timestamp(&self) -> Option<uuid::Timestamp> {
+        self.generator.last_timestamp()
+    }
+}
+
+impl Generate for EncodedUuidGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let uuid = self.generator.next_uuid()?;
+        let bytes = match self.guid {
+            true => uuid.to_bytes_le(),
+            false => uuid.into_bytes(),
+        };
+
+        let encoded = self
+            .format
+            .encode(&bytes)
+            .expect("the canonical form is printed by other generators");
+
+        Ok(match self.uppercase {
+            true => encoded.to_ascii_uppercase(),
+            false => encoded,
+        })
+    }
+}
+
+/// UUID generator printing its UUIDs as braced, uppercase Microsoft GUIDs.
+pub struct GuidUuidGenerator(UuidGenerator);
+
+impl GuidUuidGenerator {
+    pub fn new(generator: UuidGenerator) -> Self {
+        Self(generator)
+    }
+
+    /// Returns the timestamp of the last version 1 or 6 UUID (see
+    /// [`UuidGenerator::last_timestamp`]).
+    pub fn last_timestamp(&self) -> Option<uuid::Timestamp> {
+        self.0.last_timestamp()
+    }
+}
+
+impl Generate for GuidUuidGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        Ok(format!("{:X}", self.0.next_uuid()?.braced()))
+    }
+}
+
+/// Hex text forms of UUIDs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UuidText {
+    /// Hyphenated groups, like `cfbff0d1-9375-5685-968c-48ce8b15ae17`
+    Hyphenated,
+    /// 32 hex digits without hyphens
+    Simple,
+    /// The hyphenated form after `urn:uuid:`
+    Urn,
+    /// The hyphenated form in braces
+    Braced,
+}
+
+/// UUID generator printing its UUIDs in a hex text form, with lowercase or uppercase
+/// digits.
+pub struct TextUuidGenerator {
+    generator: UuidGenerator,
+    form: UuidText,
+    uppercase: bool,
+}
+
+impl TextUuidGenerator {
+    pub fn new(generator: UuidGenerator, form: UuidText, uppercase: bool) -> Self {
+        Self {
+            generator,
+            form,
+            uppercase,
+        }
+    }
+
+    /// Returns the timestamp of the last version 1 or 6 UUID (see
+    /// [`UuidGenerator::last_timestamp`]).
+    pub fn last_timestamp(&self) -> Option<uuid::Timestamp> {
+        self.generator.last_timestamp()
+    }
+}
+
+impl Generate for TextUuidGenerator {
+    fn generate(&mut self) -> anyhow::Result<String> {
+        let uuid = self.generator.next_uuid()?;
+
+        Ok(match (self.form, self.uppercase) {
+            (UuidText::Hyphenated, false) => format!("{:x}", uuid.hyphenated()),
+            (UuidText::Hyphenated, true) => format!("{:X}", uuid.hyphenated()),
+            (UuidText::Simple, false) => format!("{:x}", uuid.simple()),
+            (UuidText::Simple, true) => format!("{:X}", uuid.simple()),
+            (UuidText::Urn, false) => format!("{:x}", uuid.urn()),
+            (UuidText::Urn, true) => format!("{:X}", uuid.urn()),
+            (UuidText::Braced, false) => format!("{:x}", uuid.braced()),
+            (UuidText::Braced, true) => format!("{:X}", uuid.braced()),
+        })
+    }
+}
+
+/// Encodes a UUID as a zero-padded, 22-character base57 string.
+///
+/// The symbols are the most significant first, padded with the first alphabet symbol,
+/// as in version 1.0 and later of Python's `shortuuid`.
+fn encode_short(uuid: uuid::Uuid) -> String {
+    let mut value = uuid.as_u128();
+    let mut encoded = [SHORT_ALPHABET[0]; SHORT_LENGTH];
+
+    for symbol in encoded.iter_mut().rev() {
+        *symbol = SHORT_ALPHABET[(value % SHORT_ALPHABET.len() as u128) as usize];
+        value /= SHORT_ALPHABET.len() as u128;
+    }
+
+    String::from_utf8(encoded.to_vec()).expect("base57 symbols are ASCII")
 }
 
 #[cfg(test)]
@@ -227,41 +876,72 @@ mod tests {
 
     #[test]
     fn test_new_v1_without_node_id() {
-        let generator = UuidGenerator::new_v1(None, None);
+        let mut generator = UuidGenerator::new_v1(None, NodeIdMode::Fixed, None, None);
 
         match generator {
-            UuidGenerator::V1 { node_id, timestamp } => {
+            UuidGenerator::V1 {
+                node_id, timestamp, ..
+            } => {
                 assert_eq!(node_id.len(), 6);
                 assert!(timestamp.is_none());
             }
             _ => panic!("Expected V1 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 1);
     }
 
     #[test]
     fn test_new_v1_with_node_id() {
         let mac = eui48::MacAddress::new([0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
-        let generator = UuidGenerator::new_v1(Some(&mac), None);
+        let mut generator = UuidGenerator::new_v1(Some(&mac), NodeIdMode::Fixed, None, None);
 
         match generator {
-            UuidGenerator::V1 { node_id, timestamp } => {
+            UuidGenerator::V1 {
+                node_id, timestamp, ..
+            } => {
                 assert_eq!(node_id, [0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
                 assert!(timestamp.is_none());
             }
             _ => panic!("Expected V1 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 1);
     }
 
+    #[test]
+    fn test_new_v1_node_id_per_id() {
+        let mut generator = UuidGenerator::new_v1(None, NodeIdMode::PerId, None, None);
+
+        let node_ids: std::collections::HashSet<[u8; 6]> = (0..100)
+            .map(|_| {
+                generator.next_uuid().unwrap().as_bytes()[10..]
+                    .try_into()
+                    .unwrap()
+            })
+            .collect();
+
+        assert!(node_ids.len() > 1);
+        // Every node id is a pseudo MAC address (locally administered unicast)
+        assert!(node_ids.iter().all(|node_id| node_id[0] & 0x03 == 0x02));
+    }
+
+    #[test]
+    fn test_new_v6_node_id_fixed() {
+        let mut generator = UuidGenerator::new_v6(None, NodeIdMode::Fixed, None, None);
+
+        let first = generator.next_uuid().unwrap();
+        let second = generator.next_uuid().unwrap();
+
+        assert_eq!(first.as_bytes()[10..], second.as_bytes()[10..]);
+    }
+
     #[test]
     fn test_new_v1_with_timestamp() {
         let timestamp = (1234567890, 123456789);
-        let generator = UuidGenerator::new_v1(None, Some(timestamp));
+        let mut generator = UuidGenerator::new_v1(None, NodeIdMode::Fixed, Some(timestamp), None);
 
         match generator {
             UuidGenerator::V1 { timestamp: ts, .. } => {
@@ -270,15 +950,80 @@ mod tests {
             _ => panic!("Expected V1 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 1);
     }
 
+    #[test]
+    fn test_new_v1_with_clock_seq() {
+        let timestamp = Some((1234567890, 123456789));
+        let mut generator = UuidGenerator::new_v1(None, NodeIdMode::Fixed, timestamp, Some(0x1234));
+
+        let uuid = generator.next_uuid().unwrap();
+
+        // The clock sequence follows the variant bits in bytes 8 and 9
+        assert_eq!(
+            u16::from_be_bytes([uuid.as_bytes()[8], uuid.as_bytes()[9]]) & 0x3fff,
+            0x1234
+        );
+        // The next UUID of the same timestamp counts up from it
+        assert_eq!(&generator.generate().unwrap()[19..23], "9235");
+    }
+
+    #[test]
+    fn test_new_v1_fixed_timestamp_batch_unique() {
+        let timestamp = Some((1234567890, 123456789));
+        let mut generator = UuidGenerator::new_v1(None, NodeIdMode::Fixed, timestamp, None);
+
+        let uuids: std::collections::HashSet<_> = (0..100_000)
+            .map(|_| generator.next_uuid().unwrap())
+            .collect();
+
+        assert_eq!(uuids.len(), 100_000);
+    }
+
+    #[test]
+    fn test_new_v1_clock_seq_wrap_steps_timestamp() {
+        let timestamp = Some((1234567890, 999_999_900));
+        let mut generator = UuidGenerator::new_v1(None, NodeIdMode::Fixed, timestamp, Some(0));
+
+        let first = generator.next_uuid().unwrap();
+        let last = (1..CLOCK_SEQ_VALUES)
+            .map(|_| generator.next_uuid().unwrap())
+            .last()
+            .unwrap();
+        let wrapped = generator.next_uuid().unwrap();
+
+        assert_eq!(
+            last.get_timestamp().unwrap().to_unix(),
+            first.get_timestamp().unwrap().to_unix()
+        );
+        assert_eq!(wrapped.as_bytes()[8..10], first.as_bytes()[8..10]);
+        assert_eq!(wrapped.get_timestamp().unwrap().to_unix(), (1234567891, 0));
+    }
+
+    #[test]
+    fn test_new_v6_clock_seq_wrap_past_gregorian_range() {
+        // The latest Gregorian timestamp: one tick left for a second pass of the clock seq
+        let timestamp = Some((103_072_857_660, 684_697_500));
+        let mut generator = UuidGenerator::new_v6(None, NodeIdMode::Fixed, timestamp, Some(0));
+
+        for _ in 0..CLOCK_SEQ_VALUES {
+            generator.next_uuid().unwrap();
+        }
+
+        assert_eq!(
+            generator.next_uuid().unwrap_err().to_string(),
+            "UUID timestamps exhausted: more than 16384 UUIDs per 100-nanosecond tick would \
+             move the timestamp past 5236-03-31T21:21:00.6846975Z"
+        );
+    }
+
     #[test]
     fn test_new_v3() {
         let namespace = SupportedUUIDNamespace::DNS;
         let name = "example.com";
-        let generator = UuidGenerator::new_v3(&namespace, name);
+        let mut generator = UuidGenerator::new_v3(&namespace, name);
 
         match &generator {
             UuidGenerator::V3 {
@@ -286,33 +1031,103 @@ mod tests {
                 name: n,
             } => {
                 assert_eq!(ns, &uuid::Uuid::NAMESPACE_DNS);
-                assert_eq!(n, "example.com");
+                assert_eq!(n, b"example.com");
             }
             _ => panic!("Expected V3 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 3);
     }
 
     #[test]
     fn test_new_v4() {
-        let generator = UuidGenerator::new_v4();
+        let mut generator = UuidGenerator::new_v4(false);
+
+        match generator {
+            UuidGenerator::V4 { exclude_special } => {
+                assert!(!exclude_special);
+            }
+            _ => panic!("Expected V4 variant"),
+        }
+
+        let uuid_str = generator.generate().unwrap();
+        assert_uuid_format(&uuid_str, 4);
+    }
+
+    #[test]
+    fn test_new_v4_excluding_special() {
+        let mut generator = UuidGenerator::new_v4(true);
 
         match generator {
-            UuidGenerator::V4 => {}
+            UuidGenerator::V4 { exclude_special } => {
+                assert!(exclude_special);
+            }
             _ => panic!("Expected V4 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
+        assert_uuid_format(&uuid_str, 4);
+    }
+
+    #[test]
+    fn test_new_v4_excluding_special_retries_near_nil() {
+        let mut bytes = [[0x00; 16], [0x01; 16]].into_iter();
+        let uuid = UuidGenerator::new_v4_excluding_special(|| bytes.next().unwrap());
+
+        assert_eq!(uuid.to_string(), "01010101-0101-4101-8101-010101010101");
+        assert!(bytes.next().is_none());
+    }
+
+    #[test]
+    fn test_new_v4_with_prefix() {
+        let prefix = crate::cli::uuid::parse_prefix("CAF").unwrap();
+        let mut generator = UuidGenerator::new_v4_with_prefix(prefix, 1 << 30, false);
+
+        for _ in 0..10 {
+            let uuid_str = generator.generate().unwrap();
+
+            assert!(uuid_str.starts_with("caf"));
+            assert_uuid_format(&uuid_str, 4);
+        }
+    }
+
+    #[test]
+    fn test_new_v4_with_prefix_parallel() {
+        let prefix = crate::cli::uuid::parse_prefix("cafe0").unwrap();
+        let mut generator = UuidGenerator::new_v4_with_prefix(prefix, 1 << 30, true);
+
+        let uuid_str = generator.generate().unwrap();
+
+        assert!(uuid_str.starts_with("cafe0"));
         assert_uuid_format(&uuid_str, 4);
     }
 
+    #[test]
+    fn test_new_v4_with_prefix_exhausted() {
+        let prefix = crate::cli::uuid::parse_prefix("0123abcd").unwrap();
+        let mut generator = UuidGenerator::new_v4_with_prefix(prefix, 10, false);
+
+        assert_eq!(
+            generator.generate().unwrap_err().to_string(),
+            "no UUID starting with 0123abcd found in 10 attempts"
+        );
+    }
+
+    #[test]
+    fn test_new_v4_excluding_special_retries_near_max() {
+        let mut bytes = [[0xff; 16], [0xff; 16], [0xfe; 16]].into_iter();
+        let uuid = UuidGenerator::new_v4_excluding_special(|| bytes.next().unwrap());
+
+        assert_eq!(uuid.to_string(), "fefefefe-fefe-4efe-befe-fefefefefefe");
+        assert!(bytes.next().is_none());
+    }
+
     #[test]
     fn test_new_v5() {
         let namespace = SupportedUUIDNamespace::URL;
         let name = "https://example.com";
-        let generator = UuidGenerator::new_v5(&namespace, name);
+        let mut generator = UuidGenerator::new_v5(&namespace, name);
 
         match &generator {
             UuidGenerator::V5 {
@@ -320,52 +1135,68 @@ mod tests {
                 name: n,
             } => {
                 assert_eq!(ns, &uuid::Uuid::NAMESPACE_URL);
-                assert_eq!(n, "https://example.com");
+                assert_eq!(n, b"https://example.com");
             }
             _ => panic!("Expected V5 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 5);
     }
 
+    #[test]
+    fn test_new_v5_custom_namespace() {
+        let namespace =
+            SupportedUUIDNamespace::Custom(uuid::uuid!("1b4e28ba-2fa1-11d2-883f-0016d3cca427"));
+        let mut generator = UuidGenerator::new_v5(&namespace, "example.com");
+
+        assert_eq!(
+            generator.generate().unwrap(),
+            "cf0cd550-1aaf-5335-bf06-e668f7373987"
+        );
+    }
+
     #[test]
     fn test_new_v6_without_node_id() {
-        let generator = UuidGenerator::new_v6(None, None);
+        let mut generator = UuidGenerator::new_v6(None, NodeIdMode::Fixed, None, None);
 
         match generator {
-            UuidGenerator::V6 { node_id, timestamp } => {
+            UuidGenerator::V6 {
+                node_id, timestamp, ..
+            } => {
                 assert_eq!(node_id.len(), 6);
                 assert!(timestamp.is_none());
             }
             _ => panic!("Expected V6 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 6);
     }
 
     #[test]
     fn test_new_v6_with_node_id() {
         let mac = eui48::MacAddress::new([0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54]);
-        let generator = UuidGenerator::new_v6(Some(&mac), None);
+        let mut generator = UuidGenerator::new_v6(Some(&mac), NodeIdMode::Fixed, None, None);
 
         match generator {
-            UuidGenerator::V6 { node_id, timestamp } => {
+            UuidGenerator::V6 {
+                node_id, timestamp, ..
+            } => {
                 assert_eq!(node_id, [0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54]);
                 assert!(timestamp.is_none());
             }
             _ => panic!("Expected V6 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 6);
     }
 
     #[test]
     fn test_new_v6_with_timestamp() {
         let timestamp = (9876543210, 987654321);
-        let generator = UuidGenerator::new_v6(None, Some(timestamp));
+        let mut generator = UuidGenerator::new_v6(None, NodeIdMode::Fixed, Some(timestamp), None);
 
         match generator {
             UuidGenerator::V6 { timestamp: ts, .. } => {
@@ -374,78 +1205,263 @@ mod tests {
             _ => panic!("Expected V6 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 6);
     }
 
+    #[test]
+    fn test_new_v6_with_clock_seq() {
+        let mut generator = UuidGenerator::new_v6(None, NodeIdMode::Fixed, None, Some(0x3fff));
+
+        // The current-time path starts at the given clock sequence
+        assert_eq!(&generator.generate().unwrap()[19..23], "bfff");
+    }
+
+    #[test]
+    fn test_new_v6_fixed_timestamp_batch_unique() {
+        let timestamp = Some((1234567890, 123456789));
+        let mut generator = UuidGenerator::new_v6(None, NodeIdMode::Fixed, timestamp, None);
+
+        let uuids: Vec<uuid::Uuid> = (0..100_000)
+            .map(|_| generator.next_uuid().unwrap())
+            .collect();
+        let clock_seq = |uuid: &uuid::Uuid| {
+            u16::from_be_bytes([uuid.as_bytes()[8], uuid.as_bytes()[9]]) & 0x3fff
+        };
+
+        // The random initial clock sequence counts up by one, wrapping at 14 bits
+        assert!(
+            uuids
+                .windows(2)
+                .all(|w| clock_seq(&w[1]) == (clock_seq(&w[0]) + 1) & 0x3fff)
+        );
+        assert_eq!(
+            uuids.iter().collect::<std::collections::HashSet<_>>().len(),
+            100_000
+        );
+    }
+
     #[test]
     fn test_new_v7_without_timestamp() {
-        let generator = UuidGenerator::new_v7(None);
+        let mut generator = UuidGenerator::new_v7(None, None, TimestampPrecision::Ms);
 
         match generator {
-            UuidGenerator::V7 { timestamp } => {
+            UuidGenerator::V7 { timestamp, .. } => {
                 assert!(timestamp.is_none());
             }
             _ => panic!("Expected V7 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 7);
     }
 
     #[test]
     fn test_new_v7_with_timestamp() {
         let timestamp = (1700000000, 500000000);
-        let generator = UuidGenerator::new_v7(Some(timestamp));
+        let mut generator = UuidGenerator::new_v7(Some(timestamp), None, TimestampPrecision::Ms);
 
         match generator {
-            UuidGenerator::V7 { timestamp: ts } => {
+            UuidGenerator::V7 { timestamp: ts, .. } => {
                 assert_eq!(ts, Some((1700000000, 500000000)));
             }
             _ => panic!("Expected V7 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 7);
     }
 
+    #[test]
+    fn test_new_v7_fixed_timestamp_batch_ascending() {
+        let mut generator =
+            UuidGenerator::new_v7(Some((1700000000, 500000000)), None, TimestampPrecision::Ms);
+
+        let batch: Vec<String> = (0..10_000).map(|_| generator.generate().unwrap()).collect();
+
+        // The context is shared by the batch, so its counter keeps them sorted
+        assert!(batch.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_new_v7_with_sub_ms_precision() {
+        // 250.999 µs into the millisecond: 1024 steps of it in microseconds, 1028 in
+        // nanoseconds
+        let timestamp = Some((1700000000, 500_250_999));
+
+        for (precision, fraction) in [
+            (TimestampPrecision::Us, 1024),
+            (TimestampPrecision::Ns, 1028),
+        ] {
+            let mut generator = UuidGenerator::new_v7(timestamp, None, precision);
+
+            let uuid = generator.next_uuid().unwrap();
+            let value = uuid.as_u128();
+
+            assert_eq!(uuid.get_version_num(), 7);
+            assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+            assert_eq!(value >> 80, 1700000000500);
+            assert_eq!(value >> 64 & 0xfff, fraction);
+        }
+    }
+
+    #[test]
+    fn test_new_v7_with_sub_ms_precision_ascending() {
+        let mut generator = UuidGenerator::new_v7(
+            Some((1700000000, 500_250_999)),
+            None,
+            TimestampPrecision::Ns,
+        );
+
+        let batch: Vec<String> = (0..10_000).map(|_| generator.generate().unwrap()).collect();
+
+        // UUIDs of the same fraction count up, keeping their version and variant
+        assert!(batch.windows(2).all(|w| w[0] < w[1]));
+        assert!(batch.iter().all(|uuid| uuid[14..].starts_with("7404-")));
+        assert!(
+            batch
+                .iter()
+                .all(|uuid| matches!(uuid.as_bytes()[19], b'8' | b'9' | b'a' | b'b'))
+        );
+    }
+
+    #[test]
+    fn test_new_v7_with_per_ms() {
+        let mut generator = UuidGenerator::new_v7(
+            Some((1700000000, 500000000)),
+            NonZeroUsize::new(50),
+            TimestampPrecision::Ms,
+        );
+
+        let batch: Vec<String> = (0..500).map(|_| generator.generate().unwrap()).collect();
+        let millis: Vec<u64> = batch
+            .iter()
+            .map(|s| u64::from_str_radix(&s.replace('-', "")[..12], 16).unwrap())
+            .collect();
+
+        // Exactly 50 UUIDs per millisecond across 10 consecutive milliseconds
+        for (i, chunk) in millis.chunks(50).enumerate() {
+            assert!(chunk.iter().all(|&ms| ms == 1700000000500 + i as u64));
+        }
+
+        // All distinct and globally sorted
+        assert!(batch.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_new_v7_clock_going_backwards() {
+        let mut generator =
+            UuidGenerator::new_v7(Some((10, 500_000)), None, TimestampPrecision::Ms);
+        let mut batch = vec![generator.generate().unwrap()];
+
+        // A step back across a second boundary, then the clock moving on
+        for step in [(9, 999_000_000), (10, 1_500_000)] {
+            if let UuidGenerator::V7 { timestamp, .. } = &mut generator {
+                *timestamp = Some(step);
+            }
+            batch.push(generator.generate().unwrap());
+        }
+
+        assert!(batch.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_new_v7_with_per_ms_without_timestamp() {
+        let generator = UuidGenerator::new_v7(None, NonZeroUsize::new(10), TimestampPrecision::Ms);
+
+        match generator {
+            UuidGenerator::V7 { timestamp, .. } => {
+                assert!(timestamp.is_some());
+            }
+            _ => panic!("Expected V7 variant"),
+        }
+    }
+
+    #[test]
+    fn test_step_millis() {
+        assert_eq!(UuidGenerator::step_millis((1, 0), 0), (1, 0));
+        assert_eq!(
+            UuidGenerator::step_millis((1, 999_500_000), 1),
+            (2, 500_000)
+        );
+        assert_eq!(UuidGenerator::step_millis((1, 0), 2_500), (3, 500_000_000));
+        assert_eq!(
+            UuidGenerator::step_millis((u64::MAX, 0), 1_000),
+            (u64::MAX, 0)
+        );
+    }
+
     #[test]
     fn test_new_v8() {
         let data = [
             0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54,
             0x32, 0x10,
         ];
-        let generator = UuidGenerator::new_v8(data);
+        let mut generator = UuidGenerator::new_v8(data);
 
         match generator {
-            UuidGenerator::V8 { data: d } => {
+            UuidGenerator::V8 { data: d, .. } => {
                 assert_eq!(d, data);
             }
             _ => panic!("Expected V8 variant"),
         }
 
-        let uuid_str = generator.generate();
+        let uuid_str = generator.generate().unwrap();
         assert_uuid_format(&uuid_str, 8);
     }
 
     #[test]
-    fn test_from_params_v1() {
+    fn test_new_v8_random_fill() {
+        let mut data = [0u8; 16];
+        data[..6].copy_from_slice(&[0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
+        let mut random_mask = [0xffu8; 16];
+        random_mask[..6].fill(0);
+
+        let mut generator = UuidGenerator::new_v8_random_fill(data, random_mask);
+        let first = generator.generate().unwrap();
+        let second = generator.generate().unwrap();
+
+        assert_uuid_format(&first, 8);
+        assert!(first.starts_with("01234567-89ab-8"));
+        assert!(second.starts_with("01234567-89ab-8"));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_new_v8_sha256() {
+        // The name-based example of RFC 9562 (Appendix B.2)
+        let mut generator =
+            UuidGenerator::new_v8_sha256(&SupportedUUIDNamespace::DNS, "www.example.com");
+        assert_eq!(
+            generator.generate().unwrap(),
+            "5c146b14-3c52-8afd-938a-375d0df1fbf6"
+        );
+
+        let mut generator =
+            UuidGenerator::new_v8_sha256(&SupportedUUIDNamespace::DNS, "example.com");
+        assert_eq!(
+            generator.generate().unwrap(),
+            "0afe21b6-d5ef-819e-8ac3-9e7ad18d6f73"
+        );
+    }
+
+    #[test]
+    fn test_from_args_v1() {
         let mac = eui48::MacAddress::new([0x11, 0x22, 0x33, 0x44, 0x55, 0x66]);
         let timestamp = Some((1234567890, 123456789));
 
-        let generator = UuidGenerator::from_params(
-            SupportedUUIDVersion::V1,
+        let generator = UuidGenerator::from_args(&UuidArgs {
+            version: SupportedUUIDVersion::V1,
             timestamp,
-            None,
-            None,
-            Some(&mac),
-            None,
-        );
+            node_id: Some(mac),
+            ..Default::default()
+        });
 
         match generator {
             UuidGenerator::V1 {
                 node_id,
                 timestamp: ts,
+                ..
             } => {
                 assert_eq!(node_id, [0x11, 0x22, 0x33, 0x44, 0x55, 0x66]);
                 assert_eq!(ts, timestamp);
@@ -455,18 +1471,16 @@ mod tests {
     }
 
     #[test]
-    fn test_from_params_v3() {
+    fn test_from_args_v3() {
         let namespace = SupportedUUIDNamespace::DNS;
         let name = String::from("test.example.com");
 
-        let generator = UuidGenerator::from_params(
-            SupportedUUIDVersion::V3,
-            None,
-            Some(&namespace),
-            Some(&name),
-            None,
-            None,
-        );
+        let generator = UuidGenerator::from_args(&UuidArgs {
+            version: SupportedUUIDVersion::V3,
+            namespace: Some(namespace),
+            name: Some(name),
+            ..Default::default()
+        });
 
         match generator {
             UuidGenerator::V3 {
@@ -474,36 +1488,39 @@ mod tests {
                 name: n,
             } => {
                 assert_eq!(ns, uuid::Uuid::NAMESPACE_DNS);
-                assert_eq!(n, "test.example.com");
+                assert_eq!(n, b"test.example.com");
             }
             _ => panic!("Expected V3 variant"),
         }
     }
 
     #[test]
-    fn test_from_params_v4() {
-        let generator =
-            UuidGenerator::from_params(SupportedUUIDVersion::V4, None, None, None, None, None);
+    fn test_from_args_v4() {
+        let generator = UuidGenerator::from_args(&UuidArgs {
+            version: SupportedUUIDVersion::V4,
+            exclude_special: true,
+            ..Default::default()
+        });
 
         match generator {
-            UuidGenerator::V4 => {}
+            UuidGenerator::V4 { exclude_special } => {
+                assert!(exclude_special);
+            }
             _ => panic!("Expected V4 variant"),
         }
     }
 
     #[test]
-    fn test_from_params_v5() {
+    fn test_from_args_v5() {
         let namespace = SupportedUUIDNamespace::URL;
         let name = String::from("https://example.org");
 
-        let generator = UuidGenerator::from_params(
-            SupportedUUIDVersion::V5,
-            None,
-            Some(&namespace),
-            Some(&name),
-            None,
-            None,
-        );
+        let generator = UuidGenerator::from_args(&UuidArgs {
+            version: SupportedUUIDVersion::V5,
+            namespace: Some(namespace),
+            name: Some(name),
+            ..Default::default()
+        });
 
         match generator {
             UuidGenerator::V5 {
@@ -511,30 +1528,52 @@ mod tests {
                 name: n,
             } => {
                 assert_eq!(ns, uuid::Uuid::NAMESPACE_URL);
-                assert_eq!(n, "https://example.org");
+                assert_eq!(n, b"https://example.org");
             }
             _ => panic!("Expected V5 variant"),
         }
     }
 
     #[test]
-    fn test_from_params_v6() {
+    fn test_from_args_v5_namespace_name() {
+        let mut generator = UuidGenerator::from_args(&UuidArgs {
+            version: SupportedUUIDVersion::V5,
+            namespace: Some(SupportedUUIDNamespace::DNS),
+            namespace_name: Some(String::from("myapp.example.com")),
+            name: Some(String::from("alice")),
+            ..Default::default()
+        });
+
+        match &generator {
+            UuidGenerator::V5 { namespace, .. } => assert_eq!(
+                namespace,
+                &uuid::uuid!("4e2c26ac-0e94-5a4e-8cf6-318ae75be9be")
+            ),
+            _ => panic!("Expected V5 variant"),
+        }
+        assert_eq!(
+            generator.generate().unwrap(),
+            "c3a18e5b-32af-53a5-a780-74a1489477d0"
+        );
+    }
+
+    #[test]
+    fn test_from_args_v6() {
         let mac = eui48::MacAddress::new([0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
         let timestamp = Some((9999999999, 999999999));
 
-        let generator = UuidGenerator::from_params(
-            SupportedUUIDVersion::V6,
+        let generator = UuidGenerator::from_args(&UuidArgs {
+            version: SupportedUUIDVersion::V6,
             timestamp,
-            None,
-            None,
-            Some(&mac),
-            None,
-        );
+            node_id: Some(mac),
+            ..Default::default()
+        });
 
         match generator {
             UuidGenerator::V6 {
                 node_id,
                 timestamp: ts,
+                ..
             } => {
                 assert_eq!(node_id, [0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
                 assert_eq!(ts, timestamp);
@@ -544,14 +1583,17 @@ mod tests {
     }
 
     #[test]
-    fn test_from_params_v7() {
+    fn test_from_args_v7() {
         let timestamp = Some((1234567890, 0));
 
-        let generator =
-            UuidGenerator::from_params(SupportedUUIDVersion::V7, timestamp, None, None, None, None);
+        let generator = UuidGenerator::from_args(&UuidArgs {
+            version: SupportedUUIDVersion::V7,
+            timestamp,
+            ..Default::default()
+        });
 
         match generator {
-            UuidGenerator::V7 { timestamp: ts } => {
+            UuidGenerator::V7 { timestamp: ts, .. } => {
                 assert_eq!(ts, timestamp);
             }
             _ => panic!("Expected V7 variant"),
@@ -559,29 +1601,215 @@ mod tests {
     }
 
     #[test]
-    fn test_from_params_v8() {
+    fn test_from_args_v8() {
         let data = [
             0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x0f, 0xed, 0xcb, 0xa9, 0x87, 0x65,
             0x43, 0x21,
         ];
 
-        let generator = UuidGenerator::from_params(
-            SupportedUUIDVersion::V8,
-            None,
-            None,
-            None,
-            None,
-            Some(&data),
-        );
+        let generator = UuidGenerator::from_args(&UuidArgs {
+            version: SupportedUUIDVersion::V8,
+            data: Some(hex::encode(data)),
+            ..Default::default()
+        });
 
         match generator {
-            UuidGenerator::V8 { data: d } => {
+            UuidGenerator::V8 { data: d, .. } => {
                 assert_eq!(d, data);
             }
             _ => panic!("Expected V8 variant"),
         }
     }
 
+    #[test]
+    fn test_from_args_v8_sha256() {
+        let mut generator = UuidGenerator::from_args(&UuidArgs {
+            version: SupportedUUIDVersion::V8,
+            namespace: Some(SupportedUUIDNamespace::DNS),
+            name: Some(String::from("example.com")),
+            hash: Some(SupportedUUIDHash::Sha256),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            generator.generate().unwrap(),
+            "0afe21b6-d5ef-819e-8ac3-9e7ad18d6f73"
+        );
+    }
+
+    #[test]
+    fn test_encode_short_known_value() {
+        // The example of the `shortuuid` test suite
+        let uuid = uuid::Uuid::parse_str("3b1f8b40-222c-4a6e-b77e-779d5a94e21c").unwrap();
+
+        assert_eq!(encode_short(uuid), "CXc85b4rqinB7s5J52TRYb");
+    }
+
+    #[test]
+    fn test_encode_short_bounds() {
+        assert_eq!(encode_short(uuid::Uuid::nil()), "2222222222222222222222");
+        assert_eq!(encode_short(uuid::Uuid::max()), "oZEq7ovRbLq6UnGMPwc8B5");
+    }
+
+    #[test]
+    fn test_short_generator() {
+        let mut generator = ShortUuidGenerator::new(UuidGenerator::new_v5(
+            &SupportedUUIDNamespace::DNS,
+            "example.com",
+        ));
+        let uuid = uuid::Uuid::parse_str("cfbff0d1-9375-5685-968c-48ce8b15ae17").unwrap();
+
+        assert_eq!(generator.generate().unwrap(), encode_short(uuid));
+    }
+
+    #[test]
+    fn test_guid_generator() {
+        let mut generator = GuidUuidGenerator::new(UuidGenerator::new_v5(
+            &SupportedUUIDNamespace::DNS,
+            "example.com",
+        ));
+
+        assert_eq!(
+            generator.generate().unwrap(),
+            "{CFBFF0D1-9375-5685-968C-48CE8B15AE17}"
+        );
+    }
+
+    #[test]
+    fn test_int_generator() {
+        let mut generator = EncodedUuidGenerator::new(
+            UuidGenerator::new_v5(&SupportedUUIDNamespace::DNS, "example.com"),
+            IdFormat::Int,
+            false,
+            false,
+        );
+
+        assert_eq!(
+            generator.generate().unwrap(),
+            "276146808210084729002700941631111409175"
+        );
+    }
+
+    #[test]
+    fn test_int_generator_max() {
+        let mut generator =
+            EncodedUuidGenerator::new(UuidGenerator::Max, IdFormat::Int, false, false);
+
+        assert_eq!(generator.generate().unwrap(), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn test_encoded_generator() {
+        for (format, expected) in [
+            (IdFormat::Base32, "z677bumtovlilfumjdhiwfnoc4"),
+            (IdFormat::Base64, "z7/w0ZN1VoWWjEjOixWuFw=="),
+            (IdFormat::Base64url, "z7_w0ZN1VoWWjEjOixWuFw"),
+            (IdFormat::Z85, "=+F$%Lxx]WMw!wWIXSxh"),
+        ] {
+            let mut generator = EncodedUuidGenerator::new(
+                UuidGenerator::new_v5(&SupportedUUIDNamespace::DNS, "example.com"),
+                format,
+                false,
+                false,
+            );
+
+            assert_eq!(generator.generate().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_encoded_generator_guid_order() {
+        for (format, expected) in [
+            (
+                IdFormat::Rust,
+                "[0xd1, 0xf0, 0xbf, 0xcf, 0x75, 0x93, 0x85, 0x56, 0x96, 0x8c, 0x48, 0xce, 0x8b, \
+                 0x15, 0xae, 0x17]",
+            ),
+            (
+                IdFormat::Python,
+                "bytes.fromhex(\"d1f0bfcf75938556968c48ce8b15ae17\")",
+            ),
+        ] {
+            let mut generator = EncodedUuidGenerator::new(
+                UuidGenerator::new_v5(&SupportedUUIDNamespace::DNS, "example.com"),
+                format,
+                false,
+                true,
+            );
+
+            assert_eq!(generator.generate().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_text_generator() {
+        for (form, uppercase, expected) in [
+            (
+                UuidText::Hyphenated,
+                true,
+                "CFBFF0D1-9375-5685-968C-48CE8B15AE17",
+            ),
+            (UuidText::Simple, false, "cfbff0d193755685968c48ce8b15ae17"),
+            (UuidText::Simple, true, "CFBFF0D193755685968C48CE8B15AE17"),
+            (
+                UuidText::Urn,
+                false,
+                "urn:uuid:cfbff0d1-9375-5685-968c-48ce8b15ae17",
+            ),
+            (
+                UuidText::Urn,
+                true,
+                "urn:uuid:CFBFF0D1-9375-5685-968C-48CE8B15AE17",
+            ),
+            (
+                UuidText::Braced,
+                false,
+                "{cfbff0d1-9375-5685-968c-48ce8b15ae17}",
+            ),
+            (
+                UuidText::Braced,
+                true,
+                "{CFBFF0D1-9375-5685-968C-48CE8B15AE17}",
+            ),
+        ] {
+            let mut generator = TextUuidGenerator::new(
+                UuidGenerator::new_v5(&SupportedUUIDNamespace::DNS, "example.com"),
+                form,
+                uppercase,
+            );
+
+            assert_eq!(generator.generate().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_from_args_nil() {
+        let mut generator = UuidGenerator::from_args(&UuidArgs {
+            nil: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(generator, UuidGenerator::Nil));
+        assert_eq!(
+            generator.generate().unwrap(),
+            "00000000-0000-0000-0000-000000000000"
+        );
+    }
+
+    #[test]
+    fn test_from_args_max() {
+        let mut generator = UuidGenerator::from_args(&UuidArgs {
+            max: true,
+            ..Default::default()
+        });
+
+        assert!(matches!(generator, UuidGenerator::Max));
+        assert_eq!(
+            generator.generate().unwrap(),
+            "ffffffff-ffff-ffff-ffff-ffffffffffff"
+        );
+    }
+
     #[test]
     fn test_resolve_node_id_with_mac() {
         let mac = eui48::MacAddress::new([0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]);
@@ -603,4 +1831,19 @@ mod tests {
         );
         assert_eq!(node_id[0] & 0x01, 0x00, "Should not have multicast bit set");
     }
+
+    #[test]
+    fn test_hardware_node_id_found() {
+        let mac = [0x00, 0x1b, 0x21, 0x3a, 0x4f, 0x5c];
+
+        assert_eq!(UuidGenerator::hardware_node_id(Some(mac)), mac);
+    }
+
+    #[test]
+    fn test_hardware_node_id_fallback() {
+        let node_id = UuidGenerator::hardware_node_id(None);
+
+        // Falls back to a pseudo-MAC address (locally administered unicast)
+        assert_eq!(node_id[0] & 0x03, 0x02);
+    }
 }
@@ -0,0 +1,183 @@
+//! Identifier input of consuming commands.
+//!
+//! Commands processing existing identifiers (like `redact` and `validate`) take them as
+//! positional arguments, or from stdin (one per line) when none are given, so quick
+//! checks need no pipe. All go through [`for_each`], so their behavior cannot diverge:
+//!
+//! - Positional arguments are processed in order, and stdin is left unread
+//! - A `-` argument reads stdin in its place, which also forces stdin when it is alone
+//! - Lines are trimmed, and empty ones are skipped
+//!
+//! Names of name-based UUIDs (`--names-stdin`) are read with [`names`] instead: they are
+//! hashed as they are, so only the line endings (`\n` or `\r\n`) are stripped, and empty
+//! lines are names too unless skipped.
+//!
+//! Version 8 UUID data (`--data-stdin`) is read with [`data`], as 16 raw bytes.
+
+use std::io::{self, BufRead, Read};
+
+use anyhow::anyhow;
+
+/// Number of bytes of version 8 UUID data.
+const DATA_BYTES: usize = 16;
+
+/// Argument standing for the lines of stdin.
+const STDIN_ARG: &str = "-";
+
+/// Calls the function with every identifier from the arguments, or stdin if none are
+/// given.
+pub(crate) fn for_each(
+    args: &[String],
+    mut f: impl FnMut(&str) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    if args.is_empty() {
+        return for_each_line(io::stdin().lock(), &mut f);
+    }
+
+    for arg in args {
+        match arg.as_str() {
+            STDIN_ARG => for_each_line(io::stdin().lock(), &mut f)?,
+            value => f(value)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the lines of the reader as names, streaming them, without their line endings.
+pub(crate) fn names(
+    reader: impl BufRead,
+    skip_empty: bool,
+) -> impl Iterator<Item = io::Result<String>> {
+    reader
+        .lines()
+        .filter(move |line| !(skip_empty && line.as_ref().is_ok_and(String::is_empty)))
+}
+
+/// Reads the 16 bytes of version 8 UUID data from the reader, returning whether more
+/// input follows them (which is left unread).
+pub(crate) fn data(mut reader: impl Read) -> anyhow::Result<([u8; DATA_BYTES], bool)> {
+    let mut data = [0u8; DATA_BYTES];
+    let mut length = 0;
+
+    while length < DATA_BYTES {
+        match reader.read(&mut data[length..])? {
+            0 => {
+                return Err(anyhow!(
+                    "stdin has {length} bytes, fewer than the {DATA_BYTES} of UUID v8 data"
+                ));
+            }
+            read => length += read,
+        }
+    }
+
+    let trailing = reader.read(&mut [0u8])? > 0;
+
+    Ok((data, trailing))
+}
+
+/// Calls the function with every non-empty, trimmed line of the reader.
+fn for_each_line(
+    reader: impl BufRead,
+    f: &mut impl FnMut(&str) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    for line in reader.lines() {
+        let line = line?;
+        let value = line.trim();
+
+        if !value.is_empty() {
+            f(value)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_each_arguments_in_order() {
+        let args = [String::from("b"), String::from("a"), String::from("c")];
+        let mut values = Vec::new();
+
+        for_each(&args, |value| {
+            values.push(value.to_owned());
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(values, ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_for_each_line_skips_empty_lines() {
+        let mut values = Vec::new();
+
+        for_each_line(" a \n\n\tb\n".as_bytes(), &mut |value| {
+            values.push(value.to_owned());
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(values, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_names_keep_spaces_and_strip_line_endings() {
+        let names: Vec<String> = names(" a \r\n\nb".as_bytes(), false)
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(names, [" a ", "", "b"]);
+    }
+
+    #[test]
+    fn test_names_skip_empty() {
+        let names: Vec<String> = names("a\r\n\r\n\nb\n".as_bytes(), true)
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(names, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_data() {
+        let bytes: Vec<u8> = (0..16).collect();
+
+        assert_eq!(
+            data(bytes.as_slice()).unwrap(),
+            (std::array::from_fn(|index| index as u8), false)
+        );
+    }
+
+    #[test]
+    fn test_data_trailing_input() {
+        let bytes = [0xffu8; 17];
+
+        assert_eq!(data(bytes.as_slice()).unwrap(), ([0xff; 16], true));
+    }
+
+    #[test]
+    fn test_data_too_short() {
+        assert_eq!(
+            data([0u8; 15].as_slice()).unwrap_err().to_string(),
+            "stdin has 15 bytes, fewer than the 16 of UUID v8 data"
+        );
+    }
+
+    #[test]
+    fn test_for_each_stops_at_error() {
+        let args = [String::from("a"), String::from("b")];
+        let mut values = Vec::new();
+
+        let result = for_each(&args, |value| {
+            values.push(value.to_owned());
+            Err(anyhow::anyhow!("bad {value}"))
+        });
+
+        assert_eq!(result.unwrap_err().to_string(), "bad a");
+        assert_eq!(values, ["a"]);
+    }
+}
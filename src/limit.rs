@@ -0,0 +1,216 @@
+//! Safety limit on the size of a run.
+//!
+//! A typo in the number of results (like `-n 10000000000`) would otherwise keep writing
+//! identifiers until the disk or the pipe's consumer gives up. Before generating, runs
+//! above [`MAX_IDENTIFIERS`] identifiers or an estimated [`MAX_BYTES`] of output are
+//! refused with the estimated size, unless `--yes-really` (or `SPWD_NO_LIMIT=1`) is
+//! given.
+//!
+//! # Estimates
+//!
+//! The output size is the number of identifiers times the average width of a few
+//! samples from a fresh generator, plus a newline each. Most formats have a fixed width,
+//! and the variable ones (like snowflakes or Sqids) vary by a few characters at most, so
+//! the estimate stays within a few percent of the actual output. UUIDs searched for a
+//! vanity prefix are sampled without it, as the search does not change their width.
+
+use anyhow::anyhow;
+
+use crate::cli::Commands;
+use crate::cli::uuid::UuidArgs;
+use crate::generators::{Generate, Generator};
+use crate::utils;
+
+/// Largest number of identifiers generated without an override.
+pub(crate) const MAX_IDENTIFIERS: usize = 10_000_000;
+
+/// Largest estimated output (in bytes) generated without an override.
+pub(crate) const MAX_BYTES: u64 = 1_000_000_000;
+
+/// Number of bytes of a binary UUID.
+const UUID_BYTES: u64 = 16;
+
+/// Number of identifiers sampled to estimate their average width.
+const SAMPLES: u64 = 16;
+
+/// Fails with the estimated output size if the run exceeds the safety limit.
+pub(crate) fn check(command: &Commands, number: usize) -> anyhow::Result<()> {
+    if number == 0 {
+        return Ok(());
+    }
+
+    let bytes = estimate_bytes(command, number)?;
+
+    if number > MAX_IDENTIFIERS || bytes > MAX_BYTES {
+        return Err(anyhow!(
+            "{number} identifiers (about {}) exceed the safety limit of {MAX_IDENTIFIERS} \
+             identifiers or {}; pass --yes-really (or set SPWD_NO_LIMIT=1) to proceed",
+            utils::format_size(bytes),
+            utils::format_size(MAX_BYTES)
+        ));
+    }
+
+    Ok(())
+}
+
+/// Estimates the output size (in bytes) of the given number of identifiers.
+pub(crate) fn estimate_bytes(command: &Commands, number: usize) -> anyhow::Result<u64> {
+    // Binary UUIDs are their 16 bytes alone
+    if let Commands::Uuid(args) = command
+        && args.binary
+    {
+        return Ok((number as u64).saturating_mul(UUID_BYTES));
+    }
+
+    // A vanity prefix keeps the width, and searching for samples could take seconds each;
+    // a hardware node id keeps it too, and looking it up again would repeat its warning
+    let unprefixed;
+    let command = match command {
+        Commands::Uuid(args) if args.starts_with.is_some() || args.hardware_node => {
+            unprefixed = Commands::Uuid(UuidArgs {
+                starts_with: None,
+                hardware_node: false,
+                ..args.clone()
+            });
+            &unprefixed
+        }
+        _ => command,
+    };
+
+    let mut generator = Generator::from(command);
+
+    let mut samples = 0;
+    let mut sampled = 0;
+    while samples < SAMPLES {
+        // A generator running out (like a sequence overflowing) fails the run when it gets
+        // there, after writing the identifiers before it
+        let id = match generator.generate() {
+            Ok(id) => id,
+            Err(e) if samples == 0 => return Err(e),
+            Err(_) => break,
+        };
+
+        // Every identifier is followed by a newline
+        sampled += id.len() as u64 + 1;
+        samples += 1;
+    }
+
+    Ok((number as u64).saturating_mul(sampled) / samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "ulid")]
+    use crate::cli::IdFormat;
+
+    fn actual_bytes(command: &Commands, number: usize) -> u64 {
+        let mut generator = Generator::from(command);
+
+        (0..number)
+            .map(|_| generator.generate().unwrap().len() as u64 + 1)
+            .sum()
+    }
+
+    #[test]
+    #[cfg(feature = "ulid")]
+    fn test_estimate_bytes_fixed_width() {
+        let command = Commands::Ulid {
+            timestamp: None,
+            check: true,
+            format: IdFormat::Canonical,
+        };
+
+        assert_eq!(estimate_bytes(&command, 1000).unwrap(), 28_000);
+        assert_eq!(actual_bytes(&command, 1000), 28_000);
+    }
+
+    #[test]
+    fn test_estimate_bytes_variable_width() {
+        // Decimal snowflakes have no fixed width
+        let command = Commands::Snowflake {
+            worker_id: 0,
+            epoch: crate::generators::snowflake::TWITTER_EPOCH,
+            timestamp: None,
+        };
+
+        let estimate = estimate_bytes(&command, 1000).unwrap() as f64;
+        let actual = actual_bytes(&command, 1000) as f64;
+
+        assert!(
+            (estimate - actual).abs() / actual < 0.05,
+            "{estimate} vs {actual}"
+        );
+    }
+
+    #[test]
+    fn test_estimate_bytes_overflowing_sequence() {
+        // Only two values are left before the sequence overflows
+        let command = Commands::Seq {
+            start: u64::MAX - 1,
+            step: 1,
+            pad: 0,
+            prefix: String::new(),
+        };
+
+        assert_eq!(estimate_bytes(&command, 3).unwrap(), 63);
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_estimate_bytes_skips_vanity_search() {
+        // Sampling would fail after a single attempt, were the prefix searched for
+        let command = Commands::Uuid(UuidArgs {
+            starts_with: Some(crate::cli::uuid::parse_prefix("0123abcd").unwrap()),
+            max_attempts: 1,
+            ..Default::default()
+        });
+
+        assert_eq!(estimate_bytes(&command, 1000).unwrap(), 37_000);
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_estimate_bytes_binary_uuid() {
+        let command = Commands::Uuid(UuidArgs {
+            binary: true,
+            ..Default::default()
+        });
+
+        assert_eq!(estimate_bytes(&command, 1000).unwrap(), 16_000);
+    }
+
+    #[test]
+    #[cfg(feature = "objectid")]
+    fn test_check_within_limit() {
+        let command = Commands::ObjectId { timestamp: None };
+
+        assert!(check(&command, MAX_IDENTIFIERS).is_ok());
+        assert!(check(&command, 0).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "objectid")]
+    fn test_check_too_many_identifiers() {
+        let command = Commands::ObjectId { timestamp: None };
+        let result = check(&command, MAX_IDENTIFIERS + 1);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "10000001 identifiers (about 250.0 MB) exceed the safety limit of 10000000 \
+             identifiers or 1.0 GB; pass --yes-really (or set SPWD_NO_LIMIT=1) to proceed"
+        );
+    }
+
+    #[test]
+    fn test_check_too_many_bytes() {
+        let command = Commands::NanoId {
+            size: std::num::NonZeroUsize::new(999).unwrap(),
+            alphabet: String::from("01"),
+        };
+
+        assert!(check(&command, 999_999).is_ok());
+        assert!(check(&command, 1_000_001).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+//! Code literals of 128-bit identifiers.
+//!
+//! Test fixtures and seed data often need identifiers as source code rather than text.
+//! With `--format rust`, `c`, or `python`, UUIDs and ULIDs are printed as ready-to-paste
+//! literals of their 16 bytes:
+//!
+//! - **Rust**: A `[u8; 16]` array, like `[0xcf, 0xbf, …, 0x17]` (for `Uuid::from_bytes`)
+//! - **C**: An `unsigned char[16]` initializer, like `{0xcf, 0xbf, …, 0x17}`
+//! - **Python**: A `bytes.fromhex("cfbf…17")` call
+//!
+//! Array-like languages share the list of hex bytes, so another one only needs its
+//! brackets.
+
+/// Formats bytes as a Rust `[u8; N]` array.
+pub(crate) fn rust(bytes: &[u8]) -> String {
+    format!("[{}]", hex_list(bytes))
+}
+
+/// Formats bytes as a C `unsigned char[N]` initializer.
+pub(crate) fn c(bytes: &[u8]) -> String {
+    format!("{{{}}}", hex_list(bytes))
+}
+
+/// Formats bytes as a Python `bytes.fromhex()` call.
+pub(crate) fn python(bytes: &[u8]) -> String {
+    format!("bytes.fromhex(\"{}\")", hex::encode(bytes))
+}
+
+/// Lists bytes as comma-separated `0x` hex literals of two digits.
+fn hex_list(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:#04x}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rust() {
+        assert_eq!(rust(&[0x00, 0x0f, 0xff]), "[0x00, 0x0f, 0xff]");
+    }
+
+    #[test]
+    fn test_c() {
+        assert_eq!(c(&[0x00, 0x0f, 0xff]), "{0x00, 0x0f, 0xff}");
+    }
+
+    #[test]
+    fn test_python() {
+        assert_eq!(python(&[0x00, 0x0f, 0xff]), "bytes.fromhex(\"000fff\")");
+    }
+}
@@ -10,6 +10,22 @@
 //!
 //! - [`cli`]: Command-line interface definitions and argument parsing
 //! - [`generators`]: Identifier generator implementations (UUID, ULID, ObjectId)
+//! - [`clock`]: Source of the current time (pinnable with the `test-hooks` feature)
+//! - [`estimate`]: Collision probability estimates for capacity planning
+//! - [`input`]: Identifier input of consuming commands (arguments or stdin)
+//! - [`redact`]: Node identifier redaction for time-based UUIDs
+//! - [`validate`]: Validation of existing identifiers
+//! - [`sortkey`]: Chronological sort keys across identifier types
+//! - [`words`]: Word sequences of UUIDs and ULIDs (`--format words`), and their decoding
+//! - [`z85`]: Z85 encoding of UUIDs and ULIDs (`--format z85`)
+//! - [`probe`]: Uniqueness probes against live systems
+//! - [`limit`]: Safety limit on the size of a run
+//! - [`literal`]: Code literals of UUIDs and ULIDs (`--format rust`, `c`, or `python`)
+//! - [`mapping`]: Mapping files of identifiers and their extra renderings (`--map-file`)
+//! - [`render`]: Extra renderings of identifiers (`--also-format`)
+//! - [`shuffle`]: Shuffling of a generated batch (`--shuffle`)
+//! - [`state`]: Clock sequence state of version 1 and 6 UUIDs across runs (`--state-file`)
+//! - `sink`: Pushing identifiers to an HTTP endpoint (with the `http` feature)
 //! - [`utils`]: Shared utility functions for parsing and data generation
 //!
 //! # Flow
@@ -19,33 +35,347 @@
 //! ```
 //!
 //! 1. Arguments are parsed using `clap` with custom validation
-//! 2. A `Generator` enum is created based on the subcommand
-//! 3. The generator produces the requested number of identifiers
-//! 4. Identifiers are written to stdout, one per line
+//! 2. A `Generator` enum is created based on the subcommand (the `estimate`, `redact`,
+//!    `validate`, `sortkey`, and `words` subcommands print their results instead)
+//! 3. Runs past the safety limit are refused, unless overridden
+//! 4. The generator produces the requested number of identifiers, or one for every name
+//!    of stdin with `uuid --names-stdin` (skipping the ones a probe command reports as
+//!    existing, if any)
+//! 5. Identifiers are checked against the output assertions, if any (and held back
+//!    until the batch is complete, with `--shuffle`)
+//! 6. Identifiers are written to stdout, one per line, followed by any extra renderings
+//!    as tab-separated columns (and appended to a map file with `--map-file`, and posted
+//!    in batches with `--post`), or as their raw 16 bytes with `uuid --binary`
+//!
+//! Only identifiers are ever written to stdout; reports, warnings, and errors go to
+//! stderr. With `--single`, exactly one identifier is written, without a newline, so
+//! command substitution picks up the bare token.
+//!
+//! On Ctrl-C, generation stops between identifiers: the last one written stays whole,
+//! stdout is flushed, and the process exits with code 130.
 
 mod cli;
+mod clock;
+mod estimate;
 mod generators;
+mod input;
+mod limit;
+mod literal;
+mod mapping;
+mod probe;
+mod redact;
+mod render;
+mod shuffle;
+#[cfg(feature = "http")]
+mod sink;
+mod sortkey;
+mod state;
 mod utils;
+mod validate;
+mod words;
+mod z85;
 
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::cli::Args;
+use crate::cli::{Args, Commands, TimeMode};
 use crate::generators::{Generate, Generator};
+use crate::utils::{assert_charset, assert_max_length};
+
+/// Exit code of processes interrupted by SIGINT (128 + 2), as shells report it.
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Set by the Ctrl-C handler and checked by the generation loop.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
 
 fn main() -> anyhow::Result<()> {
     // Parsing the CLI arguments
-    let args = Args::parse();
-
-    // Creating an appropriate generator from the command
-    let generator = Generator::from(&args.command);
+    let mut args = Args::parse();
 
     // Locking stdout for efficient buffered writing
     let mut stdout = io::stdout().lock();
 
-    // Running it as many times as specified
-    for _ in 0..args.number {
-        writeln!(stdout, "{}", generator.generate())?;
+    // Pinning the clock before any generator reads it
+    #[cfg(feature = "test-hooks")]
+    if let Some(timestamp) = args.fake_now {
+        clock::freeze(timestamp);
+    }
+
+    // Reading the clock once for the whole run, if requested
+    if args.time_mode == Some(TimeMode::Fixed) {
+        clock::pin();
+    }
+
+    // Reading version 8 UUID data from stdin once, as if given with `--data`
+    if let Commands::Uuid(uuid_args) = &args.command
+        && uuid_args.data_stdin
+    {
+        let (data, trailing) = input::data(io::stdin().lock())?;
+        if trailing {
+            eprintln!("warning: ignoring stdin past the 16 bytes of UUID v8 data");
+        }
+        args.command = Commands::Uuid(uuid_args.with_data(data));
     }
 
-    Ok(())
+    // Continuing from the clock sequence of the last run, holding the state file until the end
+    let mut state_file = match &args.command {
+        Commands::Uuid(uuid_args) => match &uuid_args.state_file {
+            Some(path) => {
+                let mut state_file = state::StateFile::open(path)?;
+                if let Some(state) = state_file.read()? {
+                    let start = uuid_args.timestamp.unwrap_or_else(clock::now);
+                    args.command =
+                        Commands::Uuid(uuid_args.with_clock_seq(state.next_clock_seq(start)));
+                }
+                Some(state_file)
+            }
+            None => None,
+        },
+        _ => None,
+    };
+
+    // Refusing to garble a terminal with raw UUID bytes
+    if let Commands::Uuid(uuid_args) = &args.command
+        && uuid_args.binary
+        && !uuid_args.force
+        && io::stdout().is_terminal()
+    {
+        return Err(anyhow::anyhow!(
+            "refusing to write binary UUIDs to a terminal; redirect stdout or pass --force"
+        ));
+    }
+
+    // Creating an appropriate generator from the command
+    let mut generator = match &args.command {
+        Commands::Estimate(estimate_args) => {
+            writeln!(stdout, "{}", estimate::report(estimate_args))?;
+            return Ok(());
+        }
+        Commands::Redact { uuids, zero } => return redact::run(uuids, *zero, &mut stdout),
+        Commands::Validate {
+            ids,
+            no_future,
+            skew,
+            apikey,
+            crockford,
+        } => {
+            let options = validate::Options {
+                no_future: *no_future,
+                skew: *skew,
+                apikey: *apikey,
+                crockford: *crockford,
+            };
+
+            if !validate::run(ids, &options, &mut stdout)? {
+                stdout.flush()?;
+                std::process::exit(validate::INVALID_EXIT_CODE);
+            }
+
+            return Ok(());
+        }
+        Commands::Sortkey { ids } => return sortkey::run(ids, &mut stdout),
+        Commands::Words { phrases } => return words::run(phrases, &mut stdout),
+        Commands::Hashids {
+            salt,
+            alphabet,
+            min_length,
+            decode: Some(hashid),
+            ..
+        } => {
+            let numbers = generators::hashids::decode(hashid, salt, alphabet, *min_length)?;
+            let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+            writeln!(stdout, "{}", numbers.join(" "))?;
+            return Ok(());
+        }
+        // Replaced for every name read from stdin
+        Commands::Uuid(uuid_args) if uuid_args.names_stdin => {
+            Generator::from(&Commands::Uuid(uuid_args.with_name(String::new())))
+        }
+        command => Generator::from(command),
+    };
+
+    // Deriving a UUID from every name of stdin instead of generating a number of them
+    let mut names = match &args.command {
+        Commands::Uuid(uuid_args) if uuid_args.names_stdin => {
+            Some(input::names(io::stdin().lock(), uuid_args.skip_empty))
+        }
+        _ => None,
+    };
+
+    // Reporting the computed short code length apart from the results
+    if let (Commands::Shortcode(shortcode_args), Generator::Shortcode(shortcode)) =
+        (&args.command, &generator)
+    {
+        if shortcode_args.verbose {
+            eprintln!("length: {}", shortcode.length());
+        }
+
+        // Banned substrings shrink the space the length was chosen for
+        let probability = shortcode.collision_probability(shortcode_args.expected);
+        if probability > shortcode_args.probability {
+            eprintln!(
+                "warning: banned substrings exclude about {:.1}% of codes, raising the \
+                 collision probability to {probability:.2e}",
+                (1.0 - shortcode.clean_fraction()) * 100.0
+            );
+        }
+    }
+
+    // Refusing accidentally huge runs before writing anything out (names are streamed)
+    if !args.yes_really && names.is_none() {
+        limit::check(&args.command, args.number)?;
+    }
+
+    // Probing candidates against a live system, if requested
+    let mut probe = args
+        .probe_cmd
+        .as_ref()
+        .map(|template| probe::Probe::new(template, args.probe_parallel));
+
+    // Posting identifiers to an endpoint, or mapping them in a file, if requested
+    let mut output = Output {
+        args: &args,
+        stdout,
+        map_file: args
+            .map_file
+            .as_deref()
+            .map(|path| {
+                mapping::MapFile::open(path, &args.command, &args.also_format, args.map_lenient)
+            })
+            .transpose()?,
+        #[cfg(feature = "http")]
+        sink: sink::HttpSink::from_args(&args.post),
+    };
+
+    // Deferring Ctrl-C to the generation loop, so an identifier is never cut in half
+    ctrlc::set_handler(|| INTERRUPTED.store(true, Ordering::Relaxed))?;
+
+    // Holding the whole batch back, if it is to be shuffled
+    let mut batch = Vec::new();
+
+    // Running it as many times as specified, or once for every name
+    let mut remaining = args.number;
+    loop {
+        if INTERRUPTED.load(Ordering::Relaxed) {
+            output.stdout.flush()?;
+            if let Some(map_file) = &mut output.map_file {
+                map_file.flush()?;
+            }
+            save_state(&mut state_file, &generator)?;
+            std::process::exit(INTERRUPTED_EXIT_CODE);
+        }
+
+        match (&mut names, &args.command) {
+            (Some(names), Commands::Uuid(uuid_args)) => match names.next().transpose()? {
+                Some(name) => {
+                    generator = Generator::from(&Commands::Uuid(uuid_args.with_name(name)))
+                }
+                None => break,
+            },
+            _ if remaining == 0 => break,
+            _ => remaining -= 1,
+        }
+
+        let id = match &mut probe {
+            Some(probe) => probe.next(&mut generator)?,
+            None => generator.generate()?,
+        };
+
+        // Checking the downstream constraints before writing anything out
+        if let Some(max_length) = args.assert_max_length {
+            assert_max_length(&id, max_length)?;
+        }
+        if let Some(charset) = &args.assert_charset {
+            assert_charset(&id, charset)?;
+        }
+
+        match args.shuffle {
+            true => batch.push(id),
+            false => output.write(id)?,
+        }
+    }
+
+    // Writing the held-back batch in a random order
+    if args.shuffle {
+        shuffle::shuffle(&mut batch, args.seed);
+
+        for id in batch {
+            output.write(id)?;
+        }
+    }
+
+    // Sending the last, partial batch
+    #[cfg(feature = "http")]
+    if let Some(sink) = &mut output.sink {
+        sink.flush()?;
+    }
+
+    if let Some(map_file) = &mut output.map_file {
+        map_file.flush()?;
+    }
+
+    save_state(&mut state_file, &generator)
+}
+
+/// Saves the clock state of the last UUID generated to the state file, if any.
+fn save_state(
+    state_file: &mut Option<state::StateFile>,
+    generator: &Generator,
+) -> anyhow::Result<()> {
+    match (state_file, generator.last_uuid_timestamp()) {
+        (Some(state_file), Some(timestamp)) => {
+            state_file.write(state::ClockState::from_timestamp(timestamp))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Destinations of generated identifiers: stdout, and a map file and an HTTP endpoint if
+/// requested.
+struct Output<'a, W: Write> {
+    args: &'a Args,
+    stdout: W,
+    map_file: Option<mapping::MapFile>,
+    #[cfg(feature = "http")]
+    sink: Option<sink::HttpSink>,
+}
+
+impl<W: Write> Output<'_, W> {
+    /// Writes an identifier to stdout (unless quiet), and to the map file and the sink, if
+    /// any.
+    fn write(&mut self, id: String) -> anyhow::Result<()> {
+        #[cfg(feature = "http")]
+        let quiet = self.args.post.quiet;
+        #[cfg(not(feature = "http"))]
+        let quiet = false;
+
+        let columns = render::columns(&self.args.command, &id, &self.args.also_format)?;
+
+        if let Some(map_file) = &mut self.map_file {
+            map_file.append(&id, &columns)?;
+        }
+
+        // With `--single`, stdout holds the identifier and nothing else, not even a newline
+        if !quiet {
+            match (&self.args.command, self.args.single) {
+                (Commands::Uuid(uuid_args), _) if uuid_args.binary => {
+                    let uuid = uuid::Uuid::parse_str(&id)?;
+                    let bytes = match uuid_args.guid {
+                        true => uuid.to_bytes_le(),
+                        false => uuid.into_bytes(),
+                    };
+                    self.stdout.write_all(&bytes)?
+                }
+                (_, true) => write!(self.stdout, "{id}{columns}")?,
+                (_, false) => writeln!(self.stdout, "{id}{columns}")?,
+            }
+        }
+
+        #[cfg(feature = "http")]
+        if let Some(sink) = &mut self.sink {
+            sink.push(id)?;
+        }
+
+        Ok(())
+    }
 }
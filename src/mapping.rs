@@ -0,0 +1,157 @@
+//! Mapping files of identifiers and their extra renderings.
+//!
+//! During a migration between identifier schemes, every new identifier needs a
+//! persisted mapping to its other form. With `--map-file`, each identifier and its
+//! `--also-format` renderings are appended to a file as a tab-separated line, the same
+//! line written to stdout.
+//!
+//! # Appending
+//!
+//! The file is opened in append mode (`O_APPEND`), and lines are only ever written whole,
+//! so concurrent runs appending to the same file never interleave partial lines. Pending
+//! lines are flushed when the run ends, or is interrupted.
+//!
+//! # Existing Lines
+//!
+//! Before anything is appended, the existing lines are checked: each one must be an
+//! identifier of the same command followed by its renderings in the requested formats,
+//! and the file must end with a newline. A file failing the check (say, with a line cut
+//! short by a crashed run) is refused, unless `--map-lenient` is given, in which case
+//! a partial last line is terminated before appending.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use anyhow::anyhow;
+
+use crate::cli::{AlsoFormat, Commands};
+use crate::render;
+
+/// Appends mapping lines to a file, whole lines at a time.
+pub(crate) struct MapFile {
+    writer: BufWriter<File>,
+}
+
+impl MapFile {
+    /// Opens the file for appending, checking its existing lines unless lenient.
+    pub(crate) fn open(
+        path: &Path,
+        command: &Commands,
+        formats: &[AlsoFormat],
+        lenient: bool,
+    ) -> anyhow::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(anyhow!("failed to read map file {}: {e}", path.display())),
+        };
+
+        if !lenient {
+            check(&contents, command, formats).map_err(|e| {
+                anyhow!(
+                    "map file {} {e}; pass --map-lenient to append anyway",
+                    path.display()
+                )
+            })?;
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| anyhow!("failed to open map file {}: {e}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+
+        // Terminating a partial last line, so the next line starts on its own
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            writer.write_all(b"\n")?;
+        }
+
+        Ok(Self { writer })
+    }
+
+    /// Appends an identifier and its extra columns (each preceded by a tab) as a line.
+    pub(crate) fn append(&mut self, id: &str, columns: &str) -> anyhow::Result<()> {
+        // A single write per line, so buffer flushes never split it
+        self.writer
+            .write_all(format!("{id}{columns}\n").as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Writes out the pending lines.
+    pub(crate) fn flush(&mut self) -> anyhow::Result<()> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+/// Checks that the existing contents are complete lines the given command and formats
+/// would produce.
+fn check(contents: &str, command: &Commands, formats: &[AlsoFormat]) -> Result<(), String> {
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        return Err(String::from("ends with a partial line"));
+    }
+
+    for (index, line) in contents.lines().enumerate() {
+        let (id, columns) = line.split_once('\t').unwrap_or((line, ""));
+
+        let expected = render::columns(command, id, formats).ok();
+        if expected.is_none_or(|expected| expected.strip_prefix('\t') != Some(columns)) {
+            return Err(format!("has an unparseable line {}: {line:?}", index + 1));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "ulid"))]
+mod tests {
+    use super::*;
+    use crate::cli::IdFormat;
+
+    const ULID: Commands = Commands::Ulid {
+        timestamp: None,
+        check: false,
+        format: IdFormat::Canonical,
+    };
+
+    #[test]
+    fn test_check_valid() {
+        let contents = "01HF7YAT00ZZZZZZZZZZZZZZZZ\t018bcfe56800ffffffffffffffffffff\n";
+
+        assert!(check(contents, &ULID, &[AlsoFormat::Hex]).is_ok());
+        assert!(check("", &ULID, &[AlsoFormat::Hex]).is_ok());
+    }
+
+    #[test]
+    fn test_check_partial_line() {
+        let contents = "01HF7YAT00ZZZZZZZZZZZZZZZZ\t018bcfe568";
+
+        assert_eq!(
+            check(contents, &ULID, &[AlsoFormat::Hex]),
+            Err(String::from("ends with a partial line"))
+        );
+    }
+
+    #[test]
+    fn test_check_mismatched_line() {
+        let contents = "01HF7YAT00ZZZZZZZZZZZZZZZZ\t018bcfe56800ffffffffffffffffffff\n\
+                        01HF7YAT00ZZZZZZZZZZZZZZZZ\t018bcfe56800\n";
+
+        assert_eq!(
+            check(contents, &ULID, &[AlsoFormat::Hex]),
+            Err(String::from(
+                "has an unparseable line 2: \"01HF7YAT00ZZZZZZZZZZZZZZZZ\\t018bcfe56800\""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_check_other_formats() {
+        let contents = "01HF7YAT00ZZZZZZZZZZZZZZZZ\t018bcfe56800ffffffffffffffffffff\n";
+
+        assert!(check(contents, &ULID, &[AlsoFormat::Base58]).is_err());
+        assert!(check(contents, &ULID, &[AlsoFormat::Hex, AlsoFormat::Hex]).is_err());
+    }
+}
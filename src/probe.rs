@@ -0,0 +1,179 @@
+//! Uniqueness probes against live systems.
+//!
+//! A probe is a shell command template with a `{}` placeholder. For every candidate
+//! identifier, the placeholder is substituted and the command is run; its exit code
+//! tells whether the identifier already exists:
+//!
+//! - `0`: It exists, so the candidate is dropped and another one is generated
+//! - `1`: It does not exist, so the candidate is emitted
+//! - Anything else (or a signal): The probe itself failed, which aborts the run
+//!
+//! # Quoting
+//!
+//! The identifier is never pasted into the command text. Instead, `{}` becomes `"$1"`
+//! and the identifier is passed to `sh` as its first positional parameter, so quotes,
+//! spaces, and other shell syntax in identifiers are never interpreted.
+//!
+//! # Concurrency
+//!
+//! Candidates are probed in rounds of up to `--probe-parallel` concurrent commands. A
+//! round's candidates that do not exist are queued for emission in generation order.
+
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+use std::process::{Command, Stdio};
+use std::thread;
+
+use anyhow::anyhow;
+
+use crate::generators::Generate;
+
+/// Maximum number of probing rounds without a single new identifier.
+const RETRY_BUDGET: usize = 100;
+
+/// Filter dropping candidates the probe command reports as existing.
+pub(crate) struct Probe {
+    script: String,
+    parallel: usize,
+    queue: VecDeque<String>,
+}
+
+impl Probe {
+    pub(crate) fn new(template: &str, parallel: NonZeroUsize) -> Self {
+        Self {
+            script: template.replace("{}", "\"$1\""),
+            parallel: parallel.get(),
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Returns the next identifier the probe command reports as new.
+    pub(crate) fn next(&mut self, generator: &mut impl Generate) -> anyhow::Result<String> {
+        for _ in 0..RETRY_BUDGET {
+            if let Some(id) = self.queue.pop_front() {
+                return Ok(id);
+            }
+
+            let candidates = (0..self.parallel)
+                .map(|_| generator.generate())
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            let results = thread::scope(|scope| {
+                let handles: Vec<_> = candidates
+                    .iter()
+                    .map(|id| scope.spawn(|| self.exists(id)))
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("probe thread panicked"))
+                    .collect::<anyhow::Result<Vec<_>>>()
+            })?;
+
+            self.queue.extend(
+                candidates
+                    .into_iter()
+                    .zip(results)
+                    .filter_map(|(id, exists)| (!exists).then_some(id)),
+            );
+        }
+
+        self.queue.pop_front().ok_or_else(|| {
+            anyhow!(
+                "every candidate already exists after {} probes",
+                RETRY_BUDGET * self.parallel
+            )
+        })
+    }
+
+    /// Runs the probe command for an identifier, returning whether it exists.
+    fn exists(&self, id: &str) -> anyhow::Result<bool> {
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(&self.script)
+            .arg("sh")
+            .arg(id)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .status()
+            .map_err(|e| anyhow!("failed to run probe command: {e}"))?;
+
+        match status.code() {
+            Some(0) => Ok(true),
+            Some(1) => Ok(false),
+            _ => Err(anyhow!("probe command failed for {id:?} with {status}")),
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    /// Generator producing numbers counting up from zero.
+    struct Counter(usize);
+
+    impl Generate for Counter {
+        fn generate(&mut self) -> anyhow::Result<String> {
+            self.0 += 1;
+            Ok((self.0 - 1).to_string())
+        }
+    }
+
+    #[test]
+    fn test_probe_skips_existing() {
+        let mut probe = Probe::new("test {} -lt 3", NonZeroUsize::new(1).unwrap());
+        let mut generator = Counter(0);
+
+        assert_eq!(probe.next(&mut generator).unwrap(), "3");
+        assert_eq!(probe.next(&mut generator).unwrap(), "4");
+    }
+
+    #[test]
+    fn test_probe_parallel_keeps_order() {
+        let template = "case {} in *[02468]) exit 0;; esac; exit 1";
+        let mut probe = Probe::new(template, NonZeroUsize::new(4).unwrap());
+        let mut generator = Counter(0);
+
+        let ids: Vec<String> = (0..4)
+            .map(|_| probe.next(&mut generator).unwrap())
+            .collect();
+
+        assert_eq!(ids, ["1", "3", "5", "7"]);
+    }
+
+    #[test]
+    fn test_probe_quotes_identifiers() {
+        let probe = Probe::new(
+            "test {} = \"it's a \\$HOME\"",
+            NonZeroUsize::new(1).unwrap(),
+        );
+
+        assert!(probe.exists("it's a $HOME").unwrap());
+        assert!(!probe.exists("it's a").unwrap());
+    }
+
+    #[test]
+    fn test_probe_exhausts_retry_budget() {
+        let mut probe = Probe::new("true {}", NonZeroUsize::new(2).unwrap());
+        let result = probe.next(&mut Counter(0));
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "every candidate already exists after 200 probes"
+        );
+    }
+
+    #[test]
+    fn test_probe_command_failure() {
+        let mut probe = Probe::new("test -n {} && exit 3", NonZeroUsize::new(1).unwrap());
+        let result = probe.next(&mut Counter(0));
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "probe command failed for \"0\" with exit status: 3"
+        );
+    }
+}
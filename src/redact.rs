@@ -0,0 +1,108 @@
+//! Node identifier redaction for time-based UUIDs.
+//!
+//! UUID versions 1 and 6 embed a node identifier (usually a MAC address) in their last
+//! six bytes. Redaction replaces it with a sentinel, so UUIDs can be shared publicly
+//! without revealing the machine they came from. The timestamp, clock sequence, version,
+//! and variant stay intact, and nothing else is recomputed.
+//!
+//! The default sentinel is `02:00:00:00:00:00`, a locally-administered unicast address
+//! no real hardware uses; all zeros can be used instead.
+
+use std::io::Write;
+
+use anyhow::anyhow;
+
+use crate::input;
+
+/// Locally-administered node identifier replacing redacted ones.
+const SENTINEL_NODE_ID: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+/// Zero node identifier replacing redacted ones when requested.
+const ZERO_NODE_ID: [u8; 6] = [0x00; 6];
+
+/// Replaces the node identifier of a version 1 or 6 UUID.
+pub(crate) fn redact_node_id(value: &str, node_id: [u8; 6]) -> anyhow::Result<uuid::Uuid> {
+    let uuid = uuid::Uuid::parse_str(value).map_err(|e| anyhow!("invalid UUID {value:?}: {e}"))?;
+
+    match uuid.get_version_num() {
+        1 | 6 => {
+            let mut bytes = uuid.into_bytes();
+            bytes[10..].copy_from_slice(&node_id);
+            Ok(uuid::Uuid::from_bytes(bytes))
+        }
+        version => Err(anyhow!(
+            "UUID {uuid} is version {version}; only versions 1 and 6 have a node id to redact"
+        )),
+    }
+}
+
+/// Redacts the given UUIDs, or the ones from stdin (one per line) if none are given.
+pub(crate) fn run(uuids: &[String], zero: bool, stdout: &mut impl Write) -> anyhow::Result<()> {
+    let node_id = if zero { ZERO_NODE_ID } else { SENTINEL_NODE_ID };
+
+    input::for_each(uuids, |value| {
+        writeln!(stdout, "{}", redact_node_id(value, node_id)?)?;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_v1() {
+        let result = redact_node_id("70d9b500-fa26-11dd-8000-da81dd7abf20", SENTINEL_NODE_ID);
+
+        assert_eq!(
+            result.unwrap().to_string(),
+            "70d9b500-fa26-11dd-8000-020000000000"
+        );
+    }
+
+    #[test]
+    fn test_redact_v6_with_zero() {
+        let result = redact_node_id("1ddfa267-0d9b-6500-8000-da81dd7abf20", ZERO_NODE_ID);
+
+        assert_eq!(
+            result.unwrap().to_string(),
+            "1ddfa267-0d9b-6500-8000-000000000000"
+        );
+    }
+
+    #[test]
+    fn test_redact_preserves_version_and_variant() {
+        let uuid =
+            redact_node_id("70d9b500-fa26-11dd-bf3a-da81dd7abf20", SENTINEL_NODE_ID).unwrap();
+
+        assert_eq!(uuid.get_version_num(), 1);
+        assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+        assert_eq!(uuid.to_string()[..24], *"70d9b500-fa26-11dd-bf3a-");
+    }
+
+    #[test]
+    fn test_redact_rejects_other_versions() {
+        let result = redact_node_id("9e4a5f33-f01c-47ee-8527-18ec1f0151d5", SENTINEL_NODE_ID);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "UUID 9e4a5f33-f01c-47ee-8527-18ec1f0151d5 is version 4; \
+             only versions 1 and 6 have a node id to redact"
+        );
+    }
+
+    #[test]
+    fn test_redact_rejects_invalid() {
+        let result = redact_node_id("not-a-uuid", SENTINEL_NODE_ID);
+
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .root_cause()
+                .to_string()
+                .starts_with("invalid UUID \"not-a-uuid\"")
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! Extra renderings of generated identifiers.
+//!
+//! Systems keyed twice (like a UUID primary key exposed through a shorter public form)
+//! need both forms of every identifier. With `--also-format`, each identifier is followed
+//! by further renderings of its bytes as tab-separated columns, so both forms come from
+//! a single run.
+//!
+//! # Bytes
+//!
+//! The bytes are decoded from the canonical form the generator printed, so every column
+//! holds exactly the same value:
+//!
+//! - **UUID**: The 16 bytes of the UUID (including the nil and max UUIDs), with `--guid`
+//!   in the mixed-endian order of Microsoft GUIDs (the first three fields little-endian,
+//!   as `System.Guid.ToByteArray()` returns them)
+//! - **ULID**: The 16 bytes of the ULID (without the check symbol, if any)
+//! - **UUID or ULID as an integer, in base32, base64, or Z85** (`--format int`,
+//!   `base32`, `base64`, `base64url`, or `z85`): The 16 bytes of the identifier
+//!   (big-endian for the integer), the same as for the canonical form (so in GUID order
+//!   for UUIDs with `--guid`)
+//! - **ObjectId**: The 12 bytes of the ObjectId
+//!
+//! Other commands have no canonical byte form, and are refused upfront.
+//!
+//! # Formats
+//!
+//! - **base58**: The Bitcoin alphabet, with a leading `1` for every leading zero byte
+//! - **hex**: Lowercase hexadecimal
+
+use anyhow::anyhow;
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+
+use crate::cli::uuid::UuidArgs;
+use crate::cli::{AlsoFormat, Commands, IdFormat};
+use crate::generators::token;
+use crate::z85;
+
+/// Bitcoin base58 alphabet (alphanumeric without `0`, `I`, `O`, and `l`).
+const BASE58: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Length of a ULID without a check symbol.
+#[cfg(feature = "ulid")]
+const ULID_LENGTH: usize = 26;
+
+/// Renders the extra columns of an identifier, each preceded by a tab.
+pub(crate) fn columns(
+    command: &Commands,
+    id: &str,
+    formats: &[AlsoFormat],
+) -> anyhow::Result<String> {
+    if formats.is_empty() {
+        return Ok(String::new());
+    }
+
+    let bytes = decode(command, id)?;
+
+    Ok(formats
+        .iter()
+        .map(|format| match format {
+            AlsoFormat::Base58 => format!("\t{}", encode_base58(&bytes)),
+            AlsoFormat::Hex => format!("\t{}", hex::encode(&bytes)),
+        })
+        .collect())
+}
+
+/// Decodes the bytes of an identifier printed by the given command.
+fn decode(command: &Commands, id: &str) -> anyhow::Result<Vec<u8>> {
+    match command {
+        Commands::Uuid(UuidArgs { format, .. }) if *format != IdFormat::Canonical => {
+            decode_encoded(*format, id)
+        }
+        Commands::Uuid(args) if args.guid => Ok(uuid::Uuid::parse_str(id)?.to_bytes_le().to_vec()),
+        Commands::Uuid(_) => Ok(uuid::Uuid::parse_str(id)?.as_bytes().to_vec()),
+        #[cfg(feature = "ulid")]
+        Commands::Ulid { format, .. } if *format != IdFormat::Canonical => {
+            decode_encoded(*format, id)
+        }
+        #[cfg(feature = "ulid")]
+        Commands::Ulid { .. } => {
+            let ulid = ulid::Ulid::from_string(id.get(..ULID_LENGTH).unwrap_or(id))
+                .map_err(|e| anyhow!("invalid ULID '{id}': {e}"))?;
+            Ok(ulid.to_bytes().to_vec())
+        }
+        Commands::ObjectId { .. } => Ok(hex::decode(id)?),
+        _ => unreachable!("extra formats are validated against the command"),
+    }
+}
+
+/// Decodes the bytes of a 128-bit identifier printed as a decimal integer, in base32,
+/// base64, or Z85.
+fn decode_encoded(format: IdFormat, id: &str) -> anyhow::Result<Vec<u8>> {
+    let bytes = match format {
+        IdFormat::Int => {
+            let value: u128 = id
+                .parse()
+                .map_err(|e| anyhow!("invalid 128-bit integer '{id}': {e}"))?;
+            return Ok(value.to_be_bytes().to_vec());
+        }
+        IdFormat::Base32 => token::decode_base32(id)?,
+        IdFormat::Base64 => STANDARD
+            .decode(id)
+            .map_err(|e| anyhow!("invalid base64 '{id}': {e}"))?,
+        IdFormat::Base64url => URL_SAFE_NO_PAD
+            .decode(id)
+            .map_err(|e| anyhow!("invalid base64 '{id}': {e}"))?,
+        IdFormat::Z85 => z85::decode(id)?,
+        IdFormat::Canonical | IdFormat::Words | IdFormat::Rust | IdFormat::C | IdFormat::Python => {
+            unreachable!("extra formats are validated against the format")
+        }
+    };
+
+    match bytes.len() {
+        16 => Ok(bytes),
+        length => Err(anyhow!(
+            "invalid identifier '{id}': {length} bytes instead of 16"
+        )),
+    }
+}
+
+/// Encodes bytes in base58, keeping leading zero bytes as leading `1` symbols.
+fn encode_base58(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+
+    // Base58 digits, least significant first
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for byte in &bytes[zeros..] {
+        let mut carry = u32::from(*byte);
+
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    std::iter::repeat_n(BASE58[0], zeros)
+        .chain(digits.iter().rev().map(|digit| BASE58[usize::from(*digit)]))
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_base58_known_values() {
+        assert_eq!(encode_base58(b""), "");
+        assert_eq!(encode_base58(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+        assert_eq!(
+            encode_base58(&[0, 0, 0, 0x28, 0x7f, 0xb4, 0xcd]),
+            "111233QC4"
+        );
+    }
+
+    #[test]
+    fn test_encode_base58_uuid_bounds() {
+        assert_eq!(
+            encode_base58(uuid::Uuid::nil().as_bytes()),
+            "1111111111111111"
+        );
+        assert_eq!(
+            encode_base58(uuid::Uuid::max().as_bytes()),
+            "YcVfxkQb6JRzqk5kF2tNLv"
+        );
+    }
+
+    #[test]
+    fn test_columns() {
+        let command = Commands::ObjectId { timestamp: None };
+        let formats = [AlsoFormat::Hex, AlsoFormat::Base58];
+
+        assert_eq!(
+            columns(&command, "000000000000000000000001", &formats).unwrap(),
+            "\t000000000000000000000001\t111111111112"
+        );
+        assert_eq!(
+            columns(&command, "000000000000000000000001", &[]).unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_decode_guid() {
+        let command = Commands::Uuid(UuidArgs {
+            guid: true,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            decode(&command, "{00112233-4455-6677-8899-AABBCCDDEEFF}").unwrap(),
+            [
+                0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+                0xee, 0xff
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_int() {
+        let command = Commands::Uuid(UuidArgs {
+            format: IdFormat::Int,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            decode(&command, "276146808210084729002700941631111409175").unwrap(),
+            uuid::uuid!("cfbff0d1-9375-5685-968c-48ce8b15ae17").as_bytes()
+        );
+        assert_eq!(
+            decode(&command, "340282366920938463463374607431768211456")
+                .unwrap_err()
+                .to_string(),
+            "invalid 128-bit integer '340282366920938463463374607431768211456': number too \
+             large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn test_decode_base64() {
+        for (format, id) in [
+            (IdFormat::Base64, "z7/w0ZN1VoWWjEjOixWuFw=="),
+            (IdFormat::Base64url, "z7_w0ZN1VoWWjEjOixWuFw"),
+        ] {
+            let command = Commands::Uuid(UuidArgs {
+                format,
+                ..Default::default()
+            });
+
+            assert_eq!(
+                decode(&command, id).unwrap(),
+                uuid::uuid!("cfbff0d1-9375-5685-968c-48ce8b15ae17").as_bytes()
+            );
+        }
+
+        let command = Commands::Uuid(UuidArgs {
+            format: IdFormat::Base64url,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            decode(&command, "AAAA").unwrap_err().to_string(),
+            "invalid identifier 'AAAA': 3 bytes instead of 16"
+        );
+    }
+
+    #[test]
+    fn test_decode_base32() {
+        let command = Commands::Uuid(UuidArgs {
+            format: IdFormat::Base32,
+            uppercase: true,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            decode(&command, "Z677BUMTOVLILFUMJDHIWFNOC4").unwrap(),
+            uuid::uuid!("cfbff0d1-9375-5685-968c-48ce8b15ae17").as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_decode_z85() {
+        let command = Commands::Uuid(UuidArgs {
+            format: IdFormat::Z85,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            decode(&command, "=+F$%Lxx]WMw!wWIXSxh").unwrap(),
+            uuid::uuid!("cfbff0d1-9375-5685-968c-48ce8b15ae17").as_bytes()
+        );
+        assert_eq!(
+            decode(&command, "HelloWorld").unwrap_err().to_string(),
+            "invalid identifier 'HelloWorld': 8 bytes instead of 16"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ulid")]
+    fn test_decode_ulid_with_check_symbol() {
+        let command = Commands::Ulid {
+            timestamp: None,
+            check: true,
+            format: IdFormat::Canonical,
+        };
+
+        assert_eq!(
+            decode(&command, "7ZZZZZZZZZZZZZZZZZZZZZZZZZ5").unwrap(),
+            vec![0xff; 16]
+        );
+    }
+}
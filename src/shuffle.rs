@@ -0,0 +1,80 @@
+//! Shuffling of a generated batch.
+//!
+//! Sortable identifiers (like version 7 UUIDs or ULIDs) reveal the order they were
+//! generated in. With `--shuffle`, the whole batch is generated first, then written out
+//! in a random order, so the values still index well once sorted, but the output order
+//! does not bias downstream sampling.
+//!
+//! The batch is held in memory until it is complete, so it stays within the safety limit
+//! of a run unless overridden.
+//!
+//! # Seeds
+//!
+//! The order is a Fisher–Yates shuffle driven by a seeded generator: a given `--seed`
+//! gives the same permutation for batches of the same size, and a random seed is drawn
+//! otherwise. The seed only affects the order, not the identifiers themselves.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Shuffles the identifiers in place, seeding the order from the given seed, if any.
+pub(crate) fn shuffle<T>(ids: &mut [T], seed: Option<u64>) {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+
+    // Swapping each position (from the last) with a random one at or before it
+    for index in (1..ids.len()).rev() {
+        ids.swap(index, rng.random_range(0..=index));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shuffle_seeded_reproducible() {
+        let mut first: Vec<u32> = (0..100).collect();
+        let mut second = first.clone();
+
+        shuffle(&mut first, Some(42));
+        shuffle(&mut second, Some(42));
+
+        assert_eq!(first, second);
+        assert_ne!(first, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffle_keeps_values() {
+        let mut ids: Vec<u32> = (0..100).collect();
+
+        shuffle(&mut ids, None);
+        ids.sort_unstable();
+
+        assert_eq!(ids, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffle_seeds_differ() {
+        let mut first: Vec<u32> = (0..100).collect();
+        let mut second = first.clone();
+
+        shuffle(&mut first, Some(1));
+        shuffle(&mut second, Some(2));
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_shuffle_trivial() {
+        let mut empty: [u32; 0] = [];
+        let mut single = [7];
+
+        shuffle(&mut empty, Some(0));
+        shuffle(&mut single, Some(0));
+
+        assert_eq!(single, [7]);
+    }
+}
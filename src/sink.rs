@@ -0,0 +1,121 @@
+//! HTTP sink pushing generated identifiers to an endpoint (requires the `http` feature).
+//!
+//! Identifiers are collected into batches and sent as JSON objects holding an array
+//! under a configurable field name:
+//!
+//! ```text
+//! POST /register
+//! {"ids":["01KHF5DXJFPRAM4CX0WPMV21Z9","01KHF5DXJFPRAM4CX0WPMV21ZA"]}
+//! ```
+//!
+//! # Retries
+//!
+//! Connection errors and `5xx`/`429` responses are retried with exponential backoff
+//! (100 ms, 200 ms, 400 ms). Other responses outside `2xx` fail right away. A batch
+//! that ultimately fails aborts the run with an error.
+
+use std::thread;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use serde_json::json;
+
+use crate::cli::post::PostArgs;
+
+/// Maximum number of attempts to send a batch.
+const MAX_ATTEMPTS: u32 = 4;
+
+/// Delay before the first retry, doubled for every next one.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Sink sending identifiers to an HTTP endpoint in batches.
+pub struct HttpSink {
+    agent: ureq::Agent,
+    url: String,
+    field: String,
+    batch_size: usize,
+    batch: Vec<String>,
+}
+
+impl HttpSink {
+    pub fn new(url: &str, batch_size: usize, field: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            url: url.to_owned(),
+            field: field.to_owned(),
+            batch_size,
+            batch: Vec::with_capacity(batch_size),
+        }
+    }
+
+    /// Creates a sink if posting was requested.
+    pub fn from_args(args: &PostArgs) -> Option<Self> {
+        args.post
+            .as_ref()
+            .map(|url| Self::new(url, args.post_batch.get(), &args.post_field))
+    }
+
+    /// Adds an identifier, sending the batch once it is full.
+    pub fn push(&mut self, id: String) -> anyhow::Result<()> {
+        self.batch.push(id);
+
+        if self.batch.len() >= self.batch_size {
+            self.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the pending identifiers, if any.
+    pub fn flush(&mut self) -> anyhow::Result<()> {
+        if self.batch.is_empty() {
+            return Ok(());
+        }
+
+        let mut body = serde_json::Map::new();
+        body.insert(self.field.clone(), json!(self.batch));
+        let body = serde_json::Value::Object(body).to_string();
+        let mut backoff = INITIAL_BACKOFF;
+
+        for attempt in 1..=MAX_ATTEMPTS {
+            let failure = match self
+                .agent
+                .post(&self.url)
+                .header("Content-Type", "application/json")
+                .send(&body)
+            {
+                Ok(response) if response.status().is_success() => {
+                    self.batch.clear();
+                    return Ok(());
+                }
+                Ok(response) => {
+                    let status = response.status();
+
+                    if !status.is_server_error() && status.as_u16() != 429 {
+                        return Err(anyhow!("POST {} failed with status {status}", self.url));
+                    }
+
+                    format!("status {status}")
+                }
+                Err(e) => e.to_string(),
+            };
+
+            if attempt == MAX_ATTEMPTS {
+                return Err(anyhow!(
+                    "POST {} failed after {MAX_ATTEMPTS} attempts: {failure}",
+                    self.url
+                ));
+            }
+
+            thread::sleep(backoff);
+            backoff *= 2;
+        }
+
+        unreachable!("the last attempt always returns")
+    }
+}
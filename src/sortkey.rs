@@ -0,0 +1,197 @@
+//! Sort keys ordering identifiers of different types chronologically.
+//!
+//! Event streams keyed by different identifier types can be merged by sorting on a
+//! canonical key: each identifier maps to a fixed-width hex key that sorts, as plain
+//! text, in the order the identifiers were minted. Each key is printed with its
+//! identifier as a tab-separated column, so `sort | cut -f 2` orders the identifiers.
+//!
+//! # Layout
+//!
+//! The layout is frozen: keys from any version sort correctly against each other. A key
+//! is 50 lowercase hex characters (25 bytes, big-endian):
+//! - Timestamp: 16 characters, in 100-nanosecond intervals since 1582-10-15 (the epoch
+//!   of version 1 UUIDs), or zero for identifiers without one
+//! - Type tag: 2 characters, `01` for ObjectIds, `02` for ULIDs, and `03` for UUIDs
+//! - Bytes: 32 characters, the identifier's own bytes, padded with zeros to 16 bytes
+//!
+//! Timestamps keep the precision of their identifier (seconds for ObjectIds,
+//! milliseconds for ULIDs and version 7 UUIDs), so identifiers minted within the same
+//! second or millisecond fall back to the type tag and the bytes.
+
+use std::io::Write;
+
+use anyhow::anyhow;
+
+use crate::input;
+use crate::validate::OBJECTID_LENGTH;
+#[cfg(feature = "ulid")]
+use crate::validate::ULID_LENGTH;
+
+/// Length of a sort key in hex characters.
+#[cfg(test)]
+const KEY_LENGTH: usize = 50;
+
+/// Number of identifier bytes in a sort key.
+const KEY_BYTES: usize = 16;
+
+/// Type tag of ObjectIds.
+const OBJECTID_TAG: u8 = 0x01;
+
+/// Type tag of ULIDs.
+#[cfg(feature = "ulid")]
+const ULID_TAG: u8 = 0x02;
+
+/// Type tag of UUIDs.
+const UUID_TAG: u8 = 0x03;
+
+/// Number of 100-nanosecond intervals per millisecond.
+const TICKS_PER_MS: u64 = 10_000;
+
+/// Derives the sort key of an identifier, failing if the format is not recognized.
+fn key(value: &str) -> Result<String, String> {
+    if value.len() == OBJECTID_LENGTH && value.bytes().all(|c| c.is_ascii_hexdigit()) {
+        let bytes = hex::decode(value).expect("hex digits");
+        let seconds = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        return Ok(encode(
+            unix_ms_to_ticks(u64::from(seconds) * 1000),
+            OBJECTID_TAG,
+            &bytes,
+        ));
+    }
+
+    #[cfg(feature = "ulid")]
+    if value.len() == ULID_LENGTH {
+        // The first symbol only holds 3 bits, which the `ulid` crate silently drops
+        if value.as_bytes()[0] > b'7' {
+            return Err(String::from("invalid ULID: value overflows 128 bits"));
+        }
+
+        let ulid = ulid::Ulid::from_string(value).map_err(|e| format!("invalid ULID: {e}"))?;
+        return Ok(encode(
+            unix_ms_to_ticks(ulid.timestamp_ms()),
+            ULID_TAG,
+            &ulid.to_bytes(),
+        ));
+    }
+
+    let uuid = uuid::Uuid::parse_str(value).map_err(|e| format!("unrecognized identifier: {e}"))?;
+    let bytes = uuid.as_bytes();
+
+    // Reading Gregorian timestamps from the bytes, as the `uuid` crate goes through Unix
+    // time, which wraps for ones before 1970
+    let ticks = match uuid.get_version_num() {
+        1 => {
+            u64::from(u16::from_be_bytes([bytes[6], bytes[7]]) & 0x0fff) << 48
+                | u64::from(u16::from_be_bytes([bytes[4], bytes[5]])) << 32
+                | u64::from(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        }
+        6 => {
+            u64::from(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])) << 28
+                | u64::from(u16::from_be_bytes([bytes[4], bytes[5]])) << 12
+                | u64::from(u16::from_be_bytes([bytes[6], bytes[7]]) & 0x0fff)
+        }
+        7 => {
+            let mut timestamp = [0; 8];
+            timestamp[2..].copy_from_slice(&bytes[..6]);
+            unix_ms_to_ticks(u64::from_be_bytes(timestamp))
+        }
+        _ => 0,
+    };
+
+    Ok(encode(ticks, UUID_TAG, bytes))
+}
+
+/// Converts Unix milliseconds to 100-nanosecond intervals since the Gregorian epoch.
+fn unix_ms_to_ticks(ms: u64) -> u64 {
+    uuid::timestamp::UUID_TICKS_BETWEEN_EPOCHS + ms * TICKS_PER_MS
+}
+
+/// Writes the timestamp, type tag, and zero-padded bytes of a key in hex.
+fn encode(ticks: u64, tag: u8, bytes: &[u8]) -> String {
+    let mut padded = [0; KEY_BYTES];
+    padded[..bytes.len()].copy_from_slice(bytes);
+
+    format!("{ticks:016x}{tag:02x}{}", hex::encode(padded))
+}
+
+/// Prints the sort keys of the given identifiers, or the ones from stdin (one per line)
+/// if none are given, each followed by its identifier.
+pub(crate) fn run(ids: &[String], stdout: &mut impl Write) -> anyhow::Result<()> {
+    input::for_each(ids, |value| {
+        let key = key(value).map_err(|e| anyhow!("{value:?}: {e}"))?;
+        writeln!(stdout, "{key}\t{value}")?;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2023-11-14T22:13:20Z, in 100-nanosecond intervals since 1582-10-15.
+    const NOW_TICKS: &str = "01ee833b04afc000";
+
+    #[test]
+    fn test_key_objectid() {
+        assert_eq!(
+            key("6553f100aaaaaaaaaaaaaaaa").unwrap(),
+            format!("{NOW_TICKS}016553f100aaaaaaaaaaaaaaaa00000000")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ulid")]
+    fn test_key_ulid() {
+        assert_eq!(
+            key("01HF7YAT00ZZZZZZZZZZZZZZZZ").unwrap(),
+            format!("{NOW_TICKS}02018bcfe56800ffffffffffffffffffff")
+        );
+    }
+
+    #[test]
+    fn test_key_uuid_timestamps() {
+        // Versions 1, 6, and 7 minted at the same instant
+        for uuid in [
+            "04afc000-833b-11ee-8000-000000000000",
+            "1ee833b0-4afc-6000-8000-000000000000",
+            "018bcfe5-6800-7000-8000-000000000000",
+        ] {
+            let key = key(uuid).unwrap();
+
+            assert_eq!(&key[..16], NOW_TICKS, "{uuid}");
+            assert_eq!(&key[16..18], "03");
+            assert_eq!(&key[18..], uuid.replace('-', ""));
+        }
+    }
+
+    #[test]
+    fn test_key_without_timestamp() {
+        let key = key("9e4a5f33-f01c-47ee-8527-18ec1f0151d5").unwrap();
+
+        assert_eq!(key, "0000000000000000039e4a5f33f01c47ee852718ec1f0151d5");
+    }
+
+    #[test]
+    fn test_key_v1_before_unix_epoch() {
+        // 1969-12-31T23:59:59Z
+        let key = key("12e8a980-1dd2-11b2-8000-000000000000").unwrap();
+
+        assert_eq!(&key[..16], "01b21dd212e8a980");
+    }
+
+    #[test]
+    fn test_key_length() {
+        for id in [
+            "6553f100aaaaaaaaaaaaaaaa",
+            "018bcfe5-6800-7000-8000-000000000000",
+            "00000000-0000-0000-0000-000000000000",
+        ] {
+            assert_eq!(key(id).unwrap().len(), KEY_LENGTH, "{id}");
+        }
+    }
+
+    #[test]
+    fn test_key_unrecognized() {
+        assert!(key("not-an-id").is_err());
+    }
+}
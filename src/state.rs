@@ -0,0 +1,216 @@
+//! Persistent clock sequence state of version 1 and 6 UUIDs.
+//!
+//! RFC 9562 (like RFC 4122 before it) keeps the clock sequence in stable storage, so that
+//! a generator restarted with its clock set back does not repeat the time and node of
+//! earlier UUIDs. With `--state-file`, the timestamp and clock sequence of the last UUID
+//! of a run are saved to a file, and the next run sharing it picks up from them:
+//!
+//! - At a later time, the clock sequence is kept
+//! - At the same or an earlier time (a clock gone backwards, or a fixed `--timestamp`
+//!   used again), the clock sequence is incremented past the saved one
+//! - Without a saved state yet (a missing or empty file), the clock sequence is random,
+//!   as without `--state-file`
+//!
+//! # File
+//!
+//! The state is two `key=value` lines: `timestamp`, in 100-nanosecond intervals since
+//! 1582-10-15 (the epoch of the UUID timestamps), and `clock_seq`, from 0 to 16383.
+//!
+//! # Locking
+//!
+//! The file is locked exclusively from the start of the run to its end, so concurrent
+//! runs sharing it take turns rather than starting from the same state.
+
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+
+use crate::cli::uuid::MAX_CLOCK_SEQ;
+
+/// Timestamp and clock sequence of the last UUID of a run.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ClockState {
+    /// 100-nanosecond intervals since the Gregorian epoch.
+    pub(crate) ticks: u64,
+    pub(crate) clock_seq: u16,
+}
+
+impl ClockState {
+    /// Takes the state of a version 1 or 6 UUID timestamp.
+    pub(crate) fn from_timestamp(timestamp: uuid::Timestamp) -> Self {
+        let (ticks, clock_seq) = timestamp.to_gregorian();
+
+        Self { ticks, clock_seq }
+    }
+
+    /// Returns the clock sequence of a run starting at the given Unix timestamp.
+    pub(crate) fn next_clock_seq(self, (seconds, subsec_nanos): (u64, u32)) -> u16 {
+        let (ticks, _) =
+            uuid::Timestamp::from_unix(uuid::NoContext, seconds, subsec_nanos).to_gregorian();
+
+        match ticks > self.ticks {
+            true => self.clock_seq,
+            false => (self.clock_seq + 1) & MAX_CLOCK_SEQ,
+        }
+    }
+}
+
+/// State file, locked for the lifetime of the value.
+pub(crate) struct StateFile {
+    file: File,
+    path: PathBuf,
+}
+
+impl StateFile {
+    /// Opens the file (creating it if needed), waiting for other runs to release it.
+    pub(crate) fn open(path: &Path) -> anyhow::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(|e| anyhow!("failed to open state file {}: {e}", path.display()))?;
+
+        file.lock()
+            .map_err(|e| anyhow!("failed to lock state file {}: {e}", path.display()))?;
+
+        Ok(Self {
+            file,
+            path: path.to_owned(),
+        })
+    }
+
+    /// Reads the saved state, if any.
+    pub(crate) fn read(&mut self) -> anyhow::Result<Option<ClockState>> {
+        let mut contents = String::new();
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file
+            .read_to_string(&mut contents)
+            .map_err(|e| anyhow!("failed to read state file {}: {e}", self.path.display()))?;
+
+        parse(&contents).map_err(|e| anyhow!("state file {} {e}", self.path.display()))
+    }
+
+    /// Replaces the saved state, syncing it to disk.
+    pub(crate) fn write(&mut self, state: ClockState) -> anyhow::Result<()> {
+        let contents = format!("timestamp={}\nclock_seq={}\n", state.ticks, state.clock_seq);
+
+        self.file.set_len(0)?;
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.write_all(contents.as_bytes())?;
+        self.file
+            .sync_all()
+            .map_err(|e| anyhow!("failed to write state file {}: {e}", self.path.display()))
+    }
+}
+
+/// Parses the contents of a state file, or returns `None` for an empty one.
+fn parse(contents: &str) -> Result<Option<ClockState>, String> {
+    if contents.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let mut ticks = None;
+    let mut clock_seq = None;
+
+    for (index, line) in contents.lines().enumerate() {
+        let invalid = || format!("has an unparseable line {}: {line:?}", index + 1);
+
+        match line.trim().split_once('=') {
+            None if line.trim().is_empty() => {}
+            Some(("timestamp", value)) => ticks = Some(value.parse().map_err(|_| invalid())?),
+            Some(("clock_seq", value)) => {
+                clock_seq = Some(
+                    value
+                        .parse()
+                        .ok()
+                        .filter(|clock_seq| *clock_seq <= MAX_CLOCK_SEQ)
+                        .ok_or_else(invalid)?,
+                )
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    match (ticks, clock_seq) {
+        (Some(ticks), Some(clock_seq)) => Ok(Some(ClockState { ticks, clock_seq })),
+        (None, _) => Err(String::from("has no timestamp line")),
+        (_, None) => Err(String::from("has no clock_seq line")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2009-02-13T23:31:30Z, in 100-nanosecond intervals since the Gregorian epoch.
+    const TICKS: u64 = 134_538_606_900_000_000;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("").unwrap(), None);
+        assert_eq!(
+            parse("timestamp=42\nclock_seq=4660\n").unwrap(),
+            Some(ClockState {
+                ticks: 42,
+                clock_seq: 4660
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for (contents, message) in [
+            ("timestamp=42\n", "has no clock_seq line"),
+            ("clock_seq=1\n", "has no timestamp line"),
+            (
+                "timestamp=42\nclock_seq=16384\n",
+                "has an unparseable line 2: \"clock_seq=16384\"",
+            ),
+            (
+                "timestamp=soon\nclock_seq=1\n",
+                "has an unparseable line 1: \"timestamp=soon\"",
+            ),
+            ("42 4660\n", "has an unparseable line 1: \"42 4660\""),
+        ] {
+            assert_eq!(parse(contents).unwrap_err(), message, "{contents:?}");
+        }
+    }
+
+    #[test]
+    fn test_next_clock_seq() {
+        let state = ClockState {
+            ticks: TICKS,
+            clock_seq: MAX_CLOCK_SEQ,
+        };
+        let (seconds, subsec_nanos) = (1_234_567_890, 0);
+
+        // Later runs keep the clock sequence; the same or earlier times increment it
+        assert_eq!(
+            state.next_clock_seq((seconds + 1, subsec_nanos)),
+            MAX_CLOCK_SEQ
+        );
+        assert_eq!(state.next_clock_seq((seconds, subsec_nanos)), 0);
+        assert_eq!(state.next_clock_seq((seconds - 1, subsec_nanos)), 0);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join(format!("spwd-state-{}", std::process::id()));
+        let state = ClockState {
+            ticks: TICKS,
+            clock_seq: 7,
+        };
+
+        let mut file = StateFile::open(&path).unwrap();
+        assert_eq!(file.read().unwrap(), None);
+        file.write(state).unwrap();
+        assert_eq!(file.read().unwrap(), Some(state));
+        drop(file);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -2,26 +2,363 @@
 //!
 //! This module provides helper functions used across the application:
 //!
-//! - [`parse_timestamp_ns`]: Parses nanosecond timestamps from CLI strings
-//! - [`parse_data`]: Parses hex-encoded data for UUID v8
-//! - [`generate_pseudo_mac`]: Generates locally-administered MAC addresses for UUID v1/v6
+//! - [`parse_count`]: Parses result counts with optional decimal suffixes
+//! - [`parse_rate`]: Parses generation rates into identifiers per second
+//! - [`parse_duration`]: Parses durations with a unit suffix into milliseconds
+//! - [`parse_probability`]: Parses probabilities strictly between zero and one
+//! - [`parse_alphabet`]: Parses custom alphabets of distinct symbols
+//! - [`parse_sqids_alphabet`]: Parses Sqids alphabets of distinct ASCII symbols
+//! - [`parse_hashids_alphabet`]: Parses Hashids alphabets of distinct symbols without spaces
+//! - [`parse_apikey_prefix`]: Parses API key prefixes
+//! - [`parse_proquint_bits`], [`parse_proquint_hex`]: Parse proquint sizes and hex data
+//! - [`parse_seq_step`]: Parses positive sequence steps
+//! - [`parse_probe_command`]: Parses probe command templates with a `{}` placeholder
+//! - [`parse_timestamp_ns`]: Parses nanosecond timestamps for the hidden `--fake-now` option
+//! - [`parse_data`], [`parse_data_hex`]: Parse hex-encoded data for UUID v8
+//! - [`data_mask`]: Finds the bits of UUID v8 data given, apart from its padding
+//! - [`parse_name_hex`]: Parses hex-encoded names of any length for UUID v3/v5
+//! - [`parse_node_id`]: Parses MAC address node ids for UUID v1/v6 (with the `uuid` feature)
+//! - [`unix_to_gregorian_ticks`], [`gregorian_range`]: Check timestamps against the UUID v1/v6 range
+//! - [`format_size`]: Formats byte sizes with decimal units
+//! - [`format_timestamp`]: Formats Unix timestamps as RFC 3339 UTC dates
+//! - [`parse_charset`]: Parses identifier charset assertions into anchored regexes
+//! - [`assert_max_length`], [`assert_charset`]: Check identifiers against output constraints
+//! - [`encode_crockford`]: Encodes 128-bit values in Crockford base32
+//! - [`crockford_check_symbol`]: Computes Crockford base32 check symbols
+//! - [`hardware_mac`]: Finds the MAC address of a network interface for UUID v1/v6 (with the
+//!   `uuid` feature)
+//! - [`generate_pseudo_mac`]: Generates locally-administered MAC addresses for UUID v1/v6 and
+//!   the `mac` command
 //!
 //! These utilities handle input validation, format conversion, and random data generation
 //! needed by the various identifier generators.
 
 use anyhow::anyhow;
 use rand::Rng;
+use uuid::timestamp::UUID_TICKS_BETWEEN_EPOCHS;
+
+use crate::cli::uuid::DataPad;
 
 const TIMESTAMP_LENGTH_NANOS: usize = 9;
 const TIMESTAMP_LENGTH_CHARS: usize = 20 + TIMESTAMP_LENGTH_NANOS;
 
 const DATA_LENGTH_BYTES: usize = 16;
 const DATA_LENGTH_CHARS: usize = DATA_LENGTH_BYTES * 2;
+/// Separators ignored between the byte pairs of data.
+const DATA_SEPARATORS: [char; 4] = [' ', ':', '-', '_'];
 
 const MAX_SECONDS: u64 = u64::MAX;
 const MAX_NANOSECONDS: u32 = 999999999;
 
+const MIN_ALPHABET_SYMBOLS: usize = 2;
+const MAX_ALPHABET_SYMBOLS: usize = 255;
+
+const MAX_TYPEID_PREFIX_LENGTH: usize = 63;
+
+const MIN_SQIDS_ALPHABET_SYMBOLS: usize = 3;
+
+const MIN_HASHIDS_ALPHABET_SYMBOLS: usize = 16;
+
+const NODE_ID_BYTES: usize = 6;
+#[cfg(feature = "uuid")]
+const NODE_ID_SEPARATORS: [char; 3] = [':', '-', '.'];
+#[cfg(feature = "uuid")]
+const NODE_ID_NOTATIONS: &str = "0123456789ab, 0x0123456789ab, 01:23:45:67:89:ab, \
+                                 01-23-45-67-89-ab, and 0123.4567.89ab";
+/// Directory of the network interfaces of Linux (sysfs), each with an `address` file.
+#[cfg(feature = "uuid")]
+const NET_INTERFACES_DIR: &str = "/sys/class/net";
+
+/// Multicast (individual/group) bit of the first MAC address byte.
+pub(crate) const MAC_MULTICAST_BIT: u8 = 0x1;
+/// Locally administered (universal/local) bit of the first MAC address byte.
+pub(crate) const MAC_LOCAL_BIT: u8 = 0x2;
+
+const PROQUINT_WORD_BITS: u32 = 16;
+const MAX_PROQUINT_BITS: u32 = crate::generators::proquint::MAX_BITS;
+
+const TICKS_PER_SECOND: u64 = 10_000_000;
+const MAX_GREGORIAN_TICKS: u64 = (1 << 60) - 1;
+
+const SIZE_UNITS: [(u64, &str); 4] = [
+    (1_000, "kB"),
+    (1_000_000, "MB"),
+    (1_000_000_000, "GB"),
+    (1_000_000_000_000, "TB"),
+];
+const COUNT_SUFFIXES: [(char, usize); 3] = [('k', 1_000), ('m', 1_000_000), ('g', 1_000_000_000)];
+const RATE_UNITS_MS: [(&str, f64); 4] = [("ms", 1.0), ("s", 1e3), ("min", 6e4), ("h", 3.6e6)];
+const DURATION_UNITS_MS: [(&str, u64); 5] = [
+    ("ms", 1),
+    ("s", 1_000),
+    ("m", 60_000),
+    ("h", 3_600_000),
+    ("d", 86_400_000),
+];
+
+/// Parses a count string with an optional decimal suffix (`k`, `m`, or `g`) into a number.
+pub(crate) fn parse_count(value: &str) -> anyhow::Result<usize> {
+    let (digits, multiplier) = match value.char_indices().last() {
+        Some((index, suffix)) => match COUNT_SUFFIXES
+            .iter()
+            .find(|(s, _)| s.eq_ignore_ascii_case(&suffix))
+        {
+            Some((_, multiplier)) => (&value[..index], *multiplier),
+            None => (value, 1),
+        },
+        None => return Err(anyhow!("count must not be empty")),
+    };
+
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(anyhow!(
+            "count must be a non-negative integer with an optional k, m, or g suffix, got {value:?}"
+        ));
+    }
+
+    digits
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .ok_or_else(|| anyhow!("count must not exceed {}, got {value:?}", usize::MAX))
+}
+
+/// Parses a generation rate (like `50k/s`) into identifiers per second.
+///
+/// The count accepts the same suffixes as [`parse_count`]; a bare count is per second.
+pub(crate) fn parse_rate(value: &str) -> anyhow::Result<f64> {
+    let (count, unit) = value.split_once('/').unwrap_or((value, "s"));
+
+    let Some((_, millis)) = RATE_UNITS_MS.iter().find(|(name, _)| *name == unit) else {
+        return Err(anyhow!(
+            "unknown rate unit {unit:?} (expected ms, s, min, or h)"
+        ));
+    };
+
+    Ok(parse_count(count)? as f64 * 1e3 / millis)
+}
+
+/// Parses a duration (like `5m`) into milliseconds.
+///
+/// Supported units are `ms`, `s`, `m`, `h`, and `d`; a bare number is in seconds.
+pub(crate) fn parse_duration(value: &str) -> anyhow::Result<u64> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (digits, unit) = value.split_at(split);
+    let unit = if unit.is_empty() { "s" } else { unit };
+
+    let Some((_, millis)) = DURATION_UNITS_MS.iter().find(|(name, _)| *name == unit) else {
+        return Err(anyhow!(
+            "unknown duration unit {unit:?} (expected ms, s, m, h, or d)"
+        ));
+    };
+
+    digits
+        .parse::<u64>()
+        .map_err(|_| anyhow!("duration must be a non-negative integer with a unit, got {value:?}"))?
+        .checked_mul(*millis)
+        .ok_or_else(|| anyhow!("duration is too long, got {value:?}"))
+}
+
+/// Parses a probability (like `1e-6`) strictly between zero and one.
+pub(crate) fn parse_probability(value: &str) -> anyhow::Result<f64> {
+    match value.parse::<f64>() {
+        Ok(probability) if probability > 0.0 && probability < 1.0 => Ok(probability),
+        _ => Err(anyhow!(
+            "probability must be a number between 0 and 1 (exclusive), got {value:?}"
+        )),
+    }
+}
+
+/// Parses an alphabet of distinct symbols (between 2 and 255 of them).
+pub(crate) fn parse_alphabet(value: &str) -> anyhow::Result<String> {
+    let length = value.chars().count();
+
+    if !(MIN_ALPHABET_SYMBOLS..=MAX_ALPHABET_SYMBOLS).contains(&length) {
+        return Err(anyhow!(
+            "alphabet must contain between {MIN_ALPHABET_SYMBOLS} and {MAX_ALPHABET_SYMBOLS} symbols, got {length}"
+        ));
+    }
+
+    for (index, symbol) in value.char_indices() {
+        if value[..index].contains(symbol) {
+            return Err(anyhow!(
+                "alphabet must not contain duplicate symbols, got {symbol:?} twice"
+            ));
+        }
+    }
+
+    Ok(value.to_owned())
+}
+
+/// Parses a Sqids alphabet: at least 3 distinct single-byte (ASCII) symbols.
+pub(crate) fn parse_sqids_alphabet(value: &str) -> anyhow::Result<String> {
+    if let Some(symbol) = value.chars().find(|c| !c.is_ascii()) {
+        return Err(anyhow!(
+            "alphabet must not contain multibyte symbols, got {symbol:?}"
+        ));
+    }
+
+    let length = value.len();
+    if length < MIN_SQIDS_ALPHABET_SYMBOLS {
+        return Err(anyhow!(
+            "alphabet must contain at least {MIN_SQIDS_ALPHABET_SYMBOLS} symbols, got {length}"
+        ));
+    }
+
+    for (index, symbol) in value.char_indices() {
+        if value[..index].contains(symbol) {
+            return Err(anyhow!(
+                "alphabet must not contain duplicate symbols, got {symbol:?} twice"
+            ));
+        }
+    }
+
+    Ok(value.to_owned())
+}
+
+/// Parses a Hashids alphabet: at least 16 distinct symbols, none of them spaces.
+pub(crate) fn parse_hashids_alphabet(value: &str) -> anyhow::Result<String> {
+    if value.contains(' ') {
+        return Err(anyhow!("alphabet must not contain spaces"));
+    }
+
+    for (index, symbol) in value.char_indices() {
+        if value[..index].contains(symbol) {
+            return Err(anyhow!(
+                "alphabet must not contain duplicate symbols, got {symbol:?} twice"
+            ));
+        }
+    }
+
+    let length = value.chars().count();
+    if length < MIN_HASHIDS_ALPHABET_SYMBOLS {
+        return Err(anyhow!(
+            "alphabet must contain at least {MIN_HASHIDS_ALPHABET_SYMBOLS} symbols, got {length}"
+        ));
+    }
+
+    Ok(value.to_owned())
+}
+
+/// Parses a TypeID type prefix: up to 63 lowercase ASCII letters and underscores, not
+/// starting or ending with an underscore (and possibly empty, per the specification).
+pub(crate) fn parse_typeid_prefix(value: &str) -> anyhow::Result<String> {
+    if let Some(symbol) = value.chars().find(|c| !c.is_ascii_lowercase() && *c != '_') {
+        return Err(anyhow!(
+            "prefix must contain only lowercase ASCII letters and underscores, got {symbol:?}"
+        ));
+    }
+
+    let length = value.len();
+    if length > MAX_TYPEID_PREFIX_LENGTH {
+        return Err(anyhow!(
+            "prefix must be at most {MAX_TYPEID_PREFIX_LENGTH} characters long, got {length}"
+        ));
+    }
+
+    if value.starts_with('_') || value.ends_with('_') {
+        return Err(anyhow!("prefix must not start or end with an underscore"));
+    }
+
+    Ok(value.to_owned())
+}
+
+/// Parses an API key prefix: ASCII letters, digits, and underscores, not ending with an
+/// underscore (one is added before the random part).
+pub(crate) fn parse_apikey_prefix(value: &str) -> anyhow::Result<String> {
+    if let Some(symbol) = value
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '_')
+    {
+        return Err(anyhow!(
+            "prefix must contain only ASCII letters, digits, and underscores, got {symbol:?}"
+        ));
+    }
+
+    if value.is_empty() || value.ends_with('_') {
+        return Err(anyhow!(
+            "prefix must not be empty nor end with an underscore"
+        ));
+    }
+
+    Ok(value.to_owned())
+}
+
+/// Parses a number of proquint bits: a positive multiple of 16 (one syllable each), up
+/// to the generator's maximum.
+pub(crate) fn parse_proquint_bits(value: &str) -> anyhow::Result<u32> {
+    let bits: u32 = value
+        .parse()
+        .map_err(|_| anyhow!("bits must be a non-negative integer, got {value:?}"))?;
+
+    if bits == 0 || !bits.is_multiple_of(PROQUINT_WORD_BITS) {
+        return Err(anyhow!(
+            "bits must be a positive multiple of {PROQUINT_WORD_BITS}, got {bits}"
+        ));
+    }
+
+    if bits > MAX_PROQUINT_BITS {
+        return Err(anyhow!(
+            "bits must be at most {MAX_PROQUINT_BITS}, got {bits}"
+        ));
+    }
+
+    Ok(bits)
+}
+
+/// Parses a sequence step: a positive integer, as a step of zero would repeat values and
+/// negative ones are not supported.
+pub(crate) fn parse_seq_step(value: &str) -> anyhow::Result<u64> {
+    if value.starts_with('-') {
+        return Err(anyhow!("step must be positive, got {value}"));
+    }
+
+    let step: u64 = value
+        .parse()
+        .map_err(|_| anyhow!("step must be a positive integer, got {value:?}"))?;
+
+    if step == 0 {
+        return Err(anyhow!("step must be positive, got 0"));
+    }
+
+    Ok(step)
+}
+
+/// Parses hex-encoded proquint data: whole 16-bit words (4 hex characters each), up to
+/// the generator's maximum of bits.
+pub(crate) fn parse_proquint_hex(value: &str) -> anyhow::Result<String> {
+    if !value.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(anyhow!("data must contain only hex characters"));
+    }
+
+    let length = value.len();
+    let max_length = MAX_PROQUINT_BITS as usize / 4;
+    if length == 0 || !length.is_multiple_of(4) || length > max_length {
+        return Err(anyhow!(
+            "data length must be a multiple of 4 characters between 4 and {max_length}, got {length}"
+        ));
+    }
+
+    Ok(value.to_ascii_lowercase())
+}
+
+/// Parses a probe command template, which must contain a `{}` placeholder.
+pub(crate) fn parse_probe_command(value: &str) -> anyhow::Result<String> {
+    if !value.contains("{}") {
+        return Err(anyhow!(
+            "command must contain a {{}} placeholder for the identifier"
+        ));
+    }
+
+    Ok(value.to_owned())
+}
+
 /// Parses a timestamp string into seconds and nanoseconds.
+///
+/// Only the hidden `--fake-now` option takes bare nanoseconds like this; `--timestamp`
+/// arguments go through [`crate::cli::timestamp::TimestampArg`].
+#[cfg_attr(not(feature = "test-hooks"), allow(dead_code))]
 pub(crate) fn parse_timestamp_ns(value: &str) -> anyhow::Result<(u64, u32)> {
     let length = value.len();
     match length {
@@ -47,16 +384,33 @@ pub(crate) fn parse_timestamp_ns(value: &str) -> anyhow::Result<(u64, u32)> {
     }
 }
 
-/// Parses user data (hex-encoded) string into bytes.
-pub(crate) fn parse_data(value: &str) -> anyhow::Result<[u8; DATA_LENGTH_BYTES]> {
+/// Parses user data (hex-encoded) string into bytes, zero-extending short strings at the
+/// given end (by hex digits, so odd lengths are fine too).
+///
+/// Byte strings copied out of debuggers are accepted too, with `0x` prefixes and
+/// separators between byte pairs (see [`strip_data_hex`]); lengths count the hex
+/// characters left.
+pub(crate) fn parse_data(value: &str, pad: DataPad) -> anyhow::Result<[u8; DATA_LENGTH_BYTES]> {
+    let value = strip_data_hex(value)?;
+    let value = value.as_str();
+
     let length = value.len();
     match length {
         1..=DATA_LENGTH_CHARS if value.bytes().all(|c| u8::is_ascii_hexdigit(&c)) => {
-            // Padding short hex strings with trailing zeros
+            // Padding short hex strings with leading or trailing zeros
             // NOTE: one string allocation per call, but it is acceptable
+            let zeros = std::iter::repeat_n('0', DATA_LENGTH_CHARS - length);
             let mut full = String::with_capacity(DATA_LENGTH_CHARS);
-            full.push_str(value);
-            full.extend(std::iter::repeat_n('0', DATA_LENGTH_CHARS - length));
+            match pad {
+                DataPad::Left => {
+                    full.extend(zeros);
+                    full.push_str(value);
+                }
+                DataPad::Right => {
+                    full.push_str(value);
+                    full.extend(zeros);
+                }
+            }
 
             // Decoding the 16 bytes of data
             let mut data = [0u8; DATA_LENGTH_BYTES];
@@ -72,26 +426,438 @@ pub(crate) fn parse_data(value: &str) -> anyhow::Result<[u8; DATA_LENGTH_BYTES]>
     }
 }
 
-/// Generates a pseudo-random MAC address.
-pub(crate) fn generate_pseudo_mac() -> eui48::MacAddress {
+/// Returns the bits that user data (validated by [`parse_data`]) gives, once
+/// zero-extended at the given end, so that the others can be filled differently.
+pub(crate) fn data_mask(value: &str, pad: DataPad) -> [u8; DATA_LENGTH_BYTES] {
+    let digits = strip_data_hex(value).expect("data is validated").len();
+
+    parse_data(&"f".repeat(digits), pad).expect("data is validated")
+}
+
+/// Strips the `0x` prefixes and separators (spaces, colons, hyphens, and underscores) of
+/// hex data like `0xDE 0xAD` or `de:ad`, which must fall between byte pairs.
+fn strip_data_hex(value: &str) -> anyhow::Result<String> {
+    let groups: Vec<&str> = value
+        .split(DATA_SEPARATORS)
+        .filter(|group| !group.is_empty())
+        .map(|group| {
+            group
+                .strip_prefix("0x")
+                .or_else(|| group.strip_prefix("0X"))
+                .unwrap_or(group)
+        })
+        .collect();
+
+    if groups.len() > 1
+        && let Some(group) = groups.iter().find(|group| !group.len().is_multiple_of(2))
+    {
+        return Err(anyhow!(
+            "data separators must fall between byte pairs, got {group:?} between them"
+        ));
+    }
+
+    Ok(groups.concat())
+}
+
+/// Checks user data (hex-encoded) for UUID v8, keeping it as given until its padding is
+/// known (see [`parse_data`]).
+pub(crate) fn parse_data_hex(value: &str) -> anyhow::Result<String> {
+    parse_data(value, DataPad::Right).map(|_| value.to_owned())
+}
+
+/// Parses a hex-encoded name for UUID v3/v5 into its bytes, of any length but neither
+/// capped nor padded like [`parse_data`]: every byte takes two hex characters.
+pub(crate) fn parse_name_hex(value: &str) -> anyhow::Result<Vec<u8>> {
+    if !value.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(anyhow!("name must contain only hex characters"));
+    }
+
+    let length = value.len();
+    if !length.is_multiple_of(2) {
+        return Err(anyhow!(
+            "name length must be an even number of hex characters, got {length}"
+        ));
+    }
+
+    hex::decode(value).map_err(|e| anyhow!("hex decode error: {e}"))
+}
+
+/// Parses a node id (a MAC address) in one of the [`NODE_ID_NOTATIONS`], naming the
+/// problem precisely on failure, as the `eui48` crate's own parser both accepts near-misses
+/// (like 11 hex digits) and reports others in its own terms.
+///
+/// Whitespace around the node id (as pasted from `ip link` or a spreadsheet) is ignored.
+#[cfg(feature = "uuid")]
+pub(crate) fn parse_node_id(value: &str) -> anyhow::Result<eui48::MacAddress> {
+    let value = value.trim();
+
+    node_id_bytes(value)
+        .map(eui48::MacAddress::new)
+        .map_err(|problem| {
+            anyhow!(
+                "{problem} (got {} characters); accepted notations are {NODE_ID_NOTATIONS}",
+                value.chars().count()
+            )
+        })
+}
+
+/// Decodes the bytes of a node id, returning the problem with it.
+#[cfg(feature = "uuid")]
+fn node_id_bytes(value: &str) -> Result<[u8; NODE_ID_BYTES], String> {
+    if value.is_empty() {
+        return Err(String::from("node id is empty"));
+    }
+
+    let prefix = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"));
+    let digits = prefix.unwrap_or(value);
+
+    if let Some(c) = digits
+        .chars()
+        .find(|c| !c.is_ascii_hexdigit() && !NODE_ID_SEPARATORS.contains(c))
+    {
+        return Err(format!(
+            "node id contains {c:?}, which is neither a hex digit nor a separator"
+        ));
+    }
+
+    let separators: Vec<char> = NODE_ID_SEPARATORS
+        .into_iter()
+        .filter(|separator| digits.contains(*separator))
+        .collect();
+
+    let (separator, groups, group_digits) = match separators[..] {
+        [] => {
+            return match digits.len() {
+                12 => Ok(decode_node_id(digits)),
+                11 => Err(String::from("node id has 11 hex digits, one short of 12")),
+                13 => Err(String::from("node id has 13 hex digits, one more than 12")),
+                length => Err(format!("node id has {length} hex digits, expected 12")),
+            };
+        }
+        [separator] if prefix.is_some() => {
+            return Err(format!(
+                "the 0x prefix is only accepted on bare hex digits, not with {separator:?} \
+                 separators"
+            ));
+        }
+        [':'] | ['-'] => (separators[0], 6, 2),
+        ['.'] => ('.', 3, 4),
+        _ => {
+            return Err(format!(
+                "node id mixes the {} separators; use one of them throughout",
+                separators
+                    .iter()
+                    .map(|separator| format!("{separator:?}"))
+                    .collect::<Vec<_>>()
+                    .join(" and ")
+            ));
+        }
+    };
+
+    let parts: Vec<&str> = value.split(separator).collect();
+    if parts.len() != groups {
+        return Err(format!(
+            "node id has {} groups separated by {separator:?}, expected {groups}",
+            parts.len()
+        ));
+    }
+
+    if let Some((index, part)) = parts
+        .iter()
+        .enumerate()
+        .find(|(_, part)| part.len() != group_digits)
+    {
+        return Err(format!(
+            "group {} ({part:?}) has {} hex digits, expected {group_digits}",
+            index + 1,
+            part.len()
+        ));
+    }
+
+    Ok(decode_node_id(&parts.concat()))
+}
+
+/// Decodes 12 hex digits into node id bytes.
+#[cfg(feature = "uuid")]
+fn decode_node_id(digits: &str) -> [u8; NODE_ID_BYTES] {
+    let mut bytes = [0; NODE_ID_BYTES];
+    hex::decode_to_slice(digits, &mut bytes).expect("12 hex digits");
+    bytes
+}
+
+/// Converts a Unix timestamp into 100-nanosecond intervals since 1582-10-15 (the Gregorian epoch).
+///
+/// Returns `None` if the instant does not fit into the 60-bit timestamp of UUID v1/v6.
+pub(crate) fn unix_to_gregorian_ticks((seconds, subsec_nanos): (u64, u32)) -> Option<u64> {
+    seconds
+        .checked_mul(TICKS_PER_SECOND)
+        .and_then(|ticks| ticks.checked_add(subsec_nanos as u64 / 100))
+        .and_then(|ticks| ticks.checked_add(UUID_TICKS_BETWEEN_EPOCHS))
+        .filter(|ticks| *ticks <= MAX_GREGORIAN_TICKS)
+}
+
+/// Returns the earliest and latest instants representable by UUID v1/v6 timestamps as dates.
+pub(crate) fn gregorian_range() -> (String, String) {
+    let to_unix = |ticks: u64| {
+        let ticks = ticks as i128 - UUID_TICKS_BETWEEN_EPOCHS as i128;
+        let seconds = ticks.div_euclid(TICKS_PER_SECOND as i128) as i64;
+        let subsec_nanos = ticks.rem_euclid(TICKS_PER_SECOND as i128) as u32 * 100;
+        format_timestamp(seconds, subsec_nanos)
+    };
+
+    (to_unix(0), to_unix(MAX_GREGORIAN_TICKS))
+}
+
+/// Formats a size in bytes with a decimal unit (like `250.0 MB`).
+pub(crate) fn format_size(bytes: u64) -> String {
+    match SIZE_UNITS.iter().rev().find(|(factor, _)| bytes >= *factor) {
+        Some((factor, unit)) => format!("{:.1} {unit}", bytes as f64 / *factor as f64),
+        None => format!("{bytes} B"),
+    }
+}
+
+/// Formats a Unix timestamp (possibly before the Unix epoch) as an RFC 3339 UTC date.
+///
+/// Fractional seconds are only included when non-zero, without trailing zeros.
+pub(crate) fn format_timestamp(seconds: i64, subsec_nanos: u32) -> String {
+    let days = seconds.div_euclid(86_400);
+    let time = seconds.rem_euclid(86_400);
+
+    // NOTE: Howard Hinnant's days-to-civil algorithm, with eras of 400 years
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    let mut date = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    );
+
+    if subsec_nanos > 0 {
+        let fraction = format!("{subsec_nanos:09}");
+        date.push('.');
+        date.push_str(fraction.trim_end_matches('0'));
+    }
+
+    date.push('Z');
+    date
+}
+
+/// Parses a charset assertion into a regex that must match the whole identifier.
+pub(crate) fn parse_charset(value: &str) -> anyhow::Result<regex::Regex> {
+    regex::Regex::new(&format!("^(?:{value})$")).map_err(|e| anyhow!("invalid regex: {e}"))
+}
+
+/// Checks that an identifier does not exceed the maximum length (in characters).
+pub(crate) fn assert_max_length(id: &str, max_length: usize) -> anyhow::Result<()> {
+    let length = id.chars().count();
+
+    if length > max_length {
+        return Err(anyhow!(
+            "identifier {id:?} is {length} characters long, exceeding --assert-max-length {max_length}"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that an identifier fully matches the charset regex.
+pub(crate) fn assert_charset(id: &str, charset: &regex::Regex) -> anyhow::Result<()> {
+    if !charset.is_match(id) {
+        let length = id.chars().count();
+        let pattern = charset.as_str();
+        let pattern = &pattern[4..pattern.len() - 2];
+
+        return Err(anyhow!(
+            "identifier {id:?} ({length} characters) does not match --assert-charset {pattern:?}"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Crockford base32 symbols followed by the five extra check symbols.
+const CROCKFORD_CHECK_SYMBOLS: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+/// Encodes a 128-bit value as 26 Crockford base32 symbols (the first one holding 3 bits),
+/// as ULIDs and TypeID suffixes are.
+pub(crate) fn encode_crockford(value: u128) -> String {
+    (0..26)
+        .rev()
+        .map(|index| CROCKFORD_CHECK_SYMBOLS[(value >> (index * 5) & 0x1f) as usize] as char)
+        .collect()
+}
+
+/// Computes the Crockford base32 check symbol for an encoded value.
+///
+/// The check symbol encodes the value modulo 37. Decoding is case-insensitive,
+/// treats `I` and `L` as `1` and `O` as `0`, and ignores hyphens, as per the spec.
+pub(crate) fn crockford_check_symbol(value: &str) -> anyhow::Result<char> {
+    let mut remainder = 0u32;
+
+    for c in value.chars().filter(|&c| c != '-') {
+        let digit = match c.to_ascii_uppercase() {
+            'O' => 0,
+            'I' | 'L' => 1,
+            c => CROCKFORD_CHECK_SYMBOLS[..32]
+                .iter()
+                .position(|&s| s as char == c)
+                .ok_or_else(|| anyhow!("invalid Crockford base32 character {c:?}"))?
+                as u32,
+        };
+
+        remainder = (remainder * 32 + digit) % 37;
+    }
+
+    Ok(CROCKFORD_CHECK_SYMBOLS[remainder as usize] as char)
+}
+
+/// Returns the MAC address of a network interface, like `uuidgen -t` uses as the node id:
+/// the first universal unicast one, by interface name.
+///
+/// Interfaces are listed from sysfs, so none are found on other platforms than Linux
+/// (there are no macOS or Windows lookups), nor in containers without any, and callers
+/// fall back to [`generate_pseudo_mac`].
+#[cfg(feature = "uuid")]
+pub(crate) fn hardware_mac() -> Option<[u8; NODE_ID_BYTES]> {
+    hardware_mac_in(std::path::Path::new(NET_INTERFACES_DIR))
+}
+
+/// Returns the first universal unicast MAC address of the interfaces in a sysfs-like
+/// directory, skipping the loopback one (all zeros) and other than 6-byte addresses.
+#[cfg(feature = "uuid")]
+fn hardware_mac_in(dir: &std::path::Path) -> Option<[u8; NODE_ID_BYTES]> {
+    let mut interfaces: Vec<_> = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    interfaces.sort();
+
+    interfaces
+        .iter()
+        .filter_map(|interface| std::fs::read_to_string(interface.join("address")).ok())
+        .filter_map(|address| node_id_bytes(address.trim()).ok())
+        .find(|mac| mac[0] & (MAC_LOCAL_BIT | MAC_MULTICAST_BIT) == 0 && *mac != [0; NODE_ID_BYTES])
+}
+
+/// Generates a pseudo-random MAC address, keeping the first three bytes of an OUI if
+/// given.
+///
+/// Without an OUI, the address is locally administered and unicast unless `universal`
+/// or `multicast` flip those bits. An OUI is kept as is, with its bits checked against
+/// the flags when parsing arguments.
+pub(crate) fn generate_pseudo_mac(
+    oui: Option<[u8; 3]>,
+    multicast: bool,
+    universal: bool,
+) -> [u8; NODE_ID_BYTES] {
     let mut rng = rand::rng();
-    let mut mac = [0u8; eui48::EUI48LEN];
+    let mut mac = [0u8; NODE_ID_BYTES];
 
     rng.fill(&mut mac);
 
+    if let Some(oui) = oui {
+        mac[..3].copy_from_slice(&oui);
+        return mac;
+    }
+
     // NOTE: Setting the locally administered bit (bit 1) marks this as a generated
     // MAC address (not from real hardware). Clearing the multicast bit (bit 0)
     // ensures it is treated as a unicast address. This follows IEEE 802 standards
     // and prevents conflicts with real network hardware MAC addresses.
-    mac[0] = (mac[0] | 0x2) & 0xFE;
+    mac[0] = (mac[0] | MAC_LOCAL_BIT) & !MAC_MULTICAST_BIT;
+
+    if multicast {
+        mac[0] |= MAC_MULTICAST_BIT;
+    }
+    if universal {
+        mac[0] &= !MAC_LOCAL_BIT;
+    }
 
-    eui48::MacAddress::new(mac)
+    mac
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_count_plain() {
+        let result = parse_count("250");
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 250);
+    }
+
+    #[test]
+    fn test_parse_count_suffixes() {
+        assert_eq!(parse_count("3k").unwrap(), 3_000);
+        assert_eq!(parse_count("2M").unwrap(), 2_000_000);
+        assert_eq!(parse_count("1g").unwrap(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_parse_count_empty() {
+        let result = parse_count("");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "count must not be empty"
+        );
+    }
+
+    #[test]
+    fn test_parse_count_suffix_only() {
+        let result = parse_count("k");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "count must be a non-negative integer with an optional k, m, or g suffix, got \"k\""
+        );
+    }
+
+    #[test]
+    fn test_parse_count_invalid() {
+        let result = parse_count("-1");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "count must be a non-negative integer with an optional k, m, or g suffix, got \"-1\""
+        );
+    }
+
+    #[test]
+    fn test_parse_count_overflow() {
+        let result = parse_count("18446744073709551615k");
+
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .root_cause()
+                .to_string()
+                .starts_with("count must not exceed")
+        );
+    }
+
     #[test]
     fn test_parse_timestamp_min() {
         let result = parse_timestamp_ns("0");
@@ -160,9 +926,13 @@ mod tests {
         );
     }
 
+    fn parse_data_bytes(value: &str) -> anyhow::Result<[u8; DATA_LENGTH_BYTES]> {
+        parse_data(value, DataPad::Right)
+    }
+
     #[test]
     fn test_parse_data_short() {
-        let result = parse_data("0011223344556677");
+        let result = parse_data_bytes("0011223344556677");
 
         assert!(result.is_ok());
         assert_eq!(
@@ -173,7 +943,7 @@ mod tests {
 
     #[test]
     fn test_parse_data_full() {
-        let result = parse_data("00112233445566778899aabbccddeeff");
+        let result = parse_data_bytes("00112233445566778899aabbccddeeff");
 
         assert!(result.is_ok());
         assert_eq!(
@@ -185,45 +955,797 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_data_invalid() {
-        let result = parse_data("gg");
+    #[cfg(feature = "uuid")]
+    fn test_parse_node_id_notations() {
+        let bytes = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab];
 
-        assert!(result.is_err());
-        assert_eq!(
-            result.unwrap_err().root_cause().to_string(),
-            "data must contain only hex characters"
-        );
+        for value in [
+            "0123456789ab",
+            "0123456789AB",
+            "0x0123456789ab",
+            "0X0123456789AB",
+            "01:23:45:67:89:ab",
+            "01-23-45-67-89-ab",
+            "0123.4567.89ab",
+            "0123.4567.89AB",
+            "01:23:45:67:89:Ab",
+            " 01:23:45:67:89:ab\n",
+            "\t0123.4567.89ab ",
+        ] {
+            assert_eq!(parse_node_id(value).unwrap().to_array(), bytes, "{value}");
+        }
     }
 
     #[test]
-    fn test_parse_data_empty() {
-        let result = parse_data("");
+    #[cfg(feature = "uuid")]
+    fn test_parse_node_id_invalid() {
+        let cases = [
+            ("", "node id is empty"),
+            ("  ", "node id is empty"),
+            ("0123456789a", "node id has 11 hex digits, one short of 12"),
+            (
+                "0123456789abc",
+                "node id has 13 hex digits, one more than 12",
+            ),
+            ("0123456789", "node id has 10 hex digits, expected 12"),
+            ("0x0123456789", "node id has 10 hex digits, expected 12"),
+            (
+                "01:23:45:67:89",
+                "node id has 5 groups separated by ':', expected 6",
+            ),
+            (
+                "01-23-45-67-89-ab-cd",
+                "node id has 7 groups separated by '-', expected 6",
+            ),
+            (
+                "01:23-45:67:89:ab",
+                "node id mixes the ':' and '-' separators; use one of them throughout",
+            ),
+            (
+                "0123.4567:89ab",
+                "node id mixes the ':' and '.' separators; use one of them throughout",
+            ),
+            (
+                "01:234:5:67:89:ab",
+                "group 2 (\"234\") has 3 hex digits, expected 2",
+            ),
+            (
+                "0123.4567.89a",
+                "group 3 (\"89a\") has 3 hex digits, expected 4",
+            ),
+            (
+                "01:23:45:67:89:ag",
+                "node id contains 'g', which is neither a hex digit nor a separator",
+            ),
+            (
+                "01 23 45 67 89 ab",
+                "node id contains ' ', which is neither a hex digit nor a separator",
+            ),
+            (
+                "0x01:23:45:67:89:ab",
+                "the 0x prefix is only accepted on bare hex digits, not with ':' separators",
+            ),
+        ];
 
-        assert!(result.is_err());
+        for (value, problem) in cases {
+            let message = parse_node_id(value).unwrap_err().root_cause().to_string();
+
+            assert_eq!(
+                message,
+                format!(
+                    "{problem} (got {} characters); accepted notations are {NODE_ID_NOTATIONS}",
+                    value.trim().len()
+                ),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_data_padding() {
+        let mut last = [0u8; DATA_LENGTH_BYTES];
+        last[15] = 0x01;
+        let mut first = [0u8; DATA_LENGTH_BYTES];
+        first[0] = 0x10;
+
+        assert_eq!(parse_data("1", DataPad::Left).unwrap(), last);
+        assert_eq!(parse_data("1", DataPad::Right).unwrap(), first);
         assert_eq!(
-            result.unwrap_err().root_cause().to_string(),
-            "data length must be between 1 and 32 characters, got 0"
+            parse_data("0011", DataPad::Left).unwrap()[14..],
+            [0x00, 0x11]
         );
     }
 
     #[test]
-    fn test_parse_data_overflow() {
-        let result = parse_data("00112233445566778899aabbccddeefff");
+    fn test_parse_data_padding_odd_length() {
+        let value = "123456789abcdef0123456789abcdef";
 
-        assert!(result.is_err());
         assert_eq!(
-            result.unwrap_err().root_cause().to_string(),
-            "data length must be between 1 and 32 characters, got 33"
+            parse_data(value, DataPad::Left).unwrap(),
+            [
+                0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab,
+                0xcd, 0xef
+            ]
+        );
+        assert_eq!(
+            parse_data(value, DataPad::Right).unwrap(),
+            [
+                0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc,
+                0xde, 0xf0
+            ]
         );
     }
 
     #[test]
-    fn test_generate_pseudo_mac() {
-        let result = generate_pseudo_mac();
+    fn test_parse_data_prefixes_and_separators() {
+        let bytes = parse_data_bytes("deadbeef").unwrap();
 
-        assert!(result.is_local());
-        assert!(!result.is_multicast());
-        assert!(!result.is_broadcast());
-        assert!(!result.is_nil());
+        for value in [
+            "0xdeadbeef",
+            "0XDEADBEEF",
+            "0xDE 0xAD 0xBE 0xEF",
+            "de:ad:be:ef",
+            "de-ad-be-ef",
+            "dead_beef",
+            "de ad  be ef",
+            "0xde:ad-be_ef",
+        ] {
+            assert_eq!(parse_data_bytes(value).unwrap(), bytes, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_parse_data_separators_invalid() {
+        for (value, message) in [
+            (
+                "de:a:dbe:ef",
+                "data separators must fall between byte pairs, got \"a\" between them",
+            ),
+            ("de.ad.be.ef", "data must contain only hex characters"),
+            (
+                "0x",
+                "data length must be between 1 and 32 characters, got 0",
+            ),
+            (
+                "00:11:22:33:44:55:66:77:88:99:aa:bb:cc:dd:ee:ff:00",
+                "data length must be between 1 and 32 characters, got 34",
+            ),
+        ] {
+            assert_eq!(
+                parse_data_bytes(value)
+                    .unwrap_err()
+                    .root_cause()
+                    .to_string(),
+                message,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_data_mask() {
+        let mut prefix = [0u8; DATA_LENGTH_BYTES];
+        prefix[..6].fill(0xff);
+        prefix[6] = 0xf0;
+
+        assert_eq!(data_mask("0123456789abc", DataPad::Right), prefix);
+        assert_eq!(
+            data_mask("0x01:23", DataPad::Left)[13..],
+            [0x00, 0xff, 0xff]
+        );
+        assert_eq!(data_mask(&"0".repeat(32), DataPad::Right), [0xff; 16]);
+    }
+
+    #[test]
+    fn test_parse_data_hex() {
+        assert_eq!(parse_data_hex("00aB").unwrap(), "00aB");
+        assert!(parse_data_hex("").is_err());
+        assert!(parse_data_hex("0g").is_err());
+    }
+
+    #[test]
+    fn test_parse_data_invalid() {
+        let result = parse_data_bytes("gg");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "data must contain only hex characters"
+        );
+    }
+
+    #[test]
+    fn test_parse_data_empty() {
+        let result = parse_data_bytes("");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "data length must be between 1 and 32 characters, got 0"
+        );
+    }
+
+    #[test]
+    fn test_parse_data_overflow() {
+        let result = parse_data_bytes("00112233445566778899aabbccddeefff");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "data length must be between 1 and 32 characters, got 33"
+        );
+    }
+
+    #[test]
+    fn test_parse_name_hex() {
+        assert_eq!(
+            parse_name_hex("00112233AABBccdd").unwrap(),
+            [0x00, 0x11, 0x22, 0x33, 0xaa, 0xbb, 0xcc, 0xdd]
+        );
+        // Longer than the 16 bytes of `--data`, and without padding
+        assert_eq!(parse_name_hex(&"ab".repeat(40)).unwrap(), [0xab; 40]);
+        assert!(parse_name_hex("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_name_hex_invalid() {
+        for (value, message) in [
+            (
+                "abc",
+                "name length must be an even number of hex characters, got 3",
+            ),
+            ("0x12", "name must contain only hex characters"),
+            ("12 34", "name must contain only hex characters"),
+        ] {
+            assert_eq!(
+                parse_name_hex(value).unwrap_err().root_cause().to_string(),
+                message,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_rate() {
+        assert_eq!(parse_rate("50000/s").unwrap(), 50_000.0);
+        assert_eq!(parse_rate("50k/s").unwrap(), 50_000.0);
+        assert_eq!(parse_rate("50/ms").unwrap(), 50_000.0);
+        assert_eq!(parse_rate("3m/min").unwrap(), 50_000.0);
+        assert_eq!(parse_rate("36/h").unwrap(), 0.01);
+        assert_eq!(parse_rate("500").unwrap(), 500.0);
+    }
+
+    #[test]
+    fn test_parse_rate_invalid() {
+        let result = parse_rate("50/day");
+
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .root_cause()
+                .to_string()
+                .starts_with("unknown rate unit")
+        );
+
+        assert!(parse_rate("fast/s").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("250ms").unwrap(), 250);
+        assert_eq!(parse_duration("30s").unwrap(), 30_000);
+        assert_eq!(parse_duration("30").unwrap(), 30_000);
+        assert_eq!(parse_duration("5m").unwrap(), 300_000);
+        assert_eq!(parse_duration("2h").unwrap(), 7_200_000);
+        assert_eq!(parse_duration("1d").unwrap(), 86_400_000);
+    }
+
+    #[test]
+    fn test_parse_duration_invalid() {
+        let result = parse_duration("5min");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "unknown duration unit \"min\" (expected ms, s, m, h, or d)"
+        );
+
+        assert!(parse_duration("m").is_err());
+        assert!(parse_duration("-5m").is_err());
+        assert!(parse_duration("99999999999999999999d").is_err());
+    }
+
+    #[test]
+    fn test_parse_probability() {
+        assert_eq!(parse_probability("1e-6").unwrap(), 1e-6);
+        assert_eq!(parse_probability("0.5").unwrap(), 0.5);
+
+        assert!(parse_probability("0").is_err());
+        assert!(parse_probability("1").is_err());
+        assert!(parse_probability("NaN").is_err());
+        assert!(parse_probability("likely").is_err());
+    }
+
+    #[test]
+    fn test_parse_alphabet() {
+        assert_eq!(parse_alphabet("01").unwrap(), "01");
+        assert_eq!(parse_alphabet("αβγ").unwrap(), "αβγ");
+        assert!(parse_alphabet(&"x".repeat(256)).is_err());
+    }
+
+    #[test]
+    fn test_parse_alphabet_too_short() {
+        let result = parse_alphabet("a");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "alphabet must contain between 2 and 255 symbols, got 1"
+        );
+    }
+
+    #[test]
+    fn test_parse_alphabet_duplicates() {
+        let result = parse_alphabet("abcb");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "alphabet must not contain duplicate symbols, got 'b' twice"
+        );
+    }
+
+    #[test]
+    fn test_parse_sqids_alphabet() {
+        assert_eq!(parse_sqids_alphabet("abc").unwrap(), "abc");
+        assert!(parse_sqids_alphabet("aab").is_err());
+    }
+
+    #[test]
+    fn test_parse_sqids_alphabet_too_short() {
+        let result = parse_sqids_alphabet("ab");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "alphabet must contain at least 3 symbols, got 2"
+        );
+    }
+
+    #[test]
+    fn test_parse_sqids_alphabet_multibyte() {
+        let result = parse_sqids_alphabet("abcë");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "alphabet must not contain multibyte symbols, got 'ë'"
+        );
+    }
+
+    #[test]
+    fn test_parse_hashids_alphabet() {
+        assert_eq!(
+            parse_hashids_alphabet("0123456789abcdef").unwrap(),
+            "0123456789abcdef"
+        );
+    }
+
+    #[test]
+    fn test_parse_hashids_alphabet_invalid() {
+        for (value, message) in [
+            (
+                "0123456789abcde",
+                "alphabet must contain at least 16 symbols, got 15",
+            ),
+            ("0123456789abcdef ", "alphabet must not contain spaces"),
+            (
+                "0123456789abcdefa",
+                "alphabet must not contain duplicate symbols, got 'a' twice",
+            ),
+        ] {
+            assert_eq!(
+                parse_hashids_alphabet(value).unwrap_err().to_string(),
+                message,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_probe_command() {
+        assert_eq!(
+            parse_probe_command("redis-cli exists {}").unwrap(),
+            "redis-cli exists {}"
+        );
+
+        let result = parse_probe_command("redis-cli exists");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "command must contain a {} placeholder for the identifier"
+        );
+    }
+
+    #[test]
+    fn test_parse_typeid_prefix() {
+        assert_eq!(parse_typeid_prefix("user").unwrap(), "user");
+        assert_eq!(parse_typeid_prefix("api_key").unwrap(), "api_key");
+        assert_eq!(parse_typeid_prefix("").unwrap(), "");
+        assert!(parse_typeid_prefix(&"x".repeat(63)).is_ok());
+    }
+
+    #[test]
+    fn test_parse_typeid_prefix_invalid_symbol() {
+        let result = parse_typeid_prefix("User");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "prefix must contain only lowercase ASCII letters and underscores, got 'U'"
+        );
+        assert!(parse_typeid_prefix("order-2").is_err());
+    }
+
+    #[test]
+    fn test_parse_typeid_prefix_too_long() {
+        let result = parse_typeid_prefix(&"x".repeat(64));
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "prefix must be at most 63 characters long, got 64"
+        );
+    }
+
+    #[test]
+    fn test_parse_apikey_prefix() {
+        assert_eq!(parse_apikey_prefix("sk_live").unwrap(), "sk_live");
+        assert_eq!(parse_apikey_prefix("PK2").unwrap(), "PK2");
+    }
+
+    #[test]
+    fn test_parse_apikey_prefix_invalid() {
+        let result = parse_apikey_prefix("sk-live");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "prefix must contain only ASCII letters, digits, and underscores, got '-'"
+        );
+        assert!(parse_apikey_prefix("").is_err());
+        assert!(parse_apikey_prefix("sk_").is_err());
+    }
+
+    #[test]
+    fn test_parse_proquint_bits() {
+        assert_eq!(parse_proquint_bits("16").unwrap(), 16);
+        assert_eq!(parse_proquint_bits("8192").unwrap(), 8192);
+    }
+
+    #[test]
+    fn test_parse_proquint_bits_invalid() {
+        let result = parse_proquint_bits("24");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "bits must be a positive multiple of 16, got 24"
+        );
+        assert!(parse_proquint_bits("0").is_err());
+        assert!(parse_proquint_bits("8208").is_err());
+        assert!(parse_proquint_bits("-16").is_err());
+    }
+
+    #[test]
+    fn test_parse_seq_step() {
+        assert_eq!(parse_seq_step("1").unwrap(), 1);
+        assert_eq!(parse_seq_step("18446744073709551615").unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_parse_seq_step_invalid() {
+        let result = parse_seq_step("-5");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "step must be positive, got -5"
+        );
+        assert!(parse_seq_step("0").is_err());
+        assert!(parse_seq_step("1.5").is_err());
+        assert!(parse_seq_step("18446744073709551616").is_err());
+    }
+
+    #[test]
+    fn test_parse_proquint_hex() {
+        assert_eq!(parse_proquint_hex("7F000001").unwrap(), "7f000001");
+        assert!(parse_proquint_hex("7f00").is_ok());
+    }
+
+    #[test]
+    fn test_parse_proquint_hex_invalid() {
+        let result = parse_proquint_hex("7f0000");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "data length must be a multiple of 4 characters between 4 and 2048, got 6"
+        );
+        assert!(parse_proquint_hex("").is_err());
+        assert!(parse_proquint_hex("7g00").is_err());
+    }
+
+    #[test]
+    fn test_parse_typeid_prefix_edge_underscores() {
+        for prefix in ["_user", "user_", "_"] {
+            let result = parse_typeid_prefix(prefix);
+
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().root_cause().to_string(),
+                "prefix must not start or end with an underscore"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unix_to_gregorian_ticks() {
+        assert_eq!(
+            unix_to_gregorian_ticks((0, 0)),
+            Some(UUID_TICKS_BETWEEN_EPOCHS)
+        );
+        assert_eq!(
+            unix_to_gregorian_ticks((1234567890, 0)),
+            Some(UUID_TICKS_BETWEEN_EPOCHS + 12_345_678_900_000_000)
+        );
+    }
+
+    #[test]
+    fn test_unix_to_gregorian_ticks_boundary() {
+        // The maximum representable instant succeeds
+        assert_eq!(
+            unix_to_gregorian_ticks((103_072_857_660, 684_697_500)),
+            Some(MAX_GREGORIAN_TICKS)
+        );
+        assert_eq!(
+            unix_to_gregorian_ticks((103_072_857_660, 684_697_599)),
+            Some(MAX_GREGORIAN_TICKS)
+        );
+
+        // One tick beyond fails
+        assert_eq!(
+            unix_to_gregorian_ticks((103_072_857_660, 684_697_600)),
+            None
+        );
+        assert_eq!(unix_to_gregorian_ticks((u64::MAX, MAX_NANOSECONDS)), None);
+    }
+
+    #[test]
+    fn test_gregorian_range() {
+        assert_eq!(
+            gregorian_range(),
+            (
+                String::from("1582-10-15T00:00:00Z"),
+                String::from("5236-03-31T21:21:00.6846975Z")
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(999), "999 B");
+        assert_eq!(format_size(1_000), "1.0 kB");
+        assert_eq!(format_size(250_000_025), "250.0 MB");
+        assert_eq!(format_size(3_700_000_000_000), "3.7 TB");
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0, 0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(1234567890, 0), "2009-02-13T23:31:30Z");
+        assert_eq!(
+            format_timestamp(1709251199, 500_000_000),
+            "2024-02-29T23:59:59.5Z"
+        );
+        assert_eq!(
+            format_timestamp(-1, 999_999_999),
+            "1969-12-31T23:59:59.999999999Z"
+        );
+        assert_eq!(format_timestamp(-12_219_292_800, 0), "1582-10-15T00:00:00Z");
+    }
+
+    #[test]
+    fn test_parse_charset_anchored() {
+        let result = parse_charset("[0-9a-f]+");
+
+        assert!(result.is_ok());
+
+        let charset = result.unwrap();
+        assert!(charset.is_match("abc123"));
+        assert!(!charset.is_match("abc-123"));
+    }
+
+    #[test]
+    fn test_parse_charset_invalid() {
+        let result = parse_charset("[0-9");
+
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .root_cause()
+                .to_string()
+                .starts_with("invalid regex")
+        );
+    }
+
+    #[test]
+    fn test_assert_max_length() {
+        assert!(assert_max_length("01ETXKWW00", 10).is_ok());
+
+        let result = assert_max_length("01ETXKWW00", 9);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "identifier \"01ETXKWW00\" is 10 characters long, exceeding --assert-max-length 9"
+        );
+    }
+
+    #[test]
+    fn test_assert_charset() {
+        let charset = parse_charset("[0-9A-Z]+").unwrap();
+
+        assert!(assert_charset("01ETXKWW00", &charset).is_ok());
+
+        let result = assert_charset("01etxkww00", &charset);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "identifier \"01etxkww00\" (10 characters) does not match --assert-charset \"[0-9A-Z]+\""
+        );
+    }
+
+    #[test]
+    fn test_encode_crockford() {
+        assert_eq!(encode_crockford(0), "00000000000000000000000000");
+        assert_eq!(encode_crockford(u128::MAX), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+        assert_eq!(
+            encode_crockford(0x0156_3e3a_b5d3_d676_4c61_efb9_9302_bd5b),
+            "01ARZ3NDEKTSV4RRFFQ69G5FAV"
+        );
+    }
+
+    #[test]
+    fn test_crockford_check_symbol_small_values() {
+        assert_eq!(crockford_check_symbol("0").unwrap(), '0');
+        assert_eq!(crockford_check_symbol("Z").unwrap(), 'Z');
+        assert_eq!(crockford_check_symbol("10").unwrap(), '*');
+        assert_eq!(crockford_check_symbol("11").unwrap(), '~');
+        assert_eq!(crockford_check_symbol("12").unwrap(), '$');
+        assert_eq!(crockford_check_symbol("13").unwrap(), '=');
+        assert_eq!(crockford_check_symbol("14").unwrap(), 'U');
+        assert_eq!(crockford_check_symbol("15").unwrap(), '0');
+    }
+
+    #[test]
+    fn test_crockford_check_symbol_ulid() {
+        // 01ARZ3NDEKTSV4RRFFQ69G5FAV = 1777027686520646174104517696511196507 ≡ 34 (mod 37)
+        assert_eq!(
+            crockford_check_symbol("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap(),
+            '$'
+        );
+    }
+
+    #[test]
+    fn test_crockford_check_symbol_aliases() {
+        assert_eq!(
+            crockford_check_symbol("o1arz3ndektsv4rrffq69g5fav").unwrap(),
+            crockford_check_symbol("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap()
+        );
+        assert_eq!(
+            crockford_check_symbol("1L-i").unwrap(),
+            crockford_check_symbol("111").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_crockford_check_symbol_detects_single_substitution() {
+        let symbol = crockford_check_symbol("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+
+        assert_ne!(
+            crockford_check_symbol("01ARZ3NDEKTSV4RRFFQ69G5FAW").unwrap(),
+            symbol
+        );
+        assert_ne!(
+            crockford_check_symbol("11ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap(),
+            symbol
+        );
+    }
+
+    #[test]
+    fn test_crockford_check_symbol_invalid() {
+        let result = crockford_check_symbol("01U");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "invalid Crockford base32 character 'U'"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_generate_pseudo_mac() {
+        let result = eui48::MacAddress::new(generate_pseudo_mac(None, false, false));
+
+        assert!(result.is_local());
+        assert!(!result.is_multicast());
+        assert!(!result.is_broadcast());
+        assert!(!result.is_nil());
+    }
+
+    #[test]
+    fn test_generate_pseudo_mac_flags() {
+        for (multicast, universal) in [(false, false), (true, false), (false, true), (true, true)] {
+            let result = generate_pseudo_mac(None, multicast, universal);
+
+            assert_eq!(result[0] & MAC_MULTICAST_BIT != 0, multicast);
+            assert_eq!(result[0] & MAC_LOCAL_BIT != 0, !universal);
+        }
+    }
+
+    #[test]
+    fn test_generate_pseudo_mac_oui() {
+        let result = generate_pseudo_mac(Some([0x00, 0x16, 0x3e]), false, true);
+
+        assert_eq!(result[..3], [0x00, 0x16, 0x3e]);
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_hardware_mac_in() {
+        let dir = std::env::temp_dir().join(format!("spwd-net-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        for (interface, address) in [
+            ("wlan0", Some("00:16:3e:00:00:01\n")),
+            ("lo", Some("00:00:00:00:00:00\n")),
+            ("eth1", Some("00:1b:21:3a:4f:5c\n")),
+            ("eth0", Some("02:42:ac:11:00:02\n")),
+            ("br0", Some("01:00:5e:00:00:01\n")),
+            ("bond0", None),
+            ("docker0", Some("not a mac\n")),
+            ("dummy0", Some("00:11:22:33:44:55:66\n")),
+        ] {
+            std::fs::create_dir_all(dir.join(interface)).unwrap();
+            if let Some(address) = address {
+                std::fs::write(dir.join(interface).join("address"), address).unwrap();
+            }
+        }
+
+        // Skipping the loopback, locally administered, multicast, and malformed addresses,
+        // and picking the first of the rest by name
+        assert_eq!(
+            hardware_mac_in(&dir),
+            Some([0x00, 0x1b, 0x21, 0x3a, 0x4f, 0x5c])
+        );
+
+        std::fs::remove_dir_all(dir.join("eth1")).unwrap();
+        assert_eq!(
+            hardware_mac_in(&dir),
+            Some([0x00, 0x16, 0x3e, 0x00, 0x00, 0x01])
+        );
+
+        std::fs::remove_dir_all(dir.join("wlan0")).unwrap();
+        assert_eq!(hardware_mac_in(&dir), None);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(hardware_mac_in(&dir), None);
     }
 }
@@ -0,0 +1,234 @@
+//! Validation of existing identifiers.
+//!
+//! Identifiers are recognized by their format, and checked line by line, so a batch
+//! from stdin reports every bad identifier rather than stopping at the first one. Each
+//! identifier is echoed with its result (`ok`, or the reason it failed) as a
+//! tab-separated column.
+//!
+//! # Formats
+//!
+//! - **UUID**: Any version, in the forms the `uuid` crate parses (hyphenated, simple,
+//!   braced, or URN)
+//! - **ULID**: 26 Crockford base32 symbols, or 27 with a check symbol (as printed by
+//!   `ulid --check`), which must match before the rest is checked as a ULID
+//! - **ObjectId**: 24 hex characters
+//!
+//! # Future Timestamps
+//!
+//! Ingest pipelines treat identifiers minted in the future as suspicious (a skewed clock,
+//! or a forged identifier). With `--no-future`, the timestamp embedded in ULIDs,
+//! ObjectIds, and version 1, 6, and 7 UUIDs must not be later than the current time plus
+//! an allowance for clock skew. Identifiers without a timestamp pass the check.
+//!
+//! # Exit Codes
+//!
+//! The command exits with 0 when every identifier is valid, and with 1 when any is not
+//! (after reporting all of them).
+
+use std::io::Write;
+
+use crate::clock;
+use crate::generators::{apikey, crockford};
+use crate::input;
+use crate::utils;
+
+/// Length of an ObjectId in hex characters.
+pub(crate) const OBJECTID_LENGTH: usize = 24;
+
+/// Length of a ULID in Crockford base32 symbols.
+#[cfg(feature = "ulid")]
+pub(crate) const ULID_LENGTH: usize = 26;
+
+/// Length of a ULID followed by a Crockford check symbol.
+#[cfg(feature = "ulid")]
+const ULID_CHECK_LENGTH: usize = ULID_LENGTH + 1;
+
+/// Exit code of runs with at least one invalid identifier.
+pub(crate) const INVALID_EXIT_CODE: i32 = 1;
+
+/// Options of a validation run.
+pub(crate) struct Options {
+    /// Reject identifiers with timestamps past the current time and the skew.
+    pub(crate) no_future: bool,
+    /// Allowance for clock skew, in milliseconds.
+    pub(crate) skew: u64,
+    /// Check API keys by their checksum instead of recognizing the format.
+    pub(crate) apikey: bool,
+    /// Check Crockford base32 codes by their check symbol instead of recognizing the format.
+    pub(crate) crockford: bool,
+}
+
+/// Decodes the embedded timestamp of an identifier (in Unix milliseconds), failing if
+/// the format is not recognized.
+fn timestamp_ms(value: &str) -> Result<Option<u64>, String> {
+    if value.len() == OBJECTID_LENGTH && value.bytes().all(|c| c.is_ascii_hexdigit()) {
+        let seconds = u32::from_str_radix(&value[..8], 16).expect("hex digits");
+        return Ok(Some(u64::from(seconds) * 1000));
+    }
+
+    #[cfg(feature = "ulid")]
+    if value.len() == ULID_CHECK_LENGTH {
+        crockford::verify(value).map_err(|e| format!("invalid ULID: {e}"))?;
+
+        // A matching check symbol leaves the other symbols ASCII
+        return timestamp_ms(&value[..ULID_LENGTH]);
+    }
+
+    #[cfg(feature = "ulid")]
+    if value.len() == ULID_LENGTH {
+        // The first symbol only holds 3 bits, which the `ulid` crate silently drops
+        if value.as_bytes()[0] > b'7' {
+            return Err(String::from("invalid ULID: value overflows 128 bits"));
+        }
+
+        return ulid::Ulid::from_string(value)
+            .map(|ulid| Some(ulid.timestamp_ms()))
+            .map_err(|e| format!("invalid ULID: {e}"));
+    }
+
+    let uuid = uuid::Uuid::parse_str(value)
+        .map_err(|_| String::from("unrecognized identifier: not a UUID, ULID, or ObjectId"))?;
+
+    Ok(uuid.get_timestamp().map(|timestamp| {
+        let (seconds, subsec_nanos) = timestamp.to_unix();
+        seconds * 1000 + u64::from(subsec_nanos) / 1_000_000
+    }))
+}
+
+/// Checks an identifier against the options, returning the reason it is invalid.
+pub(crate) fn check(value: &str, options: &Options, now_ms: u64) -> Result<(), String> {
+    if options.apikey {
+        return apikey::verify(value);
+    }
+
+    if options.crockford {
+        return crockford::verify(value);
+    }
+
+    let timestamp = timestamp_ms(value)?;
+
+    if let (true, Some(timestamp)) = (options.no_future, timestamp)
+        && timestamp > now_ms.saturating_add(options.skew)
+    {
+        return Err(format!(
+            "timestamp {} is in the future",
+            utils::format_timestamp(
+                (timestamp / 1000) as i64,
+                (timestamp % 1000) as u32 * 1_000_000
+            )
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the given identifiers, or the ones from stdin (one per line) if none are
+/// given, returning whether all of them are valid.
+pub(crate) fn run(
+    ids: &[String],
+    options: &Options,
+    stdout: &mut impl Write,
+) -> anyhow::Result<bool> {
+    let (seconds, subsec_nanos) = clock::now();
+    let now_ms = seconds * 1000 + u64::from(subsec_nanos) / 1_000_000;
+
+    let mut valid = true;
+    input::for_each(ids, |value| {
+        match check(value, options, now_ms) {
+            Ok(()) => writeln!(stdout, "{value}\tok")?,
+            Err(reason) => {
+                valid = false;
+                writeln!(stdout, "{value}\t{reason}")?;
+            }
+        }
+
+        Ok(())
+    })?;
+
+    Ok(valid)
+}
+
+#[cfg(all(test, feature = "ulid"))]
+mod tests {
+    use super::*;
+
+    /// 2023-11-14T22:13:20Z, in milliseconds.
+    const NOW_MS: u64 = 1_700_000_000_000;
+
+    const NO_FUTURE: Options = Options {
+        no_future: true,
+        skew: 300_000,
+        apikey: false,
+        crockford: false,
+    };
+
+    #[test]
+    fn test_timestamp_ms() {
+        assert_eq!(timestamp_ms("6553f100aaaaaaaaaaaaaaaa"), Ok(Some(NOW_MS)));
+        assert_eq!(timestamp_ms("01HF7YAT00ZZZZZZZZZZZZZZZZ"), Ok(Some(NOW_MS)));
+        assert_eq!(
+            timestamp_ms("018bcfe5-6800-7000-8000-000000000000"),
+            Ok(Some(NOW_MS))
+        );
+        assert_eq!(
+            timestamp_ms("9e4a5f33-f01c-47ee-8527-18ec1f0151d5"),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_timestamp_ms_with_check_symbol() {
+        assert_eq!(
+            timestamp_ms("01HF7YAT007TYXDGV4SYN1AKV2V"),
+            Ok(Some(NOW_MS))
+        );
+        assert_eq!(
+            timestamp_ms("01HF7YAT007TYXDGV4SYN1AKV2W"),
+            Err(String::from(
+                "invalid ULID: check symbol mismatch: expected 'V', got 'W'"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_timestamp_ms_unrecognized() {
+        assert_eq!(
+            timestamp_ms("not-an-id"),
+            Err(String::from(
+                "unrecognized identifier: not a UUID, ULID, or ObjectId"
+            ))
+        );
+        assert!(timestamp_ms("8ZZZZZZZZZZZZZZZZZZZZZZZZZ").is_err());
+    }
+
+    #[test]
+    fn test_check_future() {
+        // An hour ahead of the clock
+        let result = check("01HF81RNM0ZZZZZZZZZZZZZZZZ", &NO_FUTURE, NOW_MS);
+
+        assert_eq!(
+            result,
+            Err(String::from(
+                "timestamp 2023-11-14T23:13:20Z is in the future"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_check_within_skew() {
+        // A minute ahead of the clock
+        assert!(check("01HF7YCMK0ZZZZZZZZZZZZZZZZ", &NO_FUTURE, NOW_MS).is_ok());
+    }
+
+    #[test]
+    fn test_check_future_allowed() {
+        let options = Options {
+            no_future: false,
+            skew: 0,
+            apikey: false,
+            crockford: false,
+        };
+
+        assert!(check("01HF81RNM0ZZZZZZZZZZZZZZZZ", &options, NOW_MS).is_ok());
+    }
+}
@@ -0,0 +1,132 @@
+//! Z85 encoding of 128-bit identifiers.
+//!
+//! Z85 is the base85 variant of ZeroMQ (RFC 32/Z85), whose alphabet of 85 printable
+//! characters leaves out quotes, the backslash, and the comma, so encoded values embed
+//! in configuration and source files as they are. With `--format z85`, UUIDs and ULIDs
+//! are printed as the Z85 encoding of their 16 bytes, in exactly 20 characters.
+//!
+//! # Encoding
+//!
+//! Every 4 bytes, read as a big-endian 32-bit value, become 5 characters (most
+//! significant first). Z85 has no padding: the data must be a multiple of 4 bytes long,
+//! as identifiers of 16 bytes are.
+
+use anyhow::anyhow;
+
+/// The Z85 alphabet, in the order of the digit values.
+const ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Number of bytes in a group.
+const GROUP_BYTES: usize = 4;
+
+/// Number of characters in an encoded group.
+const GROUP_CHARS: usize = 5;
+
+/// Encodes bytes in Z85; their number must be a multiple of 4.
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    debug_assert_eq!(
+        bytes.len() % GROUP_BYTES,
+        0,
+        "Z85 encodes groups of 4 bytes"
+    );
+
+    let mut encoded = String::with_capacity(bytes.len() / GROUP_BYTES * GROUP_CHARS);
+
+    for group in bytes.chunks_exact(GROUP_BYTES) {
+        let value = u32::from_be_bytes(group.try_into().expect("groups have 4 bytes"));
+
+        for index in (0..GROUP_CHARS as u32).rev() {
+            encoded.push(ALPHABET[(value / 85u32.pow(index) % 85) as usize] as char);
+        }
+    }
+
+    encoded
+}
+
+/// Decodes a Z85 string of a multiple of 5 characters.
+pub(crate) fn decode(encoded: &str) -> anyhow::Result<Vec<u8>> {
+    if !encoded.len().is_multiple_of(GROUP_CHARS) {
+        return Err(anyhow!(
+            "invalid Z85 '{encoded}': {} characters is not a multiple of 5",
+            encoded.len()
+        ));
+    }
+
+    let mut bytes = Vec::with_capacity(encoded.len() / GROUP_CHARS * GROUP_BYTES);
+
+    for group in encoded.as_bytes().chunks_exact(GROUP_CHARS) {
+        let value = group.iter().try_fold(0u64, |value, symbol| {
+            let digit = ALPHABET
+                .iter()
+                .position(|candidate| candidate == symbol)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "invalid Z85 '{encoded}': unexpected character {:?}",
+                        *symbol as char
+                    )
+                })?;
+            Ok::<_, anyhow::Error>(value * 85 + digit as u64)
+        })?;
+
+        let value = u32::try_from(value)
+            .map_err(|_| anyhow!("invalid Z85 '{encoded}': a group exceeds 32 bits"))?;
+        bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_reference_vector() {
+        // The example of the Z85 specification
+        assert_eq!(
+            encode(&[0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b]),
+            "HelloWorld"
+        );
+    }
+
+    #[test]
+    fn test_decode_reference_vector() {
+        assert_eq!(
+            decode("HelloWorld").unwrap(),
+            [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b]
+        );
+    }
+
+    #[test]
+    fn test_encode_bounds() {
+        assert_eq!(encode(&[0; 16]), "00000000000000000000");
+        assert_eq!(encode(&[0xff; 16]), "%nSc0%nSc0%nSc0%nSc0");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let bytes: Vec<u8> = (0..16).map(|byte| byte * 17).collect();
+
+        let encoded = encode(&bytes);
+
+        assert_eq!(encoded.len(), 20);
+        assert_eq!(decode(&encoded).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert_eq!(
+            decode("Hell").unwrap_err().to_string(),
+            "invalid Z85 'Hell': 4 characters is not a multiple of 5"
+        );
+        assert_eq!(
+            decode("Hell\"").unwrap_err().to_string(),
+            "invalid Z85 'Hell\"': unexpected character '\"'"
+        );
+        assert_eq!(
+            decode("#####").unwrap_err().to_string(),
+            "invalid Z85 '#####': a group exceeds 32 bits"
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Decodes a base58 string (Bitcoin alphabet) into bytes.
+#[cfg(any(feature = "uuid", feature = "ulid"))]
+fn decode_base58(value: &str) -> Vec<u8> {
+    const ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    let zeros = value.bytes().take_while(|c| *c == b'1').count();
+    let mut bytes: Vec<u8> = Vec::new();
+
+    for c in value[zeros..].chars() {
+        let mut carry = ALPHABET.find(c).unwrap() as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    bytes.extend(std::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    bytes
+}
+
+/// Runs the binary with the given arguments, returning the tab-separated columns.
+#[cfg(any(feature = "uuid", feature = "ulid", feature = "objectid"))]
+fn rows(args: &[&str]) -> Vec<Vec<String>> {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| line.split('\t').map(str::to_owned).collect())
+        .collect()
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_also_format_uuid_base58() {
+    let rows = rows(&["-n", "50", "--also-format", "base58", "uuid", "-v", "7"]);
+
+    assert_eq!(rows.len(), 50);
+    for row in rows {
+        assert_eq!(row.len(), 2);
+
+        let uuid = uuid::Uuid::parse_str(&row[0]).unwrap();
+        assert_eq!(decode_base58(&row[1]), uuid.as_bytes());
+    }
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_also_format_ulid_base58() {
+    let rows = rows(&["-n", "50", "--also-format", "base58", "ulid"]);
+
+    assert_eq!(rows.len(), 50);
+    for row in rows {
+        assert_eq!(row.len(), 2);
+
+        let ulid = ulid::Ulid::from_string(&row[0]).unwrap();
+        assert_eq!(decode_base58(&row[1]), ulid.to_bytes());
+    }
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_also_format_repeated() {
+    let rows = rows(&[
+        "--also-format",
+        "base58",
+        "--also-format",
+        "hex",
+        "uuid",
+        "--max",
+    ]);
+
+    assert_eq!(
+        rows,
+        [[
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "YcVfxkQb6JRzqk5kF2tNLv",
+            "ffffffffffffffffffffffffffffffff",
+        ]]
+    );
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_also_format_guid_bytes() {
+    // The bytes of `new Guid("cfbff0d1-9375-5685-968c-48ce8b15ae17").ToByteArray()`
+    let rows = rows(&[
+        "--also-format",
+        "hex",
+        "uuid",
+        "-v",
+        "5",
+        "--namespace",
+        "dns",
+        "--name",
+        "example.com",
+        "--guid",
+    ]);
+
+    assert_eq!(
+        rows,
+        [[
+            "{CFBFF0D1-9375-5685-968C-48CE8B15AE17}",
+            "d1f0bfcf75938556968c48ce8b15ae17",
+        ]]
+    );
+}
+
+#[test]
+#[cfg(feature = "objectid")]
+fn test_also_format_oid_hex() {
+    let rows = rows(&["--also-format", "hex", "oid"]);
+
+    assert_eq!(rows[0][0], rows[0][1]);
+}
+
+#[test]
+fn test_also_format_unsupported_command() {
+    cargo_bin_cmd!()
+        .args(["--also-format", "base58", "nanoid"])
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "the argument '--also-format <FORMAT>' cannot be used with 'nanoid'",
+        ));
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_also_format_short_uuid() {
+    cargo_bin_cmd!()
+        .args(["--also-format", "base58", "uuid", "--short"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--also-format <FORMAT>' cannot be used with '--short'",
+        ));
+}
+
+#[test]
+fn test_also_format_unknown_format() {
+    cargo_bin_cmd!()
+        .args(["--also-format", "base32", "uuid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'base32'"));
+}
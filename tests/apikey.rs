@@ -0,0 +1,107 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Runs the apikey command with the given arguments, returning the output lines.
+fn apikeys(args: &[&str]) -> Vec<String> {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn test_apikey_default() {
+    let lines = apikeys(&["apikey"]);
+
+    // 24 random bytes in 33 base62 symbols, and a 6-symbol checksum
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].len(), 39);
+    assert!(lines[0].bytes().all(|c| c.is_ascii_alphanumeric()));
+}
+
+#[test]
+fn test_apikey_prefix() {
+    let lines = apikeys(&["-n", "10", "apikey", "--prefix", "sk_live"]);
+
+    assert_eq!(lines.len(), 10);
+    for line in lines {
+        let body = line.strip_prefix("sk_live_").unwrap();
+
+        assert_eq!(body.len(), 39, "{line}");
+        assert!(body.bytes().all(|c| c.is_ascii_alphanumeric()), "{line}");
+    }
+}
+
+#[test]
+fn test_apikey_without_checksum() {
+    let lines = apikeys(&["apikey", "--bytes", "16", "--checksum", "none"]);
+
+    assert_eq!(lines[0].len(), 22);
+}
+
+#[test]
+fn test_multiple_apikeys() {
+    let mut lines = apikeys(&["-n", "10", "apikey"]);
+
+    lines.sort_unstable();
+    lines.dedup();
+    assert_eq!(lines.len(), 10);
+}
+
+#[test]
+fn test_apikey_bytes_out_of_range() {
+    for bytes in ["15", "65"] {
+        cargo_bin_cmd!()
+            .args(["apikey", "--bytes", bytes])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("is not in 16..=64"));
+    }
+}
+
+#[test]
+fn test_apikey_invalid_prefix() {
+    cargo_bin_cmd!()
+        .args(["apikey", "--prefix", "sk-live"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "prefix must contain only ASCII letters, digits, and underscores",
+        ));
+}
+
+#[test]
+fn test_validate_apikeys() {
+    let lines = apikeys(&["-n", "2", "apikey", "--prefix", "pk_test"]);
+
+    // Mistyping the last symbol of the random part
+    let key = &lines[1];
+    let index = key.len() - 7;
+    let symbol = match &key[index..=index] {
+        "a" => "b",
+        _ => "a",
+    };
+    let mistyped = format!("{}{symbol}{}", &key[..index], &key[index + 1..]);
+
+    cargo_bin_cmd!()
+        .args(["validate", "--apikey", &lines[0], &mistyped])
+        .assert()
+        .code(1)
+        .stdout(format!(
+            "{}\tok\n{mistyped}\tAPI key checksum mismatch\n",
+            lines[0]
+        ));
+}
+
+#[test]
+fn test_validate_apikey_conflicts_with_no_future() {
+    cargo_bin_cmd!()
+        .args(["validate", "--apikey", "--no-future", "pk_abc"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
@@ -0,0 +1,103 @@
+#![cfg(all(
+    feature = "test-hooks",
+    any(feature = "uuid", feature = "ulid", feature = "objectid")
+))]
+
+#[cfg(feature = "ulid")]
+use std::time::{Duration, SystemTime};
+
+use assert_cmd::cargo_bin_cmd;
+
+/// 2023-11-14T22:13:20.123456789Z
+const FAKE_NOW: &str = "1700000000123456789";
+
+/// Runs the binary with the given arguments, returning its only output line.
+fn generate(args: &[&str]) -> String {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap().trim().to_owned()
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_fake_now_ulid() {
+    let ulid = ulid::Ulid::from_string(&generate(&["--fake-now", FAKE_NOW, "ulid"])).unwrap();
+
+    assert_eq!(ulid.timestamp_ms(), 1_700_000_000_123);
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_fake_now_uuid_v7() {
+    let uuid = generate(&["--fake-now", FAKE_NOW, "uuid", "-v", "7"]);
+    let timestamp = uuid::Uuid::parse_str(&uuid)
+        .unwrap()
+        .get_timestamp()
+        .unwrap();
+
+    assert_eq!(timestamp.to_unix(), (1_700_000_000, 123_000_000));
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_fake_now_uuid_v1_v6() {
+    for version in ["1", "6"] {
+        let uuid = generate(&["--fake-now", FAKE_NOW, "uuid", "-v", version]);
+        let timestamp = uuid::Uuid::parse_str(&uuid)
+            .unwrap()
+            .get_timestamp()
+            .unwrap();
+
+        // Gregorian timestamps count in 100 ns ticks
+        assert_eq!(timestamp.to_unix(), (1_700_000_000, 123_456_700), "{uuid}");
+    }
+}
+
+#[test]
+#[cfg(feature = "objectid")]
+fn test_fake_now_objectid() {
+    let oid = bson::oid::ObjectId::parse_str(generate(&["--fake-now", FAKE_NOW, "oid"])).unwrap();
+
+    assert_eq!(oid.timestamp().timestamp_millis(), 1_700_000_000_000);
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_fake_now_from_env() {
+    let output = cargo_bin_cmd!()
+        .env("SPWD_FAKE_NOW", FAKE_NOW)
+        .args(["-n", "3", "ulid"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    for line in stdout.lines() {
+        let ulid = ulid::Ulid::from_string(line).unwrap();
+        assert_eq!(ulid.timestamp_ms(), 1_700_000_000_123);
+    }
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_fake_now_with_fixed_time_mode() {
+    let args = ["--fake-now", FAKE_NOW, "--time-mode", "fixed", "ulid"];
+    let ulid = ulid::Ulid::from_string(&generate(&args)).unwrap();
+
+    assert_eq!(ulid.timestamp_ms(), 1_700_000_000_123);
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_without_fake_now_uses_system_time() {
+    let before = SystemTime::now();
+    let ulid = ulid::Ulid::from_string(&generate(&["ulid"])).unwrap();
+    let after = SystemTime::now();
+
+    let timestamp = SystemTime::UNIX_EPOCH + Duration::from_millis(ulid.timestamp_ms());
+
+    // ULIDs truncate to milliseconds, so allow for the one being cut off
+    assert!(timestamp + Duration::from_millis(1) >= before && timestamp <= after);
+}
@@ -0,0 +1,163 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Instagram's epoch (2011-08-24T21:07:01.721Z), in Unix milliseconds.
+const INSTAGRAM_EPOCH: &str = "1314220021721";
+
+#[test]
+fn test_composite_generation() {
+    cargo_bin_cmd!()
+        .args(["composite", "--layout", "time:41,shard:13,seq:10"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[1-9][0-9]{0,19}\n$").unwrap());
+}
+
+#[test]
+fn test_composite_instagram_layout() {
+    // (1387263000000 - 1314220021721) << 23 | 5 << 10, then the sequence counts up
+    cargo_bin_cmd!()
+        .args([
+            "-n",
+            "2",
+            "composite",
+            "--layout",
+            "time:41,shard:13,seq:10",
+            "--epoch",
+            INSTAGRAM_EPOCH,
+            "--shard",
+            "5",
+            "--timestamp",
+            "1387263000000",
+        ])
+        .assert()
+        .success()
+        .stdout("612728911935050752\n612728911935050753\n");
+}
+
+#[test]
+fn test_composite_wide_layout() {
+    cargo_bin_cmd!()
+        .args([
+            "composite",
+            "--layout",
+            "time:48,shard:64,seq:16",
+            "--shard",
+            "18446744073709551615",
+            "--timestamp",
+            "1",
+        ])
+        .assert()
+        .success()
+        .stdout(format!(
+            "{}\n",
+            (1u128 << 80) | (u128::from(u64::MAX) << 16)
+        ));
+}
+
+#[test]
+fn test_composites_strictly_increasing() {
+    let output = cargo_bin_cmd!()
+        .args([
+            "-n",
+            "1024",
+            "composite",
+            "--layout",
+            "time:41,shard:13,seq:10",
+            "--timestamp",
+            "1700000000000",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ids: Vec<u64> = stdout.lines().map(|line| line.parse().unwrap()).collect();
+
+    assert_eq!(ids.len(), 1024);
+    assert!(ids.windows(2).all(|pair| pair[1] == pair[0] + 1));
+}
+
+#[test]
+fn test_composite_sequence_overflow() {
+    cargo_bin_cmd!()
+        .args([
+            "-n",
+            "1025",
+            "composite",
+            "--layout",
+            "time:41,shard:13,seq:10",
+            "--timestamp",
+            "1700000000000",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "composite sequence exhausted: more than 1024 identifiers in one millisecond",
+        ));
+}
+
+#[test]
+fn test_composite_invalid_layout() {
+    cargo_bin_cmd!()
+        .args(["composite", "--layout", "time:41,node:10"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unknown field \"node\" (expected time, shard, or seq)",
+        ));
+}
+
+#[test]
+fn test_composite_layout_too_wide() {
+    cargo_bin_cmd!()
+        .args(["composite", "--layout", "time:64,shard:64,seq:1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "layout is 129 bits wide, more than 128",
+        ));
+}
+
+#[test]
+fn test_composite_shard_out_of_range() {
+    cargo_bin_cmd!()
+        .args(["composite", "--layout", "time:41,shard:3", "--shard", "8"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "shards must be at most 7 to fit the 3-bit shard field",
+        ));
+}
+
+#[test]
+fn test_composite_shard_without_field() {
+    cargo_bin_cmd!()
+        .args(["composite", "--layout", "time:41,seq:10", "--shard", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--shard <SHARD>' needs a shard field in '--layout <LAYOUT>'",
+        ));
+}
+
+#[test]
+fn test_composite_timestamp_before_epoch() {
+    cargo_bin_cmd!()
+        .args([
+            "composite",
+            "--layout",
+            "time:41",
+            "--epoch",
+            "1000",
+            "--timestamp",
+            "999",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "composite timestamps must be between the epoch (1000) and 2199023256551 \
+             milliseconds to fit the 41-bit time field",
+        ));
+}
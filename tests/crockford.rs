@@ -0,0 +1,80 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Crockford base32 symbols, without the check symbols.
+const SYMBOLS: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Runs the crockford command with the given arguments, returning the output lines.
+fn codes(args: &[&str]) -> Vec<String> {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn test_crockford_default() {
+    let lines = codes(&["crockford"]);
+
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].len(), 12);
+    assert!(
+        lines[0].chars().all(|c| SYMBOLS.contains(c)),
+        "{}",
+        lines[0]
+    );
+}
+
+#[test]
+fn test_crockford_length_with_check() {
+    let lines = codes(&["-n", "20", "crockford", "--length", "8", "--check"]);
+
+    assert_eq!(lines.len(), 20);
+    for line in &lines {
+        assert_eq!(line.len(), 9, "{line}");
+        assert!(line[..8].chars().all(|c| SYMBOLS.contains(c)), "{line}");
+    }
+}
+
+#[test]
+fn test_crockford_length_out_of_range() {
+    for length in ["0", "65"] {
+        cargo_bin_cmd!()
+            .args(["crockford", "--length", length])
+            .assert()
+            .failure();
+    }
+}
+
+#[test]
+fn test_validate_generated_crockford_codes() {
+    let mut args = vec!["validate", "--crockford"];
+    let lines = codes(&["-n", "10", "crockford", "--check"]);
+    args.extend(lines.iter().map(String::as_str));
+
+    cargo_bin_cmd!().args(args).assert().success();
+}
+
+#[test]
+fn test_validate_crockford_published_examples() {
+    cargo_bin_cmd!()
+        .args(["validate", "--crockford", "16JD", "16j-d", "16KD", "14U"])
+        .assert()
+        .code(1)
+        .stdout(
+            "16JD\tok\n16j-d\tok\n16KD\tcheck symbol mismatch: expected 'E', got 'D'\n14U\tok\n",
+        );
+}
+
+#[test]
+fn test_validate_crockford_conflicts_with_apikey() {
+    cargo_bin_cmd!()
+        .args(["validate", "--crockford", "--apikey", "16JD"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_cuid_generation() {
+    cargo_bin_cmd!()
+        .arg("cuid")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^c[0-9a-z]{24}\n$").unwrap());
+}
+
+#[test]
+fn test_multiple_cuids_unique() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "10k", "cuid"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let cuids: Vec<&str> = stdout.lines().collect();
+
+    assert!(output.status.success());
+    assert_eq!(cuids.len(), 10_000);
+    assert!(cuids.iter().all(|cuid| {
+        cuid.len() == 25
+            && cuid.starts_with('c')
+            && cuid
+                .bytes()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_lowercase())
+    }));
+    assert_eq!(cuids.iter().collect::<HashSet<_>>().len(), 10_000);
+
+    // The counter advances across the run, and the fingerprint stays the same
+    assert_eq!(&cuids[0][9..13], "0000");
+    assert_eq!(&cuids[9999][9..13], "07pr");
+    assert!(cuids.iter().all(|cuid| cuid[13..17] == cuids[0][13..17]));
+}
+
+#[test]
+fn test_cuid_slug() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "100", "cuid", "--slug"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    for slug in stdout.lines() {
+        assert!((7..=10).contains(&slug.len()), "{slug}");
+        assert!(
+            slug.bytes()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_lowercase())
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_estimate_ulid() {
+    cargo_bin_cmd!()
+        .args(["estimate", "--type", "ulid", "--rate", "50000/s"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("random bits: 80"))
+        .stdout(predicate::str::contains(
+            "collision probability per millisecond: 1.03398e-21",
+        ))
+        .stdout(predicate::str::contains(
+            "collision probability per day: 8.93355e-14",
+        ));
+}
+
+#[test]
+fn test_estimate_uuid_v7_json() {
+    cargo_bin_cmd!()
+        .args(["estimate", "--type", "uuid-v7", "--rate", "50/ms", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            r#"{"type":"uuid-v7","rate_per_second":50000.0,"random_bits":74,"#,
+        ));
+}
+
+#[test]
+fn test_estimate_objectid() {
+    cargo_bin_cmd!()
+        .args(["estimate", "--type", "oid", "--rate", "20m/s"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "counter capacity per second: 16777216",
+        ))
+        .stdout(predicate::str::contains(
+            "counter wraps within a second: yes",
+        ));
+}
+
+#[test]
+fn test_estimate_invalid_rate() {
+    cargo_bin_cmd!()
+        .args(["estimate", "--type", "ulid", "--rate", "50/day"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown rate unit"));
+}
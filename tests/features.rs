@@ -0,0 +1,54 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Subcommands of the generators behind features, with their features.
+const OPTIONAL_COMMANDS: [(&str, &str, bool); 3] = [
+    ("uuid", "uuid", cfg!(feature = "uuid")),
+    ("ulid", "ulid", cfg!(feature = "ulid")),
+    ("oid", "objectid", cfg!(feature = "objectid")),
+];
+
+/// Returns whether the help lists a subcommand.
+fn lists_command(help: &str, command: &str) -> bool {
+    help.lines()
+        .any(|line| line.split_whitespace().next() == Some(command))
+}
+
+#[test]
+fn test_help_lists_compiled_in_commands() {
+    let output = cargo_bin_cmd!().arg("--help").output().unwrap();
+    let help = String::from_utf8(output.stdout).unwrap();
+
+    for (command, _, compiled_in) in OPTIONAL_COMMANDS {
+        assert_eq!(lists_command(&help, command), compiled_in, "{command}");
+    }
+    assert!(lists_command(&help, "ksuid"));
+}
+
+#[test]
+fn test_compiled_out_commands_refused() {
+    for (command, feature, compiled_in) in OPTIONAL_COMMANDS {
+        if compiled_in {
+            continue;
+        }
+
+        cargo_bin_cmd!()
+            .arg(command)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "the '{command}' command is not compiled in; rebuild with `--features {feature}`"
+            )));
+    }
+}
+
+#[test]
+fn test_compiled_in_commands_generate() {
+    for (command, _, compiled_in) in OPTIONAL_COMMANDS {
+        if !compiled_in {
+            continue;
+        }
+
+        cargo_bin_cmd!().arg(command).assert().success();
+    }
+}
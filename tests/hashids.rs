@@ -0,0 +1,137 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_hashids_encode_numbers() {
+    cargo_bin_cmd!()
+        .args(["hashids", "--salt", "this is my salt", "1", "2", "3"])
+        .assert()
+        .success()
+        .stdout("laHquq\n");
+}
+
+#[test]
+fn test_hashids_with_min_length() {
+    cargo_bin_cmd!()
+        .args([
+            "hashids",
+            "--salt",
+            "this is my salt",
+            "--min-length",
+            "8",
+            "1",
+        ])
+        .assert()
+        .success()
+        .stdout("gB0NV05e\n");
+}
+
+#[test]
+fn test_hashids_decode() {
+    cargo_bin_cmd!()
+        .args(["hashids", "--salt", "this is my salt", "--decode", "laHquq"])
+        .assert()
+        .success()
+        .stdout("1 2 3\n");
+}
+
+#[test]
+fn test_hashids_round_trip() {
+    let options = [
+        "--salt",
+        "my project",
+        "--alphabet",
+        "0123456789abcdefghijkmnopqrstuvwxyz",
+        "--min-length",
+        "12",
+    ];
+
+    for numbers in [
+        &["0"][..],
+        &["7", "0", "42"],
+        &["18446744073709551615", "1", "18446744073709551615"],
+    ] {
+        let output = cargo_bin_cmd!()
+            .arg("hashids")
+            .args(options)
+            .args(numbers)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let hashid = stdout.trim_end();
+
+        assert!(output.status.success());
+        assert!(hashid.len() >= 12);
+
+        cargo_bin_cmd!()
+            .arg("hashids")
+            .args(options)
+            .args(["--decode", hashid])
+            .assert()
+            .success()
+            .stdout(format!("{}\n", numbers.join(" ")));
+    }
+}
+
+#[test]
+fn test_multiple_hashids_without_numbers() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "5", "hashids", "--salt", "salt"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(stdout.lines().count(), 5);
+
+    for hashid in stdout.lines() {
+        cargo_bin_cmd!()
+            .args(["hashids", "--salt", "salt", "--decode", hashid])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(r"^[0-9]+\n$").unwrap());
+    }
+}
+
+#[test]
+fn test_hashids_decode_other_salt() {
+    cargo_bin_cmd!()
+        .args(["hashids", "--salt", "another salt", "--decode", "laHquq"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "\"laHquq\" is not a hashid of this salt, alphabet, and minimum length",
+        ));
+}
+
+#[test]
+fn test_hashids_decode_with_numbers() {
+    cargo_bin_cmd!()
+        .args(["hashids", "--decode", "laHquq", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--decode <HASHID>' cannot be used with '[NUMBER]...'",
+        ));
+}
+
+#[test]
+fn test_hashids_alphabet_invalid() {
+    for (alphabet, message) in [
+        (
+            "abcdefghijklmno",
+            "alphabet must contain at least 16 symbols, got 15",
+        ),
+        ("abcdefgh ijklmnop", "alphabet must not contain spaces"),
+        (
+            "abcdefghijklmnopa",
+            "alphabet must not contain duplicate symbols, got 'a' twice",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .args(["hashids", "--alphabet", alphabet, "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message));
+    }
+}
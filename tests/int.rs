@@ -0,0 +1,142 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+fn generate(args: &[&str]) -> Vec<i128> {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_int_generation() {
+    cargo_bin_cmd!()
+        .arg("int")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9]{1,20}\n$").unwrap());
+}
+
+#[test]
+fn test_int_bits() {
+    for (bits, highest) in [
+        ("32", i128::from(u32::MAX)),
+        ("53", (1 << 53) - 1),
+        ("63", i128::from(i64::MAX)),
+        ("64", i128::from(u64::MAX)),
+    ] {
+        let values = generate(&["-n", "1000", "int", "--bits", bits]);
+
+        assert_eq!(values.len(), 1000);
+        assert!(
+            values.iter().all(|value| (0..=highest).contains(value)),
+            "{bits}"
+        );
+    }
+}
+
+#[test]
+fn test_int_signed() {
+    let values = generate(&["-n", "1000", "int", "--bits", "32", "--signed"]);
+
+    assert!(
+        values
+            .iter()
+            .all(|value| (i128::from(i32::MIN)..=i128::from(i32::MAX)).contains(value))
+    );
+    // About half of them are negative
+    assert!(values.iter().any(|value| *value < 0));
+}
+
+#[test]
+fn test_int_bounds_uniform() {
+    let values = generate(&[
+        "-n", "40000", "int", "--signed", "--min", "-2", "--max", "1",
+    ]);
+
+    // 40000 draws give 10000 of each, with a standard deviation of about 87
+    let mut counts = [0u32; 4];
+    for value in values {
+        counts[usize::try_from(value + 2).unwrap()] += 1;
+    }
+
+    for (index, count) in counts.iter().enumerate() {
+        assert!(
+            (9_500..=10_500).contains(count),
+            "{} appeared {count} times",
+            index as i128 - 2
+        );
+    }
+}
+
+#[test]
+fn test_int_nonzero() {
+    let values = generate(&["-n", "1000", "int", "--nonzero", "--max", "2"]);
+
+    assert!(values.iter().all(|value| (1..=2).contains(value)));
+}
+
+#[test]
+fn test_int_min_greater_than_max() {
+    cargo_bin_cmd!()
+        .args(["int", "--min", "10", "--max", "5"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value for '--max': 5 is less than the minimum (10)",
+        ));
+}
+
+#[test]
+fn test_int_single_value_range() {
+    cargo_bin_cmd!()
+        .args(["int", "--min", "7", "--max", "7"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value for '--max': the range 7..=7 has fewer than two values to draw from",
+        ));
+
+    cargo_bin_cmd!()
+        .args(["int", "--nonzero", "--min", "0", "--max", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the range 0..=1 has fewer than two values besides zero to draw from",
+        ));
+}
+
+#[test]
+fn test_int_bound_outside_width() {
+    cargo_bin_cmd!()
+        .args(["int", "--bits", "53", "--max", "9007199254740992"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value for '--max': 9007199254740992 is not in 0..=9007199254740991 (53-bit \
+             unsigned integers)",
+        ));
+
+    cargo_bin_cmd!()
+        .args(["int", "--min", "-1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value for '--min': -1 is not in 0..=18446744073709551615",
+        ));
+}
+
+#[test]
+fn test_int_signed_with_unsigned() {
+    cargo_bin_cmd!()
+        .args(["int", "--signed", "--unsigned"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--signed' cannot be used with '--unsigned'",
+        ));
+}
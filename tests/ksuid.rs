@@ -0,0 +1,59 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_ksuid_generation() {
+    cargo_bin_cmd!()
+        .arg("ksuid")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9A-Za-z]{27}\n$").unwrap());
+}
+
+#[test]
+fn test_multiple_ksuids() {
+    cargo_bin_cmd!()
+        .args(["-n", "4", "ksuid"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^([0-9A-Za-z]{27}\n){4}$").unwrap());
+}
+
+#[test]
+fn test_ksuid_with_epoch_timestamp() {
+    // The timestamp bytes are zero, leaving only the 128-bit payload (at most 22 digits)
+    cargo_bin_cmd!()
+        .args(["ksuid", "--timestamp", "1400000000"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("00000"));
+}
+
+#[test]
+fn test_ksuids_sort_by_timestamp() {
+    let ksuids: Vec<String> = ["1609459200", "1609459201", "1700000000"]
+        .into_iter()
+        .map(|timestamp| {
+            let output = cargo_bin_cmd!()
+                .args(["ksuid", "--timestamp", timestamp])
+                .output()
+                .unwrap();
+
+            assert!(output.status.success());
+            String::from_utf8(output.stdout).unwrap().trim().to_owned()
+        })
+        .collect();
+
+    assert!(ksuids.is_sorted(), "{ksuids:?}");
+}
+
+#[test]
+fn test_ksuid_timestamp_before_epoch() {
+    cargo_bin_cmd!()
+        .args(["ksuid", "--timestamp", "1399999999"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "1399999999 is not in 1400000000..=5694967295",
+        ));
+}
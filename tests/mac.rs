@@ -0,0 +1,121 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Returns the first byte of a MAC address printed in any notation.
+fn first_byte(mac: &str) -> u8 {
+    u8::from_str_radix(&mac[..2], 16).unwrap()
+}
+
+#[test]
+fn test_mac_generation() {
+    cargo_bin_cmd!()
+        .arg("mac")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9a-f]{2}(:[0-9a-f]{2}){5}\n$").unwrap());
+}
+
+#[test]
+fn test_mac_formats() {
+    for (format, pattern) in [
+        ("colon", r"^[0-9a-f]{2}(:[0-9a-f]{2}){5}\n$"),
+        ("hyphen", r"^[0-9a-f]{2}(-[0-9a-f]{2}){5}\n$"),
+        ("dot", r"^[0-9a-f]{4}(\.[0-9a-f]{4}){2}\n$"),
+        ("bare", r"^[0-9a-f]{12}\n$"),
+    ] {
+        cargo_bin_cmd!()
+            .args(["mac", "--format", format])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(pattern).unwrap());
+    }
+}
+
+#[test]
+fn test_mac_bits() {
+    for (flags, multicast, local) in [
+        (&[][..], false, true),
+        (&["--multicast"][..], true, true),
+        (&["--universal"][..], false, false),
+        (&["--multicast", "--universal"][..], true, false),
+    ] {
+        let output = cargo_bin_cmd!()
+            .args(["-n", "100", "mac"])
+            .args(flags)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(stdout.lines().count(), 100);
+        for mac in stdout.lines() {
+            assert_eq!(first_byte(mac) & 0x1 != 0, multicast, "{flags:?} {mac}");
+            assert_eq!(first_byte(mac) & 0x2 != 0, local, "{flags:?} {mac}");
+        }
+    }
+}
+
+#[test]
+fn test_mac_oui() {
+    for (oui, flags) in [
+        ("02:00:00", &[][..]),
+        ("03-00-00", &["--multicast"][..]),
+        ("00163E", &["--universal"][..]),
+        ("01:00:5e", &["--multicast", "--universal"][..]),
+    ] {
+        let prefix = oui.replace([':', '-'], "").to_lowercase();
+
+        cargo_bin_cmd!()
+            .args(["mac", "--format", "bare", "--oui", oui])
+            .args(flags)
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with(prefix));
+    }
+}
+
+#[test]
+fn test_mac_oui_contradicting_flags() {
+    for (oui, flags, message) in [
+        (
+            "00:16:3e",
+            &[][..],
+            "00:16:3e is a universally administered OUI, which needs '--universal'",
+        ),
+        (
+            "02:00:00",
+            &["--universal"][..],
+            "02:00:00 is a locally administered OUI, which conflicts with '--universal'",
+        ),
+        (
+            "03:00:00",
+            &[][..],
+            "03:00:00 is a multicast OUI, which needs '--multicast'",
+        ),
+        (
+            "02:00:00",
+            &["--multicast"][..],
+            "02:00:00 is a unicast OUI, which conflicts with '--multicast'",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .args(["mac", "--oui", oui])
+            .args(flags)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "invalid value for '--oui': {message}"
+            )));
+    }
+}
+
+#[test]
+fn test_mac_invalid_oui() {
+    cargo_bin_cmd!()
+        .args(["mac", "--oui", "00:16"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "OUI \"00:16\" is not three bytes like 00:16:3e, 00-16-3e, or 00163e",
+        ));
+}
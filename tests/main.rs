@@ -2,6 +2,7 @@ use assert_cmd::cargo_bin_cmd;
 use predicates::prelude::*;
 
 #[test]
+#[cfg(all(feature = "uuid", feature = "ulid", feature = "objectid"))]
 fn test_help_flag() {
     cargo_bin_cmd!()
         .arg("--help")
@@ -22,6 +23,7 @@ fn test_version_flag() {
 }
 
 #[test]
+#[cfg(feature = "uuid")]
 fn test_count_zero() {
     cargo_bin_cmd!()
         .args(["-n", "0", "uuid"])
@@ -31,6 +33,7 @@ fn test_count_zero() {
 }
 
 #[test]
+#[cfg(feature = "uuid")]
 fn test_count_one_explicit() {
     cargo_bin_cmd!()
         .args(["-n", "1", "uuid"])
@@ -82,3 +85,274 @@ fn test_invalid_timestamp_non_numeric() {
 fn test_invalid_command() {
     cargo_bin_cmd!().arg("invalid_command").assert().failure();
 }
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_count_with_suffix() {
+    cargo_bin_cmd!()
+        .args(["-n", "2k", "ulid"])
+        .assert()
+        .success()
+        .stdout(predicate::function(|out: &str| out.lines().count() == 2000));
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_count_from_stdin() {
+    cargo_bin_cmd!()
+        .args(["-n", "-", "uuid"])
+        .write_stdin("250\n")
+        .assert()
+        .success()
+        .stdout(predicate::function(|out: &str| out.lines().count() == 250));
+}
+
+#[test]
+fn test_count_from_empty_stdin() {
+    cargo_bin_cmd!()
+        .args(["-n", "-", "uuid"])
+        .write_stdin("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid number from stdin"));
+}
+
+#[test]
+#[cfg(feature = "objectid")]
+fn test_count_from_file() {
+    let path = std::env::temp_dir().join(format!("spwd-count-{}.txt", std::process::id()));
+    std::fs::write(&path, "3k\n").unwrap();
+
+    cargo_bin_cmd!()
+        .args(["-n", &format!("@{}", path.display()), "oid"])
+        .assert()
+        .success()
+        .stdout(predicate::function(|out: &str| out.lines().count() == 3000));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_count_from_missing_file() {
+    cargo_bin_cmd!()
+        .args(["-n", "@/nonexistent/count.txt", "uuid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "failed to read number from file /nonexistent/count.txt",
+        ));
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_assert_max_length_passes() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "--assert-max-length", "26", "ulid"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"(?m)^([0-9A-Z]{26}\n){3}$").unwrap());
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_assert_max_length_fails() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "--assert-max-length", "20", "uuid"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(
+            "is 36 characters long, exceeding --assert-max-length 20",
+        ));
+}
+
+#[test]
+#[cfg(feature = "objectid")]
+fn test_assert_charset_passes() {
+    cargo_bin_cmd!()
+        .args(["--assert-charset", "[0-9a-f]{24}", "oid"])
+        .assert()
+        .success();
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_assert_charset_fails() {
+    cargo_bin_cmd!()
+        .args(["--assert-charset", "[0-9a-f]+", "uuid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "(36 characters) does not match --assert-charset \"[0-9a-f]+\"",
+        ));
+}
+
+#[test]
+fn test_assert_charset_invalid_regex() {
+    cargo_bin_cmd!()
+        .args(["--assert-charset", "[0-9", "uuid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid regex"));
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_single_without_newline() {
+    cargo_bin_cmd!()
+        .args(["--single", "ulid"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9A-Z]{26}$").unwrap());
+}
+
+#[test]
+#[cfg(feature = "objectid")]
+fn test_single_with_count_one() {
+    cargo_bin_cmd!()
+        .args(["--single", "-n", "1", "oid"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9a-f]{24}$").unwrap());
+}
+
+#[test]
+fn test_single_conflicts_with_count() {
+    cargo_bin_cmd!()
+        .args(["--single", "-n", "3", "uuid"])
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "the argument '--single' cannot be used with '--num <NUMBER>'",
+        ));
+}
+
+#[test]
+fn test_single_conflicts_with_stdin_count() {
+    cargo_bin_cmd!()
+        .args(["--single", "-n", "-", "uuid"])
+        .write_stdin("1")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'--single' cannot be used"));
+}
+
+#[test]
+fn test_single_conflicts_with_redact() {
+    cargo_bin_cmd!()
+        .args(["--single", "redact"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--single' cannot be used with 'redact'",
+        ));
+}
+
+#[test]
+fn test_single_keeps_reports_off_stdout() {
+    let path = std::env::temp_dir().join(format!("spwd-single-{}.txt", std::process::id()));
+    std::fs::write(&path, "00\n11\n22\n33\n44\n55\n66\n77\n88\n99\n").unwrap();
+
+    // The computed length and the banned substring warning both go to stderr
+    cargo_bin_cmd!()
+        .args(["--single", "shortcode", "--expected", "1000", "--verbose"])
+        .args(["--alphabet", "numeric", "--exclude-substrings"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9]+$").unwrap())
+        .stderr(predicate::str::contains("length: ").and(predicate::str::contains("warning: ")));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_output_limit_exceeded() {
+    cargo_bin_cmd!()
+        .args(["-n", "20m", "uuid"])
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "20000000 identifiers (about 740.0 MB) exceed the safety limit",
+        ));
+}
+
+#[test]
+fn test_output_limit_in_bytes_exceeded() {
+    cargo_bin_cmd!()
+        .args(["-n", "1m", "nanoid", "--size", "2000"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "1000000 identifiers (about 2.0 GB) exceed the safety limit",
+        ));
+}
+
+#[test]
+fn test_output_limit_overridden() {
+    // Failing the first identifier's assertion shows generation went past the limit
+    cargo_bin_cmd!()
+        .args([
+            "-n",
+            "20m",
+            "--yes-really",
+            "--assert-max-length",
+            "1",
+            "uuid",
+        ])
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains("safety limit").not());
+}
+
+#[test]
+fn test_output_limit_overridden_from_env() {
+    cargo_bin_cmd!()
+        .env("SPWD_NO_LIMIT", "1")
+        .args(["-n", "20m", "--assert-max-length", "1", "ulid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("safety limit").not());
+}
+
+#[cfg(unix)]
+#[test]
+#[cfg(feature = "ulid")]
+fn test_interrupt_finishes_last_identifier() {
+    use std::io::{BufRead, BufReader, Read};
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_spwd"))
+        .args(["-n", "100m", "--yes-really", "ulid"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+
+    // The handler is installed before the first identifier is written
+    let mut output = String::new();
+    stdout.read_line(&mut output).unwrap();
+
+    let kill = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(kill.success());
+
+    stdout.read_to_string(&mut output).unwrap();
+    let status = child.wait().unwrap();
+
+    assert_eq!(status.code(), Some(130));
+    assert!(output.ends_with('\n'));
+    assert!(output.lines().count() < 100_000_000);
+    assert!(output.lines().last().is_some_and(|line| {
+        predicate::str::is_match(r"^[0-9A-Z]{26}$")
+            .unwrap()
+            .eval(line)
+    }));
+}
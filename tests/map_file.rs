@@ -0,0 +1,129 @@
+#![cfg(feature = "ulid")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Returns a fresh map file path for the given test.
+fn map_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("spwd-map-{name}-{}.tsv", std::process::id()));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+/// Generates ULIDs with their hex forms, appending them to the map file.
+fn run(count: &str, path: &PathBuf) -> String {
+    let output = cargo_bin_cmd!()
+        .args(["-n", count, "--also-format", "hex", "--map-file"])
+        .arg(path)
+        .arg("ulid")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_map_file_sequential_runs_append() {
+    let path = map_path("append");
+
+    let first = run("3", &path);
+    let second = run("2", &path);
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), first + &second);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_map_file_lines_round_trip() {
+    let path = map_path("round-trip");
+    run("20", &path);
+
+    let contents = fs::read_to_string(&path).unwrap();
+    for line in contents.lines() {
+        let (old, new) = line.split_once('\t').unwrap();
+        let ulid = ulid::Ulid::from_string(old).unwrap();
+
+        assert_eq!(
+            uuid::Uuid::parse_str(new).unwrap().as_bytes(),
+            &ulid.to_bytes()
+        );
+    }
+
+    assert_eq!(contents.lines().count(), 20);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_map_file_refuses_partial_line() {
+    let path = map_path("partial");
+    run("2", &path);
+
+    // A run cut short in the middle of a line
+    let mut contents = fs::read_to_string(&path).unwrap();
+    contents.push_str("01HF7YAT00ZZZZ");
+    fs::write(&path, &contents).unwrap();
+
+    cargo_bin_cmd!()
+        .args(["--also-format", "hex", "--map-file"])
+        .arg(&path)
+        .arg("ulid")
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "ends with a partial line; pass --map-lenient to append anyway",
+        ));
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), contents);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_map_file_lenient_terminates_partial_line() {
+    let path = map_path("lenient");
+    fs::write(&path, "garbage\n01HF7YAT00ZZZZ").unwrap();
+
+    let output = cargo_bin_cmd!()
+        .args(["--also-format", "hex", "--map-lenient", "--map-file"])
+        .arg(&path)
+        .arg("ulid")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        format!("garbage\n01HF7YAT00ZZZZ\n{stdout}")
+    );
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_map_file_refuses_other_formats() {
+    let path = map_path("formats");
+    run("2", &path);
+
+    cargo_bin_cmd!()
+        .args(["--also-format", "base58", "--map-file"])
+        .arg(&path)
+        .arg("ulid")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("has an unparseable line 1"));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_map_file_requires_also_format() {
+    cargo_bin_cmd!()
+        .args(["--map-file", "map.tsv", "ulid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--also-format <FORMAT>"));
+}
@@ -0,0 +1,62 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_nanoid_generation() {
+    cargo_bin_cmd!()
+        .arg("nanoid")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[A-Za-z0-9_-]{21}\n$").unwrap());
+}
+
+#[test]
+fn test_multiple_nanoids_with_size() {
+    cargo_bin_cmd!()
+        .args(["-n", "100", "nanoid", "--size", "12"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^([A-Za-z0-9_-]{12}\n){100}$").unwrap());
+}
+
+#[test]
+fn test_nanoid_with_alphabet() {
+    cargo_bin_cmd!()
+        .args(["nanoid", "--alphabet", "0123456789abcdef", "--size", "8"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9a-f]{8}\n$").unwrap());
+}
+
+#[test]
+fn test_nanoid_alphabet_too_short() {
+    cargo_bin_cmd!()
+        .args(["nanoid", "--alphabet", "a"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value 'a' for '--alphabet <ALPHABET>': alphabet must contain between 2 and 255 symbols, got 1",
+        ));
+}
+
+#[test]
+fn test_nanoid_alphabet_duplicates() {
+    cargo_bin_cmd!()
+        .args(["nanoid", "--alphabet", "abca"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "alphabet must not contain duplicate symbols, got 'a' twice",
+        ));
+}
+
+#[test]
+fn test_nanoid_zero_size() {
+    cargo_bin_cmd!()
+        .args(["nanoid", "--size", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value '0' for '--size <SIZE>'",
+        ));
+}
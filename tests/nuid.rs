@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_nuid_generation() {
+    cargo_bin_cmd!()
+        .arg("nuid")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9A-Za-z]{22}\n$").unwrap());
+}
+
+#[test]
+fn test_multiple_nuids_share_prefix() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "100", "nuid"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ids: Vec<&str> = stdout.lines().collect();
+
+    assert!(output.status.success());
+    assert_eq!(ids.len(), 100);
+
+    // A rollover within 100 NUIDs is unlikely enough to only allow one prefix change
+    let prefixes: HashSet<&str> = ids.iter().map(|id| &id[..12]).collect();
+    assert!(prefixes.len() <= 2);
+}
+
+#[test]
+fn test_million_nuids_unique() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000000", "nuid"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+
+    let ids: HashSet<&str> = stdout.lines().collect();
+    assert_eq!(ids.len(), 1_000_000);
+    assert!(
+        ids.iter()
+            .all(|id| id.len() == 22 && id.bytes().all(|c| c.is_ascii_alphanumeric()))
+    );
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "objectid")]
+
 use assert_cmd::cargo_bin_cmd;
 use predicates::prelude::*;
 
@@ -36,3 +38,33 @@ fn test_multiple_objectids() {
         .success()
         .stdout(predicate::str::is_match(r"(?m)^([0-9a-f]{24}\n){4}$").unwrap());
 }
+
+#[test]
+fn test_objectids_with_timestamp_unique() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "100000", "oid", "--timestamp", "1609459200"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    let unique: std::collections::HashSet<&str> = lines.iter().copied().collect();
+
+    assert_eq!(lines.len(), 100000);
+    assert_eq!(unique.len(), 100000);
+    assert!(lines.iter().all(|line| line.starts_with("5fee6600")));
+}
+
+#[test]
+fn test_objectid_with_timestamp_out_of_range() {
+    cargo_bin_cmd!()
+        .args(["oid", "--timestamp", "2106-02-07T06:28:16Z"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "4294967296 is not in 0..=4294967295 seconds \
+             (1970-01-01T00:00:00Z to 2106-02-07T06:28:15Z)",
+        ));
+}
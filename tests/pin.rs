@@ -0,0 +1,75 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_pin_generation() {
+    cargo_bin_cmd!()
+        .arg("pin")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9]{6}\n$").unwrap());
+}
+
+#[test]
+fn test_pin_digits() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "pin", "--digits", "12"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^([0-9]{12}\n){3}$").unwrap());
+}
+
+#[test]
+fn test_pin_no_leading_zero() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000", "pin", "--digits", "4", "--no-leading-zero"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(stdout.lines().count(), 1000);
+    assert!(
+        stdout
+            .lines()
+            .all(|pin| pin.len() == 4 && !pin.starts_with('0'))
+    );
+}
+
+#[test]
+fn test_pin_digits_out_of_range() {
+    for digits in ["3", "13"] {
+        cargo_bin_cmd!()
+            .args(["pin", "--digits", digits])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "invalid value '{digits}' for '--digits <DIGITS>': {digits} is not in 4..=12"
+            )));
+    }
+}
+
+#[test]
+fn test_pin_digit_frequencies_uniform() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "100000", "pin"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+
+    // 600000 digits give 60000 of each, with a standard deviation of about 230
+    let mut counts = [0u32; 10];
+    for digit in stdout.lines().flat_map(str::bytes) {
+        counts[usize::from(digit - b'0')] += 1;
+    }
+
+    assert_eq!(counts.iter().sum::<u32>(), 600_000);
+    for (digit, count) in counts.iter().enumerate() {
+        assert!(
+            (58_500..=61_500).contains(count),
+            "digit {digit} appeared {count} times"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "http")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Serves one request per given status, returning the request bodies it received.
+fn serve(statuses: &[u16]) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/register", listener.local_addr().unwrap());
+    let statuses = statuses.to_vec();
+
+    let handle = thread::spawn(move || {
+        let mut bodies = Vec::new();
+
+        for status in statuses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            bodies.push(String::from_utf8(body).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {status} Status\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            )
+            .unwrap();
+        }
+
+        bodies
+    });
+
+    (url, handle)
+}
+
+#[test]
+fn test_post_batches() {
+    let (url, server) = serve(&[200, 200, 200]);
+
+    let output = cargo_bin_cmd!()
+        .args(["-n", "5", "--post", &url, "--post-batch", "2", "ulid"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ids: Vec<&str> = stdout.lines().collect();
+    let bodies = server.join().unwrap();
+
+    assert_eq!(ids.len(), 5);
+    assert_eq!(
+        bodies,
+        [
+            format!(r#"{{"ids":["{}","{}"]}}"#, ids[0], ids[1]),
+            format!(r#"{{"ids":["{}","{}"]}}"#, ids[2], ids[3]),
+            format!(r#"{{"ids":["{}"]}}"#, ids[4]),
+        ]
+    );
+}
+
+#[test]
+fn test_post_field_quiet() {
+    let (url, server) = serve(&[200]);
+
+    cargo_bin_cmd!()
+        .args(["--post", &url, "--post-field", "uuids", "--quiet", "oid"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    let bodies = server.join().unwrap();
+
+    assert_eq!(bodies.len(), 1);
+    assert!(
+        predicate::str::is_match(r#"^\{"uuids":\["[0-9a-f]{24}"\]\}$"#)
+            .unwrap()
+            .eval(&bodies[0])
+    );
+}
+
+#[test]
+fn test_post_retries_unavailable() {
+    let (url, server) = serve(&[503, 200]);
+
+    cargo_bin_cmd!()
+        .args(["-n", "3", "--post", &url, "uuid"])
+        .assert()
+        .success();
+
+    let bodies = server.join().unwrap();
+
+    // The same batch is sent again after the failure
+    assert_eq!(bodies.len(), 2);
+    assert_eq!(bodies[0], bodies[1]);
+}
+
+#[test]
+fn test_post_fails_on_persistent_error() {
+    let (url, server) = serve(&[500, 500, 500, 500]);
+
+    cargo_bin_cmd!()
+        .args(["--post", &url, "uuid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "failed after 4 attempts: status 500",
+        ));
+
+    assert_eq!(server.join().unwrap().len(), 4);
+}
+
+#[test]
+fn test_post_fails_on_client_error() {
+    let (url, server) = serve(&[404]);
+
+    cargo_bin_cmd!()
+        .args(["--post", &url, "uuid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("failed with status 404"));
+
+    assert_eq!(server.join().unwrap().len(), 1);
+}
+
+#[test]
+fn test_post_quiet_conflicts_with_single() {
+    cargo_bin_cmd!()
+        .args(["--post", "http://localhost:1", "--quiet", "--single", "oid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
@@ -0,0 +1,111 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_preset_postgres_v7() {
+    cargo_bin_cmd!()
+        .args(["--preset", "postgres-v7"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"^[0-9a-f]{8}-[0-9a-f]{4}-7[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_preset_postgres_v7_monotonic() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000", "--preset", "postgres-v7"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let uuids: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(uuids.len(), 1000);
+    assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+#[cfg(feature = "objectid")]
+fn test_preset_mongo() {
+    cargo_bin_cmd!()
+        .args(["--preset", "mongo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9a-f]{24}\n$").unwrap());
+}
+
+#[test]
+fn test_preset_dynamo() {
+    cargo_bin_cmd!()
+        .args(["--preset", "dynamo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9A-Za-z]{27}\n$").unwrap());
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_preset_kafka_key() {
+    cargo_bin_cmd!()
+        .args(["--preset=kafka-key"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9A-HJKMNP-TV-Z]{26}\n$").unwrap());
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_preset_explicit_flag_overrides() {
+    cargo_bin_cmd!()
+        .args(["--preset", "postgres-v7", "uuid", "--version", "4"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-").unwrap());
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_preset_with_subcommand_options() {
+    cargo_bin_cmd!()
+        .args([
+            "--preset",
+            "postgres-v7",
+            "uuid",
+            "--timestamp",
+            "1700000000000000000",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("018bcfe5-6800-7"));
+}
+
+#[test]
+fn test_preset_conflicting_subcommand() {
+    cargo_bin_cmd!()
+        .args(["--preset", "mongo", "ulid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "preset 'mongo' expands to the 'oid' command and cannot be used with 'ulid'",
+        ));
+}
+
+#[test]
+fn test_preset_invalid() {
+    cargo_bin_cmd!()
+        .args(["--preset", "oracle"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value 'oracle' for '--preset <PRESET>'",
+        ));
+}
@@ -0,0 +1,75 @@
+#![cfg(unix)]
+
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Reports ObjectIds ending in 0-7 as existing.
+#[cfg(feature = "objectid")]
+const STUB_PROBE: &str = "case {} in *[0-7]) exit 0;; *) exit 1;; esac";
+
+#[test]
+#[cfg(feature = "objectid")]
+fn test_probe_skips_existing() {
+    cargo_bin_cmd!()
+        .args(["-n", "50", "--probe-cmd", STUB_PROBE, "oid"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^([0-9a-f]{23}[89a-f]\n){50}$").unwrap());
+}
+
+#[test]
+#[cfg(feature = "objectid")]
+fn test_probe_parallel_skips_existing() {
+    cargo_bin_cmd!()
+        .args([
+            "-n",
+            "50",
+            "--probe-cmd",
+            STUB_PROBE,
+            "--probe-parallel",
+            "8",
+        ])
+        .args(["oid", "--timestamp", "1609459200"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^(5fee6600[0-9a-f]{15}[89a-f]\n){50}$").unwrap());
+}
+
+#[test]
+fn test_probe_passes_identifiers_verbatim() {
+    let path = std::env::temp_dir().join("spwd-test-probe-verbatim.txt");
+    let _ = std::fs::remove_file(&path);
+    let probe = format!("printf '%s\\n' {{}} >> '{}'; exit 1", path.display());
+
+    let output = cargo_bin_cmd!()
+        .args(["-n", "20", "--probe-cmd", &probe])
+        .args(["nanoid", "--alphabet", "'\" $;`\\", "--size", "8"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_probe_command_failure() {
+    cargo_bin_cmd!()
+        .args(["--probe-cmd", "test -n {} && exit 2", "uuid"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("with exit status: 2"));
+}
+
+#[test]
+fn test_probe_command_without_placeholder() {
+    cargo_bin_cmd!()
+        .args(["--probe-cmd", "true", "uuid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must contain a {} placeholder"));
+}
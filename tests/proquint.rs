@@ -0,0 +1,104 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Consonants and vowels of proquint syllables.
+const CONSONANTS: &str = "bdfghjklmnprstvz";
+const VOWELS: &str = "aiou";
+
+/// Runs the proquint command with the given arguments, returning the output lines.
+fn proquints(args: &[&str]) -> Vec<String> {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Checks that a syllable alternates consonants and vowels.
+fn is_syllable(syllable: &str) -> bool {
+    syllable.len() == 5
+        && syllable
+            .chars()
+            .enumerate()
+            .all(|(index, c)| match index % 2 {
+                0 => CONSONANTS.contains(c),
+                _ => VOWELS.contains(c),
+            })
+}
+
+#[test]
+fn test_proquint_default() {
+    let lines = proquints(&["proquint"]);
+
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].len(), 11);
+    assert!(lines[0].split('-').all(is_syllable), "{}", lines[0]);
+}
+
+#[test]
+fn test_proquint_bits() {
+    for (bits, syllables) in [(16, 1), (64, 4), (128, 8)] {
+        let lines = proquints(&["proquint", "--bits", &bits.to_string()]);
+
+        assert_eq!(lines[0].split('-').count(), syllables, "{}", lines[0]);
+        assert!(lines[0].split('-').all(is_syllable), "{}", lines[0]);
+    }
+}
+
+#[test]
+fn test_multiple_proquints() {
+    let mut lines = proquints(&["-n", "10", "proquint", "--bits", "64"]);
+
+    assert_eq!(lines.len(), 10);
+    lines.sort_unstable();
+    lines.dedup();
+    assert_eq!(lines.len(), 10);
+}
+
+#[test]
+fn test_proquint_from_hex() {
+    // 127.0.0.1 and 63.84.220.193, from the proquint paper
+    let lines = proquints(&["-n", "2", "proquint", "--from-hex", "7F000001"]);
+
+    assert_eq!(lines, ["lusab-babad", "lusab-babad"]);
+
+    cargo_bin_cmd!()
+        .args(["proquint", "--from-hex", "3f54dcc1"])
+        .assert()
+        .success()
+        .stdout("gutih-tugad\n");
+}
+
+#[test]
+fn test_proquint_bits_not_multiple_of_16() {
+    cargo_bin_cmd!()
+        .args(["proquint", "--bits", "24"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "bits must be a positive multiple of 16, got 24",
+        ));
+}
+
+#[test]
+fn test_proquint_from_hex_partial_word() {
+    cargo_bin_cmd!()
+        .args(["proquint", "--from-hex", "7f0000"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "data length must be a multiple of 4 characters",
+        ));
+}
+
+#[test]
+fn test_proquint_from_hex_conflicts_with_bits() {
+    cargo_bin_cmd!()
+        .args(["proquint", "--bits", "32", "--from-hex", "7f000001"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
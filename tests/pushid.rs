@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_pushid_generation() {
+    cargo_bin_cmd!()
+        .arg("pushid")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[-0-9A-Z_a-z]{20}\n$").unwrap());
+}
+
+#[test]
+fn test_pushid_with_timestamp() {
+    // 1700000000000 ms is 0, 24, 47, 15, 57, 22, 32, 0 in 6-bit groups
+    cargo_bin_cmd!()
+        .args(["pushid", "--timestamp", "1700000000000"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("-NjEtLV-"));
+}
+
+#[test]
+fn test_multiple_pushids_sorted_and_unique() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000", "pushid"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ids: Vec<&str> = stdout.lines().collect();
+
+    assert!(output.status.success());
+    assert_eq!(ids.len(), 1000);
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_multiple_pushids_with_timestamp() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000", "pushid", "--timestamp", "1700000000000"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ids: Vec<&str> = stdout.lines().collect();
+
+    // Every push ID shares the prefix, and the suffix is incremented in between
+    assert!(ids.iter().all(|id| id.starts_with("-NjEtLV-")));
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 1000);
+}
+
+#[test]
+fn test_pushid_timestamp_out_of_range() {
+    cargo_bin_cmd!()
+        .args(["pushid", "--timestamp", "281474976710656"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "281474976710656 is not in 0..=281474976710655",
+        ));
+}
@@ -0,0 +1,75 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_redact_generated_v1() {
+    let output = cargo_bin_cmd!()
+        .args(["uuid", "-v", "1", "--timestamp", "1234567890000000000"])
+        .output()
+        .unwrap();
+    let uuid = String::from_utf8(output.stdout).unwrap();
+    let uuid = uuid.trim();
+
+    let output = cargo_bin_cmd!().args(["redact", uuid]).output().unwrap();
+    assert!(output.status.success());
+
+    let redacted = String::from_utf8(output.stdout).unwrap();
+    let redacted = redacted.trim();
+
+    // Only the node id (the last 12 hex characters) differs
+    assert_eq!(redacted[..24], uuid[..24]);
+    assert_eq!(&redacted[24..], "020000000000");
+}
+
+#[test]
+fn test_redact_v6_with_zero() {
+    cargo_bin_cmd!()
+        .args(["redact", "--zero", "1ddfa267-0d9b-6500-8000-da81dd7abf20"])
+        .assert()
+        .success()
+        .stdout("1ddfa267-0d9b-6500-8000-000000000000\n");
+}
+
+#[test]
+fn test_redact_stdin() {
+    cargo_bin_cmd!()
+        .arg("redact")
+        .write_stdin(
+            "70d9b500-fa26-11dd-8000-da81dd7abf20\n\n1ddfa267-0d9b-6500-8000-da81dd7abf20\n",
+        )
+        .assert()
+        .success()
+        .stdout("70d9b500-fa26-11dd-8000-020000000000\n1ddfa267-0d9b-6500-8000-020000000000\n");
+}
+
+#[test]
+fn test_redact_rejects_v4() {
+    cargo_bin_cmd!()
+        .args(["redact", "9e4a5f33-f01c-47ee-8527-18ec1f0151d5"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "only versions 1 and 6 have a node id to redact",
+        ));
+}
+
+#[test]
+fn test_redact_dash_reads_stdin() {
+    cargo_bin_cmd!()
+        .args(["redact", "-"])
+        .write_stdin("70d9b500-fa26-11dd-8000-da81dd7abf20\n")
+        .assert()
+        .success()
+        .stdout("70d9b500-fa26-11dd-8000-020000000000\n");
+}
+
+#[test]
+fn test_redact_positionals_preferred_over_stdin() {
+    cargo_bin_cmd!()
+        .args(["redact", "1ddfa267-0d9b-6500-8000-da81dd7abf20"])
+        .write_stdin("70d9b500-fa26-11dd-8000-da81dd7abf20\n")
+        .assert()
+        .success()
+        .stdout("1ddfa267-0d9b-6500-8000-020000000000\n");
+}
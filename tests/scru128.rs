@@ -0,0 +1,75 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_scru128_generation() {
+    cargo_bin_cmd!()
+        .arg("scru128")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9a-z]{25}\n$").unwrap());
+}
+
+#[test]
+fn test_scru128_with_timestamp() {
+    let output = cargo_bin_cmd!()
+        .args(["scru128", "--timestamp", "1700000000000"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let value = u128::from_str_radix(stdout.trim(), 36).unwrap();
+
+    // The timestamp takes the top 48 bits
+    assert_eq!(value >> 80, 1_700_000_000_000);
+}
+
+#[test]
+fn test_scru128_batch_sorted() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000", "scru128"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ids: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(ids.len(), 1000);
+    assert!(ids.iter().all(|id| id.len() == 25));
+    assert!(ids.iter().all(|id| {
+        id.bytes()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_lowercase())
+    }));
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_scru128_batch_with_timestamp_sorted() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000", "scru128", "--timestamp", "1700000000000"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ids: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(ids.len(), 1000);
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_scru128_timestamp_out_of_range() {
+    cargo_bin_cmd!()
+        .args(["scru128", "--timestamp", "281474976710656"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "281474976710656 is not in 0..=281474976710655",
+        ));
+}
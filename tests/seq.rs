@@ -0,0 +1,78 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_seq_default() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "seq"])
+        .assert()
+        .success()
+        .stdout("1\n2\n3\n");
+}
+
+#[test]
+fn test_seq_start_step_pad_prefix() {
+    cargo_bin_cmd!()
+        .args([
+            "-n", "3", "seq", "--start", "98", "--step", "2", "--pad", "6", "--prefix", "INV-",
+        ])
+        .assert()
+        .success()
+        .stdout("INV-000098\nINV-000100\nINV-000102\n");
+}
+
+#[test]
+fn test_seq_overflow_mid_run() {
+    cargo_bin_cmd!()
+        .args([
+            "-n",
+            "5",
+            "seq",
+            "--start",
+            "18446744073709551610",
+            "--step",
+            "3",
+        ])
+        .assert()
+        .failure()
+        .stdout("18446744073709551610\n18446744073709551613\n")
+        .stderr(predicate::str::contains(
+            "sequence overflows 64 bits after 18446744073709551613 with a step of 3",
+        ));
+}
+
+#[test]
+fn test_seq_last_value_fits() {
+    cargo_bin_cmd!()
+        .args(["-n", "2", "seq", "--start", "18446744073709551614"])
+        .assert()
+        .success()
+        .stdout("18446744073709551614\n18446744073709551615\n");
+}
+
+#[test]
+fn test_seq_negative_step() {
+    cargo_bin_cmd!()
+        .args(["seq", "--step", "-1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("step must be positive, got -1"));
+}
+
+#[test]
+fn test_seq_zero_step() {
+    cargo_bin_cmd!()
+        .args(["seq", "--step", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("step must be positive, got 0"));
+}
+
+#[test]
+fn test_seq_pad_too_wide() {
+    cargo_bin_cmd!()
+        .args(["seq", "--pad", "21"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("21 is not in 0..=20"));
+}
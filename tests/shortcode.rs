@@ -0,0 +1,104 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_shortcode_generation() {
+    cargo_bin_cmd!()
+        .args(["shortcode", "--expected", "100000"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[2-9A-HJ-NP-Za-km-z]{9}\n$").unwrap());
+}
+
+#[test]
+fn test_shortcode_verbose() {
+    cargo_bin_cmd!()
+        .args([
+            "-n",
+            "5",
+            "shortcode",
+            "--expected",
+            "100k",
+            "--alphabet",
+            "numeric",
+            "--verbose",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"(?m)^([0-9]{16}\n){5}$").unwrap())
+        .stderr("length: 16\n");
+}
+
+#[test]
+fn test_shortcode_with_probability() {
+    cargo_bin_cmd!()
+        .args([
+            "shortcode",
+            "--expected",
+            "1000",
+            "--alphabet",
+            "no-lookalikes",
+            "--probability",
+            "0.01",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[2-9A-HJ-NP-Za-km-z]{5}\n$").unwrap());
+}
+
+#[test]
+fn test_shortcode_invalid_probability() {
+    cargo_bin_cmd!()
+        .args(["shortcode", "--expected", "1000", "--probability", "1.5"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("probability must be a number"));
+}
+
+#[test]
+fn test_shortcode_exclude_substrings() {
+    let path = std::env::temp_dir().join("spwd-test-shortcode-banned.txt");
+    std::fs::write(&path, "0\n1\n\n2\n").unwrap();
+
+    let output = cargo_bin_cmd!()
+        .args(["-n", "200", "shortcode", "--expected", "10", "--alphabet"])
+        .args(["numeric", "--exclude-substrings", path.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 200);
+    assert!(stdout.lines().all(|code| !code.contains(['0', '1', '2'])));
+}
+
+#[test]
+fn test_shortcode_exclude_every_symbol() {
+    let path = std::env::temp_dir().join("spwd-test-shortcode-banned-all.txt");
+    std::fs::write(&path, "0\n1\n2\n3\n4\n5\n6\n7\n8\n9\n").unwrap();
+
+    cargo_bin_cmd!()
+        .args(["shortcode", "--expected", "10", "--alphabet", "numeric"])
+        .args(["--exclude-substrings", path.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "banned substrings leave 0 alphabet symbol(s), but at least 2 are needed",
+        ));
+}
+
+#[test]
+fn test_shortcode_exclude_substrings_warning() {
+    let path = std::env::temp_dir().join("spwd-test-shortcode-banned-warning.txt");
+    std::fs::write(&path, "00\n11\n22\n33\n44\n55\n66\n77\n88\n99\n").unwrap();
+
+    cargo_bin_cmd!()
+        .args(["shortcode", "--expected", "1000", "--alphabet", "numeric"])
+        .args(["--exclude-substrings", path.to_str().unwrap()])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "warning: banned substrings exclude about",
+        ));
+}
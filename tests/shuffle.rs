@@ -0,0 +1,75 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Snowflakes with a fixed timestamp are the same sequence on every run.
+const SNOWFLAKES: [&str; 5] = [
+    "snowflake",
+    "--timestamp",
+    "1700000000000",
+    "--worker-id",
+    "7",
+];
+
+/// Runs the binary with the given arguments, returning the output lines.
+fn lines(args: &[&str]) -> Vec<String> {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Generates 200 snowflakes with the given top-level options, returning the lines.
+fn snowflakes(options: &[&str]) -> Vec<String> {
+    lines(&[&["-n", "200"], options, &SNOWFLAKES].concat())
+}
+
+#[test]
+fn test_shuffle_seeded_reproducible() {
+    let options = ["--shuffle", "--seed", "42"];
+
+    assert_eq!(snowflakes(&options), snowflakes(&options));
+}
+
+#[test]
+fn test_shuffle_keeps_values() {
+    let sorted = snowflakes(&[]);
+    let mut shuffled = snowflakes(&["--shuffle", "--seed", "42"]);
+
+    // Same values, in another order
+    assert_ne!(shuffled, sorted);
+    shuffled.sort_unstable();
+    assert_eq!(shuffled, sorted);
+}
+
+#[test]
+fn test_shuffle_seeds_differ() {
+    let first = snowflakes(&["--shuffle", "--seed", "1"]);
+    let second = snowflakes(&["--shuffle", "--seed", "2"]);
+
+    assert_ne!(first, second);
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_shuffle_unseeded_ulids() {
+    let mut ulids = lines(&["-n", "1000", "--shuffle", "ulid"]);
+
+    assert_eq!(ulids.len(), 1000);
+    assert!(!ulids.is_sorted());
+    ulids.sort_unstable();
+    ulids.dedup();
+    assert_eq!(ulids.len(), 1000);
+}
+
+#[test]
+fn test_seed_requires_shuffle() {
+    cargo_bin_cmd!()
+        .args(["--seed", "42", "ulid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--shuffle"));
+}
@@ -0,0 +1,87 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Runs the slug command with the given arguments, returning the output lines.
+fn slugs(args: &[&str]) -> Vec<String> {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Checks that a part of a slug is a lowercase word.
+fn is_word(part: &str) -> bool {
+    !part.is_empty() && part.bytes().all(|c| c.is_ascii_lowercase())
+}
+
+#[test]
+fn test_slug_default() {
+    let lines = slugs(&["slug"]);
+    let parts: Vec<&str> = lines[0].split('-').collect();
+
+    assert_eq!(lines.len(), 1);
+    assert_eq!(parts.len(), 3, "{}", lines[0]);
+    assert!(is_word(parts[0]) && is_word(parts[1]));
+    assert_eq!(parts[2].len(), 4);
+    assert!(parts[2].bytes().all(|c| c.is_ascii_digit()));
+}
+
+#[test]
+fn test_slug_three_words() {
+    let lines = slugs(&["-n", "10", "slug", "--words", "3", "--separator", "."]);
+
+    assert_eq!(lines.len(), 10);
+    for line in lines {
+        let parts: Vec<&str> = line.split('.').collect();
+
+        assert_eq!(parts.len(), 4, "{line}");
+        assert!(parts[..3].iter().all(|part| is_word(part)), "{line}");
+    }
+}
+
+#[test]
+fn test_slug_digits() {
+    for digits in 1..=6 {
+        let lines = slugs(&["slug", "--digits", &digits.to_string()]);
+        let number = lines[0].rsplit('-').next().unwrap();
+
+        assert_eq!(number.len(), digits, "{}", lines[0]);
+        assert!(number.bytes().all(|c| c.is_ascii_digit()));
+    }
+}
+
+#[test]
+fn test_slug_without_digits() {
+    let lines = slugs(&["-n", "20", "slug", "--digits", "0"]);
+
+    for line in lines {
+        let parts: Vec<&str> = line.split('-').collect();
+
+        assert_eq!(parts.len(), 2, "{line}");
+        assert!(parts.iter().all(|part| is_word(part)), "{line}");
+    }
+}
+
+#[test]
+fn test_slug_invalid_words() {
+    for words in ["1", "4"] {
+        cargo_bin_cmd!()
+            .args(["slug", "--words", words])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("is not in 2..=3"));
+    }
+}
+
+#[test]
+fn test_slug_too_many_digits() {
+    cargo_bin_cmd!()
+        .args(["slug", "--digits", "7"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("7 is not in 0..=6"));
+}
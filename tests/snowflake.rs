@@ -0,0 +1,97 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_snowflake_generation() {
+    cargo_bin_cmd!()
+        .arg("snowflake")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[1-9][0-9]{0,18}\n$").unwrap());
+}
+
+#[test]
+fn test_snowflake_with_timestamp_and_worker_id() {
+    // (1700000000000 - 1288834974657) << 22 | 7 << 12
+    cargo_bin_cmd!()
+        .args([
+            "snowflake",
+            "--timestamp",
+            "1700000000000",
+            "--worker-id",
+            "7",
+        ])
+        .assert()
+        .success()
+        .stdout("1724551110456274944\n");
+}
+
+#[test]
+fn test_snowflake_with_epoch() {
+    cargo_bin_cmd!()
+        .args(["snowflake", "--epoch", "1000", "--timestamp", "1001"])
+        .assert()
+        .success()
+        .stdout("4194304\n");
+}
+
+#[test]
+fn test_snowflakes_strictly_increasing() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "4096", "snowflake", "--timestamp", "1700000000000"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let snowflakes: Vec<u64> = stdout.lines().map(|line| line.parse().unwrap()).collect();
+
+    assert_eq!(snowflakes.len(), 4096);
+    assert!(snowflakes.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_snowflakes_without_timestamp_unique() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "5000", "snowflake"])
+        .output()
+        .unwrap();
+
+    // Running out of sequence numbers within a millisecond is an error, never a duplicate
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let snowflakes: Vec<u64> = stdout.lines().map(|line| line.parse().unwrap()).collect();
+
+    assert!(snowflakes.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_snowflake_sequence_overflow() {
+    cargo_bin_cmd!()
+        .args(["-n", "4097", "snowflake", "--timestamp", "1700000000000"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "snowflake sequence exhausted: more than 4096 identifiers in one millisecond",
+        ));
+}
+
+#[test]
+fn test_snowflake_worker_id_out_of_range() {
+    cargo_bin_cmd!()
+        .args(["snowflake", "--worker-id", "1024"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("1024 is not in 0..=1023"));
+}
+
+#[test]
+fn test_snowflake_timestamp_before_epoch() {
+    cargo_bin_cmd!()
+        .args(["snowflake", "--epoch", "1000", "--timestamp", "999"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "snowflake timestamps must be between the epoch (1000) and 2199023256551 milliseconds",
+        ));
+}
@@ -0,0 +1,106 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Identifiers of every keyed type, a second apart from 2023-11-14T22:13:17Z, in order.
+#[cfg(feature = "ulid")]
+const CHRONOLOGICAL: [&str; 7] = [
+    "9e4a5f33-f01c-47ee-8527-18ec1f0151d5",
+    "02e5fc80-833b-11ee-8000-000000000000",
+    "6553f0fe0000000000000000",
+    "01HF7YAS0R0000000000000000",
+    "018bcfe5-6800-7000-8000-000000000000",
+    "1ee833b0-5485-6680-8000-000000000000",
+    "6553f1020000000000000000",
+];
+
+/// Prints the keys and identifiers of the sortkey command, one pair per line.
+fn sortkey(ids: &[&str]) -> Vec<(String, String)> {
+    let output = cargo_bin_cmd!().arg("sortkey").args(ids).output().unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let (key, id) = line.split_once('\t').unwrap();
+            (key.to_owned(), id.to_owned())
+        })
+        .collect()
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_sortkey_mixed_types_sort_chronologically() {
+    let mut shuffled = CHRONOLOGICAL;
+    shuffled.reverse();
+    shuffled.swap(1, 4);
+
+    let mut keyed = sortkey(&shuffled);
+    keyed.sort();
+
+    let ids: Vec<_> = keyed.into_iter().map(|(_, id)| id).collect();
+    assert_eq!(ids, CHRONOLOGICAL);
+}
+
+#[test]
+fn test_sortkey_key_width() {
+    let ids = [
+        "6553f100aaaaaaaaaaaaaaaa",
+        "04afc000-833b-11ee-8000-000000000000",
+        "1EE833B0-4AFC-6000-8000-000000000000",
+        "018bcfe5680070008000000000000000",
+        "{9e4a5f33-f01c-47ee-8527-18ec1f0151d5}",
+        "ffffffff-ffff-ffff-ffff-ffffffffffff",
+    ];
+
+    for (key, id) in sortkey(&ids) {
+        assert_eq!(key.len(), 50, "{id}");
+        assert!(
+            key.bytes()
+                .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase())
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_sortkey_ulid_key_width() {
+    for (key, id) in sortkey(&["01HF7YAT00ZZZZZZZZZZZZZZZZ", "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"]) {
+        assert_eq!(key.len(), 50, "{id}");
+    }
+}
+
+#[test]
+fn test_sortkey_same_instant_across_types() {
+    let keyed = sortkey(&[
+        "6553f1000000000000000000",
+        "04afc000-833b-11ee-8000-000000000000",
+        "1ee833b0-4afc-6000-8000-000000000000",
+        "018bcfe5-6800-7000-8000-000000000000",
+    ]);
+
+    for (key, id) in keyed {
+        assert!(key.starts_with("01ee833b04afc000"), "{id}");
+    }
+}
+
+#[test]
+fn test_sortkey_stdin() {
+    cargo_bin_cmd!()
+        .arg("sortkey")
+        .write_stdin("6553f1000000000000000000\n")
+        .assert()
+        .success()
+        .stdout("01ee833b04afc000016553f100000000000000000000000000\t6553f1000000000000000000\n");
+}
+
+#[test]
+fn test_sortkey_unrecognized() {
+    cargo_bin_cmd!()
+        .args(["sortkey", "6553f1000000000000000000", "not-an-id"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "\"not-an-id\": unrecognized identifier",
+        ));
+}
@@ -0,0 +1,79 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_sqids_encode_numbers() {
+    cargo_bin_cmd!()
+        .args(["sqids", "1", "2", "3"])
+        .assert()
+        .success()
+        .stdout("86Rf07\n");
+}
+
+#[test]
+fn test_sqids_with_min_length() {
+    cargo_bin_cmd!()
+        .args(["sqids", "--min-length", "10", "42"])
+        .assert()
+        .success()
+        .stdout("JgaEBgznCp\n");
+}
+
+#[test]
+fn test_sqids_with_alphabet() {
+    cargo_bin_cmd!()
+        .args(["sqids", "--alphabet", "abcdefghij", "1", "2", "3"])
+        .assert()
+        .success()
+        .stdout("ibafgb\n");
+}
+
+#[test]
+fn test_multiple_sqids_without_numbers() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "5", "sqids", "--min-length", "6"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines: Vec<&str> = stdout.lines().collect();
+
+    assert!(output.status.success());
+    assert_eq!(lines.len(), 5);
+    assert!(lines.iter().all(|line| line.len() >= 6));
+
+    // Sequential numbers never encode to the same string
+    lines.sort_unstable();
+    lines.dedup();
+    assert_eq!(lines.len(), 5);
+}
+
+#[test]
+fn test_sqids_alphabet_too_short() {
+    cargo_bin_cmd!()
+        .args(["sqids", "--alphabet", "ab", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "alphabet must contain at least 3 symbols, got 2",
+        ));
+}
+
+#[test]
+fn test_sqids_alphabet_multibyte() {
+    cargo_bin_cmd!()
+        .args(["sqids", "--alphabet", "abcé", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "alphabet must not contain multibyte symbols, got 'é'",
+        ));
+}
+
+#[test]
+fn test_sqids_negative_number() {
+    cargo_bin_cmd!()
+        .args(["sqids", "--", "-1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value '-1'"));
+}
@@ -0,0 +1,113 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_stamp_generation() {
+    cargo_bin_cmd!()
+        .arg("stamp")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9]{8}T[0-9]{6}Z-[0-9a-hjkmnp-tv-z]{6}\n$").unwrap());
+}
+
+#[test]
+fn test_stamp_with_timestamp() {
+    cargo_bin_cmd!()
+        .args(["stamp", "--timestamp", "2024-06-13T14:22:33.5Z"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^20240613T142233Z-[0-9a-z]{6}\n$").unwrap());
+}
+
+#[test]
+fn test_stamp_milliseconds() {
+    cargo_bin_cmd!()
+        .args([
+            "stamp",
+            "--precision",
+            "milliseconds",
+            "--suffix-length",
+            "3",
+            "--timestamp",
+            "1718288553042",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^20240613T142233042Z-[0-9a-z]{3}\n$").unwrap());
+}
+
+#[test]
+fn test_stamp_suffix_length_out_of_range() {
+    for length in ["0", "13"] {
+        cargo_bin_cmd!()
+            .args(["stamp", "--suffix-length", length])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "invalid value '{length}' for '--suffix-length <SUFFIX_LENGTH>': {length} is not in 1..=12"
+            )));
+    }
+}
+
+#[test]
+fn test_stamp_timestamp_past_year_9999() {
+    cargo_bin_cmd!()
+        .args(["stamp", "--timestamp", "253402300800000"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "253402300800000 is not in 0..=253402300799999 milliseconds \
+             (1970-01-01T00:00:00Z to 9999-12-31T23:59:59.999Z)",
+        ));
+}
+
+#[test]
+fn test_stamps_sort_by_generation_time() {
+    let mut stamps = Vec::new();
+    for timestamp in [
+        "2024-06-13T14:22:33Z",
+        "2024-06-13T14:22:34Z",
+        "2025-01-01T00:00:00Z",
+    ] {
+        let output = cargo_bin_cmd!()
+            .args(["-n", "500", "stamp", "--timestamp", timestamp])
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        stamps.extend(
+            String::from_utf8(output.stdout)
+                .unwrap()
+                .lines()
+                .map(str::to_owned),
+        );
+    }
+
+    // Within a second the suffixes count up, so the order is strict in and across runs
+    assert_eq!(stamps.len(), 1500);
+    assert!(stamps.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(stamps.iter().all(|stamp| {
+        stamp
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'_' || c == b'-')
+    }));
+}
+
+#[test]
+fn test_stamp_suffix_exhausted() {
+    cargo_bin_cmd!()
+        .args([
+            "-n",
+            "40",
+            "stamp",
+            "--suffix-length",
+            "1",
+            "--timestamp",
+            "0",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "stamp suffixes exhausted within a second; use a longer '--suffix-length'",
+        ));
+}
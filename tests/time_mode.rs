@@ -0,0 +1,122 @@
+#[cfg(any(feature = "uuid", feature = "ulid", feature = "objectid"))]
+use std::collections::HashSet;
+
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Generates ULIDs and returns the distinct millisecond timestamps they encode.
+#[cfg(feature = "ulid")]
+fn ulid_timestamps(args: &[&str]) -> HashSet<String> {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ulids: Vec<&str> = stdout.lines().collect();
+
+    // Whatever the time mode, identifiers stay distinct
+    assert_eq!(ulids.iter().collect::<HashSet<_>>().len(), ulids.len());
+
+    ulids.iter().map(|ulid| ulid[..10].to_owned()).collect()
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_time_mode_per_id_spans_milliseconds() {
+    let timestamps = ulid_timestamps(&["-n", "20k", "--time-mode", "per-id", "ulid"]);
+
+    assert!(timestamps.len() > 1);
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_time_mode_fixed_single_instant() {
+    let timestamps = ulid_timestamps(&["-n", "20k", "--time-mode", "fixed", "ulid"]);
+
+    assert_eq!(timestamps.len(), 1);
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_time_mode_fixed_uuid_v7() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "50k", "--time-mode", "fixed", "uuid", "-v", "7"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    let millis: HashSet<&str> = stdout.lines().map(|uuid| &uuid[..13]).collect();
+
+    assert_eq!(millis.len(), 1);
+    assert!(stdout.lines().collect::<Vec<_>>().is_sorted());
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_time_mode_fixed_uuid_v1_v6_unique() {
+    // Past a full round of the 14-bit clock sequence
+    for version in ["1", "6"] {
+        let output = cargo_bin_cmd!()
+            .args(["-n", "20000", "--time-mode", "fixed", "uuid", "-v", version])
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(stdout.lines().collect::<HashSet<_>>().len(), 20000);
+    }
+}
+
+#[test]
+#[cfg(feature = "objectid")]
+fn test_time_mode_fixed_oid() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000", "--time-mode", "fixed", "oid"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    let seconds: HashSet<&str> = stdout.lines().map(|oid| &oid[..8]).collect();
+
+    assert_eq!(seconds.len(), 1);
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_time_mode_per_id_with_timestamp() {
+    cargo_bin_cmd!()
+        .args([
+            "--time-mode",
+            "per-id",
+            "ulid",
+            "--timestamp",
+            "1700000000000",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--time-mode per-id' cannot be used with '--timestamp <TIMESTAMP>'",
+        ));
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_time_mode_uuid_without_timestamps() {
+    cargo_bin_cmd!()
+        .args(["--time-mode", "fixed", "uuid", "-v", "4"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--time-mode <MODE>' cannot be used with '--version 4'",
+        ));
+}
+
+#[test]
+fn test_time_mode_without_clock() {
+    cargo_bin_cmd!()
+        .args(["--time-mode", "fixed", "nanoid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--time-mode <MODE>' cannot be used with 'nanoid'",
+        ));
+}
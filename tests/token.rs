@@ -0,0 +1,99 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Runs the token command with the given arguments, returning the output lines.
+fn tokens(args: &[&str]) -> Vec<String> {
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn test_token_default() {
+    let lines = tokens(&["token"]);
+
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].len(), 64);
+    assert!(
+        lines[0]
+            .bytes()
+            .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase())
+    );
+}
+
+#[test]
+fn test_token_lengths() {
+    let cases = [
+        ("hex", 1, 2),
+        ("hex", 32, 64),
+        ("base64", 1, 4),
+        ("base64", 32, 44),
+        ("base64url", 1, 2),
+        ("base64url", 32, 43),
+        ("base32", 1, 8),
+        ("base32", 32, 56),
+        ("base32", 35, 56),
+    ];
+
+    for (encoding, bytes, length) in cases {
+        let lines = tokens(&[
+            "token",
+            "--encoding",
+            encoding,
+            "--bytes",
+            &bytes.to_string(),
+        ]);
+
+        assert_eq!(lines[0].len(), length, "{encoding} of {bytes} bytes");
+    }
+}
+
+#[test]
+fn test_token_base64url_symbols() {
+    let lines = tokens(&["-n", "100", "token", "--encoding", "base64url"]);
+
+    assert!(lines.iter().all(|line| {
+        line.bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_')
+    }));
+}
+
+#[test]
+fn test_multiple_tokens() {
+    let mut lines = tokens(&["-n", "10", "token", "--bytes", "16"]);
+
+    assert_eq!(lines.len(), 10);
+    lines.sort_unstable();
+    lines.dedup();
+    assert_eq!(lines.len(), 10);
+}
+
+#[test]
+fn test_token_zero_bytes() {
+    cargo_bin_cmd!()
+        .args(["token", "--bytes", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("0 is not in 1..=1048576"));
+}
+
+#[test]
+fn test_token_too_many_bytes() {
+    cargo_bin_cmd!()
+        .args(["token", "--bytes", "1048577"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("1048577 is not in 1..=1048576"));
+}
+
+#[test]
+fn test_token_max_bytes() {
+    let lines = tokens(&["token", "--bytes", "1048576", "--encoding", "base64url"]);
+
+    assert_eq!(lines[0].len(), 1_398_102);
+}
@@ -0,0 +1,115 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_tsid_generation() {
+    cargo_bin_cmd!()
+        .arg("tsid")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9A-HJKMNP-TV-Z]{13}\n$").unwrap());
+}
+
+#[test]
+fn test_tsid_number_with_timestamp_and_node() {
+    // (1700000000000 - 1577836800000) << 22 | 7 << 12, plus a random 12-bit counter
+    let output = cargo_bin_cmd!()
+        .args([
+            "tsid",
+            "--node",
+            "7",
+            "--timestamp",
+            "1700000000000",
+            "--format",
+            "number",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let tsid: u64 = String::from_utf8(output.stdout)
+        .unwrap()
+        .trim_end()
+        .parse()
+        .unwrap();
+
+    assert_eq!(tsid >> 12, (122_163_200_000 << 10) | 7);
+}
+
+#[test]
+fn test_tsid_with_epoch() {
+    let output = cargo_bin_cmd!()
+        .args([
+            "tsid",
+            "--epoch",
+            "1000",
+            "--timestamp",
+            "1001",
+            "--node",
+            "0",
+            "--format",
+            "number",
+        ])
+        .output()
+        .unwrap();
+
+    let tsid: u64 = String::from_utf8(output.stdout)
+        .unwrap()
+        .trim_end()
+        .parse()
+        .unwrap();
+
+    assert_eq!(tsid >> 22, 1);
+}
+
+#[test]
+fn test_tsids_strictly_increasing() {
+    // More TSIDs than a counter holds, so the carry moves into the next milliseconds
+    let output = cargo_bin_cmd!()
+        .args(["-n", "10000", "tsid", "--timestamp", "1700000000000"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let tsids: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(tsids.len(), 10000);
+    assert!(tsids.iter().all(|tsid| tsid.len() == 13));
+    assert!(tsids.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_tsid_numbers_strictly_increasing() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000", "tsid", "--format", "number"])
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let tsids: Vec<u64> = stdout.lines().map(|line| line.parse().unwrap()).collect();
+
+    assert!(tsids.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_tsid_node_out_of_range() {
+    cargo_bin_cmd!()
+        .args(["tsid", "--node", "1024"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("1024 is not in 0..=1023"));
+}
+
+#[test]
+fn test_tsid_timestamp_before_epoch() {
+    cargo_bin_cmd!()
+        .args(["tsid", "--epoch", "1000", "--timestamp", "999"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "TSID timestamps must be between the epoch (1000) and 4398046512103 milliseconds",
+        ));
+}
@@ -0,0 +1,76 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_typeid_generation() {
+    cargo_bin_cmd!()
+        .args(["typeid", "--prefix", "user"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^user_[0-7][0-9a-hjkmnp-tv-z]{25}\n$").unwrap());
+}
+
+#[test]
+fn test_multiple_typeids_share_prefix() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "typeid", "--prefix", "order"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^(order_[0-7][0-9a-hjkmnp-tv-z]{25}\n){3}$").unwrap());
+}
+
+#[test]
+fn test_typeid_with_timestamp() {
+    // 1700000000123 ms is 0x018bcfe5687b, encoded as the first ten suffix symbols
+    cargo_bin_cmd!()
+        .args([
+            "typeid",
+            "--prefix",
+            "api_key",
+            "--timestamp",
+            "1700000000123",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("api_key_01hf7yat3v"));
+}
+
+#[test]
+fn test_typeid_empty_prefix() {
+    cargo_bin_cmd!()
+        .args(["typeid", "--prefix", ""])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-7][0-9a-hjkmnp-tv-z]{25}\n$").unwrap());
+}
+
+#[test]
+fn test_typeid_prefix_invalid_symbol() {
+    cargo_bin_cmd!()
+        .args(["typeid", "--prefix", "order-item"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value 'order-item' for '--prefix <PREFIX>': prefix must contain only lowercase ASCII letters and underscores, got '-'",
+        ));
+}
+
+#[test]
+fn test_typeid_prefix_trailing_underscore() {
+    cargo_bin_cmd!()
+        .args(["typeid", "--prefix", "user_"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "prefix must not start or end with an underscore",
+        ));
+}
+
+#[test]
+fn test_typeid_prefix_required() {
+    cargo_bin_cmd!()
+        .arg("typeid")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--prefix <PREFIX>"));
+}
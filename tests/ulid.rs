@@ -1,4 +1,8 @@
+#![cfg(feature = "ulid")]
+
 use assert_cmd::cargo_bin_cmd;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use predicates::prelude::*;
 
 #[test]
@@ -27,3 +31,65 @@ fn test_multiple_ulids() {
         .success()
         .stdout(predicate::str::is_match(r"(?m)^([0-9A-Z]{26}\n){5}$").unwrap());
 }
+
+#[test]
+fn test_ulid_with_check() {
+    cargo_bin_cmd!()
+        .args(["ulid", "--check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[0-9A-Z]{26}[0-9A-Z*~$=]\n$").unwrap());
+}
+
+#[test]
+fn test_ulid_format_int() {
+    let output = cargo_bin_cmd!()
+        .args(["ulid", "--format", "int", "--timestamp", "1609459200000"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let value: u128 = stdout.trim_end().parse().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(value >> 80, 1_609_459_200_000);
+}
+
+#[test]
+fn test_ulid_format_base64url() {
+    let output = cargo_bin_cmd!()
+        .args([
+            "ulid",
+            "--format",
+            "base64url",
+            "--timestamp",
+            "1609459200000",
+        ])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let bytes = URL_SAFE_NO_PAD.decode(stdout.trim_end()).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(bytes[..6], 1_609_459_200_000u64.to_be_bytes()[2..]);
+}
+
+#[test]
+fn test_ulid_with_check_and_timestamp() {
+    cargo_bin_cmd!()
+        .args(["ulid", "--check", "--timestamp", "1609459200000"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^01ETXKWW00[0-9A-Z]{16}[0-9A-Z*~$=]\n$").unwrap());
+}
+
+#[test]
+fn test_ulid_timestamp_syntaxes_agree() {
+    for timestamp in ["1609459200000", "1609459200s", "2021-01-01T00:00:00Z"] {
+        cargo_bin_cmd!()
+            .args(["ulid", "--timestamp", timestamp])
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with("01ETXKWW00"));
+    }
+}
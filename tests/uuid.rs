@@ -1,4 +1,12 @@
+#![cfg(feature = "uuid")]
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
 use assert_cmd::cargo_bin_cmd;
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
 use predicates::prelude::*;
 
 #[test]
@@ -80,67 +88,313 @@ fn test_uuid_v5_with_namespace_and_name() {
 }
 
 #[test]
-fn test_uuid_v7() {
+fn test_uuid_custom_namespace() {
+    for (version, expected) in [
+        ("3", "ffb1d110-911e-36e3-8d4d-a150b6eec921\n"),
+        ("5", "cf0cd550-1aaf-5335-bf06-e668f7373987\n"),
+    ] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                version,
+                "--namespace",
+                "1b4e28ba-2fa1-11d2-883f-0016d3cca427",
+                "--name",
+                "example.com",
+            ])
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_uuid_standard_namespace_as_uuid() {
     cargo_bin_cmd!()
-        .args(["uuid", "-v", "7"])
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "--name",
+            "example.com",
+        ])
         .assert()
         .success()
-        .stdout(
-            predicate::str::is_match(
-                r"^[0-9a-f]{8}-[0-9a-f]{4}-7[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
-            )
-            .unwrap(),
-        );
+        .stdout("cfbff0d1-9375-5685-968c-48ce8b15ae17\n");
 }
 
 #[test]
-fn test_uuid_v8_with_data() {
+fn test_uuid_invalid_namespace_rejected() {
     cargo_bin_cmd!()
-        .args(["uuid", "-v", "8", "--data", "0123456789abcdef"])
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "1b4e28ba-2fa1-11d2-883f",
+            "--name",
+            "example.com",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value '1b4e28ba-2fa1-11d2-883f' for '--namespace <NAMESPACE>': expected \
+             dns, oid, url, x500, or a UUID",
+        ));
+}
+
+#[test]
+fn test_uuid_namespace_name() {
+    for (version, expected) in [
+        ("3", "39758aca-97b6-39e3-8877-acf448cb0025\n"),
+        ("5", "c3a18e5b-32af-53a5-a780-74a1489477d0\n"),
+    ] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                version,
+                "--namespace",
+                "dns",
+                "--namespace-name",
+                "myapp.example.com",
+                "--name",
+                "alice",
+            ])
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_uuid_namespace_name_matches_two_steps() {
+    let namespace = cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "myapp.example.com",
+        ])
+        .output()
+        .unwrap();
+    let namespace = String::from_utf8(namespace.stdout).unwrap();
+
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            namespace.trim_end(),
+            "--name",
+            "alice",
+        ])
         .assert()
         .success()
-        .stdout(
-            predicate::str::is_match(
-                r"^[0-9a-f]{8}-[0-9a-f]{4}-8[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
-            )
-            .unwrap(),
-        );
+        .stdout("c3a18e5b-32af-53a5-a780-74a1489477d0\n");
 }
 
 #[test]
-fn test_uuid_v8_missing_data() {
+fn test_uuid_namespace_name_rejected() {
+    for (args, message) in [
+        (
+            &["-v", "4", "--namespace-name", "myapp.example.com"][..],
+            "the argument '--namespace-name <NAMESPACE_NAME>' cannot be used with '--version 4'",
+        ),
+        (
+            &[
+                "-v",
+                "5",
+                "--namespace-name",
+                "myapp.example.com",
+                "--name",
+                "alice",
+            ],
+            "--namespace <NAMESPACE>",
+        ),
+        (
+            &[
+                "-v",
+                "5",
+                "--namespace",
+                "dns",
+                "--namespace-name",
+                "myapp.example.com",
+            ],
+            "<--name <NAME>|--name-hex <HEX>|--name-file <PATH>|--names-stdin>",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .arg("uuid")
+            .args(args)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message));
+    }
+}
+
+#[test]
+fn test_uuid_name_hex() {
+    // An 8-byte device ID, against the digests of Python's `uuid` module
+    for (version, expected) in [
+        ("3", "b76514cf-3622-32e1-a5a2-6fea40671528\n"),
+        ("5", "eb0be6b2-f774-5402-b651-087289469f56\n"),
+    ] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                version,
+                "--namespace",
+                "dns",
+                "--name-hex",
+                "0011223344556677",
+            ])
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_uuid_name_hex_matches_name() {
+    // "example.com", in either case of hex digits
+    for hex in ["6578616d706c652e636f6d", "6578616D706C652E636F6D"] {
+        cargo_bin_cmd!()
+            .args(["uuid", "-v", "5", "--namespace", "dns", "--name-hex", hex])
+            .assert()
+            .success()
+            .stdout("cfbff0d1-9375-5685-968c-48ce8b15ae17\n");
+    }
+}
+
+#[test]
+fn test_uuid_name_hex_rejected() {
+    for (args, message) in [
+        (
+            &["-v", "5", "--namespace", "dns", "--name-hex", "abc"][..],
+            "name length must be an even number of hex characters, got 3",
+        ),
+        (
+            &["-v", "5", "--namespace", "dns", "--name-hex", "0xab"],
+            "name must contain only hex characters",
+        ),
+        (
+            &[
+                "-v",
+                "5",
+                "--namespace",
+                "dns",
+                "--name",
+                "alice",
+                "--name-hex",
+                "ab",
+            ],
+            "the argument '--name <NAME>' cannot be used with '--name-hex <HEX>'",
+        ),
+        (
+            &["-v", "4", "--name-hex", "ab"],
+            "the argument '--name-hex <HEX>' cannot be used with '--version 4'",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .arg("uuid")
+            .args(args)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message));
+    }
+}
+
+#[test]
+fn test_uuid_namespace_and_name_version_mismatch() {
+    for version in ["1", "4", "6", "7"] {
+        cargo_bin_cmd!()
+            .args(["uuid", "-v", version, "--namespace", "dns", "--name", "foo"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "the argument '--namespace <NAMESPACE>' cannot be used with '--version {version}'"
+            )));
+    }
+
+    // Version 8 takes them with `--hash` only
     cargo_bin_cmd!()
-        .args(["uuid", "-v", "8"])
+        .args(["uuid", "-v", "8", "--data", "00", "--name", "foo"])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("data"));
+        .stderr(predicate::str::contains(
+            "the argument '--name <NAME>' cannot be used with '--version 8'",
+        ));
+}
+
+/// Writes a name file for the given test, returning its path.
+fn name_file(name: &str, contents: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("spwd-name-{name}-{}.txt", std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path
 }
 
 #[test]
-fn test_multiple_uuids() {
+fn test_uuid_name_file_matches_name() {
+    let name = "  caf\u{e9} \t\u{1f980}\r\n";
+    let path = name_file("matches", name.as_bytes());
+
+    for version in ["3", "5"] {
+        let from_name = cargo_bin_cmd!()
+            .args(["uuid", "-v", version, "--namespace", "dns", "--name", name])
+            .output()
+            .unwrap();
+        let from_file = cargo_bin_cmd!()
+            .args(["uuid", "-v", version, "--namespace", "dns", "--name-file"])
+            .arg(&path)
+            .output()
+            .unwrap();
+
+        assert!(from_name.status.success());
+        assert!(from_file.status.success());
+        assert_eq!(from_file.stdout, from_name.stdout);
+    }
+}
+
+#[test]
+fn test_uuid_name_file_raw_bytes() {
+    let path = name_file("raw", &[0xff, 0x00, 0xfe]);
+
     cargo_bin_cmd!()
-        .args(["-n", "3", "uuid"])
+        .args(["uuid", "-v", "5", "--namespace", "dns", "--name-file"])
+        .arg(&path)
         .assert()
         .success()
-        .stdout(
-            predicate::str::is_match(
-                r"(?m)^([0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n){3}$",
-            )
-            .unwrap(),
-        );
+        .stdout(predicate::str::is_match(r"^[0-9a-f]{8}-[0-9a-f]{4}-5[0-9a-f]{3}-").unwrap());
 }
 
 #[test]
-fn test_uuid_v4_with_timestamp_rejected() {
+fn test_uuid_name_file_missing() {
+    let path = std::env::temp_dir().join("spwd-name-missing.txt");
+
     cargo_bin_cmd!()
-        .args(["uuid", "-v", "4", "--timestamp", "1234567890000000000"])
+        .args(["uuid", "-v", "5", "--namespace", "dns", "--name-file"])
+        .arg(&path)
         .assert()
         .failure()
-        .stderr(predicate::str::contains("timestamp"));
+        .stderr(predicate::str::contains(format!(
+            "for '--name-file <PATH>': failed to read {}",
+            path.display()
+        )));
 }
 
 #[test]
-fn test_uuid_v5_with_timestamp_rejected() {
+fn test_uuid_name_file_with_name_rejected() {
+    let path = name_file("conflict", b"alice");
+
     cargo_bin_cmd!()
         .args([
             "uuid",
@@ -149,111 +403,2150 @@ fn test_uuid_v5_with_timestamp_rejected() {
             "--namespace",
             "dns",
             "--name",
-            "test",
-            "--timestamp",
-            "1234567890000000000",
+            "alice",
+            "--name-file",
         ])
+        .arg(&path)
         .assert()
         .failure()
-        .stderr(predicate::str::contains("timestamp"));
+        .stderr(predicate::str::contains(
+            "the argument '--name <NAME>' cannot be used with '--name-file <PATH>'",
+        ));
 }
 
 #[test]
-fn test_uuid_v8_with_timestamp_rejected() {
+fn test_uuid_names_stdin() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "5", "--namespace", "dns", "--names-stdin"])
+        .write_stdin("alice\r\nbob\n\ncarol")
+        .assert()
+        .success()
+        .stdout(
+            "c2ef90b9-02bc-5d53-93cd-92652b6e1b41\n\
+             ef45d397-0411-5f5e-8940-9bdbdef3958b\n\
+             4ebd0208-8328-5d69-8c44-ec50939c0967\n\
+             17f615a4-4544-5549-9039-7ff246bc88a2\n",
+        );
+}
+
+#[test]
+fn test_uuid_names_stdin_skip_empty() {
     cargo_bin_cmd!()
         .args([
+            "-n",
+            "10",
             "uuid",
             "-v",
-            "8",
-            "--data",
-            "0123456789abcdef",
-            "--timestamp",
-            "1234567890000000000",
+            "3",
+            "--namespace",
+            "url",
+            "--names-stdin",
+            "--skip-empty",
+            "--simple",
+        ])
+        .write_stdin("alice\n\n\r\n")
+        .assert()
+        .success()
+        .stdout("bec4ca40bf7d377193a3901f3695e0c9\n");
+}
+
+#[test]
+fn test_uuid_names_stdin_with_other_versions_rejected() {
+    for version in ["4", "7"] {
+        cargo_bin_cmd!()
+            .args(["uuid", "-v", version, "--names-stdin"])
+            .write_stdin("alice\n")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "the argument '--names-stdin' cannot be used with '--version {version}'"
+            )));
+    }
+}
+
+#[test]
+fn test_uuid_names_stdin_with_name_rejected() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "alice",
+            "--names-stdin",
         ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("timestamp"));
+        .stderr(predicate::str::contains(
+            "the argument '--name <NAME>' cannot be used with '--names-stdin'",
+        ));
 }
 
 #[test]
-fn test_uuid_v8_with_invalid_data_too_long() {
+fn test_uuid_names_stdin_with_num_from_stdin_rejected() {
     cargo_bin_cmd!()
         .args([
+            "-n",
+            "-",
             "uuid",
             "-v",
-            "8",
-            "--data",
-            "0123456789abcdef0123456789abcdef01",
+            "5",
+            "--namespace",
+            "dns",
+            "--names-stdin",
         ])
+        .write_stdin("3\n")
         .assert()
         .failure()
-        .stderr(predicate::str::contains("data"));
+        .stderr(predicate::str::contains(
+            "the argument '--names-stdin' cannot be used with '--num -'",
+        ));
 }
 
 #[test]
-fn test_uuid_v8_with_invalid_data_non_hex() {
+fn test_uuid_skip_empty_requires_names_stdin() {
     cargo_bin_cmd!()
-        .args(["uuid", "-v", "8", "--data", "ghijklmnopqrstuv"])
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "alice",
+            "--skip-empty",
+        ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("hex"));
+        .stderr(predicate::str::contains("--names-stdin"));
 }
 
 #[test]
-fn test_uuid_v3_with_invalid_namespace() {
+fn test_uuid_version_spellings() {
+    for version in ["7", "v7", "V7"] {
+        cargo_bin_cmd!()
+            .args(["uuid", "--version", version])
+            .assert()
+            .success()
+            .stdout(
+                predicate::str::is_match(
+                    r"^[0-9a-f]{8}-[0-9a-f]{4}-7[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
+                )
+                .unwrap(),
+            );
+    }
+}
+
+#[test]
+fn test_uuid_version_spelling_keeps_requirements() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "V5"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--namespace <NAMESPACE>"));
+
     cargo_bin_cmd!()
         .args([
             "uuid",
             "-v",
-            "3",
+            "v5",
             "--namespace",
-            "invalid",
+            "dns",
             "--name",
-            "test",
+            "example.com",
         ])
         .assert()
+        .success()
+        .stdout("cfbff0d1-9375-5685-968c-48ce8b15ae17\n");
+}
+
+#[test]
+fn test_uuid_unknown_version() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "v9"])
+        .assert()
         .failure()
-        .stderr(predicate::str::contains("namespace"));
+        .stderr(predicate::str::contains(
+            "invalid value 'v9' for '--version <VERSION>'\n  [possible values: 1, v1, V1, 3, v3, \
+             V3, 4, v4, V4, 5, v5, V5, 6, v6, V6, 7, v7, V7, 8, v8, V8]",
+        ));
 }
 
 #[test]
-fn test_uuid_v1_with_timestamp() {
+fn test_uuid_help_lists_version_digits() {
     cargo_bin_cmd!()
-        .args(["uuid", "-v", "1", "--timestamp", "1234567890000000000"])
+        .args(["uuid", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "[possible values: 1, 3, 4, 5, 6, 7, 8]",
+        ));
+}
+
+#[test]
+fn test_uuid_v7() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "7"])
         .assert()
         .success()
         .stdout(
             predicate::str::is_match(
-                r"^[0-9a-f]{8}-[0-9a-f]{4}-1[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
+                r"^[0-9a-f]{8}-[0-9a-f]{4}-7[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
             )
             .unwrap(),
         );
 }
 
 #[test]
-fn test_uuid_v6_with_timestamp() {
+fn test_uuid_v8_with_data() {
     cargo_bin_cmd!()
-        .args(["uuid", "-v", "6", "--timestamp", "1234567890000000000"])
+        .args(["uuid", "-v", "8", "--data", "0123456789abcdef"])
         .assert()
         .success()
         .stdout(
             predicate::str::is_match(
-                r"^[0-9a-f]{8}-[0-9a-f]{4}-6[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
+                r"^[0-9a-f]{8}-[0-9a-f]{4}-8[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
             )
             .unwrap(),
         );
 }
 
 #[test]
-fn test_uuid_v7_with_timestamp() {
+fn test_uuid_v8_with_data_pad() {
+    for (pad, expected) in [
+        (None, "10000000-0000-8000-8000-000000000000\n"),
+        (Some("right"), "10000000-0000-8000-8000-000000000000\n"),
+        (Some("left"), "00000000-0000-8000-8000-000000000001\n"),
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", "-v", "8", "--data", "1"])
+            .args(pad.map(|pad| ["--data-pad", pad]).iter().flatten())
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_uuid_v8_with_separated_data() {
+    for data in ["0xDE 0xAD 0xBE 0xEF", "de:ad:be:ef", "0xdead_beef"] {
+        cargo_bin_cmd!()
+            .args(["uuid", "-v", "8", "--data", data])
+            .assert()
+            .success()
+            .stdout("deadbeef-0000-8000-8000-000000000000\n");
+    }
+}
+
+#[test]
+fn test_uuid_v8_with_data_fill_random() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "2", "uuid", "-v", "8", "--data", "0123456789ab"])
+        .args(["--data-fill", "random"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let uuids: Vec<&str> = stdout.lines().collect();
+
+    assert!(output.status.success());
+    assert_eq!(uuids.len(), 2);
+    assert_ne!(uuids[0], uuids[1]);
+    for uuid in uuids {
+        assert!(uuid.starts_with("01234567-89ab-8"), "{uuid}");
+    }
+}
+
+#[test]
+fn test_uuid_v8_with_data_fill_zero() {
     cargo_bin_cmd!()
-        .args(["uuid", "-v", "7", "--timestamp", "1234567890000000000"])
+        .args(["-n", "2", "uuid", "-v", "8", "--data", "0123456789ab"])
+        .args(["--data-fill", "zero"])
         .assert()
         .success()
         .stdout(
-            predicate::str::is_match(
-                r"^[0-9a-f]{8}-[0-9a-f]{4}-7[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
-            )
-            .unwrap(),
+            "01234567-89ab-8000-8000-000000000000\n\
+             01234567-89ab-8000-8000-000000000000\n",
         );
 }
+
+#[test]
+fn test_uuid_v8_with_data_stdin() {
+    let bytes: Vec<u8> = (0..16).map(|byte| byte * 17).collect();
+
+    // The version and variant bits overwrite the high bits of bytes 6 and 8
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "8", "--data-stdin"])
+        .write_stdin(bytes)
+        .assert()
+        .success()
+        .stdout("00112233-4455-8677-8899-aabbccddeeff\n")
+        .stderr("");
+}
+
+#[test]
+fn test_uuid_v8_with_data_stdin_trailing_input() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "8", "--data-stdin"])
+        .write_stdin("0123456789abcdef\n")
+        .assert()
+        .success()
+        .stdout("30313233-3435-8637-b839-616263646566\n")
+        .stderr("warning: ignoring stdin past the 16 bytes of UUID v8 data\n");
+}
+
+#[test]
+fn test_uuid_v8_with_data_stdin_too_short() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "8", "--data-stdin"])
+        .write_stdin("0123456789")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "stdin has 10 bytes, fewer than the 16 of UUID v8 data",
+        ));
+}
+
+#[test]
+fn test_uuid_v8_with_data_stdin_and_data_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "8", "--data-stdin", "--data", "00"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--data-stdin' cannot be used with '--data <DATA>'",
+        ));
+}
+
+#[test]
+fn test_uuid_v8_with_data_stdin_and_num_from_stdin_rejected() {
+    cargo_bin_cmd!()
+        .args(["-n", "-", "uuid", "-v", "8", "--data-stdin"])
+        .write_stdin("1\n0123456789abcdef")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--data-stdin' cannot be used with '--num -'",
+        ));
+}
+
+#[test]
+fn test_uuid_v8_data_pad_without_data_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "4", "--data-pad", "left"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--data <DATA>"));
+}
+
+#[test]
+fn test_uuid_v8_missing_data() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "8"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("data"));
+}
+
+#[test]
+fn test_uuid_v8_sha256() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "8",
+            "--hash",
+            "sha256",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+        ])
+        .assert()
+        .success()
+        .stdout("0afe21b6-d5ef-819e-8ac3-9e7ad18d6f73\n");
+}
+
+#[test]
+fn test_uuid_v8_sha256_rfc_example() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "8",
+            "--hash",
+            "sha256",
+            "--namespace",
+            "dns",
+            "--name",
+            "www.example.com",
+        ])
+        .assert()
+        .success()
+        .stdout("5c146b14-3c52-8afd-938a-375d0df1fbf6\n");
+}
+
+#[test]
+fn test_uuid_v8_sha256_requires_name() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "8", "--hash", "sha256", "--namespace", "dns"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--name <NAME>"));
+}
+
+#[test]
+fn test_uuid_v8_hash_with_data_rejected() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "8",
+            "--hash",
+            "sha256",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+            "--data",
+            "0123456789abcdef",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_uuid_v8_name_without_hash_rejected() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "8",
+            "--data",
+            "0123456789abcdef",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--namespace"));
+}
+
+#[test]
+fn test_uuid_v4_with_name_rejected() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "4",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "cannot be used with '--version 4'",
+        ));
+}
+
+#[test]
+fn test_uuid_v5_with_hash_rejected() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--hash",
+            "sha256",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--hash"));
+}
+
+#[test]
+fn test_uuid_guid() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+            "--guid",
+        ])
+        .assert()
+        .success()
+        .stdout("{CFBFF0D1-9375-5685-968C-48CE8B15AE17}\n");
+}
+
+#[test]
+fn test_uuid_guid_literals() {
+    // {CFBFF0D1-9375-5685-968C-48CE8B15AE17}, as `Guid.ToByteArray()` returns it
+    let bytes = "0xd1, 0xf0, 0xbf, 0xcf, 0x75, 0x93, 0x85, 0x56, 0x96, 0x8c, 0x48, 0xce, 0x8b, \
+                 0x15, 0xae, 0x17";
+
+    for (format, expected) in [
+        ("rust", format!("[{bytes}]\n")),
+        ("c", format!("{{{bytes}}}\n")),
+        (
+            "python",
+            String::from("bytes.fromhex(\"d1f0bfcf75938556968c48ce8b15ae17\")\n"),
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                "5",
+                "--namespace",
+                "dns",
+                "--name",
+                "example.com",
+            ])
+            .args(["--guid", "--format", format])
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_uuid_guid_encoded_with_also_format() {
+    // The encoded bytes and the extra column agree on the GUID order
+    cargo_bin_cmd!()
+        .args([
+            "--also-format",
+            "hex",
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+        ])
+        .args(["--name", "example.com", "--guid", "--format", "base64"])
+        .assert()
+        .success()
+        .stdout("0fC/z3WThVaWjEjOixWuFw==\td1f0bfcf75938556968c48ce8b15ae17\n");
+}
+
+#[test]
+fn test_uuid_guid_with_short_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--guid", "--short"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--guid' cannot be used with '--short'",
+        ));
+}
+
+#[test]
+fn test_uuid_uppercase_every_version() {
+    for args in [
+        &["-v", "1"][..],
+        &[
+            "-v",
+            "3",
+            "--namespace",
+            "url",
+            "--name",
+            "https://example.com",
+        ],
+        &["-v", "4"],
+        &["-v", "5", "--namespace", "dns", "--name", "example.com"],
+        &["-v", "6"],
+        &["-v", "7"],
+        &["-v", "8", "--data", "0123456789abcdef0123456789abcdef"],
+        &["--max"],
+    ] {
+        let output = cargo_bin_cmd!()
+            .args(["-n", "10", "uuid", "--uppercase"])
+            .args(args)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(stdout.lines().count(), 10);
+        assert!(
+            predicate::str::is_match(
+                r"^([0-9A-F]{8}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{12}\n){10}$"
+            )
+            .unwrap()
+            .eval(&stdout),
+            "{args:?}: {stdout}"
+        );
+        assert!(!stdout.chars().any(|c| c.is_ascii_lowercase()), "{args:?}");
+    }
+}
+
+#[test]
+fn test_uuid_uppercase_v5() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+            "--uppercase",
+        ])
+        .assert()
+        .success()
+        .stdout("CFBFF0D1-9375-5685-968C-48CE8B15AE17\n");
+}
+
+#[test]
+fn test_uuid_uppercase_with_guid() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--guid", "--uppercase"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"^\{[0-9A-F]{8}-[0-9A-F]{4}-4[0-9A-F]{3}-[89AB][0-9A-F]{3}-[0-9A-F]{12}\}\n$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_uuid_uppercase_with_short_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--uppercase", "--short"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--uppercase' cannot be used with '--short'",
+        ));
+}
+
+#[test]
+fn test_uuid_uppercase_with_other_formats_rejected() {
+    for format in [
+        "words",
+        "int",
+        "base64",
+        "base64url",
+        "z85",
+        "rust",
+        "c",
+        "python",
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", "--uppercase", "--format", format])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "the argument '--uppercase' cannot be used with '--format {format}'"
+            )));
+    }
+}
+
+#[test]
+fn test_uuid_simple() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "uuid", "--simple"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^([0-9a-f]{32}\n){3}$").unwrap());
+}
+
+#[test]
+fn test_uuid_simple_v5() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+            "--simple",
+        ])
+        .assert()
+        .success()
+        .stdout("cfbff0d193755685968c48ce8b15ae17\n");
+}
+
+#[test]
+fn test_uuid_simple_uppercase() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "uuid", "-v", "7", "--simple", "--uppercase"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^([0-9A-F]{32}\n){3}$").unwrap());
+}
+
+#[test]
+fn test_uuid_guid_simple_and_urn() {
+    for (arg, expected) in [
+        ("--simple", "CFBFF0D193755685968C48CE8B15AE17\n"),
+        ("--urn", "urn:uuid:CFBFF0D1-9375-5685-968C-48CE8B15AE17\n"),
+        ("--braces", "{CFBFF0D1-9375-5685-968C-48CE8B15AE17}\n"),
+    ] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                "5",
+                "--namespace",
+                "dns",
+                "--name",
+                "example.com",
+            ])
+            .args(["--guid", arg])
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_uuid_simple_with_other_forms_rejected() {
+    for (arg, message) in [
+        (
+            "--short",
+            "the argument '--simple' cannot be used with '--short'",
+        ),
+        (
+            "--format=words",
+            "the argument '--simple' cannot be used with '--format <FORMAT>'",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", "--simple", arg])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message));
+    }
+}
+
+#[test]
+fn test_uuid_urn_every_version() {
+    for (version, args) in [
+        ('1', &[][..]),
+        (
+            '3',
+            &["--namespace", "url", "--name", "https://example.com"],
+        ),
+        ('4', &[]),
+        ('5', &["--namespace", "dns", "--name", "example.com"]),
+        ('6', &[]),
+        ('7', &[]),
+        ('8', &["--data", "0123456789abcdef0123456789abcdef"]),
+    ] {
+        let output = cargo_bin_cmd!()
+            .args(["-n", "3", "uuid", "--urn", "-v", &version.to_string()])
+            .args(args)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(stdout.lines().count(), 3);
+
+        for urn in stdout.lines() {
+            let uuid = urn.strip_prefix("urn:uuid:").unwrap();
+
+            assert!(
+                predicate::str::is_match(
+                    r"^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$"
+                )
+                .unwrap()
+                .eval(uuid),
+                "{urn}"
+            );
+            assert_eq!(uuid.chars().nth(14), Some(version), "{urn}");
+        }
+    }
+}
+
+#[test]
+fn test_uuid_urn_v5_uppercase() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+            "--urn",
+            "--uppercase",
+        ])
+        .assert()
+        .success()
+        .stdout("urn:uuid:CFBFF0D1-9375-5685-968C-48CE8B15AE17\n");
+}
+
+#[test]
+fn test_uuid_urn_with_other_forms_rejected() {
+    for (arg, message) in [
+        (
+            "--simple",
+            "the argument '--urn' cannot be used with '--simple'",
+        ),
+        (
+            "--short",
+            "the argument '--urn' cannot be used with '--short'",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", "--urn", arg])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message));
+    }
+}
+
+#[test]
+fn test_uuid_braces() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "uuid", "--braces"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"^(\{[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\}\n){3}$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_uuid_braces_uppercase() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "uuid", "-v", "7", "--braces", "--uppercase"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"^(\{[0-9A-F]{8}-[0-9A-F]{4}-7[0-9A-F]{3}-[89AB][0-9A-F]{3}-[0-9A-F]{12}\}\n){3}$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_uuid_braces_v5() {
+    for (uppercase, expected) in [
+        (false, "{cfbff0d1-9375-5685-968c-48ce8b15ae17}\n"),
+        (true, "{CFBFF0D1-9375-5685-968C-48CE8B15AE17}\n"),
+    ] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                "5",
+                "--namespace",
+                "dns",
+                "--name",
+                "example.com",
+                "--braces",
+            ])
+            .args(uppercase.then_some("--uppercase"))
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_uuid_braces_with_other_forms_rejected() {
+    for (arg, message) in [
+        (
+            "--simple",
+            "the argument '--braces' cannot be used with '--simple'",
+        ),
+        (
+            "--urn",
+            "the argument '--braces' cannot be used with '--urn'",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", "--braces", arg])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message));
+    }
+}
+
+#[test]
+fn test_uuid_format_int() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+            "--format",
+            "int",
+        ])
+        .assert()
+        .success()
+        .stdout("276146808210084729002700941631111409175\n");
+}
+
+#[test]
+fn test_uuid_format_int_bounds() {
+    for (constant, expected) in [
+        ("--nil", "0\n"),
+        ("--max", "340282366920938463463374607431768211455\n"),
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", constant, "--format", "int"])
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_multiple_uuids_format_int_every_version() {
+    for (version, args) in [
+        (1, &[][..]),
+        (3, &["--namespace", "url", "--name", "https://example.com"]),
+        (4, &[]),
+        (5, &["--namespace", "dns", "--name", "example.com"]),
+        (6, &[]),
+        (7, &[]),
+        (8, &["--data", "0123456789abcdef0123456789abcdef"]),
+    ] {
+        let output = cargo_bin_cmd!()
+            .args([
+                "-n",
+                "5",
+                "uuid",
+                "--format",
+                "int",
+                "-v",
+                &version.to_string(),
+            ])
+            .args(args)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(stdout.lines().count(), 5);
+
+        for line in stdout.lines() {
+            let value: u128 = line.parse().unwrap();
+
+            // The version sits in bits 76-79 of the 128-bit value
+            assert_eq!(value >> 76 & 0xf, version, "{line}");
+        }
+    }
+}
+
+#[test]
+fn test_uuid_format_int_with_also_format() {
+    cargo_bin_cmd!()
+        .args(["--also-format", "hex", "uuid", "--max", "--format", "int"])
+        .assert()
+        .success()
+        .stdout("340282366920938463463374607431768211455\tffffffffffffffffffffffffffffffff\n");
+}
+
+#[test]
+fn test_uuid_format_base64() {
+    for (format, expected) in [
+        ("base64", "z7/w0ZN1VoWWjEjOixWuFw==\n"),
+        ("base64url", "z7_w0ZN1VoWWjEjOixWuFw\n"),
+    ] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                "5",
+                "--namespace",
+                "dns",
+                "--name",
+                "example.com",
+                "--format",
+                format,
+            ])
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_multiple_uuids_format_base64_round_trip_every_version() {
+    for (format, engine) in [("base64", STANDARD), ("base64url", URL_SAFE_NO_PAD)] {
+        for (version, args) in [
+            (1, &[][..]),
+            (3, &["--namespace", "url", "--name", "https://example.com"]),
+            (4, &[]),
+            (5, &["--namespace", "dns", "--name", "example.com"]),
+            (6, &[]),
+            (7, &[]),
+            (8, &["--data", "0123456789abcdef0123456789abcdef"]),
+        ] {
+            let output = cargo_bin_cmd!()
+                .args([
+                    "-n",
+                    "5",
+                    "uuid",
+                    "--format",
+                    format,
+                    "-v",
+                    &version.to_string(),
+                ])
+                .args(args)
+                .output()
+                .unwrap();
+            let stdout = String::from_utf8(output.stdout).unwrap();
+
+            assert!(output.status.success());
+            assert_eq!(stdout.lines().count(), 5);
+
+            for line in stdout.lines() {
+                let bytes = engine.decode(line).unwrap();
+
+                assert_eq!(bytes.len(), 16, "{line}");
+                assert_eq!(bytes[6] >> 4, version, "{line}");
+                // RFC 9562 variant: the two high bits of byte 8 are 10
+                assert_eq!(bytes[8] & 0xc0, 0x80, "{line}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_uuid_format_base64_with_also_format() {
+    cargo_bin_cmd!()
+        .args([
+            "--also-format",
+            "hex",
+            "uuid",
+            "--max",
+            "--format",
+            "base64url",
+        ])
+        .assert()
+        .success()
+        .stdout("_____________________w\tffffffffffffffffffffffffffffffff\n");
+}
+
+#[test]
+fn test_uuid_format_base32() {
+    for (args, expected) in [
+        (&[][..], "z677bumtovlilfumjdhiwfnoc4\n"),
+        (&["--upper"], "Z677BUMTOVLILFUMJDHIWFNOC4\n"),
+    ] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                "5",
+                "--namespace",
+                "dns",
+                "--name",
+                "example.com",
+                "--format",
+                "base32",
+            ])
+            .args(args)
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_multiple_uuids_format_base32_round_trip() {
+    let output = cargo_bin_cmd!()
+        .args([
+            "-n",
+            "1000",
+            "--also-format",
+            "hex",
+            "uuid",
+            "--format",
+            "base32",
+        ])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+
+    let mut encodings = HashSet::new();
+    let mut uuids = HashSet::new();
+
+    for line in stdout.lines() {
+        let (encoded, hex) = line.split_once('\t').unwrap();
+
+        assert_eq!(encoded.len(), 26, "{line}");
+        assert!(
+            encoded
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c)),
+            "{line}"
+        );
+        // The decoded bytes are those of a version 4 UUID
+        assert_eq!(&hex[12..13], "4", "{line}");
+
+        encodings.insert(encoded.to_owned());
+        uuids.insert(hex.to_owned());
+    }
+
+    assert_eq!(encodings.len(), 1000);
+    assert_eq!(uuids.len(), 1000);
+}
+
+#[test]
+fn test_uuid_format_code_literals() {
+    for (format, literal) in [
+        (
+            "rust",
+            "[0xcf, 0xbf, 0xf0, 0xd1, 0x93, 0x75, 0x56, 0x85, 0x96, 0x8c, 0x48, 0xce, 0x8b, \
+             0x15, 0xae, 0x17]",
+        ),
+        (
+            "c",
+            "{0xcf, 0xbf, 0xf0, 0xd1, 0x93, 0x75, 0x56, 0x85, 0x96, 0x8c, 0x48, 0xce, 0x8b, \
+             0x15, 0xae, 0x17}",
+        ),
+        (
+            "python",
+            "bytes.fromhex(\"cfbff0d193755685968c48ce8b15ae17\")",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                "5",
+                "--namespace",
+                "dns",
+                "--name",
+                "example.com",
+                "--format",
+                format,
+            ])
+            .assert()
+            .success()
+            .stdout(format!("{literal}\n"));
+    }
+}
+
+#[test]
+fn test_uuid_format_code_literal_with_also_format_rejected() {
+    cargo_bin_cmd!()
+        .args(["--also-format", "hex", "uuid", "--format", "rust"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--also-format <FORMAT>' cannot be used with '--format <FORMAT>'",
+        ));
+}
+
+#[test]
+fn test_uuid_format_z85_round_trip() {
+    cargo_bin_cmd!()
+        .args([
+            "--also-format",
+            "hex",
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+            "--format",
+            "z85",
+        ])
+        .assert()
+        .success()
+        .stdout("=+F$%Lxx]WMw!wWIXSxh\tcfbff0d193755685968c48ce8b15ae17\n");
+}
+
+#[test]
+fn test_multiple_uuids_format_z85() {
+    cargo_bin_cmd!()
+        .args(["-n", "5", "uuid", "--format", "z85"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(r"^([0-9a-zA-Z.\-:+=^!/*?&<>()\[\]{}@%$#]{20}\n){5}$")
+                .unwrap(),
+        );
+}
+
+#[test]
+fn test_multiple_uuids() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "uuid"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"(?m)^([0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n){3}$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_uuid_v4_with_timestamp_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "4", "--timestamp", "1234567890000000000"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("timestamp"));
+}
+
+#[test]
+fn test_uuid_v5_with_timestamp_rejected() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "test",
+            "--timestamp",
+            "1234567890000000000",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("timestamp"));
+}
+
+#[test]
+fn test_uuid_v8_with_timestamp_rejected() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "8",
+            "--data",
+            "0123456789abcdef",
+            "--timestamp",
+            "1234567890000000000",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("timestamp"));
+}
+
+#[test]
+fn test_uuid_v8_with_invalid_data_too_long() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "8",
+            "--data",
+            "0123456789abcdef0123456789abcdef01",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("data"));
+}
+
+#[test]
+fn test_uuid_v8_with_invalid_data_non_hex() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "8", "--data", "ghijklmnopqrstuv"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("hex"));
+}
+
+#[test]
+fn test_uuid_v3_with_invalid_namespace() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "3",
+            "--namespace",
+            "invalid",
+            "--name",
+            "test",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("namespace"));
+}
+
+#[test]
+fn test_uuid_v1_with_timestamp() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "1", "--timestamp", "1234567890000000000"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"^[0-9a-f]{8}-[0-9a-f]{4}-1[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_uuid_v6_with_timestamp() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "6", "--timestamp", "1234567890000000000"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"^[0-9a-f]{8}-[0-9a-f]{4}-6[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_uuid_with_clock_seq() {
+    // 0x1234 under the variant bits (10), with a fixed timestamp or the current time
+    for (version, timestamp) in [
+        ("1", Some("1234567890000000000")),
+        ("1", None),
+        ("6", Some("1234567890000000000")),
+        ("6", None),
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", "-v", version, "--clock-seq", "4660"])
+            .args(
+                timestamp
+                    .map(|timestamp| ["--timestamp", timestamp])
+                    .iter()
+                    .flatten(),
+            )
+            .assert()
+            .success()
+            .stdout(
+                predicate::str::is_match(r"^[0-9a-f]{8}-[0-9a-f]{4}-[16][0-9a-f]{3}-9234-")
+                    .unwrap(),
+            );
+    }
+}
+
+#[test]
+fn test_uuid_with_clock_seq_bounds() {
+    for (clock_seq, bits) in [("0", "8000"), ("16383", "bfff")] {
+        cargo_bin_cmd!()
+            .args([
+                "uuid",
+                "-v",
+                "1",
+                "--timestamp",
+                "0",
+                "--clock-seq",
+                clock_seq,
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(format!("-{bits}-")));
+    }
+}
+
+#[test]
+fn test_uuid_clock_seq_wrap_past_gregorian_range() {
+    // The second pass of the clock sequence would step past the latest timestamp
+    let output = cargo_bin_cmd!()
+        .args(["-n", "16386", "uuid", "-v", "1"])
+        .args(["--timestamp", "103072857660684697500", "--clock-seq", "0"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("UUID timestamps exhausted: more than 16384 UUIDs per 100-nanosecond tick")
+    );
+    assert_eq!(stdout.lines().count(), 16384);
+    assert!(
+        stdout
+            .lines()
+            .all(|uuid| uuid.starts_with("ffffffff-ffff-1fff-"))
+    );
+}
+
+#[test]
+fn test_uuid_with_clock_seq_rejected() {
+    for (args, message) in [
+        (
+            &["-v", "1", "--clock-seq", "16384"][..],
+            "invalid value '16384' for '--clock-seq <NUMBER>': UUID clock sequences have 14 \
+             bits, from 0 to 16383",
+        ),
+        (
+            &["-v", "7", "--clock-seq", "1"],
+            "the argument '--clock-seq <NUMBER>' cannot be used with '--version 7'",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .arg("uuid")
+            .args(args)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message));
+    }
+}
+
+/// 2009-02-13T23:31:30Z, in 100-nanosecond intervals since the Gregorian epoch.
+const STATE_TICKS: u64 = 134_538_606_900_000_000;
+
+/// Returns a fresh clock sequence state file path for the given test.
+fn state_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("spwd-state-{name}-{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+/// Generates a version 1 UUID at 2009-02-13T23:31:30Z, returning its clock sequence.
+fn state_run(path: &PathBuf) -> u16 {
+    let output = cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "1",
+            "--timestamp",
+            "1234567890000000000",
+            "--state-file",
+        ])
+        .arg(path)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let uuid = uuid::Uuid::parse_str(String::from_utf8(output.stdout).unwrap().trim()).unwrap();
+    uuid.get_timestamp().unwrap().to_gregorian().1
+}
+
+#[test]
+fn test_uuid_state_file_repeated_timestamp_increments() {
+    let path = state_path("repeated");
+
+    let first = state_run(&path);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        format!("timestamp={STATE_TICKS}\nclock_seq={first}\n")
+    );
+
+    let second = state_run(&path);
+    assert_eq!(second, (first + 1) & 0x3fff);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        format!("timestamp={STATE_TICKS}\nclock_seq={second}\n")
+    );
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_uuid_state_file_clock_backwards_increments() {
+    let path = state_path("backwards");
+
+    // The last run was a second later than this one
+    fs::write(
+        &path,
+        format!("timestamp={}\nclock_seq=4660\n", STATE_TICKS + 10_000_000),
+    )
+    .unwrap();
+    assert_eq!(state_run(&path), 4661);
+
+    // Wrapping around past the largest clock sequence
+    fs::write(&path, format!("timestamp={STATE_TICKS}\nclock_seq=16383\n")).unwrap();
+    assert_eq!(state_run(&path), 0);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_uuid_state_file_clock_forwards_keeps_clock_seq() {
+    let path = state_path("forwards");
+
+    fs::write(
+        &path,
+        format!("timestamp={}\nclock_seq=4660\n", STATE_TICKS - 10_000_000),
+    )
+    .unwrap();
+    assert_eq!(state_run(&path), 4660);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        format!("timestamp={STATE_TICKS}\nclock_seq=4660\n")
+    );
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_uuid_state_file_batch_saves_last_uuid() {
+    let path = state_path("batch");
+    fs::write(&path, format!("timestamp={STATE_TICKS}\nclock_seq=0\n")).unwrap();
+
+    cargo_bin_cmd!()
+        .args([
+            "-n",
+            "3",
+            "uuid",
+            "-v",
+            "6",
+            "--timestamp",
+            "1234567890000000000",
+        ])
+        .arg("--state-file")
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("-8001-").and(predicate::str::contains("-8003-")));
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        format!("timestamp={STATE_TICKS}\nclock_seq=3\n")
+    );
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_uuid_state_file_invalid() {
+    let path = state_path("invalid");
+    fs::write(&path, "timestamp=42\n").unwrap();
+
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "1", "--state-file"])
+        .arg(&path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("has no clock_seq line"));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_uuid_state_file_rejected() {
+    for (args, message) in [
+        (
+            &["-v", "7", "--state-file", "state"][..],
+            "the argument '--state-file <PATH>' cannot be used with '--version 7'",
+        ),
+        (
+            &["-v", "1", "--state-file", "state", "--clock-seq", "1"],
+            "the argument '--state-file <PATH>' cannot be used with '--clock-seq <NUMBER>'",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .arg("uuid")
+            .args(args)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message));
+    }
+}
+
+#[test]
+fn test_uuid_v7_with_rfc3339_timestamp() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "7", "--timestamp", "2023-11-14T22:13:20Z"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("018bcfe5-6800-7"));
+}
+
+#[test]
+fn test_uuid_v7_with_timestamp() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "7", "--timestamp", "1234567890000000000"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"^[0-9a-f]{8}-[0-9a-f]{4}-7[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_uuid_v4_exclude_special() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--exclude-special"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_uuid_v7_exclude_special_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "7", "--exclude-special"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--exclude-special"));
+}
+
+#[test]
+fn test_uuid_v4_starts_with() {
+    cargo_bin_cmd!()
+        .args(["-n", "5", "uuid", "--starts-with", "CAF"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"^(caf[0-9a-f]{5}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\n){5}$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_uuid_v4_starts_with_invalid() {
+    for (prefix, message) in [
+        ("cafg", "prefix contains 'g', which is not a hex digit"),
+        (
+            "cafebabe0",
+            "prefix has 9 hex digits, more than 8 (longer ones make the search impractical)",
+        ),
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", "--starts-with", prefix])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "invalid value '{prefix}' for '--starts-with <HEX>': {message}"
+            )));
+    }
+}
+
+#[test]
+fn test_uuid_v7_starts_with_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "7", "--starts-with", "ab"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--starts-with <HEX>' cannot be used with '--version 7'",
+        ));
+}
+
+#[test]
+fn test_uuid_v4_starts_with_max_attempts() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--starts-with", "0123abcd", "--max-attempts", "1k"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no UUID starting with 0123abcd found in 1000 attempts",
+        ));
+}
+
+#[test]
+fn test_uuid_max_attempts_requires_starts_with() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--max-attempts", "1k"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--starts-with <HEX>"));
+}
+
+#[test]
+fn test_uuid_v7_per_ms() {
+    let output = cargo_bin_cmd!()
+        .args([
+            "-n",
+            "500",
+            "uuid",
+            "-v",
+            "7",
+            "--per-ms",
+            "50",
+            "--timestamp",
+            "1700000000000000000",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(lines.len(), 500);
+    assert!(lines.windows(2).all(|w| w[0] < w[1]));
+
+    for (i, chunk) in lines.chunks(50).enumerate() {
+        let expected = format!("{:012x}", 1700000000000u64 + i as u64);
+        assert!(
+            chunk
+                .iter()
+                .all(|line| line.replace('-', "").starts_with(&expected))
+        );
+    }
+}
+
+/// Decodes UUIDs printed one per line into their bytes.
+fn uuid_bytes(stdout: &[u8]) -> Vec<Vec<u8>> {
+    String::from_utf8(stdout.to_vec())
+        .unwrap()
+        .lines()
+        .map(|line| hex::decode(line.replace('-', "")).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_uuid_v7_batch_strictly_increasing() {
+    // Many UUIDs share a millisecond, where the counter keeps them ordered
+    let output = cargo_bin_cmd!()
+        .args(["-n", "50000", "uuid", "-v", "7"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let uuids = uuid_bytes(&output.stdout);
+
+    assert_eq!(uuids.len(), 50000);
+    assert!(uuids.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_uuid_v7_batch_within_one_millisecond_strictly_increasing() {
+    let output = cargo_bin_cmd!()
+        .args([
+            "-n",
+            "50000",
+            "uuid",
+            "-v",
+            "7",
+            "--timestamp",
+            "1700000000000000000",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let uuids = uuid_bytes(&output.stdout);
+
+    assert_eq!(uuids.len(), 50000);
+    assert!(uuids.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_uuid_v4_per_ms_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--per-ms", "50"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--per-ms"));
+}
+
+#[test]
+fn test_uuid_v7_with_precision() {
+    // 250 µs into the millisecond is a quarter of it, 0x400 of the 12 bits
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "7",
+            "--precision",
+            "us",
+            "--timestamp",
+            "1700000000500250999",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("018bcfe5-69f4-7400-"));
+}
+
+#[test]
+fn test_uuid_v4_precision_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--precision", "ns"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--precision' cannot be used with '--version 4'",
+        ));
+}
+
+#[test]
+fn test_uuid_binary() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "1000", "uuid", "-v", "7", "--binary"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(output.stdout.len(), 16_000);
+
+    // The version is the high nibble of the 7th byte of every record
+    assert!(
+        output
+            .stdout
+            .chunks_exact(16)
+            .all(|record| record[6] >> 4 == 7)
+    );
+}
+
+#[test]
+fn test_uuid_binary_nil() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--nil", "--binary"])
+        .assert()
+        .success()
+        .stdout(predicate::eq(&[0u8; 16][..]));
+}
+
+#[test]
+fn test_uuid_binary_guid() {
+    // {CFBFF0D1-9375-5685-968C-48CE8B15AE17}, as `Guid.ToByteArray()` returns it
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "5",
+            "--namespace",
+            "dns",
+            "--name",
+            "example.com",
+        ])
+        .args(["--guid", "--binary"])
+        .assert()
+        .success()
+        .stdout(predicate::eq(
+            &hex::decode("d1f0bfcf75938556968c48ce8b15ae17").unwrap()[..],
+        ));
+}
+
+#[test]
+fn test_uuid_binary_with_also_format_rejected() {
+    cargo_bin_cmd!()
+        .args(["--also-format", "hex", "uuid", "--binary"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--also-format <FORMAT>' cannot be used with '--binary'",
+        ));
+}
+
+#[test]
+fn test_uuid_binary_with_format_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--binary", "--format", "base64"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_uuid_force_without_binary_rejected() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--force"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--binary"));
+}
+
+#[test]
+fn test_uuid_v1_with_max_timestamp() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "1", "--timestamp", "103072857660684697500"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("ffffffff-ffff-1fff-"));
+}
+
+#[test]
+fn test_uuid_v6_with_timestamp_out_of_range() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "6", "--timestamp", "103072857660684697600"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "UUID version 6 timestamps must be between 1582-10-15T00:00:00Z and \
+             5236-03-31T21:21:00.6846975Z",
+        ));
+}
+
+/// Decodes a short (base57) UUID back to its 128-bit value.
+fn decode_short(short: &str) -> u128 {
+    const ALPHABET: &str = "23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    short.chars().fold(0, |value, symbol| {
+        value * 57 + ALPHABET.find(symbol).unwrap() as u128
+    })
+}
+
+#[test]
+fn test_uuid_short() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "uuid", "--short"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^([2-9A-HJ-NP-Za-km-z]{22}\n){3}$").unwrap());
+}
+
+#[test]
+fn test_uuid_short_round_trip() {
+    let args = [
+        "uuid",
+        "-v",
+        "5",
+        "--namespace",
+        "dns",
+        "--name",
+        "example.com",
+    ];
+
+    let output = cargo_bin_cmd!().args(args).output().unwrap();
+    let uuid = String::from_utf8(output.stdout).unwrap();
+
+    let output = cargo_bin_cmd!().args(args).arg("--short").output().unwrap();
+    let short = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(
+        format!("{:032x}", decode_short(short.trim_end())),
+        uuid.trim_end().replace('-', "")
+    );
+}
+
+#[test]
+fn test_uuid_short_with_timestamp() {
+    let output = cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "7",
+            "--timestamp",
+            "1700000000000000000",
+            "--short",
+        ])
+        .output()
+        .unwrap();
+    let short = String::from_utf8(output.stdout).unwrap();
+    let value = decode_short(short.trim_end());
+
+    assert_eq!(value >> 80, 1700000000000);
+    assert_eq!((value >> 76) & 0xf, 7);
+}
+
+#[test]
+fn test_uuid_nil() {
+    cargo_bin_cmd!()
+        .args(["-n", "3", "uuid", "--nil"])
+        .assert()
+        .success()
+        .stdout("00000000-0000-0000-0000-000000000000\n".repeat(3));
+}
+
+#[test]
+fn test_uuid_max() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--max"])
+        .assert()
+        .success()
+        .stdout("ffffffff-ffff-ffff-ffff-ffffffffffff\n");
+}
+
+#[test]
+fn test_uuid_nil_with_version() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--nil", "-v", "7"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--nil' cannot be used with '--version <VERSION>'",
+        ));
+}
+
+#[test]
+fn test_uuid_max_with_nil() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--max", "--nil"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--nil' cannot be used with '--max'",
+        ));
+}
+
+#[test]
+fn test_uuid_v1_with_node_id_notations() {
+    for node_id in [
+        "0123456789ab",
+        "01-23-45-67-89-ab",
+        "0123.4567.89AB",
+        " 01:23:45:67:89:Ab\n",
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", "-v", "1", "--node-id", node_id])
+            .assert()
+            .success()
+            .stdout(predicate::str::ends_with("-0123456789ab\n"));
+    }
+}
+
+#[test]
+fn test_uuid_node_id_version_mismatch() {
+    for version in ["4", "7"] {
+        cargo_bin_cmd!()
+            .args(["uuid", "-v", version, "--node-id", "01:23:45:67:89:ab"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "the argument '--node-id <NODE_ID>' cannot be used with '--version {version}'"
+            )));
+    }
+}
+
+#[test]
+fn test_uuid_v1_with_node_id_near_miss() {
+    cargo_bin_cmd!()
+        .args(["uuid", "-v", "1", "--node-id", "0123456789a"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value '0123456789a' for '--node-id <NODE_ID>': node id has 11 hex digits, \
+             one short of 12 (got 11 characters)",
+        ))
+        .stderr(predicate::str::contains(
+            "accepted notations are 0123456789ab",
+        ));
+}
+
+#[test]
+fn test_uuid_hardware_node() {
+    for version in ["1", "6"] {
+        let output = cargo_bin_cmd!()
+            .args(["-n", "3", "uuid", "-v", version, "--hardware-node"])
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+
+        assert!(output.status.success());
+
+        // The same node id for the whole run, universal when found, or else a pseudo one
+        let node_ids: HashSet<&str> = stdout.lines().map(|uuid| &uuid[24..]).collect();
+        assert_eq!(node_ids.len(), 1);
+
+        let first_byte = u8::from_str_radix(&stdout[24..26], 16).unwrap();
+        match stderr.is_empty() {
+            true => assert_eq!(first_byte & 0x03, 0x00, "{stdout}"),
+            false => {
+                assert_eq!(
+                    stderr,
+                    "warning: no network interface with a universal MAC address found; using \
+                     a pseudo one as the node id\n"
+                );
+                assert_eq!(first_byte & 0x03, 0x02, "{stdout}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_uuid_node_id_mode_per_id() {
+    for version in ["1", "6"] {
+        let output = cargo_bin_cmd!()
+            .args([
+                "-n",
+                "100",
+                "uuid",
+                "-v",
+                version,
+                "--node-id-mode",
+                "per-id",
+            ])
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        assert!(output.status.success());
+        let node_ids: HashSet<&str> = stdout.lines().map(|uuid| &uuid[24..]).collect();
+        assert!(node_ids.len() > 1);
+    }
+}
+
+#[test]
+fn test_uuid_node_id_mode_per_id_rejected() {
+    for (args, prior) in [
+        (&["-v", "4"][..], "--version 4"),
+        (
+            &["-v", "1", "--node-id", "0123456789ab"],
+            "--node-id <NODE_ID>",
+        ),
+        (&["-v", "6", "--hardware-node"], "--hardware-node"),
+    ] {
+        cargo_bin_cmd!()
+            .args(["uuid", "--node-id-mode", "per-id"])
+            .args(args)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "the argument '--node-id-mode per-id' cannot be used with '{prior}'"
+            )));
+    }
+}
+
+#[test]
+fn test_uuid_hardware_node_with_node_id_rejected() {
+    cargo_bin_cmd!()
+        .args([
+            "uuid",
+            "-v",
+            "1",
+            "--hardware-node",
+            "--node-id",
+            "0123456789ab",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--hardware-node' cannot be used with '--node-id <NODE_ID>'",
+        ));
+}
+
+#[test]
+fn test_uuid_v6_with_node_id_errors_avoid_eui48_wording() {
+    for node_id in ["01:23", "0123456789abcdef01", "01:23-45:67:89:ab", "zz"] {
+        cargo_bin_cmd!()
+            .args(["uuid", "-v", "6", "--node-id", node_id])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("accepted notations are"))
+            .stderr(predicate::str::contains("Invalid length").not())
+            .stderr(predicate::str::contains("Invalid byte count").not())
+            .stderr(predicate::str::contains("expecting").not());
+    }
+}
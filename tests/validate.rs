@@ -0,0 +1,218 @@
+#[cfg(feature = "ulid")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// Mints a ULID at the given offset (in milliseconds) from the current time.
+#[cfg(feature = "ulid")]
+fn ulid_at_offset(offset_ms: u64) -> String {
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+
+    let output = cargo_bin_cmd!()
+        .args(["ulid", "--timestamp", &(now_ms + offset_ms).to_string()])
+        .output()
+        .unwrap();
+
+    String::from_utf8(output.stdout).unwrap().trim().to_owned()
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_validate_future_ulid() {
+    let ulid = ulid_at_offset(3_600_000);
+
+    cargo_bin_cmd!()
+        .args(["validate", "--no-future", &ulid])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::starts_with(format!("{ulid}\ttimestamp ")))
+        .stdout(predicate::str::ends_with("is in the future\n"));
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_validate_ulid_within_skew() {
+    let ulid = ulid_at_offset(60_000);
+
+    cargo_bin_cmd!()
+        .args(["validate", "--no-future", &ulid])
+        .assert()
+        .success()
+        .stdout(format!("{ulid}\tok\n"));
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_validate_custom_skew() {
+    let ulid = ulid_at_offset(3_600_000);
+
+    cargo_bin_cmd!()
+        .args(["validate", "--no-future", "--skew", "2h", &ulid])
+        .assert()
+        .success();
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_validate_future_allowed_without_flag() {
+    let ulid = ulid_at_offset(3_600_000);
+
+    cargo_bin_cmd!()
+        .args(["validate", &ulid])
+        .assert()
+        .success()
+        .stdout(format!("{ulid}\tok\n"));
+}
+
+#[test]
+fn test_validate_ignores_uuid_v4() {
+    cargo_bin_cmd!()
+        .args([
+            "validate",
+            "--no-future",
+            "9e4a5f33-f01c-47ee-8527-18ec1f0151d5",
+        ])
+        .assert()
+        .success()
+        .stdout("9e4a5f33-f01c-47ee-8527-18ec1f0151d5\tok\n");
+}
+
+#[test]
+fn test_validate_future_uuid_v7_and_oid() {
+    // Both embed 2100-01-01T00:00:00Z
+    cargo_bin_cmd!()
+        .args([
+            "validate",
+            "--no-future",
+            "03bb2cc3-d800-7000-8000-000000000000",
+            "f4865700aaaaaaaaaaaaaaaa",
+        ])
+        .assert()
+        .code(1)
+        .stdout(
+            "03bb2cc3-d800-7000-8000-000000000000\ttimestamp 2100-01-01T00:00:00Z is in the future\n\
+             f4865700aaaaaaaaaaaaaaaa\ttimestamp 2100-01-01T00:00:00Z is in the future\n",
+        );
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_validate_stdin_partial_failure() {
+    let past = ulid_at_offset(0);
+    let future = ulid_at_offset(3_600_000);
+
+    let output = cargo_bin_cmd!()
+        .args(["validate", "--no-future"])
+        .write_stdin(format!("{past}\n\nnot-an-id\n{future}\n"))
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    // Every line is reported, not just the first failure
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], format!("{past}\tok"));
+    assert!(lines[1].starts_with("not-an-id\tunrecognized identifier"));
+    assert!(lines[2].ends_with("is in the future"));
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_validate_future_ulid_with_check_symbol() {
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+
+    let output = cargo_bin_cmd!()
+        .args(["ulid", "--check", "--timestamp"])
+        .arg((now_ms + 3_600_000).to_string())
+        .output()
+        .unwrap();
+    let ulid = String::from_utf8(output.stdout).unwrap().trim().to_owned();
+
+    cargo_bin_cmd!()
+        .args(["validate", "--no-future", &ulid])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::ends_with("is in the future\n"));
+
+    cargo_bin_cmd!()
+        .args(["validate", &ulid])
+        .assert()
+        .success()
+        .stdout(format!("{ulid}\tok\n"));
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_validate_ulid_with_check_symbol_mismatch() {
+    cargo_bin_cmd!()
+        .args(["validate", "01HF7YAT007TYXDGV4SYN1AKV2W"])
+        .assert()
+        .code(1)
+        .stdout(
+            "01HF7YAT007TYXDGV4SYN1AKV2W\tinvalid ULID: check symbol mismatch: expected 'V', \
+             got 'W'\n",
+        );
+}
+
+#[test]
+fn test_validate_skew_requires_no_future() {
+    cargo_bin_cmd!()
+        .args([
+            "validate",
+            "--skew",
+            "1m",
+            "9e4a5f33-f01c-47ee-8527-18ec1f0151d5",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--no-future"));
+}
+
+#[test]
+fn test_validate_positionals_in_order() {
+    cargo_bin_cmd!()
+        .args([
+            "validate",
+            "9e4a5f33-f01c-47ee-8527-18ec1f0151d5",
+            "not-an-id",
+            "018bcfe5-6800-7000-8000-000000000000",
+        ])
+        .assert()
+        .code(1)
+        .stdout(
+            predicate::str::is_match(
+                "^9e4a5f33-f01c-47ee-8527-18ec1f0151d5\tok\n\
+             not-an-id\tunrecognized identifier: not a UUID, ULID, or ObjectId\n\
+             018bcfe5-6800-7000-8000-000000000000\tok\n$",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_validate_positionals_preferred_over_stdin() {
+    cargo_bin_cmd!()
+        .args(["validate", "9e4a5f33-f01c-47ee-8527-18ec1f0151d5"])
+        .write_stdin("not-an-id\n")
+        .assert()
+        .success()
+        .stdout("9e4a5f33-f01c-47ee-8527-18ec1f0151d5\tok\n");
+}
+
+#[test]
+fn test_validate_dash_reads_stdin() {
+    cargo_bin_cmd!()
+        .args(["validate", "9e4a5f33-f01c-47ee-8527-18ec1f0151d5", "-"])
+        .write_stdin("6553f100aaaaaaaaaaaaaaaa\n")
+        .assert()
+        .success()
+        .stdout("9e4a5f33-f01c-47ee-8527-18ec1f0151d5\tok\n6553f100aaaaaaaaaaaaaaaa\tok\n");
+}
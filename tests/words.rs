@@ -0,0 +1,146 @@
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+/// The words of the nil UUID (the BIP39 test vector for zero entropy).
+const NIL_WORDS: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                         abandon abandon abandon about";
+
+/// Twelve lowercase words separated by single spaces, on a line of their own.
+#[cfg(any(feature = "uuid", feature = "ulid"))]
+const WORDS_PATTERN: &str = r"^[a-z]+( [a-z]+){11}\n$";
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_uuid_words() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--format", "words"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(WORDS_PATTERN).unwrap());
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_uuid_nil_words() {
+    cargo_bin_cmd!()
+        .args(["uuid", "--nil", "--format", "words"])
+        .assert()
+        .success()
+        .stdout(format!("{NIL_WORDS}\n"));
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_uuid_words_round_trip() {
+    let output = cargo_bin_cmd!()
+        .args(["-n", "20", "uuid", "-v", "7", "--format", "words"])
+        .output()
+        .unwrap();
+    let words = String::from_utf8(output.stdout).unwrap();
+
+    let output = cargo_bin_cmd!()
+        .arg("words")
+        .write_stdin(words.clone())
+        .output()
+        .unwrap();
+    let uuids = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(uuids.lines().count(), 20);
+
+    // The decoded UUIDs encode back into the same words
+    for (uuid, words) in uuids.lines().zip(words.lines()) {
+        let output = cargo_bin_cmd!().arg("words").arg(words).output().unwrap();
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            format!("{uuid}\n")
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_ulid_words_decode_to_the_same_bytes() {
+    let output = cargo_bin_cmd!()
+        .args(["ulid", "--timestamp", "1609459200000", "--format", "words"])
+        .output()
+        .unwrap();
+    let words = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        predicate::str::is_match(WORDS_PATTERN)
+            .unwrap()
+            .eval(&words)
+    );
+
+    // 1609459200000 is 0x0176bb3e7000, the first 6 bytes of the ULID
+    cargo_bin_cmd!()
+        .arg("words")
+        .arg(words.trim_end())
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("0176bb3e-7000-"));
+}
+
+#[test]
+#[cfg(feature = "ulid")]
+fn test_ulid_words_conflict_with_check() {
+    cargo_bin_cmd!()
+        .args(["ulid", "--check", "--format", "words"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--check' cannot be used with '--format <FORMAT>'",
+        ));
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn test_words_conflict_with_also_format() {
+    cargo_bin_cmd!()
+        .args(["--also-format", "hex", "uuid", "--format", "words"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--also-format <FORMAT>' cannot be used with '--format <FORMAT>'",
+        ));
+}
+
+#[test]
+fn test_words_decode() {
+    cargo_bin_cmd!()
+        .args(["words", NIL_WORDS])
+        .assert()
+        .success()
+        .stdout("00000000-0000-0000-0000-000000000000\n");
+}
+
+#[test]
+fn test_words_decode_wrong_count() {
+    cargo_bin_cmd!()
+        .args(["words", "legal winner thank year"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected 12 words, got 4"));
+}
+
+#[test]
+fn test_words_decode_checksum_mismatch() {
+    cargo_bin_cmd!()
+        .args(["words", "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo"])
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "checksum mismatch (a word is likely mistyped or out of order)",
+        ));
+}
+
+#[test]
+fn test_words_decode_unknown_word() {
+    cargo_bin_cmd!()
+        .arg("words")
+        .arg(NIL_WORDS.replace("about", "abuot"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "word 12 (\"abuot\") is not in the word list",
+        ));
+}